@@ -60,7 +60,16 @@ mod records;
 use std::{io::Read, path::Path};
 
 use csv::{Reader, ReaderBuilder};
-use tabled::{builder::Builder, tables::IterTable, Table};
+use tabled::{
+    builder::Builder,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::{
+        object::{Columns, Object, Rows},
+        Alignment,
+    },
+    tables::IterTable,
+    Table,
+};
 
 pub mod iter {
     //! The module contains [`CsvRecords`] which is an [`Iterator`] abstraction for [`IterTable`].
@@ -105,6 +114,63 @@ pub mod iter {
         IterTable::new(CsvRecords::new(rdr))
     }
 
+    /// Creates [`IterTable`] from a csv [`Read`]er,
+    /// where the first record is read up front as a header.
+    ///
+    /// A number of columns is pinned by the header,
+    /// so the longer rows are cut and the shorter ones are filled with empty cells.
+    /// Notice that by default [`csv`] fails on rows of different length,
+    /// such rows are skipped (see [`CsvRecordsIter::set_catch`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use csv_to_table::iter::from_reader_with_header;
+    ///
+    /// let csv = "Name,Number\n\
+    ///            Maxim,12345\n\
+    ///            Alex,45678";
+    ///
+    /// let table = from_reader_with_header(csv.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+--------+\n\
+    ///      | Name  | Number |\n\
+    ///      +-------+--------+\n\
+    ///      | Maxim | 12345  |\n\
+    ///      +-------+--------+\n\
+    ///      | Alex  | 45678  |\n\
+    ///      +-------+--------+",
+    /// );
+    /// ```
+    pub fn from_reader_with_header<R>(reader: R) -> Result<IterTable<CsvRecords<R>>, csv::Error>
+    where
+        R: Read,
+    {
+        let rdr = ReaderBuilder::new().has_headers(true).from_reader(reader);
+
+        from_csv_reader_with_header(rdr)
+    }
+
+    /// Creates [`IterTable`] from a [`csv::Reader`],
+    /// where the first record is read up front as a header.
+    ///
+    /// See [`from_reader_with_header`].
+    pub fn from_csv_reader_with_header<R>(
+        reader: Reader<R>,
+    ) -> Result<IterTable<CsvRecords<R>>, csv::Error>
+    where
+        R: Read,
+    {
+        let records = CsvRecords::with_header(reader)?;
+        let columns = records.columns().unwrap_or(0);
+
+        let table = IterTable::new(records).columns(columns);
+
+        Ok(table)
+    }
+
     /// Creates [`IterTable`] from a [`File`] which suppose to have a csv.
     ///
     /// # Example
@@ -173,6 +239,43 @@ where
     read_into_table(rdr)
 }
 
+/// Creates [`Table`] from [`Read`]er, aligning numeric columns to the right.
+///
+/// A column is considered numeric if each of its cells is a finite number,
+/// which may be separated by thousands with a comma (like `1,234.5`).
+/// Empty cells are ignored.
+///
+/// The first row is considered a header, so it's not inspected and not aligned.
+///
+/// # Example
+///
+/// ```
+/// let csv = "name,price\n\
+///            Apple,1.5\n\
+///            Melon,\"1,200\"";
+///
+/// let table = csv_to_table::from_reader_aligned(csv.as_bytes()).unwrap().to_string();
+///
+/// let expected = "+-------+-------+\n\
+///                 | name  | price |\n\
+///                 +-------+-------+\n\
+///                 | Apple |   1.5 |\n\
+///                 +-------+-------+\n\
+///                 | Melon | 1,200 |\n\
+///                 +-------+-------+";
+///
+/// assert_eq!(table, expected);
+/// ```
+pub fn from_reader_aligned<R>(reader: R) -> Result<Table, csv::Error>
+where
+    R: Read,
+{
+    let mut table = from_reader(reader)?;
+    align_numeric_columns(&mut table);
+
+    Ok(table)
+}
+
 /// Creates [`Table`] from a csv [`File`].
 ///
 /// Notice that in case of big files you might better use [`iter::CsvRecords`].
@@ -234,3 +337,57 @@ where
 
     Ok(table)
 }
+
+fn align_numeric_columns(table: &mut Table) {
+    let count_columns = table.get_records().count_columns();
+    for col in 0..count_columns {
+        if is_numeric_column(table, col) {
+            let target = Columns::single(col).not(Rows::first());
+            table.modify(target, Alignment::right());
+        }
+    }
+}
+
+fn is_numeric_column(table: &Table, col: usize) -> bool {
+    let records = table.get_records();
+
+    let mut has_numbers = false;
+    for row in 1..records.count_rows() {
+        let text = records.get_text((row, col)).trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        if !is_number(text) {
+            return false;
+        }
+
+        has_numbers = true;
+    }
+
+    has_numbers
+}
+
+fn is_number(text: &str) -> bool {
+    if !text.contains(',') {
+        return text.parse::<f64>().is_ok_and(f64::is_finite);
+    }
+
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let (int, frac) = match unsigned.split_once('.') {
+        Some((int, frac)) => (int, Some(frac)),
+        None => (unsigned, None),
+    };
+
+    let mut groups = int.split(',');
+    let first = groups.next().unwrap_or_default();
+    let is_first_ok = (1..=3).contains(&first.len()) && is_digits(first);
+    let is_rest_ok = groups.all(|group| group.len() == 3 && is_digits(group));
+    let is_frac_ok = !matches!(frac, Some(frac) if frac.is_empty() || !is_digits(frac));
+
+    is_first_ok && is_rest_ok && is_frac_ok
+}
+
+fn is_digits(text: &str) -> bool {
+    text.bytes().all(|b| b.is_ascii_digit())
+}
@@ -11,6 +11,8 @@ use tabled::grid::records::IntoRecords;
 /// [`CsvRecordsIter::set_catch`]: CsvRecordsIter.set_catch
 pub struct CsvRecords<R> {
     rows: StringRecordsIntoIter<R>,
+    header: Option<StringRecord>,
+    columns: Option<usize>,
 }
 
 impl<R> CsvRecords<R> {
@@ -21,8 +23,46 @@ impl<R> CsvRecords<R> {
     {
         Self {
             rows: reader.into_records(),
+            header: None,
+            columns: None,
         }
     }
+
+    /// Creates a new [`CsvRecords`] structure,
+    /// reading the first record up front as a header.
+    ///
+    /// So a number of columns is known before the iteration,
+    /// see [`CsvRecords::columns`].
+    /// The header is still returned as the first row,
+    /// and it's read from the reader only once.
+    pub fn with_header(mut reader: Reader<R>) -> Result<Self, csv::Error>
+    where
+        R: Read,
+    {
+        let header = reader.headers()?.clone();
+        let columns = header.len();
+
+        // a reader with `has_headers` set skips the first record itself,
+        // otherwise it's returned once again.
+        let header = if reader.has_headers() && !header.is_empty() {
+            Some(header)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rows: reader.into_records(),
+            header,
+            columns: Some(columns),
+        })
+    }
+
+    /// Returns a number of columns of a header.
+    ///
+    /// It's known only if [`CsvRecords`] was created by [`CsvRecords::with_header`].
+    pub fn columns(&self) -> Option<usize> {
+        self.columns
+    }
 }
 
 impl<R> IntoRecords for CsvRecords<R>
@@ -36,6 +76,9 @@ where
     fn iter_rows(self) -> Self::IterRows {
         CsvRecordsIter {
             iter: self.rows,
+            header: self.header,
+            columns: self.columns.unwrap_or(0),
+            min_columns: self.columns.unwrap_or(0),
             err_logic: ErrorLogic::Ignore,
             err: None,
         }
@@ -45,6 +88,9 @@ where
 /// A row iterator.
 pub struct CsvRecordsIter<R> {
     iter: StringRecordsIntoIter<R>,
+    header: Option<StringRecord>,
+    columns: usize,
+    min_columns: usize,
     err_logic: ErrorLogic,
     err: Option<std::io::Error>,
 }
@@ -63,6 +109,14 @@ impl<R> CsvRecordsIter<R> {
         self.err.as_ref()
     }
 
+    /// Returns a max number of columns met so far.
+    ///
+    /// Rows of a csv may have different lengths (see [`csv::ReaderBuilder::flexible`]),
+    /// so it may grow while iterating.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
     /// Show underlying [Read] errors inside a table.
     pub fn set_catch(mut self, catch: bool) -> Self {
         self.err_logic = if catch {
@@ -82,11 +136,18 @@ where
     type Item = CsvStringRecord;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(header) = self.header.take() {
+            return Some(CsvStringRecord::new(header, self.min_columns));
+        }
+
         loop {
             let result = self.iter.next()?;
 
             match result {
-                Ok(record) => return Some(CsvStringRecord::new(record)),
+                Ok(record) => {
+                    self.columns = self.columns.max(record.len());
+                    return Some(CsvStringRecord::new(record, self.min_columns));
+                }
                 Err(err) => match self.err_logic {
                     ErrorLogic::Ignore => continue,
                     ErrorLogic::Catch => {
@@ -100,15 +161,23 @@ where
 }
 
 /// A column iterator.
+///
+/// In case a number of columns is known by a header,
+/// a shorter record is filled with empty cells.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct CsvStringRecord {
     record: StringRecord,
+    min_columns: usize,
     i: usize,
 }
 
 impl CsvStringRecord {
-    fn new(record: StringRecord) -> Self {
-        Self { record, i: 0 }
+    fn new(record: StringRecord, min_columns: usize) -> Self {
+        Self {
+            record,
+            min_columns,
+            i: 0,
+        }
     }
 }
 
@@ -116,8 +185,11 @@ impl Iterator for CsvStringRecord {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let text = self.record.get(self.i)?;
-        let text = String::from(text);
+        let text = match self.record.get(self.i) {
+            Some(text) => String::from(text),
+            None if self.i < self.min_columns => String::new(),
+            None => return None,
+        };
 
         self.i += 1;
 
@@ -46,6 +46,39 @@ test_table!(
     "+------+---------+----+-------------+--------------------+-----+----------------------------------------+-----------------------+---------+---------------------+"
 );
 
+test_table!(
+    test_aligned,
+    csv_to_table::from_reader_aligned(csv2()).unwrap(),
+    "+-------+-----------+------+-----+"
+    "| item  | price     | code | qty |"
+    "+-------+-----------+------+-----+"
+    "| Apple |       1.5 | A-1  | 3   |"
+    "+-------+-----------+------+-----+"
+    "| Melon |     1,200 | B-2  | 1,2 |"
+    "+-------+-----------+------+-----+"
+    "| Grape |       -30 | C-3  |     |"
+    "+-------+-----------+------+-----+"
+    "| Lime  | 12,345.25 | D-4  | 5   |"
+    "+-------+-----------+------+-----+"
+);
+
+test_table!(
+    test_aligned_non_finite,
+    csv_to_table::from_reader_aligned(
+        "name,value,ratio\na,inf,1\nb,NaN,2.5\nc,-infinity,3".as_bytes()
+    )
+    .unwrap(),
+    "+------+-----------+-------+"
+    "| name | value     | ratio |"
+    "+------+-----------+-------+"
+    "| a    | inf       |     1 |"
+    "+------+-----------+-------+"
+    "| b    | NaN       |   2.5 |"
+    "+------+-----------+-------+"
+    "| c    | -infinity |     3 |"
+    "+------+-----------+-------+"
+);
+
 fn csv1() -> &'static [u8] {
     static DATA: &'_ str = r#"
 2021,Level 1,AA,"Agriculture",Dollars (millions),H10,Indirect taxes,Financial performance,489,ANZSIC06 division A
@@ -72,3 +105,15 @@ fn csv1() -> &'static [u8] {
 
     DATA.trim().as_bytes()
 }
+
+fn csv2() -> &'static [u8] {
+    static DATA: &'_ str = r#"
+item,price,code,qty
+Apple,1.5,A-1,3
+Melon,"1,200",B-2,"1,2"
+Grape,-30,C-3,
+Lime,"12,345.25",D-4,5
+"#;
+
+    DATA.trim().as_bytes()
+}
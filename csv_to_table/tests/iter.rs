@@ -1,3 +1,6 @@
+use csv::ReaderBuilder;
+use csv_to_table::iter::CsvRecords;
+use tabled::grid::records::IntoRecords;
 use testing_table::test_table;
 
 test_table!(
@@ -206,6 +209,72 @@ test_table!(
     "+--+--+--+"
 );
 
+test_table!(
+    test_iter_with_header,
+    csv_to_table::iter::from_reader_with_header(csv3()).unwrap(),
+    "+--------+--------+------+"
+    "| name   | lang   | year |"
+    "+--------+--------+------+"
+    "| pandas | python | 2008 |"
+    "+--------+--------+------+"
+);
+
+test_table!(
+    test_iter_with_header_flexible,
+    csv_to_table::iter::from_csv_reader_with_header(
+        ReaderBuilder::new().flexible(true).from_reader(csv3())
+    )
+    .unwrap(),
+    "+--------+--------+------+"
+    "| name   | lang   | year |"
+    "+--------+--------+------+"
+    "| tabled | rust   |      |"
+    "+--------+--------+------+"
+    "| csv    | rust   | 2015 |"
+    "+--------+--------+------+"
+    "| pandas | python | 2008 |"
+    "+--------+--------+------+"
+);
+
+#[test]
+fn test_iter_records_columns() {
+    let reader = ReaderBuilder::new().flexible(true).from_reader(csv3());
+    let records = CsvRecords::with_header(reader).unwrap();
+    assert_eq!(records.columns(), Some(3));
+
+    let mut rows = records.iter_rows();
+    assert_eq!(rows.columns(), 3);
+    assert_eq!(
+        rows.next().unwrap().collect::<Vec<_>>(),
+        ["name", "lang", "year"]
+    );
+    assert_eq!(rows.columns(), 3);
+    assert_eq!(
+        rows.next().unwrap().collect::<Vec<_>>(),
+        ["tabled", "rust", ""]
+    );
+    assert_eq!(rows.columns(), 3);
+    assert_eq!(rows.next().unwrap().count(), 4);
+    assert_eq!(rows.columns(), 4);
+    assert_eq!(rows.count(), 1);
+
+    // the first record is not returned twice without `has_headers`
+    let reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(csv3());
+    let records = CsvRecords::with_header(reader).unwrap();
+    assert_eq!(records.columns(), Some(3));
+    assert_eq!(records.iter_rows().count(), 4);
+
+    let records = CsvRecords::new(ReaderBuilder::new().from_reader(csv3()));
+    assert_eq!(records.columns(), None);
+
+    let records = CsvRecords::with_header(ReaderBuilder::new().from_reader("".as_bytes())).unwrap();
+    assert_eq!(records.columns(), Some(0));
+    assert_eq!(records.iter_rows().count(), 0);
+}
+
 fn csv1() -> &'static [u8] {
     static DATA: &'_ str = r#"
 Year,Industry_aggregation_NZSIOC,Industry_name_NZSIOC,Units,Variable_code,Variable_name,Variable_category,Value
@@ -233,3 +302,14 @@ Year,Industry_aggregation_NZSIOC,Industry_name_NZSIOC,Units,Variable_code,Variab
 
     DATA.trim().as_bytes()
 }
+
+fn csv3() -> &'static [u8] {
+    static DATA: &'_ str = r#"
+name,lang,year
+tabled,rust
+csv,rust,2015,extra
+pandas,python,2008
+"#;
+
+    DATA.trim().as_bytes()
+}
@@ -1,7 +1,6 @@
 use std::{
     cmp::{self, max},
     collections::HashMap,
-    iter::repeat,
 };
 
 use serde_json::Map;
@@ -70,23 +69,16 @@ pub(super) fn collapsed_table(value: &Value, cfg: &Config) -> String {
 fn _collapsed_table(val: &Value, cfg: &Config, dims: &Dimensions, ctx: PrintContext) -> CellData {
     match val {
         Value::String(..) | Value::Bool(..) | Value::Number(..) | Value::Null => {
-            let value = match val {
-                Value::String(val) => val.to_string(),
-                Value::Bool(val) => val.to_string(),
-                Value::Number(val) => val.to_string(),
-                Value::Null => String::new(),
-                _ => unreachable!(),
-            };
-
+            let value = json_value_to_string(val, cfg);
             generate_value_cell(&value, cfg, ctx)
         }
         Value::Object(obj) => {
             if obj.is_empty() {
                 // a corner case where the object must behave as empty string
-                return _collapsed_table(&Value::Null, cfg, dims, ctx);
+                return generate_value_cell("", cfg, ctx);
             }
 
-            match cfg.object_orientation {
+            match cfg.get_object_orientation(ctx.pos == 0) {
                 Orientation::Column => generate_vertical_object(obj, cfg, dims, ctx),
                 Orientation::Row => generate_horizontal_object(obj, cfg, dims, ctx),
             }
@@ -94,7 +86,7 @@ fn _collapsed_table(val: &Value, cfg: &Config, dims: &Dimensions, ctx: PrintCont
         Value::Array(list) => {
             if list.is_empty() {
                 // a corner case where the list must behave as empty string
-                return _collapsed_table(&Value::Null, cfg, dims, ctx);
+                return generate_value_cell("", cfg, ctx);
             }
 
             match cfg.array_orientation {
@@ -750,9 +742,8 @@ fn __collect_table_dims(
 ) -> (Dim, usize) {
     match val {
         Value::String(text) => (str_dimension(text, cfg), 0),
-        Value::Bool(b) => (bool_dimension(b, cfg), 0),
+        Value::Bool(_) | Value::Null => (str_dimension(&json_value_to_string(val, cfg), cfg), 0),
         Value::Number(num) => (num_dimension(num, cfg), 0),
-        Value::Null => (empty_dimension(cfg), 0),
         Value::Object(obj) => {
             if obj.is_empty() {
                 return (empty_dimension(cfg), 0);
@@ -799,7 +790,7 @@ fn __collect_table_dims(
             let has_vertical = cfg.cfg.get_borders().has_left();
             let has_horizontal = cfg.cfg.get_borders().has_top();
 
-            match cfg.object_orientation {
+            match cfg.get_object_orientation(pos == 0) {
                 Orientation::Column => {
                     let total_width = key_max.width + val_max.width + has_vertical as usize;
                     total_height += has_horizontal as usize * (obj.len() - 1);
@@ -869,14 +860,6 @@ fn __collect_table_dims(
     }
 }
 
-fn bool_dimension(b: &bool, cfg: &Config) -> Dim {
-    let width = if *b { 4 } else { 5 };
-    Dim::new(
-        width + get_padding_horizontal(cfg),
-        1 + get_padding_vertical(cfg),
-    )
-}
-
 fn num_dimension(num: &serde_json::Number, cfg: &Config) -> Dim {
     Dim::new(
         num.to_string().len() + get_padding_horizontal(cfg),
@@ -933,21 +916,21 @@ fn set_string_dimension(
 
     let (top, bottom) = indent_vertical(av, height, count_lines);
 
-    out.extend(repeat(String::new()).take(top));
+    out.extend(std::iter::repeat_n(String::new(), top));
 
     for line in get_lines(text) {
         let w = get_line_width(&line);
         let (left, right) = indent_horizontal(ah, width, w);
 
         let mut buf = String::new();
-        buf.extend(repeat(' ').take(left));
+        buf.extend(std::iter::repeat_n(' ', left));
         buf.push_str(&line);
-        buf.extend(repeat(' ').take(right));
+        buf.extend(std::iter::repeat_n(' ', right));
 
         out.push(buf);
     }
 
-    out.extend(repeat(String::new()).take(bottom));
+    out.extend(std::iter::repeat_n(String::new(), bottom));
 
     out.join("\n")
 }
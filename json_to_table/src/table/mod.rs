@@ -73,6 +73,10 @@ impl<T> JsonTable<T> {
                 cfg: ColoredConfig::new(configure_grid()),
                 array_orientation: Orientation::Column,
                 object_orientation: Orientation::Column,
+                root_object_orientation: None,
+                index: None,
+                bool_glyphs: None,
+                null_text: None,
             },
         }
     }
@@ -188,6 +192,46 @@ impl<T> JsonTable<T> {
         self
     }
 
+    /// Set a table mode for a root [`serde_json::Value::Object`].
+    ///
+    /// Nested objects are still rendered according to [`JsonTable::object_orientation`].
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::{json_to_table, Orientation};
+    ///
+    /// let value = json!({
+    ///     "key1": 123,
+    ///     "key2": {
+    ///         "k1": 1,
+    ///         "k2": 2,
+    ///     }
+    /// });
+    ///
+    /// let table = json_to_table(&value)
+    ///     .root_object_orientation(Orientation::Row)
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-------+--------------+\n",
+    ///         "| key1  | key2         |\n",
+    ///         "+-------+--------------+\n",
+    ///         "|  123  | +----+-----+ |\n",
+    ///         "|       | | k1 |  1  | |\n",
+    ///         "|       | +----+-----+ |\n",
+    ///         "|       | | k2 |  2  | |\n",
+    ///         "|       | +----+-----+ |\n",
+    ///         "+-------+--------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn root_object_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.cfg.root_object_orientation = Some(mode);
+        self
+    }
+
     /// Set a table mode for a [`serde_json::Value::Array`].
     ///
     /// ```
@@ -258,6 +302,104 @@ impl<T> JsonTable<T> {
         self
     }
 
+    /// Adds an index of an element to a root [`serde_json::Value::Array`].
+    ///
+    /// The index starts from a given number,
+    /// and it's put into a leading column or a leading row depending on [`Orientation`].
+    /// Nested arrays are left as they are.
+    ///
+    /// It does not recognizes collapsed mode.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!([
+    ///     {"name": "Alice"},
+    ///     {"name": "Bob"},
+    /// ]);
+    ///
+    /// let table = json_to_table(&value).with_index(1).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---+--------------------+\n",
+    ///         "| 1 | +------+---------+ |\n",
+    ///         "|   | | name |  Alice  | |\n",
+    ///         "|   | +------+---------+ |\n",
+    ///         "+---+--------------------+\n",
+    ///         "| 2 | +------+-------+   |\n",
+    ///         "|   | | name |  Bob  |   |\n",
+    ///         "|   | +------+-------+   |\n",
+    ///         "+---+--------------------+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn with_index(&mut self, start: usize) -> &mut Self {
+        self.cfg.index = Some(start);
+        self
+    }
+
+    /// Set texts which are used instead of `true` and `false`.
+    ///
+    /// Numbers and strings are left as they are.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!({"active": true, "deleted": false});
+    ///
+    /// let table = json_to_table(&value).bool_glyphs("✔", "✘").to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---------+-----+\n",
+    ///         "| active  |  ✔  |\n",
+    ///         "+---------+-----+\n",
+    ///         "| deleted |  ✘  |\n",
+    ///         "+---------+-----+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn bool_glyphs<S1, S2>(&mut self, yes: S1, no: S2) -> &mut Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.cfg.bool_glyphs = Some((yes.into(), no.into()));
+        self
+    }
+
+    /// Set a text which is used for [`serde_json::Value::Null`] instead of an empty cell.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use json_to_table::json_to_table;
+    ///
+    /// let value = json!({"name": null});
+    ///
+    /// let table = json_to_table(&value).null_text("—").to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+------+---+\n",
+    ///         "| name | — |\n",
+    ///         "+------+---+",
+    ///     ),
+    /// );
+    /// ```
+    pub fn null_text<S>(&mut self, text: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.cfg.null_text = Some(text.into());
+        self
+    }
+
     /// Set a config which will be used.
     ///
     /// You can obtain a config from a [`Table`].
@@ -523,7 +665,40 @@ struct Config {
     plain: bool,
     cfg: ColoredConfig,
     object_orientation: Orientation,
+    root_object_orientation: Option<Orientation>,
     array_orientation: Orientation,
+    index: Option<usize>,
+    bool_glyphs: Option<(String, String)>,
+    null_text: Option<String>,
+}
+
+impl Config {
+    fn get_object_orientation(&self, is_root: bool) -> Orientation {
+        match (is_root, self.root_object_orientation) {
+            (true, Some(orientation)) => orientation,
+            _ => self.object_orientation,
+        }
+    }
+
+    fn bool_text(&self, value: bool) -> Option<&str> {
+        self.bool_glyphs
+            .as_ref()
+            .map(|(yes, no)| if value { yes.as_str() } else { no.as_str() })
+    }
+
+    fn null_text(&self) -> Option<&str> {
+        self.null_text.as_deref()
+    }
+
+    // Root only settings must not be applied to nested values,
+    // which are rendered as separate tables.
+    fn nested(&self) -> Config {
+        Config {
+            root_object_orientation: None,
+            index: None,
+            ..self.clone()
+        }
+    }
 }
 
 /// The structure represents a table mode for a given entity,
@@ -561,19 +736,30 @@ fn json_to_string(value: &Value, cfg: &Config) -> String {
 }
 
 fn json_to_table(value: &Value, cfg: &Config) -> Table {
+    let nested_cfg = cfg.nested();
+
     match value {
         Value::Array(array) => {
             let list = array
                 .iter()
-                .map(|value| json_to_string_inner(value, cfg))
+                .map(|value| json_to_string_inner(value, &nested_cfg))
                 .collect::<Vec<_>>();
 
+            let index = cfg.index.map(|start| build_index(start, list.len()));
+
             match cfg.array_orientation {
-                Orientation::Row => Builder::from(vec![list]).build(),
+                Orientation::Row => {
+                    let rows = index.into_iter().chain([list]).collect::<Vec<_>>();
+                    Builder::from(rows).build()
+                }
                 Orientation::Column => {
                     let list = list
                         .into_iter()
-                        .map(|value| vec![value])
+                        .enumerate()
+                        .map(|(i, value)| match &index {
+                            Some(index) => vec![index[i].clone(), value],
+                            None => vec![value],
+                        })
                         .collect::<Vec<_>>();
                     Builder::from(list).build()
                 }
@@ -582,10 +768,10 @@ fn json_to_table(value: &Value, cfg: &Config) -> Table {
         Value::Object(map) => {
             let list = map
                 .iter()
-                .map(|(key, value)| vec![key.clone(), json_to_string_inner(value, cfg)])
+                .map(|(key, value)| vec![key.clone(), json_to_string_inner(value, &nested_cfg)])
                 .collect::<Vec<_>>();
 
-            match cfg.object_orientation {
+            match cfg.get_object_orientation(true) {
                 Orientation::Row => {
                     let (keys, values) = list.into_iter().fold(
                         (Vec::with_capacity(map.len()), Vec::with_capacity(map.len())),
@@ -606,7 +792,7 @@ fn json_to_table(value: &Value, cfg: &Config) -> Table {
             }
         }
         Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
-            let value = json_value_to_string(value);
+            let value = json_value_to_string(value, cfg);
             Builder::from(vec![vec![value]]).build()
         }
     }
@@ -656,14 +842,29 @@ fn json_to_pool_table_value(value: &Value, cfg: &Config) -> TableValue {
                 TableValue::Column(values)
             }
         },
+        Value::Bool(b) => match cfg.bool_text(*b) {
+            Some(text) => TableValue::Cell(text.to_owned()),
+            None => TableValue::Cell(value.to_string()),
+        },
+        Value::Null => match cfg.null_text() {
+            Some(text) => TableValue::Cell(text.to_owned()),
+            None => TableValue::Cell(value.to_string()),
+        },
         value => TableValue::Cell(value.to_string()),
     }
 }
 
-fn json_value_to_string(value: &Value) -> String {
+fn build_index(start: usize, count: usize) -> Vec<String> {
+    (start..start + count).map(|i| i.to_string()).collect()
+}
+
+fn json_value_to_string(value: &Value, cfg: &Config) -> String {
     match value {
-        Value::Null => String::new(),
-        Value::Bool(value) => value.to_string(),
+        Value::Null => cfg.null_text().unwrap_or_default().to_owned(),
+        Value::Bool(value) => match cfg.bool_text(*value) {
+            Some(text) => text.to_owned(),
+            None => value.to_string(),
+        },
         Value::Number(value) => value.to_string(),
         Value::String(value) => value.to_string(),
         Value::Array(_) | Value::Object(_) => unreachable!(),
@@ -674,7 +875,7 @@ fn json_to_string_inner(value: &Value, cfg: &Config) -> String {
     match cfg.plain {
         true => match value {
             Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
-                json_value_to_string(value)
+                json_value_to_string(value, cfg)
             }
             Value::Array(_) | Value::Object(_) => json_to_string(value, cfg),
         },
@@ -10,30 +10,42 @@ pub(super) fn plain_table(v: &Value, cfg: &Config) -> String {
 
 fn _plain_table(v: &Value, cfg: &Config, outer: bool) -> String {
     match v {
-        Value::Array(arr) => match cfg.array_orientation {
-            Orientation::Column => {
-                let mut buf = Builder::with_capacity(1, 1);
-                for value in arr {
-                    let val = _plain_table(value, cfg, false);
-                    buf.push_record([val]);
-                }
+        Value::Array(arr) => {
+            let index = match outer {
+                true => cfg.index.map(|start| build_index(start, arr.len())),
+                false => None,
+            };
 
-                buf.build().with(cfg.cfg.clone()).to_string()
-            }
-            Orientation::Row => {
-                let mut buf = Vec::with_capacity(arr.len());
-                for value in arr {
-                    let val = _plain_table(value, cfg, false);
-                    buf.push(val);
+            match cfg.array_orientation {
+                Orientation::Column => {
+                    let mut buf = Builder::with_capacity(1, 1);
+                    for (i, value) in arr.iter().enumerate() {
+                        let val = _plain_table(value, cfg, false);
+                        match &index {
+                            Some(index) => buf.push_record([index[i].clone(), val]),
+                            None => buf.push_record([val]),
+                        }
+                    }
+
+                    buf.build().with(cfg.cfg.clone()).to_string()
                 }
+                Orientation::Row => {
+                    let mut buf = Vec::with_capacity(arr.len());
+                    for value in arr {
+                        let val = _plain_table(value, cfg, false);
+                        buf.push(val);
+                    }
 
-                Builder::from(vec![buf])
-                    .build()
-                    .with(cfg.cfg.clone())
-                    .to_string()
+                    let rows = index.into_iter().chain([buf]).collect::<Vec<_>>();
+
+                    Builder::from(rows)
+                        .build()
+                        .with(cfg.cfg.clone())
+                        .to_string()
+                }
             }
-        },
-        Value::Object(map) => match cfg.object_orientation {
+        }
+        Value::Object(map) => match cfg.get_object_orientation(outer) {
             Orientation::Column => {
                 let mut buf = Builder::with_capacity(map.len(), 2);
                 for (key, value) in map {
@@ -58,14 +70,9 @@ fn _plain_table(v: &Value, cfg: &Config, outer: bool) -> String {
                     .to_string()
             }
         },
-        Value::Null => String::new(),
+        Value::Null => json_value_to_string(v, cfg),
         value => {
-            let val = match value {
-                Value::String(text) => text.to_owned(),
-                Value::Bool(val) => val.to_string(),
-                Value::Number(num) => num.to_string(),
-                _ => unreachable!(),
-            };
+            let val = json_value_to_string(value, cfg);
 
             let mut table = Table::from_iter([[val]]);
             table.with(cfg.cfg.clone());
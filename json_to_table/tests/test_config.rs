@@ -1,4 +1,4 @@
-use json_to_table::json_to_table;
+use json_to_table::{json_to_table, Orientation};
 use serde_json::json;
 use tabled::settings::{Alignment, Padding, Style};
 
@@ -142,3 +142,149 @@ test_table!(
     "\u{1b}[34m│\u{1b}[39m     \u{1b}[34m│\u{1b}[39mk2\u{1b}[34m│\u{1b}[39m2\u{1b}[34m│\u{1b}[39m"
     "\u{1b}[34m└─────\u{1b}[39m\u{1b}[34m┴──\u{1b}[39m\u{1b}[34m┴─┘\u{1b}[39m"
 );
+
+test_table!(
+    config_with_index_test,
+    json_to_table(&json!([
+            {"name": "Alice", "tags": [1, 2]},
+            {"name": "Bob", "tags": [3]},
+        ]))
+        .with_index(0),
+    "+---+--------------------+"
+    "| 0 | +------+---------+ |"
+    "|   | | name |  Alice  | |"
+    "|   | +------+---------+ |"
+    "|   | | tags | +-----+ | |"
+    "|   | |      | |  1  | | |"
+    "|   | |      | +-----+ | |"
+    "|   | |      | |  2  | | |"
+    "|   | |      | +-----+ | |"
+    "|   | +------+---------+ |"
+    "+---+--------------------+"
+    "| 1 | +------+---------+ |"
+    "|   | | name |  Bob    | |"
+    "|   | +------+---------+ |"
+    "|   | | tags | +-----+ | |"
+    "|   | |      | |  3  | | |"
+    "|   | |      | +-----+ | |"
+    "|   | +------+---------+ |"
+    "+---+--------------------+"
+);
+
+test_table!(
+    config_with_index_row_orientation_test,
+    json_to_table(&json!([
+            {"name": "Alice"},
+            {"name": "Bob"},
+        ]))
+        .array_orientation(Orientation::Row)
+        .with_index(0),
+    "+--------------------+------------------+"
+    "| 0                  | 1                |"
+    "+--------------------+------------------+"
+    "| +------+---------+ | +------+-------+ |"
+    "| | name |  Alice  | | | name |  Bob  | |"
+    "| +------+---------+ | +------+-------+ |"
+    "+--------------------+------------------+"
+);
+
+test_table!(
+    config_with_index_into_table_test,
+    json_to_table(&json!([
+            {"name": "Alice"},
+            {"name": "Bob"},
+        ]))
+        .with_index(0)
+        .into_table(),
+    "+---+--------------------+"
+    "| 0 | +------+---------+ |"
+    "|   | | name |  Alice  | |"
+    "|   | +------+---------+ |"
+    "+---+--------------------+"
+    "| 1 | +------+-------+   |"
+    "|   | | name |  Bob  |   |"
+    "|   | +------+-------+   |"
+    "+---+--------------------+"
+);
+
+test_table!(
+    config_bool_glyphs_and_null_text_test,
+    json_to_table(&json!({
+            "active": true,
+            "deleted": false,
+            "owner": null,
+            "name": "true",
+            "id": 0,
+            "tags": [false, null, []]
+        }))
+        .bool_glyphs("✔", "✘")
+        .null_text("—"),
+    "+---------+---------+"
+    "| active  |  ✔      |"
+    "+---------+---------+"
+    "| deleted |  ✘      |"
+    "+---------+---------+"
+    "| id      |  0      |"
+    "+---------+---------+"
+    "| name    |  true   |"
+    "+---------+---------+"
+    "| owner   | —       |"
+    "+---------+---------+"
+    "| tags    | +-----+ |"
+    "|         | |  ✘  | |"
+    "|         | +-----+ |"
+    "|         | | —   | |"
+    "|         | +-----+ |"
+    "|         | |     | |"
+    "|         | +-----+ |"
+    "+---------+---------+"
+);
+
+test_table!(
+    config_bool_glyphs_and_null_text_collapsed_test,
+    json_to_table(&json!({
+            "active": true,
+            "deleted": false,
+            "owner": null,
+            "name": "true",
+            "id": 0,
+            "tags": [false, null, []]
+        }))
+        .bool_glyphs("✔", "✘")
+        .null_text("—")
+        .collapse(),
+    "+---------+------+"
+    "| active  | ✔    |"
+    "+---------+------+"
+    "| deleted | ✘    |"
+    "+---------+------+"
+    "| id      | 0    |"
+    "+---------+------+"
+    "| name    | true |"
+    "+---------+------+"
+    "| owner   | —    |"
+    "+---------+------+"
+    "| tags    | ✘    |"
+    "|         +------+"
+    "|         | —    |"
+    "|         +------+"
+    "|         |      |"
+    "+---------+------+"
+);
+
+test_table!(
+    config_bool_glyphs_and_null_text_table_test,
+    json_to_table(&json!([true, null, "null", 1.5]))
+        .bool_glyphs("yes", "no")
+        .null_text("-")
+        .into_table(),
+    "+------+"
+    "| yes  |"
+    "+------+"
+    "| -    |"
+    "+------+"
+    "| null |"
+    "+------+"
+    "| 1.5  |"
+    "+------+"
+);
@@ -897,3 +897,84 @@ test_table!(
     "║      ║     ║     ║        ║        ║        ║ ;         ║            ║      ║        ║           ║       ║           ║            ║      ║        ║         ║       ║                                           ║      ║       ║         ║         ║"
     "╚══════╩═════╩═════╩════════╩════════╩════════╩═══════════╩════════════╩══════╩════════╩═══════════╩═══════╩═══════════╩════════════╩══════╩════════╩═════════╩═══════╩═══════════════════════════════════════════╩══════╩═══════╩═════════╩═════════╝"
 );
+
+test_table!(
+    root_object_orientation_flat_test,
+    json_to_table(&json!({"a": 1, "b": "text", "c": true}))
+        .root_object_orientation(Orientation::Row),
+    "+-----+--------+--------+"
+    "| a   | b      | c      |"
+    "+-----+--------+--------+"
+    "|  1  |  text  |  true  |"
+    "+-----+--------+--------+"
+);
+
+test_table!(
+    root_object_orientation_nested_test,
+    json_to_table(&json!({"a": 1, "b": {"c": 2, "d": [{"e": 3}]}}))
+        .root_object_orientation(Orientation::Row),
+    "+-----+-------------------------+"
+    "| a   | b                       |"
+    "+-----+-------------------------+"
+    "|  1  | +---+-----------------+ |"
+    "|     | | c |  2              | |"
+    "|     | +---+-----------------+ |"
+    "|     | | d | +-------------+ | |"
+    "|     | |   | | +---+-----+ | | |"
+    "|     | |   | | | e |  3  | | | |"
+    "|     | |   | | +---+-----+ | | |"
+    "|     | |   | +-------------+ | |"
+    "|     | +---+-----------------+ |"
+    "+-----+-------------------------+"
+);
+
+test_table!(
+    root_object_orientation_nested_row_test,
+    json_to_table(&json!({"a": 1, "b": {"c": 2, "d": 3}}))
+        .object_orientation(Orientation::Row)
+        .root_object_orientation(Orientation::Column),
+    "+---+---------------+"
+    "| a |  1            |"
+    "+---+---------------+"
+    "| b | +-----+-----+ |"
+    "|   | | c   | d   | |"
+    "|   | +-----+-----+ |"
+    "|   | |  2  |  3  | |"
+    "|   | +-----+-----+ |"
+    "+---+---------------+"
+);
+
+test_table!(
+    root_object_orientation_collapsed_test,
+    json_to_table(&json!({"a": 1, "b": {"c": 2, "d": 3}}))
+        .root_object_orientation(Orientation::Row)
+        .collapse(),
+    "+---+-------+"
+    "| a | b     |"
+    "+---+---+---+"
+    "| 1 | c | 2 |"
+    "|   +---+---+"
+    "|   | d | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    root_object_orientation_into_table_test,
+    json_to_table(&json!({"a": 1, "b": {"c": 2, "d": [4, 5]}}))
+        .root_object_orientation(Orientation::Row)
+        .with_index(0)
+        .into_table(),
+    "+---+-----------------+"
+    "| a | b               |"
+    "+---+-----------------+"
+    "| 1 | +---+---------+ |"
+    "|   | | c |  2      | |"
+    "|   | +---+---------+ |"
+    "|   | | d | +-----+ | |"
+    "|   | |   | |  4  | | |"
+    "|   | |   | +-----+ | |"
+    "|   | |   | |  5  | | |"
+    "|   | |   | +-----+ | |"
+    "|   | +---+---------+ |"
+    "+---+-----------------+"
+);
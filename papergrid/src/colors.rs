@@ -1,5 +1,7 @@
 //! A module which contains [Colors] trait and its blanket implementations.
 
+use core::marker::PhantomData;
+
 use crate::{ansi::ANSIFmt, config::Position};
 
 /// A trait which represents map of colors.
@@ -93,6 +95,59 @@ impl Colors for NoColors {
     }
 }
 
+/// The structure represents a [`Colors`] map which is computed on the fly by a closure.
+///
+/// The closure is called each time a grid renders a line of a cell,
+/// so a multiline cell makes it called several times for the same position.
+/// Therefore it's expected to return the same color for a given position.
+/// Returning [`None`] means that a cell is not colored.
+///
+/// As [`Colors`] hands out references, the closure returns a reference to a color,
+/// e.g. to one of a palette.
+///
+/// ```
+/// use papergrid::{ansi::ANSIStr, colors::FnColors, config::Position};
+///
+/// const RED: ANSIStr<'static> = ANSIStr::new("\u{1b}[31m", "\u{1b}[39m");
+///
+/// let colors = FnColors::new(|pos: Position| (pos.row() == pos.col()).then_some(&RED));
+/// # let _ = colors;
+/// ```
+#[derive(Debug, Clone)]
+pub struct FnColors<'a, F, C> {
+    f: F,
+    _color: PhantomData<&'a C>,
+}
+
+impl<'a, F, C> FnColors<'a, F, C>
+where
+    F: Fn(Position) -> Option<&'a C>,
+{
+    /// Creates a new [`FnColors`] from a closure.
+    pub const fn new(f: F) -> Self {
+        Self {
+            f,
+            _color: PhantomData,
+        }
+    }
+}
+
+impl<'a, F, C> Colors for FnColors<'a, F, C>
+where
+    F: Fn(Position) -> Option<&'a C>,
+    C: ANSIFmt + 'a,
+{
+    type Color = C;
+
+    fn get_color(&self, pos: Position) -> Option<&Self::Color> {
+        (self.f)(pos)
+    }
+
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
 /// A color which is actually has not value.
 #[derive(Debug)]
 pub struct EmptyColor;
@@ -57,6 +57,11 @@ impl<T> EntityMap<T> {
             .unwrap_or(&self.global)
     }
 
+    /// Get a global value, which is used for not overridden entities.
+    pub fn get_global(&self) -> &T {
+        &self.global
+    }
+
     /// Removes a value for an [`Entity`].
     pub fn remove(&mut self, entity: Entity) {
         match entity {
@@ -74,6 +79,120 @@ impl<T> EntityMap<T> {
     }
 }
 
+impl<T> EntityMap<T> {
+    /// Removes values of a column, shifting values of the following columns to the left.
+    pub fn remove_column(&mut self, col: usize) {
+        self.columns = std::mem::take(&mut self.columns)
+            .into_iter()
+            .filter_map(|(c, value)| shift_index(c, col).map(|c| (c, value)))
+            .collect();
+
+        self.cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .filter_map(|(pos, value)| {
+                shift_index(pos.col(), col).map(|c| (Position::new(pos.row(), c), value))
+            })
+            .collect();
+    }
+
+    /// Removes values of a row, shifting values of the following rows up.
+    pub fn remove_row(&mut self, row: usize) {
+        self.rows = std::mem::take(&mut self.rows)
+            .into_iter()
+            .filter_map(|(r, value)| shift_index(r, row).map(|r| (r, value)))
+            .collect();
+
+        self.cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .filter_map(|(pos, value)| {
+                shift_index(pos.row(), row).map(|r| (Position::new(r, pos.col()), value))
+            })
+            .collect();
+    }
+
+    /// Shifts values of a column and the following columns to the right,
+    /// so a new column can be inserted at the index.
+    pub fn insert_column(&mut self, col: usize) {
+        self.columns = std::mem::take(&mut self.columns)
+            .into_iter()
+            .map(|(c, value)| (expand_index(c, col), value))
+            .collect();
+
+        self.cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .map(|(pos, value)| {
+                (
+                    Position::new(pos.row(), expand_index(pos.col(), col)),
+                    value,
+                )
+            })
+            .collect();
+    }
+
+    /// Shifts values of a row and the following rows down,
+    /// so a new row can be inserted at the index.
+    pub fn insert_row(&mut self, row: usize) {
+        self.rows = std::mem::take(&mut self.rows)
+            .into_iter()
+            .map(|(r, value)| (expand_index(r, row), value))
+            .collect();
+
+        self.cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .map(|(pos, value)| {
+                (
+                    Position::new(expand_index(pos.row(), row), pos.col()),
+                    value,
+                )
+            })
+            .collect();
+    }
+
+    /// Moves values of columns to the mirrored ones,
+    /// so a column `i` becomes a column `count_columns - 1 - i`.
+    ///
+    /// Values of columns out of the given count are kept as they are.
+    pub fn reverse_columns(&mut self, count_columns: usize) {
+        let mirror = |col: usize| match col < count_columns {
+            true => count_columns - 1 - col,
+            false => col,
+        };
+
+        self.columns = std::mem::take(&mut self.columns)
+            .into_iter()
+            .map(|(col, value)| (mirror(col), value))
+            .collect();
+
+        self.cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .map(|(pos, value)| (Position::new(pos.row(), mirror(pos.col())), value))
+            .collect();
+    }
+
+    /// Returns a mutable iterator over all values, including a global one.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        std::iter::once(&mut self.global)
+            .chain(self.columns.values_mut())
+            .chain(self.rows.values_mut())
+            .chain(self.cells.values_mut())
+    }
+}
+
+fn shift_index(index: usize, removed: usize) -> Option<usize> {
+    match index.cmp(&removed) {
+        std::cmp::Ordering::Less => Some(index),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Greater => Some(index - 1),
+    }
+}
+
+fn expand_index(index: usize, inserted: usize) -> usize {
+    match index >= inserted {
+        true => index + 1,
+        false => index,
+    }
+}
+
 impl<T: Clone> EntityMap<T> {
     /// Set a value for an [`Entity`].
     pub fn insert(&mut self, entity: Entity, value: T) {
@@ -103,6 +222,30 @@ impl<T: Clone> EntityMap<T> {
     }
 }
 
+impl<T: Clone> EntityMap<T> {
+    /// Copies values of a row to another row, replacing the ones set for it.
+    pub fn copy_row(&mut self, from: usize, to: usize) {
+        self.cells.retain(|pos, _| pos.row() != to);
+
+        let cells = self
+            .cells
+            .iter()
+            .filter(|(pos, _)| pos.row() == from)
+            .map(|(pos, value)| (Position::new(to, pos.col()), value.clone()))
+            .collect::<Vec<_>>();
+        self.cells.extend(cells);
+
+        match self.rows.get(&from).cloned() {
+            Some(value) => {
+                self.rows.insert(to, value);
+            }
+            None => {
+                self.rows.remove(&to);
+            }
+        }
+    }
+}
+
 impl<T> From<EntityMap<T>> for HashMap<Entity, T> {
     fn from(value: EntityMap<T>) -> Self {
         let mut m = HashMap::new();
@@ -5,6 +5,7 @@
 mod borders_config;
 mod entity_map;
 mod offset;
+mod span_error;
 
 use std::collections::HashMap;
 
@@ -15,7 +16,7 @@ use crate::config::{
 };
 use borders_config::BordersConfig;
 
-pub use self::{entity_map::EntityMap, offset::Offset};
+pub use self::{entity_map::EntityMap, offset::Offset, span_error::SpanError};
 
 use super::Formatting;
 
@@ -25,6 +26,10 @@ type HorizontalLine = super::HorizontalLine<char>;
 /// VerticalLine represents a vertical border line.
 type VerticalLine = super::VerticalLine<char>;
 
+/// A function which picks an intersection character
+/// by whether there's a border line going (up, down, left, right) from it.
+pub type IntersectionResolver = fn(bool, bool, bool, bool) -> char;
+
 /// This structure represents a settings of a grid.
 ///
 /// grid: crate::Grid.
@@ -43,12 +48,15 @@ pub struct SpannedConfig {
     borders: BordersConfig<char>,
     borders_colors: BordersConfig<ANSIBuf>,
     borders_missing_char: char,
+    intersection_resolver: Option<Resolver>,
     horizontal_chars: HashMap<Position, HashMap<Offset, char>>,
     horizontal_colors: HashMap<Position, HashMap<Offset, ANSIBuf>>, // squash a map to be HashMap<(Pos, Offset), char>
     vertical_chars: HashMap<Position, HashMap<Offset, char>>,
     vertical_colors: HashMap<Position, HashMap<Offset, ANSIBuf>>,
     justification: EntityMap<char>,
     justification_color: EntityMap<Option<ANSIBuf>>,
+    fill_override: EntityMap<Option<char>>,
+    bottom_border_text: Option<(String, Offset)>,
 }
 
 impl Default for SpannedConfig {
@@ -67,12 +75,15 @@ impl Default for SpannedConfig {
             borders: BordersConfig::default(),
             borders_colors: BordersConfig::default(),
             borders_missing_char: ' ',
+            intersection_resolver: None,
             horizontal_chars: HashMap::default(),
             horizontal_colors: HashMap::default(),
             vertical_chars: HashMap::default(),
             vertical_colors: HashMap::default(),
             justification: EntityMap::new(' '),
             justification_color: EntityMap::default(),
+            fill_override: EntityMap::default(),
+            bottom_border_text: None,
         }
     }
 }
@@ -246,6 +257,40 @@ impl SpannedConfig {
         self.borders.get_horizontal_lines()
     }
 
+    /// Set a main character of a horizontal line by row index.
+    ///
+    /// The intersections of the line are kept as they are,
+    /// in case the line wasn't set they're inherited from the borders.
+    ///
+    /// Row `0` means the top row.
+    /// Row `grid.count_rows()` means the bottom row.
+    pub fn set_horizontal_line_char(&mut self, line: usize, c: char) {
+        let mut val = self
+            .get_horizontal_line(line)
+            .copied()
+            .unwrap_or_else(HorizontalLine::empty);
+        val.main = Some(c);
+
+        self.insert_horizontal_line(line, val);
+    }
+
+    /// Set a main character of a vertical line by column index.
+    ///
+    /// The intersections of the line are kept as they are,
+    /// in case the line wasn't set they're inherited from the borders.
+    ///
+    /// Column `0` means the left column.
+    /// Column `grid.count_columns()` means the rightmost column.
+    pub fn set_vertical_line_char(&mut self, line: usize, c: char) {
+        let mut val = self
+            .get_vertical_line(line)
+            .copied()
+            .unwrap_or_else(VerticalLine::empty);
+        val.main = Some(c);
+
+        self.insert_vertical_line(line, val);
+    }
+
     /// Override a character on a horizontal line.
     ///
     /// If borders are not set the char won't be used.
@@ -300,6 +345,29 @@ impl SpannedConfig {
         self.horizontal_chars.remove(&pos);
     }
 
+    /// Set a text which is written on the bottom border line of a grid.
+    ///
+    /// The text replaces border characters, intersections included,
+    /// starting from an [`Offset::Begin`] or ending at an [`Offset::End`] of the line.
+    /// A text which doesn't fit into the line is truncated.
+    ///
+    /// If the bottom border is not set the text won't be used.
+    pub fn set_bottom_border_text(&mut self, text: String, offset: Offset) {
+        self.bottom_border_text = Some((text, offset));
+    }
+
+    /// Get a text which is written on the bottom border line of a grid.
+    pub fn get_bottom_border_text(&self) -> Option<(&str, Offset)> {
+        self.bottom_border_text
+            .as_ref()
+            .map(|(text, offset)| (text.as_str(), *offset))
+    }
+
+    /// Removes a text which is written on the bottom border line of a grid.
+    pub fn remove_bottom_border_text(&mut self) {
+        self.bottom_border_text = None;
+    }
+
     /// Override a vertical split line.
     ///
     /// If borders are not set the char won't be used.
@@ -433,6 +501,11 @@ impl SpannedConfig {
         *self.padding.get(pos)
     }
 
+    /// Get a padding which is used for all cells without a specific one.
+    pub fn get_padding_default(&self) -> Sides<Indent> {
+        *self.padding.get_global()
+    }
+
     /// Get a padding color for a given cell by [Position].
     pub fn get_padding_color(&self, pos: Position) -> Sides<Option<ANSIBuf>> {
         self.padding_color.get(pos).clone()
@@ -487,6 +560,11 @@ impl SpannedConfig {
         self.alignment_v.get(pos)
     }
 
+    /// Get a vertical alignment which is used for all cells without a specific one.
+    pub fn get_alignment_vertical_default(&self) -> &AlignmentVertical {
+        self.alignment_v.get_global()
+    }
+
     /// Set a horizontal alignment to a given cells.
     pub fn set_alignment_horizontal(&mut self, entity: Entity, alignment: AlignmentHorizontal) {
         self.alignment_h.insert(entity, alignment);
@@ -497,6 +575,11 @@ impl SpannedConfig {
         self.alignment_h.get(pos)
     }
 
+    /// Get a horizontal alignment which is used for all cells without a specific one.
+    pub fn get_alignment_horizontal_default(&self) -> &AlignmentHorizontal {
+        self.alignment_h.get_global()
+    }
+
     /// Set border set a border value to all cells in [`Entity`].
     pub fn set_border(&mut self, pos: Position, border: Border<char>) {
         self.borders.insert_border(pos, border);
@@ -524,6 +607,27 @@ impl SpannedConfig {
         self.borders_missing_char
     }
 
+    /// Set a function which will be used to choose an intersection character
+    /// in case it was not set explicitly.
+    ///
+    /// The function receives whether there's a border line going up, down, left and right from the intersection.
+    /// It's called only for real junctions, meaning when lines are present on at least 2 sides.
+    ///
+    /// Otherwise a [`SpannedConfig::get_borders_missing`] character is used.
+    pub fn set_intersection_resolver(&mut self, resolver: IntersectionResolver) {
+        self.intersection_resolver = Some(Resolver(resolver));
+    }
+
+    /// Get a function which will be used to choose an intersection character, if any is set.
+    pub fn get_intersection_resolver(&self) -> Option<IntersectionResolver> {
+        self.intersection_resolver.map(|r| r.0)
+    }
+
+    /// Removes an intersection resolver.
+    pub fn remove_intersection_resolver(&mut self) {
+        self.intersection_resolver = None;
+    }
+
     /// Gets a color of all borders on the grid.
     pub fn get_border_color_default(&self) -> Option<&ANSIBuf> {
         self.borders_colors.get_global()
@@ -590,6 +694,21 @@ impl SpannedConfig {
         self.justification_color.insert(entity, color);
     }
 
+    /// Get a fill override of a cell, if any is set.
+    ///
+    /// When set a whole cell body (its content and padding) is rendered with the character.
+    pub fn get_fill_override(&self, pos: Position) -> Option<char> {
+        *self.fill_override.get(pos)
+    }
+
+    /// Set a fill override which replaces a cell body while rendering.
+    ///
+    /// Borders are not affected.
+    /// `None` removes it.
+    pub fn set_fill_override(&mut self, entity: Entity, c: Option<char>) {
+        self.fill_override.insert(entity, c);
+    }
+
     /// Get a span value of the cell, if any is set.
     pub fn get_column_spans(&self) -> HashMap<Position, usize> {
         self.span_columns.clone()
@@ -620,6 +739,211 @@ impl SpannedConfig {
         self.span_rows.clear()
     }
 
+    /// Removes settings of a column, shifting settings of the following columns to the left.
+    ///
+    /// It's supposed to be called once a column was removed from records,
+    /// so the settings are kept aligned with the content.
+    ///
+    /// A span which starts at the column is removed, and a span which covers it is shrunk.
+    /// Borders and lines set for particular cells are not moved.
+    pub fn remove_column(&mut self, col: usize) {
+        self.padding.remove_column(col);
+        self.padding_color.remove_column(col);
+        self.alignment_h.remove_column(col);
+        self.alignment_v.remove_column(col);
+        self.formatting_trim_h.remove_column(col);
+        self.formatting_trim_v.remove_column(col);
+        self.formatting_line_alignment.remove_column(col);
+        self.justification.remove_column(col);
+        self.justification_color.remove_column(col);
+        self.fill_override.remove_column(col);
+
+        self.span_columns = shrink_spans(&self.span_columns, col, |p| p.col(), |p, c| (p.row(), c));
+        self.span_rows = self
+            .span_rows
+            .iter()
+            .filter(|(p, _)| p.col() != col)
+            .map(|(p, &span)| match p.col() > col {
+                true => (Position::new(p.row(), p.col() - 1), span),
+                false => (*p, span),
+            })
+            .collect();
+    }
+
+    /// Shifts settings of a column and the following columns to the right.
+    ///
+    /// It's supposed to be called once a column was inserted into records,
+    /// so the settings are kept aligned with the content.
+    ///
+    /// A span which starts at or after the column is moved,
+    /// and a span which covers the column is expanded so it still covers the same cells.
+    /// Borders and lines set for particular cells are not moved.
+    pub fn insert_column(&mut self, col: usize) {
+        self.padding.insert_column(col);
+        self.padding_color.insert_column(col);
+        self.alignment_h.insert_column(col);
+        self.alignment_v.insert_column(col);
+        self.formatting_trim_h.insert_column(col);
+        self.formatting_trim_v.insert_column(col);
+        self.formatting_line_alignment.insert_column(col);
+        self.justification.insert_column(col);
+        self.justification_color.insert_column(col);
+        self.fill_override.insert_column(col);
+
+        self.span_columns = expand_spans(&self.span_columns, col, |p| p.col(), |p, c| (p.row(), c));
+        self.span_rows = self
+            .span_rows
+            .iter()
+            .map(|(p, &span)| match p.col() >= col {
+                true => (Position::new(p.row(), p.col() + 1), span),
+                false => (*p, span),
+            })
+            .collect();
+    }
+
+    /// Mirrors settings of columns, so a column `i` becomes a column `count_columns - 1 - i`.
+    ///
+    /// Left and right paddings are swapped and horizontal alignments are flipped.
+    /// Spans are moved so they cover the mirrored columns.
+    /// Borders and lines are not changed.
+    pub fn reverse_columns(&mut self, count_columns: usize) {
+        self.padding.reverse_columns(count_columns);
+        self.padding_color.reverse_columns(count_columns);
+        self.alignment_h.reverse_columns(count_columns);
+        self.alignment_v.reverse_columns(count_columns);
+        self.formatting_trim_h.reverse_columns(count_columns);
+        self.formatting_trim_v.reverse_columns(count_columns);
+        self.formatting_line_alignment
+            .reverse_columns(count_columns);
+        self.justification.reverse_columns(count_columns);
+        self.justification_color.reverse_columns(count_columns);
+        self.fill_override.reverse_columns(count_columns);
+
+        for padding in self.padding.values_mut() {
+            std::mem::swap(&mut padding.left, &mut padding.right);
+        }
+
+        for padding in self.padding_color.values_mut() {
+            std::mem::swap(&mut padding.left, &mut padding.right);
+        }
+
+        for alignment in self.alignment_h.values_mut() {
+            *alignment = match alignment {
+                AlignmentHorizontal::Left => AlignmentHorizontal::Right,
+                AlignmentHorizontal::Right => AlignmentHorizontal::Left,
+                AlignmentHorizontal::Center => AlignmentHorizontal::Center,
+            };
+        }
+
+        let mirror = |pos: &Position, span: usize| match pos.col() + span <= count_columns {
+            true => Position::new(pos.row(), count_columns - pos.col() - span),
+            false => *pos,
+        };
+
+        // a cell spanned in both directions must keep the same origin
+        self.span_rows = self
+            .span_rows
+            .iter()
+            .map(|(pos, &span)| {
+                let span_columns = self.span_columns.get(pos).copied().unwrap_or(1);
+                (mirror(pos, span_columns), span)
+            })
+            .collect();
+        self.span_columns = self
+            .span_columns
+            .iter()
+            .map(|(pos, &span)| (mirror(pos, span), span))
+            .collect();
+    }
+
+    /// Removes settings of a row, shifting settings of the following rows up.
+    ///
+    /// It's supposed to be called once a row was removed from records,
+    /// so the settings are kept aligned with the content.
+    ///
+    /// A span which starts at the row is removed, and a span which covers it is shrunk.
+    /// Borders and lines set for particular cells are not moved.
+    pub fn remove_row(&mut self, row: usize) {
+        self.padding.remove_row(row);
+        self.padding_color.remove_row(row);
+        self.alignment_h.remove_row(row);
+        self.alignment_v.remove_row(row);
+        self.formatting_trim_h.remove_row(row);
+        self.formatting_trim_v.remove_row(row);
+        self.formatting_line_alignment.remove_row(row);
+        self.justification.remove_row(row);
+        self.justification_color.remove_row(row);
+        self.fill_override.remove_row(row);
+
+        self.span_rows = shrink_spans(&self.span_rows, row, |p| p.row(), |p, r| (r, p.col()));
+        self.span_columns = self
+            .span_columns
+            .iter()
+            .filter(|(p, _)| p.row() != row)
+            .map(|(p, &span)| match p.row() > row {
+                true => (Position::new(p.row() - 1, p.col()), span),
+                false => (*p, span),
+            })
+            .collect();
+    }
+
+    /// Shifts settings of a row and the following rows down.
+    ///
+    /// It's supposed to be called once a row was inserted into records,
+    /// so the settings are kept aligned with the content.
+    ///
+    /// A span which starts at or after the row is moved,
+    /// and a span which covers the row is expanded so it still covers the same cells.
+    /// Borders and lines set for particular cells are not moved.
+    pub fn insert_row(&mut self, row: usize) {
+        self.padding.insert_row(row);
+        self.padding_color.insert_row(row);
+        self.alignment_h.insert_row(row);
+        self.alignment_v.insert_row(row);
+        self.formatting_trim_h.insert_row(row);
+        self.formatting_trim_v.insert_row(row);
+        self.formatting_line_alignment.insert_row(row);
+        self.justification.insert_row(row);
+        self.justification_color.insert_row(row);
+        self.fill_override.insert_row(row);
+
+        self.span_rows = expand_spans(&self.span_rows, row, |p| p.row(), |p, r| (r, p.col()));
+        self.span_columns = self
+            .span_columns
+            .iter()
+            .map(|(p, &span)| match p.row() >= row {
+                true => (Position::new(p.row() + 1, p.col()), span),
+                false => (*p, span),
+            })
+            .collect();
+    }
+
+    /// Copies settings of a row to another row.
+    ///
+    /// Column spans set in the row are copied as well.
+    /// Borders and lines set for particular cells are not copied.
+    pub fn copy_row(&mut self, from: usize, to: usize) {
+        self.padding.copy_row(from, to);
+        self.padding_color.copy_row(from, to);
+        self.alignment_h.copy_row(from, to);
+        self.alignment_v.copy_row(from, to);
+        self.formatting_trim_h.copy_row(from, to);
+        self.formatting_trim_v.copy_row(from, to);
+        self.formatting_line_alignment.copy_row(from, to);
+        self.justification.copy_row(from, to);
+        self.justification_color.copy_row(from, to);
+        self.fill_override.copy_row(from, to);
+
+        self.span_columns.retain(|pos, _| pos.row() != to);
+        let spans = self
+            .span_columns
+            .iter()
+            .filter(|(pos, _)| pos.row() == from)
+            .map(|(pos, &span)| (Position::new(to, pos.col()), span))
+            .collect::<Vec<_>>();
+        self.span_columns.extend(spans);
+    }
+
     /// Set a column span to a given cells.
     ///
     /// BEWARE
@@ -630,6 +954,24 @@ impl SpannedConfig {
         set_cell_column_span(self, pos, span);
     }
 
+    /// Set a column span to a given cell,
+    /// verifying that it fits into a grid of a given shape (count rows, count columns)
+    /// and doesn't intersect with other spans.
+    ///
+    /// In case of an error the configuration is not changed.
+    pub fn try_set_column_span(
+        &mut self,
+        pos: Position,
+        span: usize,
+        shape: (usize, usize),
+    ) -> Result<(), SpanError> {
+        let row_span = self.get_row_span(pos).unwrap_or(1);
+        verify_span(self, pos, (row_span, span), shape)?;
+        set_cell_column_span(self, pos, span);
+
+        Ok(())
+    }
+
     /// Verifies if there's any spans set.
     pub fn has_column_spans(&self) -> bool {
         !self.span_columns.is_empty()
@@ -645,6 +987,24 @@ impl SpannedConfig {
         set_cell_row_span(self, pos, span);
     }
 
+    /// Set a row span to a given cell,
+    /// verifying that it fits into a grid of a given shape (count rows, count columns)
+    /// and doesn't intersect with other spans.
+    ///
+    /// In case of an error the configuration is not changed.
+    pub fn try_set_row_span(
+        &mut self,
+        pos: Position,
+        span: usize,
+        shape: (usize, usize),
+    ) -> Result<(), SpanError> {
+        let column_span = self.get_column_span(pos).unwrap_or(1);
+        verify_span(self, pos, (span, column_span), shape)?;
+        set_cell_row_span(self, pos, span);
+
+        Ok(())
+    }
+
     /// Verifies if there's any spans set.
     pub fn has_row_spans(&self) -> bool {
         !self.span_rows.is_empty()
@@ -667,6 +1027,11 @@ impl SpannedConfig {
             || self.justification_color.as_ref().is_some()
     }
 
+    /// Verifies if there's any fill override set.
+    pub fn has_fill_override(&self) -> bool {
+        !self.fill_override.is_empty() || self.fill_override.as_ref().is_some()
+    }
+
     /// Verifies if there's any custom padding set.
     pub fn has_padding(&self) -> bool {
         !self.padding.is_empty()
@@ -716,6 +1081,12 @@ impl SpannedConfig {
             return Some(*c);
         }
 
+        if let Some(Resolver(resolver)) = self.intersection_resolver {
+            if let Some(c) = resolve_intersection(self, resolver, pos, shape) {
+                return Some(c);
+            }
+        }
+
         if self.has_horizontal(pos.row(), shape.0) && self.has_vertical(pos.col(), shape.1) {
             return Some(self.get_borders_missing());
         }
@@ -754,6 +1125,74 @@ impl SpannedConfig {
         None
     }
 
+    /// Returns a map of border characters which would be rendered on a grid of a given shape.
+    ///
+    /// It resolves each character the way a grid does,
+    /// including overrides, an intersection resolver and a missing character.
+    /// It's handy for debugging, as it shows what's actually configured.
+    ///
+    /// Each line of the map is a horizontal line or a row of cells.
+    /// Borders which are not set are shown as `' '`, while cells are shown as `'.'`.
+    /// Spans and margin are not considered.
+    ///
+    /// ```
+    /// use papergrid::config::{spanned::SpannedConfig, Borders};
+    ///
+    /// let mut cfg = SpannedConfig::default();
+    /// cfg.set_borders(Borders {
+    ///     top: Some('-'),
+    ///     bottom: Some('-'),
+    ///     left: Some('|'),
+    ///     right: Some('|'),
+    ///     vertical: Some('|'),
+    ///     ..Default::default()
+    /// });
+    /// cfg.set_borders_missing('+');
+    ///
+    /// assert_eq!(
+    ///     cfg.describe_borders((1, 2)),
+    ///     "+-+-+\n\
+    ///      |.|.|\n\
+    ///      +-+-+"
+    /// );
+    /// ```
+    pub fn describe_borders(&self, shape: (usize, usize)) -> String {
+        let (count_rows, count_cols) = shape;
+
+        let mut buf = String::new();
+        for row in 0..=count_rows {
+            if row > 0 {
+                buf.push('\n');
+            }
+
+            for col in 0..=count_cols {
+                let pos = Position::new(row, col);
+                buf.push(self.get_intersection(pos, shape).unwrap_or(' '));
+
+                if col < count_cols {
+                    buf.push(self.get_horizontal(pos, count_rows).unwrap_or(' '));
+                }
+            }
+
+            if row == count_rows {
+                break;
+            }
+
+            buf.push('\n');
+
+            for col in 0..=count_cols {
+                let pos = Position::new(row, col);
+                buf.push(self.get_vertical(pos, count_cols).unwrap_or(' '));
+
+                if col < count_cols {
+                    buf.push('.');
+                }
+            }
+        }
+
+        buf
+    }
+
     /// Gets a color of a cell horizontal.
     pub fn get_horizontal_color(&self, pos: Position, count_rows: usize) -> Option<&ANSIBuf> {
         self.borders_colors.get_horizontal(pos, count_rows)
@@ -883,6 +1322,88 @@ fn set_cell_column_span(cfg: &mut SpannedConfig, pos: Position, span: usize) {
     cfg.span_columns.insert(pos, span);
 }
 
+fn verify_span(
+    cfg: &SpannedConfig,
+    pos: Position,
+    (row_span, column_span): (usize, usize),
+    (count_rows, count_columns): (usize, usize),
+) -> Result<(), SpanError> {
+    if row_span == 0 || column_span == 0 {
+        return Err(SpanError::Zero);
+    }
+
+    if pos.row() + row_span > count_rows || pos.col() + column_span > count_columns {
+        return Err(SpanError::OutOfBounds);
+    }
+
+    if is_cell_covered_by_column_span(cfg, pos)
+        || is_cell_covered_by_row_span(cfg, pos)
+        || is_cell_covered_by_both_spans(cfg, pos)
+    {
+        return Err(SpanError::Overlap);
+    }
+
+    for row in pos.row()..pos.row() + row_span {
+        for col in pos.col()..pos.col() + column_span {
+            let p = Position::new(row, col);
+            if p == pos {
+                continue;
+            }
+
+            let is_span_origin =
+                cfg.span_columns.contains_key(&p) || cfg.span_rows.contains_key(&p);
+            if is_span_origin || is_cell_covered_by_other_span(cfg, pos, p) {
+                return Err(SpanError::Overlap);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_cell_covered_by_other_span(cfg: &SpannedConfig, origin: Position, pos: Position) -> bool {
+    cfg.span_columns
+        .keys()
+        .chain(cfg.span_rows.keys())
+        .filter(|&&p| p != origin)
+        .any(|&p| {
+            let row_span = cfg.get_row_span(p).unwrap_or(1);
+            let column_span = cfg.get_column_span(p).unwrap_or(1);
+
+            pos.row() >= p.row()
+                && pos.row() < p.row() + row_span
+                && pos.col() >= p.col()
+                && pos.col() < p.col() + column_span
+        })
+}
+
+fn resolve_intersection(
+    cfg: &SpannedConfig,
+    resolver: IntersectionResolver,
+    pos: Position,
+    (count_rows, count_columns): (usize, usize),
+) -> Option<char> {
+    let (row, col) = (pos.row(), pos.col());
+
+    let up = row > 0
+        && cfg
+            .get_vertical((row - 1, col).into(), count_columns)
+            .is_some();
+    let down = row < count_rows && cfg.get_vertical(pos, count_columns).is_some();
+    let left = col > 0
+        && cfg
+            .get_horizontal((row, col - 1).into(), count_rows)
+            .is_some();
+    let right = col < count_columns && cfg.get_horizontal(pos, count_rows).is_some();
+
+    let count_sides = [up, down, left, right].iter().filter(|&&b| b).count();
+    if count_sides < 2 {
+        return None;
+    }
+
+    Some(resolver(up, down, left, right))
+}
+
 fn is_cell_covered_by_column_span(cfg: &SpannedConfig, pos: Position) -> bool {
     cfg.span_columns
         .iter()
@@ -933,3 +1454,63 @@ impl Default for MarginIndent {
         }
     }
 }
+
+/// A wrapper around [`IntersectionResolver`] which is compared by a function address.
+#[derive(Debug, Clone, Copy)]
+struct Resolver(IntersectionResolver);
+
+impl PartialEq for Resolver {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+impl Eq for Resolver {}
+
+fn expand_spans(
+    spans: &HashMap<Position, usize>,
+    inserted: usize,
+    get_index: impl Fn(Position) -> usize,
+    set_index: impl Fn(Position, usize) -> (usize, usize),
+) -> HashMap<Position, usize> {
+    spans
+        .iter()
+        .map(|(&pos, &span)| {
+            let index = get_index(pos);
+            if index >= inserted {
+                (set_index(pos, index + 1).into(), span)
+            } else if index + span > inserted {
+                (pos, span + 1)
+            } else {
+                (pos, span)
+            }
+        })
+        .collect()
+}
+
+fn shrink_spans(
+    spans: &HashMap<Position, usize>,
+    removed: usize,
+    get_index: impl Fn(Position) -> usize,
+    set_index: impl Fn(Position, usize) -> (usize, usize),
+) -> HashMap<Position, usize> {
+    let mut result = HashMap::with_capacity(spans.len());
+    for (&pos, &span) in spans {
+        let index = get_index(pos);
+        let (pos, span) = if index == removed {
+            continue;
+        } else if index > removed {
+            (set_index(pos, index - 1).into(), span)
+        } else if index + span > removed {
+            (pos, span - 1)
+        } else {
+            (pos, span)
+        };
+
+        if span > 1 {
+            let _ = result.insert(pos, span);
+        }
+    }
+
+    result
+}
@@ -0,0 +1,26 @@
+use core::fmt::{self, Display};
+
+/// An error which may happen on a span set in [`SpannedConfig`].
+///
+/// [`SpannedConfig`]: crate::config::spanned::SpannedConfig
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpanError {
+    /// A span value is 0 which is not supported.
+    Zero,
+    /// A span goes beyond the grid.
+    OutOfBounds,
+    /// A span intersects with another span.
+    Overlap,
+}
+
+impl Display for SpanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpanError::Zero => f.write_str("a span must be greater than 0"),
+            SpanError::OutOfBounds => f.write_str("a span goes beyond the grid"),
+            SpanError::Overlap => f.write_str("a span intersects with another span"),
+        }
+    }
+}
+
+impl std::error::Error for SpanError {}
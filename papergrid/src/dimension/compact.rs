@@ -17,7 +17,7 @@ use crate::config::compact::CompactConfig;
 /// [`Grid`]: crate::grid::iterable::Grid
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct CompactGridDimension {
-    height: usize,
+    height: Vec<usize>,
     width: Vec<usize>,
 }
 
@@ -55,8 +55,8 @@ impl Dimension for CompactGridDimension {
         self.width[column]
     }
 
-    fn get_height(&self, _: usize) -> usize {
-        self.height
+    fn get_height(&self, row: usize) -> usize {
+        self.height[row]
     }
 }
 
@@ -66,9 +66,9 @@ where
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
 {
     fn estimate(&mut self, records: R, cfg: &CompactConfig) {
-        self.width = build_width(records, cfg);
-        let pad = cfg.get_padding();
-        self.height = 1 + pad.top.size + pad.bottom.size;
+        let (width, height) = build_dims(records, cfg);
+        self.width = width;
+        self.height = height;
     }
 }
 
@@ -5,12 +5,13 @@
 use std::{
     cmp::{max, Ordering},
     collections::HashMap,
+    iter::repeat_n,
 };
 
 use crate::{
     config::Position,
     dimension::{Dimension, Estimate},
-    records::{IntoRecords, Records},
+    records::{ExactRecords, IntoRecords, IterRecords, PeekableRecords, Records},
     util::string::{count_lines, get_text_dimension, get_text_width},
 };
 
@@ -53,6 +54,33 @@ impl SpannedGridDimension {
         get_width_total(records, cfg)
     }
 
+    /// Calculates width of columns,
+    /// narrowing them so a total width of a grid is not bigger than a given one.
+    ///
+    /// A column to narrow is chosen by a `peak` function one character at a time;
+    /// it gets minimum widths of columns and their current widths,
+    /// and returns a column index or [`None`] to stop.
+    /// A column is never narrowed below its minimum width,
+    /// which is a width of its padding and spans.
+    pub fn width_within<R, F>(records: R, cfg: &SpannedConfig, width: usize, peak: F) -> Vec<usize>
+    where
+        R: Records,
+        <R::Iter as IntoRecords>::Cell: AsRef<str>,
+        F: FnMut(&[usize], &[usize]) -> Option<usize>,
+    {
+        let (mut widths, count_rows) = match records.hint_count_rows() {
+            Some(count_rows) => (build_width(records, cfg), count_rows),
+            None => {
+                let (widths, heights) = build_dimensions(records, cfg);
+                (widths, heights.len())
+            }
+        };
+
+        shrink_widths(&mut widths, count_rows, cfg, width, peak);
+
+        widths
+    }
+
     /// Calculates height of rows.
     pub fn height_total<R>(records: R, cfg: &SpannedConfig) -> usize
     where
@@ -62,6 +90,63 @@ impl SpannedGridDimension {
         get_height_total(records, cfg)
     }
 
+    /// Updates a width of a column and a height of a row of a given cell.
+    ///
+    /// It's supposed to be used after a content of a single cell was changed,
+    /// so the whole grid is not estimated again.
+    /// The result is the same as of a full [`Estimate::estimate`].
+    ///
+    /// Notice that if the config has any spans the dimension is fully recalculated,
+    /// because a spanned cell affects other columns and rows.
+    /// The same happens if the dimension was not estimated for records of this shape.
+    pub fn update_cell<R>(&mut self, pos: Position, records: R, cfg: &SpannedConfig)
+    where
+        R: Records + ExactRecords + PeekableRecords,
+        <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let is_estimated = self.width.len() == count_columns && self.height.len() == count_rows;
+        let has_spans = cfg.has_column_spans() || cfg.has_row_spans();
+        if !is_estimated || has_spans || !pos.is_covered((count_rows, count_columns).into()) {
+            self.estimate(records, cfg);
+            return;
+        }
+
+        let (row, col) = pos.into();
+
+        self.width[col] = (0..count_rows)
+            .map(|row| get_cell_dimension(&records, cfg, (row, col).into()).1)
+            .max()
+            .unwrap_or(0);
+
+        self.height[row] = (0..count_columns)
+            .map(|col| get_cell_dimension(&records, cfg, (row, col).into()).0)
+            .max()
+            .unwrap_or(0);
+    }
+
+    /// Estimates a dimension like [`Estimate::estimate`] does,
+    /// but the widths are narrowed so a total width of a grid is not bigger than a given one.
+    ///
+    /// Heights are estimated for the records as they are,
+    /// so the content is expected to be fit into the widths afterwards (wrapped or truncated).
+    ///
+    /// See [`SpannedGridDimension::width_within`].
+    pub fn estimate_within<R, F>(&mut self, records: R, cfg: &SpannedConfig, width: usize, peak: F)
+    where
+        R: Records,
+        <R::Iter as IntoRecords>::Cell: AsRef<str>,
+        F: FnMut(&[usize], &[usize]) -> Option<usize>,
+    {
+        let (mut widths, height) = build_dimensions(records, cfg);
+        shrink_widths(&mut widths, height.len(), cfg, width, peak);
+
+        self.width = widths;
+        self.height = height;
+    }
+
     /// Return width and height lists.
     pub fn get_values(self) -> (Vec<usize>, Vec<usize>) {
         (self.width, self.height)
@@ -90,6 +175,60 @@ where
     }
 }
 
+// It's expected to give the same result as estimating widths and then narrowing them,
+// like it's done by `Width::wrap` in `tabled`.
+fn shrink_widths<F>(
+    widths: &mut [usize],
+    count_rows: usize,
+    cfg: &SpannedConfig,
+    width: usize,
+    mut peak: F,
+) where
+    F: FnMut(&[usize], &[usize]) -> Option<usize>,
+{
+    let mut total = get_total_width(widths, cfg);
+    if total <= width {
+        return;
+    }
+
+    let count_columns = widths.len();
+    let empty = repeat_n(repeat_n("", count_columns), count_rows);
+    let min_widths = build_width(
+        IterRecords::new(empty, count_columns, Some(count_rows)),
+        cfg,
+    );
+
+    let mut count_narrowest = (0..count_columns)
+        .filter(|&col| widths[col] == 0 || widths[col] <= min_widths[col])
+        .count();
+
+    while total > width && count_narrowest != count_columns {
+        let col = match peak(&min_widths, widths) {
+            Some(col) => col,
+            None => break,
+        };
+
+        if widths[col] == 0 || widths[col] <= min_widths[col] {
+            continue;
+        }
+
+        widths[col] -= 1;
+        total -= 1;
+
+        if widths[col] == 0 || widths[col] <= min_widths[col] {
+            count_narrowest += 1;
+        }
+    }
+}
+
+fn get_total_width(widths: &[usize], cfg: &SpannedConfig) -> usize {
+    let margin = cfg.get_margin();
+    widths.iter().sum::<usize>()
+        + cfg.count_vertical(widths.len())
+        + margin.left.size
+        + margin.right.size
+}
+
 fn build_dimensions<R>(records: R, cfg: &SpannedConfig) -> (Vec<usize>, Vec<usize>)
 where
     R: Records,
@@ -192,6 +331,18 @@ fn range_height(
     count_borders + range_height
 }
 
+fn get_cell_dimension<R>(records: &R, cfg: &SpannedConfig, pos: Position) -> (usize, usize)
+where
+    R: PeekableRecords,
+{
+    let (height, width) = get_text_dimension(records.get_text(pos));
+    let pad = cfg.get_padding(pos);
+    let width = width + pad.left.size + pad.right.size;
+    let height = height + pad.top.size + pad.bottom.size;
+
+    (height, width)
+}
+
 fn count_horizontal_borders(cfg: &SpannedConfig, len: usize, start: usize, end: usize) -> usize {
     (start..end)
         .skip(1)
@@ -43,6 +43,30 @@ impl SpannedVecRecordsDimension {
         build_width(records, cfg)
     }
 
+    /// Calculates width of a single column.
+    ///
+    /// Column spans are not taken into account,
+    /// so the value is exact only when there's no spans in the column.
+    pub fn column_width<T: Cell + AsRef<str>>(
+        records: &VecRecords<T>,
+        cfg: &SpannedConfig,
+        column: usize,
+    ) -> usize {
+        build_column_width(records, cfg, column)
+    }
+
+    /// Calculates height of a single row.
+    ///
+    /// Row spans are not taken into account,
+    /// so the value is exact only when there's no spans in the row.
+    pub fn row_height<T: Cell + AsRef<str>>(
+        records: &VecRecords<T>,
+        cfg: &SpannedConfig,
+        row: usize,
+    ) -> usize {
+        build_row_height(records, cfg, row)
+    }
+
     /// Return width and height lists.
     pub fn get_values(self) -> (Vec<usize>, Vec<usize>) {
         (self.width, self.height)
@@ -268,6 +292,54 @@ fn count_vertical_borders(cfg: &SpannedConfig, len: usize, start: usize, end: us
         .count()
 }
 
+fn build_row_height<T: Cell + AsRef<str>>(
+    records: &VecRecords<T>,
+    cfg: &SpannedConfig,
+    row: usize,
+) -> usize {
+    let columns = match records.get(row) {
+        Some(columns) => columns,
+        None => return 0,
+    };
+
+    let mut height = 0;
+    for (col, cell) in columns.iter().enumerate() {
+        let pos = (row, col).into();
+        if !cfg.is_cell_visible(pos) {
+            continue;
+        }
+
+        let cell_height = cell.count_lines() + get_cell_vertical_padding(cfg, pos);
+        height = max(height, cell_height);
+    }
+
+    height
+}
+
+fn build_column_width<T: Cell + AsRef<str>>(
+    records: &VecRecords<T>,
+    cfg: &SpannedConfig,
+    column: usize,
+) -> usize {
+    let mut width = 0;
+    for (row, columns) in records.iter_rows().enumerate() {
+        let cell = match columns.get(column) {
+            Some(cell) => cell,
+            None => continue,
+        };
+
+        let pos = (row, column).into();
+        if !cfg.is_cell_visible(pos) {
+            continue;
+        }
+
+        let cell_width = cell.width() + get_cell_padding_horizontal(cfg, pos);
+        width = max(width, cell_width);
+    }
+
+    width
+}
+
 fn build_height<T: Cell + AsRef<str>>(records: &VecRecords<T>, cfg: &SpannedConfig) -> Vec<usize> {
     let mut heights = vec![];
     let mut hspans = HashMap::new();
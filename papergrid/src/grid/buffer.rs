@@ -0,0 +1,325 @@
+//! The module contains a [`BufferGrid`] structure.
+
+use core::borrow::Borrow;
+use std::fmt::{self, Write};
+
+use crate::{
+    ansi::ANSIBuf,
+    colors::Colors,
+    config::{spanned::SpannedConfig, Position},
+    dimension::Dimension,
+    grid::peekable::PeekableGrid,
+    records::{
+        vec_records::{Text, VecRecords},
+        ExactRecords, PeekableRecords, Records,
+    },
+    util::string::get_char_width,
+};
+
+/// A char which is put into the cells covered by a wide character,
+/// except the first one where the character itself is put.
+pub const WIDE_CHAR_TAIL: char = '\0';
+
+/// A cell of a buffer built by [`BufferGrid`].
+pub type BufferCell = (char, Option<ANSIBuf>);
+
+/// Grid renders a table into a 2D buffer of characters instead of a string.
+///
+/// Each row of a buffer is a line of a table including borders, margin, etc.
+/// Each cell of a row takes exactly 1 column of a terminal,
+/// so a character which is wider takes several cells;
+/// the first one keeps the character and the rest are filled with [`WIDE_CHAR_TAIL`].
+/// Zero width characters are dropped.
+///
+/// Colors are taken from the SGR sequences written by the grid,
+/// so a color is kept for a content from the [`Colors`] map as well as for borders, padding, etc.
+/// Other ANSI sequences (like OSC 8 hyperlinks) are dropped,
+/// as well as any ANSI sequences which are part of a content.
+#[derive(Debug, Clone)]
+pub struct BufferGrid<R, G, D, C> {
+    records: R,
+    config: G,
+    dimension: D,
+    colors: C,
+}
+
+impl<R, G, D, C> BufferGrid<R, G, D, C> {
+    /// The new method creates a grid instance with default styles.
+    pub fn new(records: R, config: G, dimension: D, colors: C) -> Self {
+        Self {
+            records,
+            config,
+            dimension,
+            colors,
+        }
+    }
+
+    /// Builds a table into a buffer.
+    pub fn build(self) -> Vec<Vec<BufferCell>>
+    where
+        R: Records + PeekableRecords + ExactRecords,
+        D: Dimension,
+        G: Borrow<SpannedConfig>,
+        C: Colors,
+    {
+        let count_rows = self.records.count_rows();
+        let count_cols = self.records.count_columns();
+
+        let mut data = Vec::with_capacity(count_rows);
+        for row in 0..count_rows {
+            let mut line = Vec::with_capacity(count_cols);
+            for col in 0..count_cols {
+                let text = self.records.get_text(Position::new(row, col));
+
+                // so any ANSI sequence written by a grid is a color of a grid not of a content
+                let text = strip_ansi_sequences(text);
+                line.push(Text::new(text));
+            }
+
+            data.push(line);
+        }
+
+        let records = VecRecords::new(data);
+        let grid = PeekableGrid::new(&records, self.config, self.dimension, self.colors);
+
+        let mut buf = BufferWriter::new();
+        grid.build(&mut buf).expect("It's guaranteed to never happen otherwise it's considered an stdlib error or impl error");
+        buf.into_buffer()
+    }
+}
+
+#[derive(Debug, Default)]
+struct BufferWriter {
+    lines: Vec<Vec<BufferCell>>,
+    line: Vec<BufferCell>,
+    parser: AnsiParser,
+    style: SgrStyle,
+    color: Option<ANSIBuf>,
+}
+
+impl BufferWriter {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn push_char(&mut self, c: char) {
+        if c == '\n' {
+            let line = std::mem::take(&mut self.line);
+            self.lines.push(line);
+            return;
+        }
+
+        let width = get_char_width(c);
+        if width == 0 {
+            return;
+        }
+
+        self.line.push((c, self.color.clone()));
+        for _ in 1..width {
+            self.line.push((WIDE_CHAR_TAIL, self.color.clone()));
+        }
+    }
+
+    fn into_buffer(mut self) -> Vec<Vec<BufferCell>> {
+        if !self.line.is_empty() {
+            self.lines.push(self.line);
+        }
+
+        self.lines
+    }
+}
+
+impl Write for BufferWriter {
+    // A sequence is parsed char by char,
+    // so it doesn't matter how a grid splits it between the calls.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match self.parser.next(c) {
+                Some(Token::Char(c)) => self.push_char(c),
+                Some(Token::Sgr(params)) => {
+                    self.style.apply(&params);
+                    self.color = self.style.to_color();
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn strip_ansi_sequences(text: &str) -> String {
+    let mut parser = AnsiParser::default();
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        if let Some(Token::Char(c)) = parser.next(c) {
+            buf.push(c);
+        }
+    }
+
+    buf
+}
+
+#[derive(Debug)]
+enum Token {
+    Char(char),
+    Sgr(String),
+}
+
+// A parser of ANSI escape sequences.
+//
+// It recognizes CSI sequences, OSC sequences terminated either by BEL or by ST,
+// and 2 char escape sequences.
+// Only SGR sequences (the ones which set colors) are returned, the rest are dropped.
+#[derive(Debug, Default)]
+enum AnsiParser {
+    #[default]
+    Text,
+    Escape,
+    Csi(String),
+    Osc,
+    OscEscape,
+}
+
+impl AnsiParser {
+    fn next(&mut self, c: char) -> Option<Token> {
+        const ESC: char = '\u{1b}';
+        const BEL: char = '\u{7}';
+
+        match self {
+            AnsiParser::Text => match c {
+                ESC => *self = AnsiParser::Escape,
+                c => return Some(Token::Char(c)),
+            },
+            AnsiParser::Escape => match c {
+                '[' => *self = AnsiParser::Csi(String::new()),
+                ']' => *self = AnsiParser::Osc,
+                _ => *self = AnsiParser::Text,
+            },
+            AnsiParser::Csi(params) => {
+                if ('\u{40}'..='\u{7e}').contains(&c) {
+                    let params = std::mem::take(params);
+                    *self = AnsiParser::Text;
+
+                    if c == 'm' {
+                        return Some(Token::Sgr(params));
+                    }
+                } else {
+                    params.push(c);
+                }
+            }
+            AnsiParser::Osc => match c {
+                BEL => *self = AnsiParser::Text,
+                ESC => *self = AnsiParser::OscEscape,
+                _ => {}
+            },
+            AnsiParser::OscEscape => match c {
+                '\\' => *self = AnsiParser::Text,
+                ESC => {}
+                _ => *self = AnsiParser::Osc,
+            },
+        }
+
+        None
+    }
+}
+
+// A set of SGR attributes in use.
+//
+// Each attribute is stored together with a code which resets it,
+// so a color which turns the attributes on and off can be built back.
+#[derive(Debug, Default)]
+struct SgrStyle {
+    attrs: Vec<(u16, String)>,
+}
+
+impl SgrStyle {
+    fn apply(&mut self, params: &str) {
+        let mut params = params.split(';');
+        while let Some(param) = params.next() {
+            // a subparameter form (like `38:5:100`) keeps an attribute in a single parameter
+            let code = param.split(':').next().unwrap_or_default();
+            let code = if code.is_empty() {
+                0
+            } else {
+                match code.parse::<u16>() {
+                    Ok(code) => code,
+                    Err(_) => continue,
+                }
+            };
+
+            let mut attr = param.to_string();
+            if matches!(code, 38 | 48 | 58) && !param.contains(':') {
+                let count_args = match params.next() {
+                    Some("5") => {
+                        attr.push_str(";5");
+                        1
+                    }
+                    Some("2") => {
+                        attr.push_str(";2");
+                        3
+                    }
+                    _ => continue,
+                };
+
+                for arg in params.by_ref().take(count_args) {
+                    attr.push(';');
+                    attr.push_str(arg);
+                }
+            }
+
+            match code {
+                0 => self.attrs.clear(),
+                22..=25 | 27..=29 | 39 | 49 | 55 | 59 => self.attrs.retain(|(r, _)| *r != code),
+                code => {
+                    if let Some(reset) = sgr_reset_code(code) {
+                        self.attrs.retain(|(r, _)| *r != reset);
+                        self.attrs.push((reset, attr));
+                    }
+                }
+            }
+        }
+    }
+
+    fn to_color(&self) -> Option<ANSIBuf> {
+        if self.attrs.is_empty() {
+            return None;
+        }
+
+        let mut prefix = String::from("\u{1b}[");
+        let mut suffix = String::from("\u{1b}[");
+        for (i, (reset, attr)) in self.attrs.iter().enumerate() {
+            if i > 0 {
+                prefix.push(';');
+                suffix.push(';');
+            }
+
+            prefix.push_str(attr);
+            suffix.push_str(&reset.to_string());
+        }
+
+        prefix.push('m');
+        suffix.push('m');
+
+        Some(ANSIBuf::new(prefix, suffix))
+    }
+}
+
+fn sgr_reset_code(code: u16) -> Option<u16> {
+    let reset = match code {
+        1 | 2 => 22,
+        3 => 23,
+        4 | 21 => 24,
+        5 | 6 => 25,
+        7 => 27,
+        8 => 28,
+        9 => 29,
+        30..=38 | 90..=97 => 39,
+        40..=48 | 100..=107 => 49,
+        53 => 55,
+        58 => 59,
+        _ => return None,
+    };
+
+    Some(reset)
+}
@@ -15,6 +15,9 @@ use crate::{
     util::string::get_line_width,
 };
 
+#[cfg(feature = "std")]
+use crate::util::string::get_lines;
+
 use crate::config::compact::CompactConfig;
 
 /// Grid provides a set of methods for building a text-based table.
@@ -347,9 +350,9 @@ where
         f.write_char('\n')?;
     }
 
-    print_indent(f, margin.left)?;
-    print_row_columns_one_line(f, data, dims, colors, borders, padding, alignment, row)?;
-    print_indent(f, margin.right)?;
+    print_row_columns(
+        f, data, dims, colors, margin, padding, borders, alignment, row,
+    )?;
 
     for _ in 0..padding.top.space.size {
         f.write_char('\n')?;
@@ -440,6 +443,61 @@ fn count_verticals(cfg: &CompactConfig, count_columns: usize) -> usize {
         + borders.has_right() as usize
 }
 
+// A row is printed line by line in case its height is bigger then 1,
+// which requires the cells to be buffered, so it's done only when `std` is available.
+#[allow(clippy::too_many_arguments)]
+fn print_row_columns<F, I, T, D, C>(
+    f: &mut F,
+    data: I,
+    dims: &D,
+    colors: &C,
+    margin: &Sides<ColoredIndent>,
+    padding: &Sides<ColoredIndent>,
+    borders: &HorizontalLine<ColoredIndent>,
+    alignment: AlignmentHorizontal,
+    row: usize,
+) -> fmt::Result
+where
+    F: Write,
+    I: Iterator<Item = T>,
+    T: AsRef<str>,
+    D: Dimension,
+    C: Colors,
+{
+    #[cfg(feature = "std")]
+    {
+        let pad = padding.top.space.size + padding.bottom.space.size;
+        let count_lines = dims.get_height(row).saturating_sub(pad);
+        if count_lines > 1 {
+            let cells = data.collect::<Vec<_>>();
+
+            for line in 0..count_lines {
+                if line > 0 {
+                    f.write_char('\n')?;
+                }
+
+                let data = cells
+                    .iter()
+                    .map(|text| get_lines(text.as_ref()).nth(line).unwrap_or_default());
+
+                print_indent(f, margin.left)?;
+                print_row_columns_one_line(
+                    f, data, dims, colors, borders, padding, alignment, row,
+                )?;
+                print_indent(f, margin.right)?;
+            }
+
+            return Ok(());
+        }
+    }
+
+    print_indent(f, margin.left)?;
+    print_row_columns_one_line(f, data, dims, colors, borders, padding, alignment, row)?;
+    print_indent(f, margin.right)?;
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn print_row_columns_one_line<F, I, T, D, C>(
     f: &mut F,
@@ -561,7 +619,7 @@ where
     F: Write,
     C: ANSIFmt,
 {
-    let available = width - (padding.left.space.size + padding.right.space.size);
+    let available = width.saturating_sub(padding.left.space.size + padding.right.space.size);
 
     let text_width = get_line_width(text);
     let (left, right) = if available > text_width {
@@ -724,7 +782,7 @@ fn calculate_indent(
     text_width: usize,
     available: usize,
 ) -> (usize, usize) {
-    let diff = available - text_width;
+    let diff = available.saturating_sub(text_width);
     match alignment {
         AlignmentHorizontal::Left => (0, diff),
         AlignmentHorizontal::Right => (diff, 0),
@@ -294,6 +294,10 @@ fn print_single_line_column<F: Write, C: ANSIFmt>(
     color: Option<&C>,
     pos: Position,
 ) -> fmt::Result {
+    if let Some(c) = cfg.get_fill_override(pos) {
+        return repeat_char(f, c, width);
+    }
+
     let pad = cfg.get_padding(pos);
     let pad_color = cfg.get_padding_color(pos);
     let fmt = cfg.get_formatting(pos);
@@ -313,7 +317,7 @@ fn print_single_line_column<F: Write, C: ANSIFmt>(
     };
 
     let alignment = *cfg.get_alignment_horizontal(pos);
-    let available_width = width - pad.left.size - pad.right.size;
+    let available_width = width.saturating_sub(pad.left.size + pad.right.size);
     let (left, right) = calculate_indent(alignment, text_width, available_width);
 
     print_padding(f, &pad.left, pad_color.left.as_ref())?;
@@ -776,6 +780,7 @@ struct Cell<T, C> {
     pad_color: Sides<Option<ANSIBuf>>,
     color: Option<C>,
     justification: (char, Option<ANSIBuf>),
+    fill: Option<char>,
 }
 
 impl<T, C> Cell<T, C>
@@ -799,6 +804,7 @@ where
             cfg.get_justification(pos),
             cfg.get_justification_color(pos).cloned(),
         );
+        let fill = cfg.get_fill_override(pos);
 
         let (count_lines, skip) = if fmt.vertical_trim {
             let (len, top, _) = count_empty_lines(text.as_ref());
@@ -812,7 +818,7 @@ where
         let mut indent_left = None;
         if !fmt.allow_lines_alignment {
             let text_width = text_width(text.as_ref(), fmt.horizontal_trim);
-            let available = width - pad.left.size - pad.right.size;
+            let available = width.saturating_sub(pad.left.size + pad.right.size);
             indent_left = Some(calculate_indent(alignh, text_width, available).0);
         }
 
@@ -832,6 +838,7 @@ where
             pad_color,
             color,
             justification,
+            fill,
         }
     }
 }
@@ -841,6 +848,10 @@ where
     C: ANSIFmt,
 {
     fn display<F: Write>(&mut self, f: &mut F) -> fmt::Result {
+        if let Some(c) = self.fill {
+            return repeat_char(f, c, self.width);
+        }
+
         if self.indent_top > 0 {
             self.indent_top -= 1;
             print_padding_n(f, &self.pad.top, self.pad_color.top.as_ref(), self.width)?;
@@ -863,13 +874,15 @@ where
         };
 
         let line_width = get_line_width(&line);
-        let available_width = self.width - self.pad.left.size - self.pad.right.size;
+        let available_width = self
+            .width
+            .saturating_sub(self.pad.left.size + self.pad.right.size);
 
         let (left, right) = if self.fmt.allow_lines_alignment {
             calculate_indent(self.alignh, line_width, available_width)
         } else {
             let left = self.indent_left.expect("must be here");
-            (left, available_width - line_width - left)
+            (left, available_width.saturating_sub(line_width + left))
         };
 
         let (justification, justification_color) =
@@ -970,7 +983,7 @@ fn top_indent(
     cell_height: usize,
     available: usize,
 ) -> usize {
-    let height = available - padding.top.size;
+    let height = available.saturating_sub(padding.top.size);
     let indent = indent_from_top(alignment, height, cell_height);
 
     indent + padding.top.size
@@ -979,8 +992,8 @@ fn top_indent(
 fn indent_from_top(alignment: AlignmentVertical, available: usize, real: usize) -> usize {
     match alignment {
         AlignmentVertical::Top => 0,
-        AlignmentVertical::Bottom => available - real,
-        AlignmentVertical::Center => (available - real) / 2,
+        AlignmentVertical::Bottom => available.saturating_sub(real),
+        AlignmentVertical::Center => available.saturating_sub(real) / 2,
     }
 }
 
@@ -989,7 +1002,7 @@ fn calculate_indent(
     text_width: usize,
     available: usize,
 ) -> (usize, usize) {
-    let diff = available - text_width;
+    let diff = available.saturating_sub(text_width);
     match alignment {
         AlignmentHorizontal::Left => (0, diff),
         AlignmentHorizontal::Right => (diff, 0),
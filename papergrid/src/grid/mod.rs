@@ -2,6 +2,9 @@
 
 pub mod compact;
 
+#[cfg(feature = "std")]
+pub mod buffer;
+
 #[cfg(feature = "std")]
 pub mod iterable;
 
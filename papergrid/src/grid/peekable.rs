@@ -55,8 +55,20 @@ impl<R, G, D, C> PeekableGrid<R, G, D, C> {
             return Ok(());
         }
 
+        let shape = (self.records.count_rows(), self.records.count_columns());
+
+        let cfg = self.config.borrow();
+        let cfg = match cfg.get_bottom_border_text() {
+            Some((text, offset)) => {
+                let mut cfg = cfg.clone();
+                set_bottom_border_text(&mut cfg, &self.dimension, shape, text, offset);
+                Cow::Owned(cfg)
+            }
+            None => Cow::Borrowed(cfg),
+        };
+
         let ctx = PrintCtx {
-            cfg: self.config.borrow(),
+            cfg: &cfg,
             colors: &self.colors,
             dims: &self.dimension,
             records: &self.records,
@@ -116,6 +128,84 @@ where
     }
 }
 
+// Translates a bottom border text into overridden characters of a bottom line.
+fn set_bottom_border_text<D>(
+    cfg: &mut SpannedConfig,
+    dims: &D,
+    shape: (usize, usize),
+    text: &str,
+    offset: Offset,
+) where
+    D: Dimension,
+{
+    let (count_rows, count_columns) = shape;
+    if count_rows == 0 || !cfg.has_horizontal(count_rows, count_rows) {
+        return;
+    }
+
+    let mut total_width = 0;
+    for col in 0..count_columns {
+        total_width += cfg.has_vertical(col, count_columns) as usize;
+        total_width += dims.get_width(col);
+    }
+    total_width += cfg.has_vertical(count_columns, count_columns) as usize;
+
+    let text_width = text.chars().count();
+    let start = match offset {
+        Offset::Begin(i) => i,
+        Offset::End(i) => total_width.saturating_sub(i + text_width),
+    };
+
+    let mut chars = text.chars();
+    let last_row = count_rows - 1;
+    let mut pos = 0;
+
+    if cfg.has_vertical(0, count_columns) {
+        if pos >= start {
+            let c = match chars.next() {
+                Some(c) => c,
+                None => return,
+            };
+
+            let mut border = cfg.get_border((last_row, 0).into(), shape);
+            border.left_bottom_corner = Some(c);
+            cfg.set_border((last_row, 0).into(), border);
+        }
+
+        pos += 1;
+    }
+
+    for col in 0..count_columns {
+        for i in 0..dims.get_width(col) {
+            if pos >= start {
+                let c = match chars.next() {
+                    Some(c) => c,
+                    None => return,
+                };
+
+                cfg.set_horizontal_char((count_rows, col).into(), c, Offset::Begin(i));
+            }
+
+            pos += 1;
+        }
+
+        if cfg.has_vertical(col + 1, count_columns) {
+            if pos >= start {
+                let c = match chars.next() {
+                    Some(c) => c,
+                    None => return,
+                };
+
+                let mut border = cfg.get_border((last_row, col).into(), shape);
+                border.right_bottom_corner = Some(c);
+                cfg.set_border((last_row, col).into(), border);
+            }
+
+            pos += 1;
+        }
+    }
+}
+
 fn has_margin(cfg: &SpannedConfig) -> bool {
     let margin = cfg.get_margin();
     margin.left.size > 0 || margin.right.size > 0 || margin.top.size > 0 || margin.bottom.size > 0
@@ -315,6 +405,10 @@ mod grid_basic {
     {
         let width = ctx.dims.get_width(pos.col());
 
+        if let Some(c) = ctx.cfg.get_fill_override(pos) {
+            return repeat_char(f, c, width);
+        }
+
         let pad = ctx.cfg.get_padding(pos);
         let valignment = *ctx.cfg.get_alignment_vertical(pos);
         let text_cfg = TextCfg {
@@ -325,8 +419,10 @@ mod grid_basic {
 
         let mut cell_height = ctx.records.count_lines(pos);
         if text_cfg.formatting.vertical_trim {
-            cell_height -= count_empty_lines_at_start(ctx.records, pos)
-                + count_empty_lines_at_end(ctx.records, pos);
+            cell_height = cell_height.saturating_sub(
+                count_empty_lines_at_start(ctx.records, pos)
+                    + count_empty_lines_at_end(ctx.records, pos),
+            );
         }
 
         if cell_height > height {
@@ -355,7 +451,7 @@ mod grid_basic {
             }
         }
 
-        let width = width - pad.left.size - pad.right.size;
+        let width = width.saturating_sub(pad.left.size + pad.right.size);
 
         repeat_char(f, pad.left.fill, pad.left.size)?;
         print_line(f, ctx.records, pos, index, width, text_cfg)?;
@@ -405,7 +501,7 @@ mod grid_basic {
         print_text_padded(f, &line, cfg.justification, indent)?;
 
         // todo: remove me?
-        let rest_width = cell_width - line_width;
+        let rest_width = cell_width.saturating_sub(line_width);
         repeat_char(f, ' ', rest_width)?;
 
         Ok(())
@@ -428,7 +524,7 @@ mod grid_basic {
         height: usize,
         available: usize,
     ) -> usize {
-        let available = available - pad.top.size;
+        let available = available.saturating_sub(pad.top.size);
         let indent = indent_from_top(alignment, available, height);
 
         indent + pad.top.size
@@ -437,13 +533,13 @@ mod grid_basic {
     fn indent_from_top(alignment: AlignmentVertical, available: usize, real: usize) -> usize {
         match alignment {
             AlignmentVertical::Top => 0,
-            AlignmentVertical::Bottom => available - real,
-            AlignmentVertical::Center => (available - real) / 2,
+            AlignmentVertical::Bottom => available.saturating_sub(real),
+            AlignmentVertical::Center => available.saturating_sub(real) / 2,
         }
     }
 
     fn calculate_indent(alignment: AlignmentHorizontal, width: usize, available: usize) -> HIndent {
-        let diff = available - width;
+        let diff = available.saturating_sub(width);
 
         let (left, right) = match alignment {
             AlignmentHorizontal::Left => (0, diff),
@@ -825,6 +921,10 @@ mod grid_not_spanned {
     {
         let width = ctx.dims.get_width(pos.col());
 
+        if let Some(c) = ctx.cfg.get_fill_override(pos) {
+            return repeat_char(f, c, width);
+        }
+
         let formatting = ctx.cfg.get_formatting(pos);
         let text_cfg = TextCfg {
             alignment: *ctx.cfg.get_alignment_horizontal(pos),
@@ -842,8 +942,10 @@ mod grid_not_spanned {
 
         let mut cell_height = ctx.records.count_lines(pos);
         if formatting.vertical_trim {
-            cell_height -= count_empty_lines_at_start(ctx.records, pos)
-                + count_empty_lines_at_end(ctx.records, pos);
+            cell_height = cell_height.saturating_sub(
+                count_empty_lines_at_start(ctx.records, pos)
+                    + count_empty_lines_at_end(ctx.records, pos),
+            );
         }
 
         if cell_height > height {
@@ -872,7 +974,7 @@ mod grid_not_spanned {
             }
         }
 
-        let width = width - pad.left.size - pad.right.size;
+        let width = width.saturating_sub(pad.left.size + pad.right.size);
 
         print_indent(f, pad.left.fill, pad.left.size, pad_color.left.as_ref())?;
         print_line(f, ctx.records, pos, index, width, text_cfg)?;
@@ -925,7 +1027,7 @@ mod grid_not_spanned {
         print_text_padded(f, text, cfg.justification, indent)?;
 
         // todo: remove me?
-        let rest_width = cell_width - line_width;
+        let rest_width = cell_width.saturating_sub(line_width);
         repeat_char(f, ' ', rest_width)?;
 
         Ok(())
@@ -970,7 +1072,7 @@ mod grid_not_spanned {
         height: usize,
         available: usize,
     ) -> usize {
-        let available = available - pad.top.size;
+        let available = available.saturating_sub(pad.top.size);
         let indent = indent_from_top(alignment, available, height);
 
         indent + pad.top.size
@@ -979,13 +1081,13 @@ mod grid_not_spanned {
     fn indent_from_top(alignment: AlignmentVertical, available: usize, real: usize) -> usize {
         match alignment {
             AlignmentVertical::Top => 0,
-            AlignmentVertical::Bottom => available - real,
-            AlignmentVertical::Center => (available - real) / 2,
+            AlignmentVertical::Bottom => available.saturating_sub(real),
+            AlignmentVertical::Center => available.saturating_sub(real) / 2,
         }
     }
 
     fn calculate_indent(alignment: AlignmentHorizontal, width: usize, available: usize) -> HIndent {
-        let diff = available - width;
+        let diff = available.saturating_sub(width);
 
         let (left, right) = match alignment {
             AlignmentHorizontal::Left => (0, diff),
@@ -1687,11 +1789,17 @@ mod grid_spanned {
         R: Records + PeekableRecords + ExactRecords,
         C: Colors,
     {
+        if let Some(c) = ctx.cfg.get_fill_override(pos) {
+            return repeat_char(f, c, width);
+        }
+
         let mut cell_height = ctx.records.count_lines(pos);
         let formatting = ctx.cfg.get_formatting(pos);
         if formatting.vertical_trim {
-            cell_height -= count_empty_lines_at_start(ctx.records, pos)
-                + count_empty_lines_at_end(ctx.records, pos);
+            cell_height = cell_height.saturating_sub(
+                count_empty_lines_at_start(ctx.records, pos)
+                    + count_empty_lines_at_end(ctx.records, pos),
+            );
         }
 
         if cell_height > height {
@@ -1725,7 +1833,7 @@ mod grid_spanned {
 
         print_indent(f, pad.left.fill, pad.left.size, pad_color.left.as_ref())?;
 
-        let width = width - pad.left.size - pad.right.size;
+        let width = width.saturating_sub(pad.left.size + pad.right.size);
 
         let line_cfg = TextCfg {
             alignment: *ctx.cfg.get_alignment_horizontal(pos),
@@ -1789,7 +1897,7 @@ mod grid_spanned {
         print_text_with_pad(f, text, text_cfg.justification, indent)?;
 
         // todo: remove me?
-        let rest_width = cell_width - line_width;
+        let rest_width = cell_width.saturating_sub(line_width);
         repeat_char(f, ' ', rest_width)?;
 
         Ok(())
@@ -1833,7 +1941,7 @@ mod grid_spanned {
         cell_height: usize,
         available: usize,
     ) -> usize {
-        let height = available - pad.top.size;
+        let height = available.saturating_sub(pad.top.size);
         let indent = indent_from_top(alignment, height, cell_height);
 
         indent + pad.top.size
@@ -1842,13 +1950,13 @@ mod grid_spanned {
     fn indent_from_top(alignment: AlignmentVertical, available: usize, real: usize) -> usize {
         match alignment {
             AlignmentVertical::Top => 0,
-            AlignmentVertical::Bottom => available - real,
-            AlignmentVertical::Center => (available - real) / 2,
+            AlignmentVertical::Bottom => available.saturating_sub(real),
+            AlignmentVertical::Center => available.saturating_sub(real) / 2,
         }
     }
 
     fn calculate_indent(alignment: AlignmentHorizontal, width: usize, available: usize) -> HIndent {
-        let diff = available - width;
+        let diff = available.saturating_sub(width);
 
         let (left, right) = match alignment {
             AlignmentHorizontal::Left => (0, diff),
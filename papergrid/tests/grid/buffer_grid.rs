@@ -0,0 +1,288 @@
+#![cfg(feature = "std")]
+
+use std::{collections::HashMap, fmt};
+
+use papergrid::{
+    ansi::{ANSIBuf, ANSIFmt},
+    colors::NoColors,
+    config::{pos, spanned::SpannedConfig, Entity, Indent, Sides},
+    dimension::{spanned::SpannedGridDimension, Estimate},
+    grid::{
+        buffer::{BufferCell, BufferGrid, WIDE_CHAR_TAIL},
+        peekable::PeekableGrid,
+    },
+    records::vec_records::{Text, VecRecords},
+    util::string::get_line_width,
+};
+
+use crate::util::DEFAULT_BORDERS;
+
+#[test]
+fn buffer_grid_matches_string_grid() {
+    let (records, cfg, dims) = build_parts(&[["Hello", "World"], ["Привет", "你好"]]);
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, NoColors).build();
+    let text = PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string();
+
+    assert_eq!(join_rows(&buffer), text);
+    assert!(buffer.iter().flatten().all(|(_, color)| color.is_none()));
+}
+
+#[test]
+fn buffer_grid_wide_chars_take_several_cells() {
+    let (records, cfg, dims) = build_parts(&[["a", "你好"]]);
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, NoColors).build();
+    let text = PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string();
+
+    assert_eq!(buffer.len(), text.lines().count());
+    for (row, line) in buffer.iter().zip(text.lines()) {
+        assert_eq!(row.len(), get_line_width(line));
+    }
+
+    let row = buffer[1].iter().map(|(c, _)| *c).collect::<String>();
+    assert_eq!(row, "|a|你\0好\0|");
+}
+
+#[test]
+fn buffer_grid_colors() {
+    let (records, cfg, dims) = build_parts(&[["a", "b"]]);
+
+    let color = ANSIBuf::new("\u{1b}[31m", "\u{1b}[39m");
+    let mut colors = HashMap::new();
+    colors.insert(pos(0, 1), color.clone());
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, &colors).build();
+    let text = PeekableGrid::new(&records, &cfg, &dims, &colors).to_string();
+
+    assert_eq!(text, "+-+-+\n|a|\u{1b}[31mb\u{1b}[39m|\n+-+-+");
+    assert_eq!(join_rows(&buffer), "+-+-+\n|a|b|\n+-+-+");
+
+    let colored = buffer
+        .iter()
+        .flatten()
+        .filter(|(_, c)| c.is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(colored, [&('b', Some(color))]);
+}
+
+#[test]
+fn buffer_grid_colors_ansi_only_content() {
+    let (records, cfg, dims) = build_parts(&[["\u{1b}[1m", "b", "c"]]);
+
+    let color = ANSIBuf::new("\u{1b}[31m", "\u{1b}[39m");
+    let mut colors = HashMap::new();
+    colors.insert(pos(0, 2), color.clone());
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, &colors).build();
+
+    let colored = buffer
+        .iter()
+        .flatten()
+        .filter(|(_, c)| c.is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(colored, [&('c', Some(color))]);
+    assert!(join_rows(&buffer)
+        .lines()
+        .all(|line| !line.contains('\u{1b}')));
+}
+
+#[test]
+fn buffer_grid_colored_borders() {
+    let color = ANSIBuf::new("\u{1b}[34m", "\u{1b}[39m");
+    let (records, cfg, dims) = build_parts_with(&[["a", "b"]], |cfg| {
+        cfg.set_border_color_default(color.clone());
+    });
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, NoColors).build();
+
+    assert_eq!(join_rows(&buffer), "+-+-+\n|a|b|\n+-+-+");
+    for (c, clr) in buffer.iter().flatten() {
+        match c {
+            'a' | 'b' => assert_eq!(clr, &None),
+            _ => assert_eq!(clr, &Some(color.clone())),
+        }
+    }
+}
+
+#[test]
+fn buffer_grid_colored_padding() {
+    let red = ANSIBuf::new("\u{1b}[31m", "\u{1b}[39m");
+    let blue_bg = ANSIBuf::new("\u{1b}[44m", "\u{1b}[49m");
+    let (records, cfg, dims) = build_parts_with(&[["a"]], |cfg| {
+        cfg.set_padding(
+            Entity::Global,
+            Sides::new(
+                Indent::new(1, '<'),
+                Indent::new(1, '>'),
+                Indent::zero(),
+                Indent::zero(),
+            ),
+        );
+        cfg.set_padding_color(
+            Entity::Global,
+            Sides::new(Some(red.clone()), Some(blue_bg.clone()), None, None),
+        );
+    });
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, NoColors).build();
+
+    assert_eq!(join_rows(&buffer), "+---+\n|<a>|\n+---+");
+    assert_eq!(
+        buffer[1],
+        [
+            ('|', None),
+            ('<', Some(red)),
+            ('a', None),
+            ('>', Some(blue_bg)),
+            ('|', None),
+        ]
+    );
+}
+
+#[test]
+fn buffer_grid_colored_border_and_cell() {
+    let border = ANSIBuf::new("\u{1b}[44m", "\u{1b}[49m");
+    let cell = ANSIBuf::new("\u{1b}[1;31m", "\u{1b}[22;39m");
+    let (records, cfg, dims) = build_parts_with(&[["a"]], |cfg| {
+        cfg.set_border_color_default(border.clone());
+    });
+
+    let mut colors = HashMap::new();
+    colors.insert(pos(0, 0), cell.clone());
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, &colors).build();
+
+    assert_eq!(
+        buffer[1],
+        [
+            ('|', Some(border.clone())),
+            ('a', Some(cell)),
+            ('|', Some(border))
+        ]
+    );
+}
+
+#[test]
+fn buffer_grid_drops_osc_sequences() {
+    let link = ANSIBuf::new(
+        "\u{1b}]8;;https://example.com\u{1b}\\\u{1b}[38;5;100m",
+        "\u{1b}[39m\u{1b}]8;;\u{7}",
+    );
+    let (records, cfg, dims) = build_parts_with(&[["a"]], |cfg| {
+        cfg.set_border_color_default(link.clone());
+    });
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, NoColors).build();
+
+    let color = ANSIBuf::new("\u{1b}[38;5;100m", "\u{1b}[39m");
+    assert_eq!(join_rows(&buffer), "+-+\n|a|\n+-+");
+    assert_eq!(
+        buffer[1],
+        [('|', Some(color.clone())), ('a', None), ('|', Some(color))]
+    );
+}
+
+#[test]
+fn buffer_grid_colors_written_in_parts() {
+    struct Red;
+
+    impl ANSIFmt for Red {
+        fn fmt_ansi_prefix<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+            f.write_str("\u{1b}[")?;
+            f.write_str("31m")
+        }
+
+        fn fmt_ansi_suffix<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+            f.write_char('\u{1b}')?;
+            f.write_str("[39m")
+        }
+    }
+
+    let (records, cfg, dims) = build_parts(&[["a", "b"]]);
+
+    let mut colors = HashMap::new();
+    colors.insert(pos(0, 0), Red);
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, &colors).build();
+
+    let red = ANSIBuf::new("\u{1b}[31m", "\u{1b}[39m");
+    assert_eq!(
+        buffer[1],
+        [
+            ('|', None),
+            ('a', Some(red)),
+            ('|', None),
+            ('b', None),
+            ('|', None)
+        ]
+    );
+}
+
+#[test]
+fn buffer_grid_empty() {
+    let (records, cfg, dims) = build_parts::<[&str; 0]>(&[]);
+
+    let buffer = BufferGrid::new(&records, &cfg, &dims, NoColors).build();
+
+    assert!(buffer.is_empty());
+}
+
+fn build_parts<R>(
+    data: &[R],
+) -> (
+    VecRecords<Text<String>>,
+    SpannedConfig,
+    SpannedGridDimension,
+)
+where
+    R: AsRef<[&'static str]>,
+{
+    build_parts_with(data, |_| {})
+}
+
+fn build_parts_with<R, F>(
+    data: &[R],
+    f: F,
+) -> (
+    VecRecords<Text<String>>,
+    SpannedConfig,
+    SpannedGridDimension,
+)
+where
+    R: AsRef<[&'static str]>,
+    F: FnOnce(&mut SpannedConfig),
+{
+    let data = data
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|s| Text::new(s.to_string()))
+                .collect()
+        })
+        .collect();
+    let records = VecRecords::new(data);
+
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(DEFAULT_BORDERS);
+    f(&mut cfg);
+
+    let mut dims = SpannedGridDimension::default();
+    dims.estimate(&records, &cfg);
+
+    (records, cfg, dims)
+}
+
+fn join_rows(buffer: &[Vec<BufferCell>]) -> String {
+    buffer
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(c, _)| *c)
+                .filter(|&c| c != WIDE_CHAR_TAIL)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
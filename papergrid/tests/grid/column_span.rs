@@ -1,6 +1,10 @@
 #![cfg(feature = "std")]
 
-use papergrid::config::{pos, AlignmentHorizontal, Borders, Entity, Indent, Sides};
+use papergrid::config::{
+    pos,
+    spanned::{SpanError, SpannedConfig},
+    AlignmentHorizontal, Borders, Entity, Indent, Sides,
+};
 
 use crate::util::grid;
 use testing_table::test_table;
@@ -429,3 +433,118 @@ test_table!(
     "|1-0|1-1|"
     "+---+---+"
 );
+
+#[test]
+fn try_set_column_span_test() {
+    let mut cfg = SpannedConfig::default();
+
+    assert_eq!(cfg.try_set_column_span(pos(0, 0), 2, (2, 3)), Ok(()));
+    assert_eq!(cfg.get_column_span(pos(0, 0)), Some(2));
+
+    assert_eq!(cfg.try_set_column_span(pos(0, 0), 3, (2, 3)), Ok(()));
+    assert_eq!(cfg.get_column_span(pos(0, 0)), Some(3));
+
+    assert_eq!(cfg.try_set_column_span(pos(1, 1), 2, (2, 3)), Ok(()));
+    assert_eq!(cfg.get_column_span(pos(1, 1)), Some(2));
+}
+
+#[test]
+fn try_set_column_span_out_of_bounds_test() {
+    let mut cfg = SpannedConfig::default();
+
+    assert_eq!(
+        cfg.try_set_column_span(pos(0, 1), 3, (2, 3)),
+        Err(SpanError::OutOfBounds)
+    );
+    assert_eq!(
+        cfg.try_set_column_span(pos(2, 0), 2, (2, 3)),
+        Err(SpanError::OutOfBounds)
+    );
+    assert_eq!(
+        cfg.try_set_column_span(pos(0, 0), 0, (2, 3)),
+        Err(SpanError::Zero)
+    );
+    assert!(!cfg.has_column_spans());
+}
+
+#[test]
+fn try_set_column_span_overlap_test() {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_column_span(pos(0, 1), 2);
+    cfg.set_row_span(pos(1, 1), 2);
+
+    // a cell covered by a span
+    assert_eq!(
+        cfg.try_set_column_span(pos(0, 2), 2, (3, 4)),
+        Err(SpanError::Overlap)
+    );
+    // covers a span origin
+    assert_eq!(
+        cfg.try_set_column_span(pos(0, 0), 2, (3, 4)),
+        Err(SpanError::Overlap)
+    );
+    // covers a cell hidden by a row span
+    assert_eq!(
+        cfg.try_set_column_span(pos(2, 0), 2, (3, 4)),
+        Err(SpanError::Overlap)
+    );
+    // extends a row span into a span origin
+    cfg.set_column_span(pos(2, 2), 2);
+    assert_eq!(
+        cfg.try_set_column_span(pos(1, 1), 2, (3, 4)),
+        Err(SpanError::Overlap)
+    );
+
+    assert_eq!(cfg.get_column_span(pos(0, 0)), None);
+    assert_eq!(cfg.get_column_span(pos(0, 2)), None);
+    assert_eq!(cfg.get_column_span(pos(1, 1)), None);
+    assert_eq!(cfg.get_column_span(pos(2, 0)), None);
+}
+
+test_table!(
+    span_moved_on_insert_column_before,
+    grid(2, 4)
+        .config(|cfg| {
+            cfg.set_column_span(pos(0, 1), 2);
+            cfg.insert_column(0);
+        })
+        .build(),
+    "+---+---+---+---+"
+    "|0-0|0-1|0-2    |"
+    "+---+---+---+---+"
+    "|1-0|1-1|1-2|1-3|"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    span_expanded_on_insert_column_inside,
+    grid(2, 4)
+        .config(|cfg| {
+            cfg.set_column_span(pos(0, 1), 2);
+            cfg.insert_column(2);
+        })
+        .build(),
+    "+---+---+---+---+"
+    "|0-0|0-1        |"
+    "+---+---+---+---+"
+    "|1-0|1-1|1-2|1-3|"
+    "+---+---+---+---+"
+);
+
+#[test]
+fn span_on_insert_row_test() {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_column_span(pos(1, 0), 2);
+    cfg.set_row_span(pos(0, 2), 2);
+
+    cfg.insert_row(1);
+
+    assert_eq!(cfg.get_column_span(pos(1, 0)), None);
+    assert_eq!(cfg.get_column_span(pos(2, 0)), Some(2));
+    assert_eq!(cfg.get_row_span(pos(0, 2)), Some(3));
+
+    cfg.insert_row(0);
+
+    assert_eq!(cfg.get_column_span(pos(3, 0)), Some(2));
+    assert_eq!(cfg.get_row_span(pos(1, 2)), Some(3));
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "std")]
+
+use papergrid::{
+    config::{spanned::SpannedConfig, Entity, Indent, Position, Sides},
+    dimension::{spanned::SpannedGridDimension, Estimate},
+    records::{
+        vec_records::{Text, VecRecords},
+        ExactRecords,
+    },
+};
+
+fn records(data: &[&[&str]]) -> VecRecords<Text<String>> {
+    let data = data
+        .iter()
+        .map(|row| row.iter().map(|s| Text::new(s.to_string())).collect())
+        .collect();
+
+    VecRecords::new(data)
+}
+
+fn full(records: &VecRecords<Text<String>>, cfg: &SpannedConfig) -> SpannedGridDimension {
+    let mut dims = SpannedGridDimension::default();
+    dims.estimate(records, cfg);
+    dims
+}
+
+#[test]
+fn update_cell_matches_full_estimate() {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_padding(
+        Entity::Cell(1, 1),
+        Sides::new(
+            Indent::spaced(1),
+            Indent::spaced(2),
+            Indent::spaced(0),
+            Indent::spaced(1),
+        ),
+    );
+
+    let mut records = records(&[&["a", "bb", "ccc"], &["dddd", "e", "f"], &["g", "h", "i"]]);
+
+    let mut dims = full(&records, &cfg);
+
+    records[1][1] = Text::new(String::from("a longer\ntext\nline"));
+    dims.update_cell(Position::new(1, 1), &records, &cfg);
+    assert_eq!(dims, full(&records, &cfg));
+    assert_eq!(dims.clone().get_values(), (vec![4, 11, 3], vec![1, 4, 1]));
+
+    records[1][1] = Text::new(String::new());
+    dims.update_cell(Position::new(1, 1), &records, &cfg);
+    assert_eq!(dims, full(&records, &cfg));
+    assert_eq!(dims.get_values(), (vec![4, 3, 3], vec![1, 2, 1]));
+}
+
+#[test]
+fn update_cell_with_spans() {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_column_span(Position::new(0, 0), 2);
+
+    let mut records = records(&[&["a", "", "ccc"], &["dd", "e", "f"]]);
+
+    let mut dims = full(&records, &cfg);
+
+    records[0][0] = Text::new(String::from("a very long text"));
+    dims.update_cell(Position::new(0, 0), &records, &cfg);
+    assert_eq!(dims, full(&records, &cfg));
+}
+
+#[test]
+fn update_cell_not_estimated() {
+    let cfg = SpannedConfig::default();
+    let records = records(&[&["a", "bb"], &["ccc", "d"]]);
+
+    let mut dims = SpannedGridDimension::default();
+    dims.update_cell(Position::new(0, 0), &records, &cfg);
+    assert_eq!(dims, full(&records, &cfg));
+}
+
+// A widest column is narrowed first, the right most one on a tie.
+fn peak_widest(mins: &[usize], widths: &[usize]) -> Option<usize> {
+    (0..widths.len())
+        .filter(|&i| widths[i] > mins[i])
+        .max_by_key(|&i| widths[i])
+}
+
+fn two_pass_width(
+    records: &VecRecords<Text<String>>,
+    cfg: &SpannedConfig,
+    width: usize,
+) -> Vec<usize> {
+    let mut widths = SpannedGridDimension::width(records, cfg);
+    let empty = vec![vec![Text::new(String::new()); widths.len()]; records.count_rows()];
+    let min_widths = SpannedGridDimension::width(VecRecords::new(empty), cfg);
+
+    let mut total = widths.iter().sum::<usize>() + cfg.count_vertical(widths.len());
+    while total > width {
+        match peak_widest(&min_widths, &widths) {
+            Some(col) => widths[col] -= 1,
+            None => break,
+        }
+
+        total -= 1;
+    }
+
+    widths
+}
+
+#[test]
+fn width_within_matches_two_pass() {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(papergrid::config::Borders {
+        vertical: Some('|'),
+        left: Some('|'),
+        right: Some('|'),
+        ..Default::default()
+    });
+    cfg.set_padding(
+        Entity::Global,
+        Sides::new(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::zero(),
+            Indent::zero(),
+        ),
+    );
+
+    let records = records(&[
+        &["id", "name", "description"],
+        &[
+            "0",
+            "tabled",
+            "An easy to use library for pretty print tables",
+        ],
+        &["1", "papergrid", "Core library to print a table"],
+    ]);
+
+    for width in [0, 10, 20, 40, 67, 100] {
+        let widths = SpannedGridDimension::width_within(&records, &cfg, width, peak_widest);
+        assert_eq!(
+            widths,
+            two_pass_width(&records, &cfg, width),
+            "width={width}"
+        );
+
+        let mut dims = SpannedGridDimension::default();
+        dims.estimate_within(&records, &cfg, width, peak_widest);
+        let (dims_widths, dims_heights) = dims.get_values();
+        assert_eq!(dims_widths, widths);
+        assert_eq!(dims_heights, full(&records, &cfg).get_values().1);
+    }
+
+    assert_eq!(
+        SpannedGridDimension::width_within(&records, &cfg, 40, peak_widest),
+        [4, 11, 21]
+    );
+    assert_eq!(
+        SpannedGridDimension::width_within(&records, &cfg, 10, peak_widest),
+        [2, 2, 2]
+    );
+}
+
+#[test]
+fn width_within_shrink_order() {
+    let cfg = SpannedConfig::default();
+    let records = records(&[&["aaaa", "bbbb", "cccc"]]);
+
+    let widths = SpannedGridDimension::width_within(&records, &cfg, 8, |_, widths| {
+        (0..widths.len()).find(|&i| widths[i] > 1)
+    });
+    assert_eq!(widths, [1, 3, 4]);
+}
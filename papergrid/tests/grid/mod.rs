@@ -1,4 +1,6 @@
+mod buffer_grid;
 mod column_span;
+mod dimension;
 mod format_configuration;
 mod peekable_grid;
 mod render;
@@ -1,16 +1,21 @@
 #![cfg(feature = "std")]
 
+use std::cell::Cell;
+
 use papergrid::{
-    colors::NoColors,
+    ansi::ANSIStr,
+    colors::{FnColors, NoColors},
     config::{
-        spanned::SpannedConfig, AlignmentHorizontal, AlignmentVertical, Borders, Entity, Indent,
-        Sides,
+        spanned::{Offset, SpannedConfig},
+        AlignmentHorizontal, AlignmentVertical, Borders, Entity, Indent, Sides,
     },
     dimension::{spanned::SpannedGridDimension, Dimension},
     grid::peekable::PeekableGrid,
     records::vec_records::{Text, VecRecords},
 };
 
+use crate::util::DEFAULT_BORDERS;
+
 use testing_table::test_table;
 
 struct Dims {
@@ -165,3 +170,229 @@ test_table!(
     "|is a library     |is a library|is a library    |is a library|"
     "+-----------------+------------+----------------+------------+"
 );
+
+test_table!(
+    zero_width_column_with_padding_does_not_panic,
+    {
+        let mut cfg = SpannedConfig::default();
+        cfg.set_borders(Borders {
+            vertical: Some('|'),
+            left: Some('|'),
+            right: Some('|'),
+            ..Default::default()
+        });
+        cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Center);
+        cfg.set_alignment_vertical(Entity::Global, AlignmentVertical::Bottom);
+        cfg.set_padding(
+            Entity::Global,
+            Sides::new(
+                Indent::spaced(1),
+                Indent::spaced(1),
+                Indent::spaced(0),
+                Indent::spaced(0),
+            ),
+        );
+
+        let records = VecRecords::new(vec![vec![Text::new("hello")], vec![Text::new("a\nb\nc")]]);
+        let dims = Dims {
+            width: vec![0],
+            height: vec![1, 1],
+        };
+
+        PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string()
+    },
+    "| hello |"
+    "| a |"
+);
+
+test_table!(
+    cjk_cell_wider_than_column_does_not_panic,
+    {
+        let mut cfg = SpannedConfig::default();
+        cfg.set_borders(Borders {
+            vertical: Some('|'),
+            left: Some('|'),
+            right: Some('|'),
+            ..Default::default()
+        });
+        cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Right);
+        cfg.set_alignment_vertical(Entity::Global, AlignmentVertical::Center);
+
+        let records = VecRecords::new(vec![vec![Text::new("你好世界"), Text::new("")]]);
+        let dims = Dims {
+            width: vec![3, 0],
+            height: vec![1],
+        };
+
+        PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string()
+    },
+    "|你好世界||"
+);
+
+test_table!(
+    bottom_border_text,
+    build_with_bottom_text("Total", Offset::Begin(0)),
+    "+----+-----+"
+    "|name|value|"
+    "+----+-----+"
+    "|a   |1    |"
+    "Total+-----+"
+);
+
+test_table!(
+    bottom_border_text_offset_begin,
+    build_with_bottom_text("Total", Offset::Begin(3)),
+    "+----+-----+"
+    "|name|value|"
+    "+----+-----+"
+    "|a   |1    |"
+    "+--Total---+"
+);
+
+test_table!(
+    bottom_border_text_offset_end,
+    build_with_bottom_text("Total", Offset::End(1)),
+    "+----+-----+"
+    "|name|value|"
+    "+----+-----+"
+    "|a   |1    |"
+    "+----+Total+"
+);
+
+test_table!(
+    bottom_border_text_truncated,
+    build_with_bottom_text("Statistics of all the data", Offset::Begin(2)),
+    "+----+-----+"
+    "|name|value|"
+    "+----+-----+"
+    "|a   |1    |"
+    "+-Statistics"
+);
+
+test_table!(
+    bottom_border_text_offset_out_of_line,
+    build_with_bottom_text("Total", Offset::Begin(100)),
+    "+----+-----+"
+    "|name|value|"
+    "+----+-----+"
+    "|a   |1    |"
+    "+----+-----+"
+);
+
+fn build_with_bottom_text(text: &str, offset: Offset) -> String {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(Borders {
+        top: Some('-'),
+        top_left: Some('+'),
+        top_right: Some('+'),
+        top_intersection: Some('+'),
+        bottom: Some('-'),
+        bottom_left: Some('+'),
+        bottom_right: Some('+'),
+        bottom_intersection: Some('+'),
+        horizontal: Some('-'),
+        left_intersection: Some('+'),
+        right_intersection: Some('+'),
+        vertical: Some('|'),
+        left: Some('|'),
+        right: Some('|'),
+        intersection: Some('+'),
+    });
+    cfg.set_bottom_border_text(text.to_string(), offset);
+
+    let records = VecRecords::new(vec![
+        vec![Text::new("name"), Text::new("value")],
+        vec![Text::new("a"), Text::new("1")],
+    ]);
+
+    let dims = Dims {
+        width: SpannedGridDimension::width(&records, &cfg),
+        height: vec![1, 1],
+    };
+
+    PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string()
+}
+
+#[test]
+fn fn_colors_diagonal() {
+    const RED: ANSIStr<'static> = ANSIStr::new("\u{1b}[31m", "\u{1b}[39m");
+
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(DEFAULT_BORDERS);
+
+    let data = [["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]];
+    let data = data
+        .iter()
+        .map(|row| row.iter().map(|s| Text::new(s.to_string())).collect())
+        .collect();
+    let records = VecRecords::new(data);
+    let dims = Dims {
+        width: SpannedGridDimension::width(&records, &cfg),
+        height: SpannedGridDimension::height(&records, &cfg),
+    };
+
+    let calls = Cell::new(0);
+    let colors = FnColors::new(|pos: papergrid::config::Position| {
+        calls.set(calls.get() + 1);
+        (pos.row() == pos.col()).then_some(&RED)
+    });
+
+    let table = PeekableGrid::new(&records, &cfg, &dims, &colors).to_string();
+
+    assert_eq!(calls.get(), 9);
+    assert_eq!(
+        table,
+        "+-+-+-+\n\
+         |\u{1b}[31ma\u{1b}[39m|b|c|\n\
+         +-+-+-+\n\
+         |d|\u{1b}[31me\u{1b}[39m|f|\n\
+         +-+-+-+\n\
+         |g|h|\u{1b}[31mi\u{1b}[39m|\n\
+         +-+-+-+"
+    );
+}
+
+#[test]
+fn fn_colors_multiline_is_called_per_line() {
+    const RED: ANSIStr<'static> = ANSIStr::new("\u{1b}[31m", "\u{1b}[39m");
+
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(DEFAULT_BORDERS);
+
+    let records = VecRecords::new(vec![
+        vec![
+            Text::new(String::from("a\nb")),
+            Text::new(String::from("c")),
+        ],
+        vec![
+            Text::new(String::from("d")),
+            Text::new(String::from("e\nf\ng")),
+        ],
+    ]);
+    let dims = Dims {
+        width: SpannedGridDimension::width(&records, &cfg),
+        height: SpannedGridDimension::height(&records, &cfg),
+    };
+
+    let calls = Cell::new(0);
+    let colors = FnColors::new(|pos: papergrid::config::Position| {
+        calls.set(calls.get() + 1);
+        (pos.row() == pos.col()).then_some(&RED)
+    });
+
+    let table = PeekableGrid::new(&records, &cfg, &dims, &colors).to_string();
+
+    // 2 lines of a first row and 3 lines of a second row for each of 2 columns
+    assert_eq!(calls.get(), 10);
+    assert_eq!(
+        table,
+        "+-+-+\n\
+         |\u{1b}[31ma\u{1b}[39m|c|\n\
+         |\u{1b}[31mb\u{1b}[39m| |\n\
+         +-+-+\n\
+         |d|\u{1b}[31me\u{1b}[39m|\n\
+         | |\u{1b}[31mf\u{1b}[39m|\n\
+         | |\u{1b}[31mg\u{1b}[39m|\n\
+         +-+-+"
+    );
+}
@@ -1,7 +1,9 @@
 #![cfg(feature = "std")]
 
 use papergrid::config::{
-    pos, AlignmentHorizontal, AlignmentVertical, Borders,
+    pos,
+    spanned::{SpanError, SpannedConfig},
+    AlignmentHorizontal, AlignmentVertical, Borders,
     Entity::{self, *},
     Indent, Sides,
 };
@@ -1060,3 +1062,26 @@ test_table!(
 //         )
 //     );
 // }
+
+#[test]
+fn try_set_row_span_test() {
+    let mut cfg = SpannedConfig::default();
+
+    assert_eq!(cfg.try_set_row_span(pos(0, 0), 2, (3, 2)), Ok(()));
+    assert_eq!(cfg.get_row_span(pos(0, 0)), Some(2));
+
+    assert_eq!(
+        cfg.try_set_row_span(pos(1, 0), 2, (3, 2)),
+        Err(SpanError::Overlap)
+    );
+    assert_eq!(cfg.try_set_row_span(pos(0, 0), 3, (3, 2)), Ok(()));
+    assert_eq!(
+        cfg.try_set_row_span(pos(2, 0), 1, (3, 2)),
+        Err(SpanError::Overlap)
+    );
+    assert_eq!(
+        cfg.try_set_row_span(pos(1, 1), 3, (3, 2)),
+        Err(SpanError::OutOfBounds)
+    );
+    assert_eq!(cfg.try_set_row_span(pos(1, 1), 2, (3, 2)), Ok(()));
+}
@@ -263,3 +263,17 @@ test_table!(
     "|     |Hello Hello Hello Hello Hello|"
     "+-----+-----------------------------+"
 );
+
+test_table!(
+    test_fill_override,
+    grid(2, 2)
+        .data([["Hello", "World"], ["", "Hello\nHello"]])
+        .config(|cfg| cfg.set_fill_override(Entity::Cell(1, 1), Some('/')))
+        .build(),
+    "+-----+-----+"
+    "|Hello|World|"
+    "+-----+-----+"
+    "|     |/////|"
+    "|     |/////|"
+    "+-----+-----+"
+);
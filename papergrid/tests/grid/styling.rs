@@ -1,6 +1,8 @@
 #![cfg(feature = "std")]
 
-use papergrid::config::{pos, AlignmentHorizontal, Border, Borders, Entity, Indent, Sides};
+use papergrid::config::{
+    pos, AlignmentHorizontal, Border, Borders, Entity, HorizontalLine, Indent, Sides,
+};
 use testing_table::test_table;
 
 use crate::util::grid;
@@ -338,3 +340,126 @@ fn grid_2x2_ansi_border_none_if_string_is_not_1_char_test() {
     assert!(ANSIBuf::try_from("\u{1b}[1;31;44m1\u{1b}[22m\u{1b}[39m\u{1b}[49m").is_ok());
     assert!(ANSIBuf::try_from("\u{1b}[1;31;44m1\u{1b}[0m").is_ok());
 }
+
+test_table!(
+    grid_2x2_intersection_resolver_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                top: Some('─'),
+                bottom: Some('─'),
+                horizontal: Some('━'),
+                left: Some('│'),
+                right: Some('│'),
+                vertical: Some('│'),
+                ..Default::default()
+            });
+            cfg.set_intersection_resolver(|up, down, left, right| match (up, down, left, right) {
+                (true, true, true, true) => '┿',
+                (false, true, true, true) => '┬',
+                (true, false, true, true) => '┴',
+                (true, true, false, true) => '├',
+                (true, true, true, false) => '┤',
+                (false, true, false, true) => '┌',
+                (false, true, true, false) => '┐',
+                (true, false, false, true) => '└',
+                (true, false, true, false) => '┘',
+                _ => '?',
+            });
+        })
+        .build(),
+    "┌───┬───┐"
+    "│0-0│0-1│"
+    "├━━━┿━━━┤"
+    "│1-0│1-1│"
+    "└───┴───┘"
+);
+
+test_table!(
+    grid_2x2_intersection_resolver_explicit_chars_test,
+    grid(2, 2)
+        .config(|cfg| {
+            cfg.set_borders(Borders {
+                horizontal: Some('-'),
+                vertical: Some('|'),
+                intersection: Some('+'),
+                ..Default::default()
+            });
+            cfg.set_intersection_resolver(|_, _, _, _| '#');
+        })
+        .build(),
+    "0-0|0-1"
+    "---+---"
+    "1-0|1-1"
+);
+
+test_table!(
+    grid_3x2_set_horizontal_line_char_test,
+    grid(3, 2)
+        .config(|cfg| cfg.set_horizontal_line_char(1, '='))
+        .build(),
+    "+---+---+"
+    "|0-0|0-1|"
+    "+===+===+"
+    "|1-0|1-1|"
+    "+---+---+"
+    "|2-0|2-1|"
+    "+---+---+"
+);
+
+test_table!(
+    grid_3x2_set_horizontal_line_char_keeps_intersections_test,
+    grid(3, 2)
+        .config(|cfg| {
+            cfg.insert_horizontal_line(1, HorizontalLine::new(Some('~'), Some('#'), Some('['), Some(']')));
+            cfg.set_horizontal_line_char(1, '=');
+        })
+        .build(),
+    "+---+---+"
+    "|0-0|0-1|"
+    "[===#===]"
+    "|1-0|1-1|"
+    "+---+---+"
+    "|2-0|2-1|"
+    "+---+---+"
+);
+
+test_table!(
+    grid_2x3_set_vertical_line_char_test,
+    grid(2, 3)
+        .config(|cfg| cfg.set_vertical_line_char(1, '!'))
+        .build(),
+    "+---+---+---+"
+    "|0-0!0-1|0-2|"
+    "+---+---+---+"
+    "|1-0!1-1|1-2|"
+    "+---+---+---+"
+);
+
+#[test]
+fn describe_borders_2x2_with_override_test() {
+    let mut cfg = papergrid::config::spanned::SpannedConfig::default();
+    cfg.set_borders(Borders {
+        top: Some('-'),
+        bottom: Some('-'),
+        left: Some('|'),
+        right: Some('|'),
+        vertical: Some('|'),
+        horizontal: Some('-'),
+        ..Default::default()
+    });
+    cfg.set_borders_missing('+');
+    cfg.set_border(
+        pos(1, 1),
+        Border::full('*', '*', '#', '#', '@', '@', '@', '@'),
+    );
+
+    assert_eq!(
+        cfg.describe_borders((2, 2)),
+        "+-+-+\n\
+         |.|.|\n\
+         +-@*@\n\
+         |.#.#\n\
+         +-@*@"
+    );
+}
@@ -1,7 +1,4 @@
-use std::{
-    cmp,
-    iter::{repeat, FromIterator},
-};
+use std::{cmp, iter::FromIterator};
 
 use ron::{Number, Value};
 use tabled::{
@@ -29,6 +26,7 @@ pub struct RonTable {
     cfg: CompactMultilineConfig,
     plain: bool,
     object_orientation: Orientation,
+    root_object_orientation: Option<Orientation>,
     array_orientation: Orientation,
 }
 
@@ -39,6 +37,7 @@ impl Default for RonTable {
             cfg: configure_grid(),
             array_orientation: Orientation::Column,
             object_orientation: Orientation::Column,
+            root_object_orientation: None,
         }
     }
 }
@@ -61,6 +60,14 @@ impl RonTable {
         self
     }
 
+    /// Set a table mode for a root [`ron::Value::Map`].
+    ///
+    /// Nested maps are still rendered according to [`RonTable::map_orientation`].
+    pub fn root_map_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.root_object_orientation = Some(mode);
+        self
+    }
+
     /// Set a table mode for a [`ron::Value::Seq`].
     pub fn seq_orientation(&mut self, mode: Orientation) -> &mut Self {
         self.array_orientation = mode;
@@ -79,6 +86,13 @@ impl RonTable {
         self
     }
 
+    fn get_map_orientation(&self, is_root: bool) -> Orientation {
+        match (is_root, self.root_object_orientation) {
+            (true, Some(orientation)) => orientation,
+            _ => self.object_orientation,
+        }
+    }
+
     /// Build a table.
     pub fn build(&self, value: &Value) -> String {
         match self.plain {
@@ -123,7 +137,7 @@ fn collapsed_table(value: &Value, cfg: &RonTable) -> String {
         alignment_horizontal: cfg.cfg.get_alignment_horizontal(),
         alignment_vertical: cfg.cfg.get_alignment_vertical(),
     };
-    let value = convert_value_to_table_value(value, ctx);
+    let value = convert_root_to_table_value(value, cfg.get_map_orientation(true), ctx);
 
     PoolTable::from(value)
         .with(cfg.cfg)
@@ -136,10 +150,7 @@ fn collapsed_table(value: &Value, cfg: &RonTable) -> String {
 
 fn convert_value_to_table_value(value: &Value, ctx: CollapseCtx) -> TableValue {
     match value {
-        Value::Map(map) => match ctx.map_orientation {
-            Orientation::Row => convert_map_to_row(map, ctx),
-            Orientation::Column => convert_map_to_column(map, ctx),
-        },
+        Value::Map(map) => convert_map(map, ctx.map_orientation, ctx),
         Value::Option(opt) => match opt {
             Some(value) => convert_value_to_table_value(value, ctx),
             None => TableValue::Cell(String::new()),
@@ -154,6 +165,25 @@ fn convert_value_to_table_value(value: &Value, ctx: CollapseCtx) -> TableValue {
     }
 }
 
+fn convert_root_to_table_value(
+    value: &Value,
+    orientation: Orientation,
+    ctx: CollapseCtx,
+) -> TableValue {
+    match value {
+        Value::Map(map) => convert_map(map, orientation, ctx),
+        Value::Option(Some(value)) => convert_root_to_table_value(value, orientation, ctx),
+        value => convert_value_to_table_value(value, ctx),
+    }
+}
+
+fn convert_map(map: &ron::Map, orientation: Orientation, ctx: CollapseCtx) -> TableValue {
+    match orientation {
+        Orientation::Row => convert_map_to_row(map, ctx),
+        Orientation::Column => convert_map_to_column(map, ctx),
+    }
+}
+
 fn convert_list(list: &[Value], ctx: CollapseCtx) -> TableValue {
     let list = list
         .iter()
@@ -306,9 +336,9 @@ fn increase_string_width(text: &str, by: usize, ah: AlignmentHorizontal) -> Stri
         let (left, right) = indent_horizontal(ah, w + by, w);
 
         let mut buf = String::new();
-        buf.extend(repeat(' ').take(left));
+        buf.extend(std::iter::repeat_n(' ', left));
         buf.push_str(&line);
-        buf.extend(repeat(' ').take(right));
+        buf.extend(std::iter::repeat_n(' ', right));
 
         out.push(buf);
     }
@@ -323,13 +353,13 @@ fn increase_string_height(text: &str, by: usize, av: AlignmentVertical) -> Strin
 
     let (top, bottom) = indent_vertical(av, count_lines + by, count_lines);
 
-    out.extend(repeat(String::new()).take(top));
+    out.extend(std::iter::repeat_n(String::new(), top));
 
     for line in get_lines(text) {
         out.push(line.into_owned());
     }
 
-    out.extend(repeat(String::new()).take(bottom));
+    out.extend(std::iter::repeat_n(String::new(), bottom));
 
     out.join("\n")
 }
@@ -374,7 +404,7 @@ fn _plain_table(value: &Value, cfg: &RonTable, outer: bool) -> String {
             Orientation::Column => seq_column_table(arr, cfg, &config),
             Orientation::Row => seq_row_table(arr, cfg, &config),
         },
-        Value::Map(map) => match cfg.object_orientation {
+        Value::Map(map) => match cfg.get_map_orientation(outer) {
             Orientation::Column => map_column_table(map, cfg, &config),
             Orientation::Row => map_row_table(map, cfg, &config),
         },
@@ -554,6 +554,28 @@ test_table!(
     "+---------------------------+"
 );
 
+test_table!(
+    test_root_map_orientation,
+    RonTable::default()
+        .collapse()
+        .root_map_orientation(Orientation::Row)
+        .build(&Value::Map(Map::from_iter([
+            (Value::String(String::from("a")), Value::Number(Number::Integer(1))),
+            (
+                Value::String(String::from("b")),
+                Value::Map(Map::from_iter([(
+                    Value::String(String::from("c")),
+                    Value::Number(Number::Integer(2)),
+                )])),
+            ),
+        ]))),
+    "+---+-------+"
+    "| a | b     |"
+    "+---+---+---+"
+    "| 1 | c | 2 |"
+    "+---+---+---+"
+);
+
 fn build_ron_table(value: Value) -> String {
     RonTable::default().collapse().build(&value)
 }
@@ -150,6 +150,36 @@
 //! )
 //! ```
 //!
+//! `ALIGNMENT` may be a comma separated list, in which case alignments are set to columns in order.
+//! If there are fewer values than columns only the leading columns are changed.
+//!
+//! ```
+//! use static_table::static_table;
+//!
+//! const INTRO_TABLE: &str = static_table!(
+//!     [
+//!         ["name", "designed by", "first release"],
+//!         ["C", "Dennis Ritchie", "1972"],
+//!         ["Go", "Rob Pike", "2009"],
+//!         ["Rust", "Graydon Hoare", "2010"]
+//!     ],
+//!     ALIGNMENT = "LEFT, RIGHT, CENTER",
+//! );
+//!
+//! assert_eq!(
+//!     INTRO_TABLE,
+//!     "+------+----------------+---------------+\n\
+//!      | name |    designed by | first release |\n\
+//!      +------+----------------+---------------+\n\
+//!      | C    | Dennis Ritchie |     1972      |\n\
+//!      +------+----------------+---------------+\n\
+//!      | Go   |       Rob Pike |     2009      |\n\
+//!      +------+----------------+---------------+\n\
+//!      | Rust |  Graydon Hoare |     2010      |\n\
+//!      +------+----------------+---------------+"
+//! )
+//! ```
+//!
 //! [`static_table`]: crate::static_table!
 //! [`pool_table`]: crate::pool_table!
 
@@ -247,19 +277,65 @@ mod static_table;
 /// Supported settings are:
 ///
 /// - THEME
-/// - ALIGNMENT
+/// - ALIGNMENT (a single value or a list of values per column, like `"LEFT, RIGHT"`)
 /// - PADDING
 /// - MARGIN
+///
+/// Alternatively the input can be a CSV string.
+/// Quoted fields may contain commas, quotes (as `""`) and new lines.
+/// Blank lines are skipped.
+///
+/// ```
+/// use static_table::static_table;
+///
+/// const TABLE: &str = static_table!(
+///     csv = "name,designed by\nC,Dennis Ritchie\n\"Go, golang\",Rob Pike\n",
+///     THEME = "MODERN",
+/// );
+///
+/// assert_eq!(
+///     TABLE,
+///     "┌────────────┬────────────────┐\n\
+///      │ name       │ designed by    │\n\
+///      ├────────────┼────────────────┤\n\
+///      │ C          │ Dennis Ritchie │\n\
+///      ├────────────┼────────────────┤\n\
+///      │ Go, golang │ Rob Pike       │\n\
+///      └────────────┴────────────────┘"
+/// );
+/// ```
+///
+/// A CSV can be read from a file by `include_str!`.
+/// Notice that the path is relative to the crate root (where `Cargo.toml` is),
+/// not to the current file.
+///
+/// ```
+/// # use static_table::static_table;
+/// static_table!(csv = include_str!("tests/data/languages.csv"), THEME = "ROUNDED");
+/// ```
+///
+/// A quoted field which is not terminated is a compile error.
+///
+/// ```rust,compile_fail
+/// # use static_table::static_table;
+/// static_table!(csv = "name,quote\nDoe,\"unterminated\n");
+/// ```
 #[proc_macro]
 #[proc_macro_error]
 pub fn static_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     use crate::static_table::{build_table, TableStruct};
 
     let table = parse_macro_input!(input as TableStruct);
+    let file = table.get_file();
     let table = build_table(&table);
-    match table {
-        Ok(table) => proc_macro::TokenStream::from(quote! { #table }),
-        Err(err) => proc_macro::TokenStream::from(err.into_compile_error()),
+    match (table, file) {
+        // the file is included so the table is rebuilt once the file is changed
+        (Ok(table), Some(file)) => proc_macro::TokenStream::from(quote! {{
+            const _: &str = include_str!(#file);
+            #table
+        }}),
+        (Ok(table), None) => proc_macro::TokenStream::from(quote! { #table }),
+        (Err(err), _) => proc_macro::TokenStream::from(err.into_compile_error()),
     }
 }
 
@@ -322,9 +398,42 @@ pub fn static_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// Supported settings are:
 ///
 /// - THEME
-/// - ALIGNMENT
+/// - ALIGNMENT (a single value or a list of values per column, like `"LEFT, RIGHT"`)
 /// - PADDING
 /// - MARGIN
+/// - VALUE_ALIGNMENT (a vertical alignment of cells, one of `"TOP"`, `"BOTTOM"`, `"CENTER"`)
+/// - COLUMN_SPLIT (which cells of a row get extra width, one of `"EVEN"`, `"LEFT"`, `"RIGHT"`)
+///
+/// By default an extra width is split evenly among cells of a row,
+/// while `COLUMN_SPLIT` makes a layout more predictable by giving it to the first (`"LEFT"`)
+/// or the last (`"RIGHT"`) cell.
+///
+/// ```
+/// use static_table::pool_table;
+///
+/// let table = pool_table!(
+///     [
+///         ["name", "designed by", "first release"],
+///         ["C", "Dennis Ritchie", "1972"],
+///         ["Go", "Rob Pike", "2009"],
+///         ["Rust", "Graydon Hoare", "2010"],
+///     ],
+///     COLUMN_SPLIT = "RIGHT",
+/// );
+///
+/// assert_eq!(
+///     table,
+///     "+------+-------------+---------------+\n\
+///      | name | designed by | first release |\n\
+///      +---+--+-------------+---------------+\n\
+///      | C | Dennis Ritchie | 1972          |\n\
+///      +---++----------+----+---------------+\n\
+///      | Go | Rob Pike | 2009               |\n\
+///      +----+-+--------+------+-------------+\n\
+///      | Rust | Graydon Hoare | 2010        |\n\
+///      +------+---------------+-------------+"
+/// );
+/// ```
 #[proc_macro]
 #[proc_macro_error]
 pub fn pool_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
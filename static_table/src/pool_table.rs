@@ -8,6 +8,10 @@ use syn::{
     ExprLit, Ident, Lit, LitInt, LitStr, Result, Token,
 };
 use tabled::{
+    grid::{
+        config::AlignmentHorizontal,
+        dimension::{DimensionPriority, PoolTableDimension},
+    },
     settings::{Alignment, Margin, Padding, Style},
     tables::{PoolTable, TableValue},
 };
@@ -332,19 +336,46 @@ fn panic_not_supported_alignment(ident: &LitStr) {
     )
 }
 
+fn panic_not_supported_column_alignment(ident: &LitStr) {
+    proc_macro_error2::abort!(
+        ident,
+        "The given settings is not supported";
+        note="only a horizontal alignment can be set per column";
+        help = r#"Supported alignment are [LEFT, RIGHT, CENTER]"#
+    )
+}
+
+fn panic_not_supported_value_alignment(ident: &LitStr) -> ! {
+    proc_macro_error2::abort!(
+        ident,
+        "The given settings is not supported";
+        help = r#"Supported vertical alignment are [TOP, BOTTOM, CENTER]"#
+    )
+}
+
+fn panic_not_supported_column_split(ident: &LitStr) -> ! {
+    proc_macro_error2::abort!(
+        ident,
+        "The given settings is not supported";
+        help = r#"Supported column split are [EVEN, LEFT, RIGHT]"#
+    )
+}
+
 fn panic_not_supported_settings(ident: &Ident) {
     proc_macro_error2::abort!(
         ident,
         "The given settings is not supported";
-        help = r#"Supported list is [THEME, PADDING, MARGIN]"#
+        help = r#"Supported list is [THEME, PADDING, MARGIN, ALIGNMENT, VALUE_ALIGNMENT, COLUMN_SPLIT]"#
     )
 }
 
 pub(crate) fn build_table(table_st: &TableStruct) -> Result<String> {
-    let mut table = create_table(&table_st.matrix)?;
+    let data = collect_matrix(&table_st.matrix)?;
+    let count_columns = data.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut table = PoolTable::from(matrix_to_table_value(data));
 
     if table_st.comma_token.is_some() {
-        apply_settings(&mut table, &table_st.settings)?;
+        apply_settings(&mut table, count_columns, &table_st.settings)?;
     }
 
     Ok(table.to_string())
@@ -352,10 +383,11 @@ pub(crate) fn build_table(table_st: &TableStruct) -> Result<String> {
 
 fn apply_settings(
     table: &mut PoolTable,
+    count_columns: usize,
     settings: &Punctuated<KeyValue<LitStr>, Token![,]>,
 ) -> Result<()> {
     for kv in settings {
-        config_table(table, kv)?;
+        config_table(table, count_columns, kv)?;
     }
 
     Ok(())
@@ -380,7 +412,7 @@ fn apply_alignment(table: &mut PoolTable, name: &str) {
     };
 }
 
-fn config_table(table: &mut PoolTable, kv: &KeyValue<LitStr>) -> Result<()> {
+fn config_table(table: &mut PoolTable, count_columns: usize, kv: &KeyValue<LitStr>) -> Result<()> {
     if kv.key == "THEME" {
         let theme = kv.value.value();
         if !is_supported_theme(&theme) {
@@ -395,12 +427,56 @@ fn config_table(table: &mut PoolTable, kv: &KeyValue<LitStr>) -> Result<()> {
         let margin = kv.value.parse().and_then(build_margin)?;
         table.with(margin);
     } else if kv.key == "ALIGNMENT" {
-        let alignment = kv.value.value();
-        if !is_supported_alignment(&alignment) {
-            panic_not_supported_alignment(&kv.value);
+        let value = kv.value.value();
+        let list = value.split(',').map(str::trim).collect::<Vec<_>>();
+        for alignment in &list {
+            if !is_supported_alignment(alignment) {
+                panic_not_supported_alignment(&kv.value);
+            }
         }
 
-        apply_alignment(table, &alignment);
+        if list.len() == 1 {
+            apply_alignment(table, list[0]);
+        } else {
+            if list.len() > count_columns {
+                let msg = format!(
+                    "Expected at most {} alignments, one per column, but got {}",
+                    count_columns,
+                    list.len()
+                );
+                return Err(syn::Error::new_spanned(&kv.value, msg));
+            }
+
+            let mut alignments = Vec::with_capacity(list.len());
+            for alignment in list {
+                match alignment {
+                    "LEFT" => alignments.push(AlignmentHorizontal::Left),
+                    "RIGHT" => alignments.push(AlignmentHorizontal::Right),
+                    "CENTER" => alignments.push(AlignmentHorizontal::Center),
+                    _ => panic_not_supported_column_alignment(&kv.value),
+                }
+            }
+
+            table.alignment_columns(alignments);
+        }
+    } else if kv.key == "VALUE_ALIGNMENT" {
+        let alignment = match kv.value.value().as_str() {
+            "TOP" => Alignment::top(),
+            "BOTTOM" => Alignment::bottom(),
+            "CENTER" => Alignment::center_vertical(),
+            _ => panic_not_supported_value_alignment(&kv.value),
+        };
+
+        table.with(alignment);
+    } else if kv.key == "COLUMN_SPLIT" {
+        let priority = match kv.value.value().as_str() {
+            "EVEN" => DimensionPriority::List,
+            "LEFT" => DimensionPriority::First,
+            "RIGHT" => DimensionPriority::Last,
+            _ => panic_not_supported_column_split(&kv.value),
+        };
+
+        table.with(PoolTableDimension::new(priority, DimensionPriority::List));
     } else {
         panic_not_supported_settings(&kv.key);
     }
@@ -408,12 +484,6 @@ fn config_table(table: &mut PoolTable, kv: &KeyValue<LitStr>) -> Result<()> {
     Ok(())
 }
 
-fn create_table(mat: &MatrixInput) -> Result<PoolTable> {
-    let data = collect_matrix(mat)?;
-    let value = matrix_to_table_value(data);
-    Ok(PoolTable::from(value))
-}
-
 fn matrix_to_table_value(m: Vec<Vec<String>>) -> TableValue {
     TableValue::Column(
         m.into_iter()
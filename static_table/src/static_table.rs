@@ -1,11 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::FromIterator,
+    path::PathBuf,
 };
 
 use quote::ToTokens;
 use syn::{
-    braced, bracketed,
+    braced, bracketed, parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::{self, Brace},
@@ -13,7 +14,10 @@ use syn::{
 };
 use tabled::{
     builder::Builder,
-    settings::{style::BorderSpanCorrection, Alignment, Margin, Modify, Padding, Span, Style},
+    settings::{
+        object::Columns, style::BorderSpanCorrection, Alignment, Margin, Modify, Padding, Span,
+        Style,
+    },
     Table,
 };
 
@@ -227,12 +231,96 @@ impl<V: Parse> Parse for KeyValue<V> {
     }
 }
 
+enum TableInput {
+    Matrix(MatrixInput),
+    Csv(CsvInput),
+}
+
+impl Parse for TableInput {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            return input.parse().map(TableInput::Csv);
+        }
+
+        input.parse().map(TableInput::Matrix)
+    }
+}
+
+struct CsvInput {
+    data: Vec<Vec<String>>,
+    file: Option<PathBuf>,
+}
+
+impl Parse for CsvInput {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "csv" {
+            return Err(syn::Error::new_spanned(
+                key,
+                "Expected either a matrix or `csv = ...` input",
+            ));
+        }
+
+        let _: Token![=] = input.parse()?;
+
+        if input.peek(LitStr) {
+            let text: LitStr = input.parse()?;
+            let data =
+                parse_csv(&text.value()).map_err(|msg| syn::Error::new_spanned(&text, msg))?;
+
+            return Ok(Self { data, file: None });
+        }
+
+        let name: Ident = input.parse()?;
+        if name != "include_str" {
+            return Err(syn::Error::new_spanned(
+                name,
+                "Expected either a string literal or `include_str!`",
+            ));
+        }
+
+        let _: Token![!] = input.parse()?;
+        let content;
+        let _ = parenthesized!(content in input);
+        let path: LitStr = content.parse()?;
+
+        // a proc macro doesn't know a location of a file it's called from,
+        // so a path is considered to be relative to a crate root.
+        let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+        let file = PathBuf::from(root).join(path.value());
+        let text = std::fs::read_to_string(&file).map_err(|err| {
+            let msg = format!("Couldn't read a file {}: {}", file.display(), err);
+            syn::Error::new_spanned(&path, msg)
+        })?;
+
+        let data = parse_csv(&text).map_err(|msg| {
+            let msg = format!("Couldn't parse a file {}: {}", file.display(), msg);
+            syn::Error::new_spanned(&path, msg)
+        })?;
+
+        Ok(Self {
+            data,
+            file: Some(file),
+        })
+    }
+}
+
 pub(crate) struct TableStruct {
-    matrix: MatrixInput,
+    matrix: TableInput,
     comma_token: Option<Token![,]>,
     settings: Punctuated<KeyValue<LitStr>, Token!(,)>,
 }
 
+impl TableStruct {
+    /// Returns a file which was used as an input.
+    pub(crate) fn get_file(&self) -> Option<String> {
+        match &self.matrix {
+            TableInput::Csv(csv) => csv.file.as_ref().map(|f| f.display().to_string()),
+            TableInput::Matrix(_) => None,
+        }
+    }
+}
+
 impl Parse for TableStruct {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let matrix = input.parse()?;
@@ -366,7 +454,7 @@ fn collect_row(elems: &MatrixRowElements) -> Result<Vec<String>> {
         MatrixRowElements::Static { elem, len, .. } => {
             let len = len.base10_parse::<usize>()?;
             let elem = expr_val_to_list(elem)?;
-            let iter = std::iter::repeat(elem).take(len).flatten();
+            let iter = std::iter::repeat_n(elem, len).flatten();
 
             row.extend(iter);
         }
@@ -679,6 +767,20 @@ fn apply_alignment(table: &mut Table, name: &str) {
     };
 }
 
+fn apply_column_alignment(table: &mut Table, col: usize, name: &str) {
+    let alignment = match name {
+        "LEFT" => Alignment::left(),
+        "RIGHT" => Alignment::right(),
+        "CENTER" => Alignment::center(),
+        "CENTER_VERTICAL" => Alignment::center_vertical(),
+        "TOP" => Alignment::top(),
+        "BOTTOM" => Alignment::bottom(),
+        _ => unreachable!(),
+    };
+
+    table.modify(Columns::single(col), alignment);
+}
+
 fn config_table(table: &mut Table, kv: &KeyValue<LitStr>) -> Result<()> {
     if kv.key == "THEME" {
         let theme = kv.value.value();
@@ -694,12 +796,31 @@ fn config_table(table: &mut Table, kv: &KeyValue<LitStr>) -> Result<()> {
         let margin = kv.value.parse().and_then(build_margin)?;
         table.with(margin);
     } else if kv.key == "ALIGNMENT" {
-        let alignment = kv.value.value();
-        if !is_supported_alignment(&alignment) {
-            panic_not_supported_alignment(&kv.value);
+        let value = kv.value.value();
+        let list = value.split(',').map(str::trim).collect::<Vec<_>>();
+        for alignment in &list {
+            if !is_supported_alignment(alignment) {
+                panic_not_supported_alignment(&kv.value);
+            }
         }
 
-        apply_alignment(table, &alignment);
+        if list.len() == 1 {
+            apply_alignment(table, list[0]);
+        } else {
+            let count_columns = table.count_columns();
+            if list.len() > count_columns {
+                let msg = format!(
+                    "Expected at most {} alignments, one per column, but got {}",
+                    count_columns,
+                    list.len()
+                );
+                return Err(syn::Error::new_spanned(&kv.value, msg));
+            }
+
+            for (col, alignment) in list.iter().enumerate() {
+                apply_column_alignment(table, col, alignment);
+            }
+        }
     } else {
         panic_not_supported_settings(&kv.key);
     }
@@ -707,7 +828,14 @@ fn config_table(table: &mut Table, kv: &KeyValue<LitStr>) -> Result<()> {
     Ok(())
 }
 
-fn create_table(mat: &MatrixInput) -> Result<Table> {
+fn create_table(input: &TableInput) -> Result<Table> {
+    match input {
+        TableInput::Matrix(mat) => create_matrix_table(mat),
+        TableInput::Csv(csv) => Ok(Builder::from_iter(csv.data.clone()).build()),
+    }
+}
+
+fn create_matrix_table(mat: &MatrixInput) -> Result<Table> {
     let data = collect_matrix(mat)?;
     let vspan = collect_vspan(mat)?;
     let hspan = collect_hspan(mat)?;
@@ -716,12 +844,73 @@ fn create_table(mat: &MatrixInput) -> Result<Table> {
     let mut table = builder.build();
 
     for (pos, span) in vspan {
-        table.with(Modify::new(pos).with(Span::column(span)));
+        table.with(Modify::new(pos).with(Span::column(span as isize)));
     }
 
     for (pos, span) in hspan {
-        table.with(Modify::new(pos).with(Span::row(span)));
+        table.with(Modify::new(pos).with(Span::row(span as isize)));
     }
 
     Ok(table)
 }
+
+// A minimal CSV parser,
+// which supports quoted fields with escaped quotes, commas and new lines inside.
+//
+// Blank lines are skipped.
+fn parse_csv(text: &str) -> std::result::Result<Vec<Vec<String>>, String> {
+    let mut data = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut is_empty_row = true;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    let _ = chars.next();
+                }
+                '"' => is_quoted = false,
+                c => field.push(c),
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => {
+                is_quoted = true;
+                is_empty_row = false;
+            }
+            ',' => {
+                row.push(std::mem::take(&mut field));
+                is_empty_row = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' if is_empty_row => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                data.push(std::mem::take(&mut row));
+                is_empty_row = true;
+            }
+            c => {
+                field.push(c);
+                is_empty_row = false;
+            }
+        }
+    }
+
+    if is_quoted {
+        return Err(String::from("A quoted field is not terminated"));
+    }
+
+    if !is_empty_row {
+        row.push(field);
+        data.push(row);
+    }
+
+    Ok(data)
+}
@@ -111,3 +111,49 @@ test_table!(
     "|      1 |    1 |    1 |"
     "+--------+------+------+"
 );
+
+test_table!(
+    static_table_with_alignment_list,
+    static_table!(
+        [["name", "count", "status"], ["apple", 1, "ok"], ["kiwi", 1000, "not ok"]],
+        ALIGNMENT = "LEFT, RIGHT, CENTER"
+    ),
+    "+-------+-------+--------+"
+    "| name  | count | status |"
+    "+-------+-------+--------+"
+    "| apple |     1 |   ok   |"
+    "+-------+-------+--------+"
+    "| kiwi  |  1000 | not ok |"
+    "+-------+-------+--------+"
+);
+
+test_table!(
+    static_table_with_alignment_list_less_than_columns,
+    static_table!(
+        [["name", "count", "status"], ["apple", 1, "ok"], ["kiwi", 1000, "not ok"]],
+        ALIGNMENT = "CENTER,RIGHT"
+    ),
+    "+-------+-------+--------+"
+    "| name  | count | status |"
+    "+-------+-------+--------+"
+    "| apple |     1 | ok     |"
+    "+-------+-------+--------+"
+    "| kiwi  |  1000 | not ok |"
+    "+-------+-------+--------+"
+);
+
+test_table!(
+    static_table_with_alignment_list_vertical,
+    static_table!(
+        [["some\nmulti-\nline\nstring", "line", "line"], [1, 1, 1]],
+        ALIGNMENT = "TOP, BOTTOM, CENTER_VERTICAL"
+    ),
+    "+--------+------+------+"
+    "| some   |      |      |"
+    "| multi- |      | line |"
+    "| line   |      |      |"
+    "| string | line |      |"
+    "+--------+------+------+"
+    "| 1      | 1    | 1    |"
+    "+--------+------+------+"
+);
@@ -349,3 +349,95 @@ test_table!(
     "║ 1 ║ 2 ║ 3 ║ 4 ║ 5 ║ 6 ║ 7 ║ 8 ║ 9 ║"
     "╚═══╩═══╩═══╩═══╩═══╩═══╩═══╩═══╩═══╝"
 );
+
+test_table!(
+    pool_table_with_alignment_list,
+    pool_table!(
+        [["Hello World"], [1, 2, 123], [1, 2, 3, 4, 5, 6, 7, 8, 9],],
+        ALIGNMENT = "CENTER, RIGHT"
+    ),
+    "+-----------------------------------+"
+    "|            Hello World            |"
+    "+-----------+----------+------------+"
+    "|     1     |        2 | 123        |"
+    "+---+---+---+---+---+--++---+---+---+"
+    "| 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 |"
+    "+---+---+---+---+---+---+---+---+---+"
+);
+
+test_table!(
+    pool_table_with_column_split_left,
+    pool_table!(
+        [["Hello World"], [1, 2, 123], [1, 2, 3, 4, 5, 6, 7, 8, 9],],
+        COLUMN_SPLIT = "LEFT"
+    ),
+    "+-----------------------------------+"
+    "| Hello World                       |"
+    "+-------------------------+---+-----+"
+    "| 1                       | 2 | 123 |"
+    "+---+---+---+---+---+---+-+-+-+-+---+"
+    "| 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 |"
+    "+---+---+---+---+---+---+---+---+---+"
+);
+
+test_table!(
+    pool_table_with_column_split_right,
+    pool_table!(
+        [["Hello World"], [1, 2, 123], [1, 2, 3, 4, 5, 6, 7, 8, 9],],
+        COLUMN_SPLIT = "RIGHT"
+    ),
+    "+-----------------------------------+"
+    "| Hello World                       |"
+    "+---+---+---------------------------+"
+    "| 1 | 2 | 123                       |"
+    "+---+---+---+---+---+---+---+---+---+"
+    "| 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 |"
+    "+---+---+---+---+---+---+---+---+---+"
+);
+
+test_table!(
+    pool_table_with_column_split_even,
+    pool_table!(
+        [["Hello World"], [1, 2, 123], [1, 2, 3, 4, 5, 6, 7, 8, 9],],
+        COLUMN_SPLIT = "EVEN"
+    ),
+    "+-----------------------------------+"
+    "| Hello World                       |"
+    "+-----------+----------+------------+"
+    "| 1         | 2        | 123        |"
+    "+---+---+---+---+---+--++---+---+---+"
+    "| 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 |"
+    "+---+---+---+---+---+---+---+---+---+"
+);
+
+test_table!(
+    pool_table_with_value_alignment,
+    pool_table!(
+        [["Hello\nWorld\n!", 1, 2], ["a", "b\nc"]],
+        VALUE_ALIGNMENT = "BOTTOM"
+    ),
+    "+-------+---+---+"
+    "| Hello |   |   |"
+    "| World |   |   |"
+    "| !     | 1 | 2 |"
+    "+-------+---+---+"
+    "|       | b     |"
+    "| a     | c     |"
+    "+-------+-------+"
+);
+
+test_table!(
+    pool_table_with_value_alignment_center,
+    pool_table!(
+        [["Hello\nWorld\n!", 1, 2], ["a", "b\nc"]],
+        VALUE_ALIGNMENT = "CENTER"
+    ),
+    "+-------+---+---+"
+    "| Hello |   |   |"
+    "| World | 1 | 2 |"
+    "| !     |   |   |"
+    "+-------+---+---+"
+    "| a     | b     |"
+    "|       | c     |"
+    "+-------+-------+"
+);
@@ -512,3 +512,76 @@ test_table!(
     " ╰─────┴─────┴───────╯ "
     "                       "
 );
+
+test_table!(
+    static_table_csv,
+    static_table!(csv = "a,b,c\n1,2,3\n4,5,6"),
+    "+---+---+---+"
+    "| a | b | c |"
+    "+---+---+---+"
+    "| 1 | 2 | 3 |"
+    "+---+---+---+"
+    "| 4 | 5 | 6 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    static_table_csv_quoted,
+    static_table!(csv = "name,quote\r\n\"Smith, John\",\"He said \"\"hi\"\"\"\r\nDoe,\"multi\nline\"\r\n"),
+    "+-------------+--------------+"
+    "| name        | quote        |"
+    "+-------------+--------------+"
+    "| Smith, John | He said \"hi\" |"
+    "+-------------+--------------+"
+    "| Doe         | multi        |"
+    "|             | line         |"
+    "+-------------+--------------+"
+);
+
+test_table!(
+    static_table_csv_empty_fields,
+    static_table!(csv = "a,,c\n,,\n"),
+    "+---+--+---+"
+    "| a |  | c |"
+    "+---+--+---+"
+    "|   |  |   |"
+    "+---+--+---+"
+);
+
+test_table!(
+    static_table_csv_blank_lines,
+    static_table!(csv = "\na,b\n\n1,2\r\n\r\n3,\"\n\n\"\n\n"),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 |   |"
+    "|   |   |"
+    "|   |   |"
+    "+---+---+"
+);
+
+test_table!(static_table_csv_empty, static_table!(csv = ""), "");
+
+test_table!(
+    static_table_csv_with_settings,
+    static_table!(csv = "a,b\n1,2", THEME = "MARKDOWN", ALIGNMENT = "RIGHT"),
+    "| a | b |"
+    "|---|---|"
+    "| 1 | 2 |"
+);
+
+test_table!(
+    static_table_csv_include_str,
+    static_table!(csv = include_str!("tests/data/languages.csv"), THEME = "MODERN"),
+    "┌──────┬───────────────────────┬───────────────┐"
+    "│ name │ designed by           │ first release │"
+    "├──────┼───────────────────────┼───────────────┤"
+    "│ C    │ Dennis Ritchie        │ 1972          │"
+    "├──────┼───────────────────────┼───────────────┤"
+    "│ Go   │ Rob Pike              │ 2009          │"
+    "├──────┼───────────────────────┼───────────────┤"
+    "│ Rust │ Graydon Hoare, et al. │ 2010          │"
+    "└──────┴───────────────────────┴───────────────┘"
+);
@@ -127,7 +127,7 @@ impl HtmlElement {
 
 impl Display for HtmlElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        build_html(f, self)
+        build_html(f, self, false)
     }
 }
 
@@ -155,25 +155,60 @@ fn print_attributes(mut f: impl std::fmt::Write, attrs: &[Attribute]) -> std::fm
     Ok(())
 }
 
-fn build_html(f: impl std::fmt::Write, e: &HtmlElement) -> std::fmt::Result {
+// A minified element is printed without indentation and newlines,
+// while a content is printed as it is.
+pub(crate) fn build_html(
+    f: impl std::fmt::Write,
+    e: &HtmlElement,
+    minified: bool,
+) -> std::fmt::Result {
     struct Builder<W> {
         writer: W,
         tab: usize,
+        minified: bool,
         result: std::fmt::Result,
     }
 
     impl<W> Builder<W> {
-        fn new(writer: W, tab: usize) -> Self {
+        fn new(writer: W, tab: usize, minified: bool) -> Self {
             Self {
                 writer,
                 tab,
+                minified,
                 result: Ok(()),
             }
         }
     }
 
+    impl<W: std::fmt::Write> Builder<W> {
+        fn build_minified(&mut self, e: &HtmlElement) -> std::fmt::Result {
+            print_tag(&mut self.writer, e.tag(), e.attrs())?;
+
+            let val = match e.value() {
+                Some(val) => val,
+                None => return Ok(()),
+            };
+
+            match val {
+                HtmlValue::Elements(elems) => {
+                    for e in elems {
+                        self.build_minified(e)?;
+                    }
+                }
+                HtmlValue::Content(content) => self.writer.write_str(content)?,
+            }
+
+            write!(self.writer, "</{}>", e.tag())
+        }
+    }
+
     impl<W: std::fmt::Write> HtmlVisitor for Builder<W> {
         fn visit_element(&mut self, e: &HtmlElement) -> bool {
+            if self.minified {
+                self.result = self.build_minified(e);
+                return false;
+            }
+
             let space = " ".repeat(self.tab);
 
             let is_void_element = e.value().is_none();
@@ -214,7 +249,7 @@ fn build_html(f: impl std::fmt::Write, e: &HtmlElement) -> std::fmt::Result {
         }
     }
 
-    let mut builder = Builder::new(f, 0);
+    let mut builder = Builder::new(f, 0, minified);
     e.visit(&mut builder);
 
     builder.result
@@ -6,6 +6,8 @@ mod value;
 
 pub use attr::Attribute;
 pub use html_element::HtmlElement;
+
+pub(crate) use html_element::build_html;
 pub use value::HtmlValue;
 
 /// A visitor which traverses a HTML elements tree.
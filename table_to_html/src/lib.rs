@@ -385,7 +385,7 @@ use std::{
     fmt::{Display, Write},
 };
 
-use html::{HtmlElement, HtmlValue, HtmlVisitor, HtmlVisitorMut};
+use html::{build_html, HtmlElement, HtmlValue, HtmlVisitor, HtmlVisitorMut};
 use tabled::{
     builder::Builder,
     grid::{config::Sides, util::string::get_lines},
@@ -406,6 +406,8 @@ pub use tabled::settings::Alignment;
 pub struct HtmlTable {
     table: HtmlElement,
     css: BTreeMap<String, BTreeMap<String, String>>,
+    styles: BTreeMap<Position, Vec<(String, String)>>,
+    minified: bool,
 }
 
 impl HtmlTable {
@@ -474,6 +476,49 @@ impl HtmlTable {
         );
     }
 
+    /// Set an inline style property for a given cell.
+    ///
+    /// Properties are merged into a single `style` attribute,
+    /// so setting the same property again overwrites only it.
+    /// A property and a value are escaped, so they can't break the attribute.
+    ///
+    /// ```
+    /// use table_to_html::HtmlTable;
+    ///
+    /// let mut html_table = HtmlTable::new([["Hello", "World"]]);
+    /// html_table.set_minified(true);
+    /// html_table.set_style((0, 1), "color", "red");
+    /// html_table.set_style((0, 1), "font-family", "\"Fira Code\"");
+    ///
+    /// assert_eq!(
+    ///     html_table.to_string(),
+    ///     "<table><tbody><tr>\
+    ///      <td><div><p>Hello</p></div></td>\
+    ///      <td style=\"color: red; font-family: &quot;Fira Code&quot;\"><div><p>World</p></div></td>\
+    ///      </tr></tbody></table>"
+    /// );
+    /// ```
+    pub fn set_style<K, V>(&mut self, pos: Position, property: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let property = property.into();
+        let value = value.into();
+
+        let styles = self.styles.entry(pos).or_default();
+        match styles.iter_mut().find(|(key, _)| *key == property) {
+            Some((_, val)) => *val = value,
+            None => styles.push((property, value)),
+        }
+
+        let style = build_style(styles);
+        update_cell_attributes(&mut self.table, pos, |attrs| {
+            attrs.retain(|attr| attr.key() != "style");
+            attrs.push(Attribute::new("style", style));
+        });
+    }
+
     /// Set a margin for a whole table.
     pub fn set_margin(&mut self, margin: Margin) {
         let mut m = BTreeMap::new();
@@ -493,6 +538,28 @@ impl HtmlTable {
         self.css.insert(String::from("table, th, td"), m);
     }
 
+    /// Set a table to be printed without indentation and newlines.
+    ///
+    /// A content is kept as it is.
+    ///
+    /// ```
+    /// use table_to_html::HtmlTable;
+    ///
+    /// let mut html_table = HtmlTable::new([["Hello", "World"]]);
+    /// html_table.set_minified(true);
+    ///
+    /// assert_eq!(
+    ///     html_table.to_string(),
+    ///     "<table><tbody><tr>\
+    ///      <td><div><p>Hello</p></div></td>\
+    ///      <td><div><p>World</p></div></td>\
+    ///      </tr></tbody></table>"
+    /// );
+    /// ```
+    pub fn set_minified(&mut self, minified: bool) {
+        self.minified = minified;
+    }
+
     /// Uses the visitor to traverse a table.
     pub fn visit<V: HtmlVisitor>(&self, visitor: V) {
         self.table.visit(visitor);
@@ -520,23 +587,36 @@ impl Display for HtmlTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if !self.css.is_empty() {
             let css = build_css(&self.css);
-            css.fmt(f)?;
-            f.write_char('\n')?;
+            build_html(&mut *f, &css, self.minified)?;
+
+            if !self.minified {
+                f.write_char('\n')?;
+            }
         }
 
-        self.table.fmt(f)
+        build_html(f, &self.table, self.minified)
     }
 }
 
 fn set_cell_attribute(table: &mut HtmlElement, pos: Position, attr: Attribute) {
-    struct Setter {
+    update_cell_attributes(table, pos, |attrs| attrs.push(attr));
+}
+
+fn update_cell_attributes<F>(table: &mut HtmlElement, pos: Position, update: F)
+where
+    F: FnOnce(&mut Vec<Attribute>),
+{
+    struct Setter<F> {
         pos: Position,
-        attr: Attribute,
+        update: Option<F>,
         cursor: Position,
         is_started: bool,
     }
 
-    impl HtmlVisitorMut for Setter {
+    impl<F> HtmlVisitorMut for Setter<F>
+    where
+        F: FnOnce(&mut Vec<Attribute>),
+    {
         fn visit_element_mut(&mut self, e: &mut HtmlElement) -> bool {
             if self.cursor.0 != self.pos.0 {
                 // looking for a row
@@ -553,7 +633,9 @@ fn set_cell_attribute(table: &mut HtmlElement, pos: Position, attr: Attribute) {
                 if e.tag() == "td" || e.tag() == "th" {
                     if self.cursor == self.pos {
                         let mut attrs = e.attrs().to_vec();
-                        attrs.push(self.attr.clone());
+                        if let Some(update) = self.update.take() {
+                            update(&mut attrs);
+                        }
 
                         let val = e.value().cloned();
 
@@ -575,7 +657,7 @@ fn set_cell_attribute(table: &mut HtmlElement, pos: Position, attr: Attribute) {
     }
 
     table.visit_mut(&mut Setter {
-        attr,
+        update: Some(update),
         pos,
         cursor: (0, 0),
         is_started: false,
@@ -723,9 +805,34 @@ fn build_css_config(target: &str, values: &BTreeMap<String, String>) -> String {
     buf
 }
 
+fn build_style(styles: &[(String, String)]) -> String {
+    styles
+        .iter()
+        .map(|(key, value)| format!("{}: {}", escape_attribute(key), escape_attribute(value)))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn escape_attribute(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '"' => buf.push_str("&quot;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            c => buf.push(c),
+        }
+    }
+
+    buf
+}
+
 fn html_table(data: Vec<Vec<String>>, has_header: bool) -> HtmlTable {
     HtmlTable {
         table: build_table(data, has_header),
         css: BTreeMap::default(),
+        styles: BTreeMap::default(),
+        minified: false,
     }
 }
@@ -1138,3 +1138,89 @@ test_table!(
     "    </tbody>"
     "</table>"
 );
+
+#[test]
+fn html_table_minified() {
+    let data = [["&lt;b&gt;", "a &amp; b"], ["multi\nline", "\"quoted\""]];
+
+    let mut table = HtmlTable::with_header(data);
+    table.set_alignment(Entity::Column(1), Alignment::right());
+
+    let pretty = table.to_string();
+
+    table.set_minified(true);
+    let minified = table.to_string();
+
+    assert_eq!(
+        minified,
+        concat!(
+            "<style>tbody > tr > :nth-child(2), thead > tr > :nth-child(2) {\n",
+            "  text-align: right;\n",
+            "}</style>",
+            "<table>",
+            "<thead><tr>",
+            "<th><div><p>&lt;b&gt;</p></div></th>",
+            "<th><div><p>a &amp; b</p></div></th>",
+            "</tr></thead>",
+            "<tbody><tr>",
+            "<td><div><p>multi</p><p>line</p></div></td>",
+            "<td><div><p>\"quoted\"</p></div></td>",
+            "</tr></tbody>",
+            "</table>",
+        )
+    );
+
+    let strip = |s: &str| s.split_whitespace().collect::<String>();
+    assert_eq!(strip(&minified), strip(&pretty));
+
+    table.set_minified(false);
+    assert_eq!(table.to_string(), pretty);
+}
+
+test_table!(
+    html_table_set_style,
+    {
+        let mut table = HtmlTable::new([["1", "2"], ["3", "4"]]);
+        table.set_column_span((1, 0), 1);
+        table.set_style((1, 0), "color", "red");
+        table.set_style((1, 0), "font-family", "\"Fira Code\" <mono>");
+        table.set_style((1, 0), "color", "blue");
+        table
+    },
+    "<table>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        2"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td colspan=\"1\" style=\"color: blue; font-family: &quot;Fira Code&quot; &lt;mono&gt;\">"
+    "                <div>"
+    "                    <p>"
+    "                        3"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td>"
+    "                <div>"
+    "                    <p>"
+    "                        4"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
@@ -0,0 +1,49 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use tabled::Table;
+
+fn build_table(size: usize) -> Table {
+    let data = vec![["This is a text 0", "1", "2", "3", "4", "5", "6", "7"]; size];
+    Table::new(data)
+}
+
+pub fn edit_full_estimate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("edit_full_estimate");
+    for size in [64, 512, 1024, 4096] {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            // no dimension is cached, so the whole table is estimated on each render
+            let mut table = build_table(size);
+
+            let mut i = 0;
+            b.iter(|| {
+                i += 1;
+                table.set_cell((1, 1), i.to_string());
+
+                let _ = black_box(table.to_string());
+            });
+        });
+    }
+    group.finish();
+}
+
+pub fn edit_single_cell(c: &mut Criterion) {
+    let mut group = c.benchmark_group("edit_single_cell");
+    for size in [64, 512, 1024, 4096] {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            let mut table = build_table(size);
+            table.cache_dimension();
+
+            let mut i = 0;
+            b.iter(|| {
+                i += 1;
+                table.set_cell((1, 1), i.to_string());
+
+                let _ = black_box(table.to_string());
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, edit_full_estimate, edit_single_cell);
+criterion_main!(benches);
@@ -130,7 +130,7 @@ fn create_small_table(style: Borders<char>) -> Table {
 // todo: very likely can be simplified
 fn create_main_table(message: &str) -> Table {
     let (count_lines, message_width) = string::get_text_dimension(message);
-    let count_additional_separators = if count_lines > 2 { count_lines - 2 } else { 0 };
+    let count_additional_separators = count_lines.saturating_sub(2);
     let left_table_space = (0..count_additional_separators)
         .map(|_| "    ║   \n")
         .collect::<String>();
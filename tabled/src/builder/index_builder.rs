@@ -223,6 +223,7 @@ impl IndexBuilder {
 impl From<Builder> for IndexBuilder {
     fn from(builder: Builder) -> Self {
         let count_columns = builder.count_columns();
+        let column = builder.get_index();
         let data: Vec<Vec<_>> = builder.into();
 
         let mut index = Vec::new();
@@ -232,13 +233,18 @@ impl From<Builder> for IndexBuilder {
             index = build_range_index(count_rows);
         }
 
-        Self {
+        let builder = Self {
             index,
             data,
             count_columns,
             name: None,
             print_index: true,
             transposed: false,
+        };
+
+        match column {
+            Some(column) => builder.column(column),
+            None => builder,
         }
     }
 }
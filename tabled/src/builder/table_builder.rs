@@ -1,6 +1,9 @@
-use std::iter::FromIterator;
+use std::{cmp, iter::FromIterator};
 
-use crate::{grid::records::vec_records::Text, Table};
+use crate::{
+    grid::records::{vec_records::Text, FlatRecords},
+    Table,
+};
 
 use super::IndexBuilder;
 
@@ -42,8 +45,12 @@ pub struct Builder {
     data: Vec<Vec<Text<String>>>,
     /// A number of columns.
     count_columns: usize,
+    /// A number of columns to reserve for each row.
+    capacity_columns: usize,
     /// A content of cells which are created in case rows has different length.
     empty_text: Text<String>,
+    /// A column which is marked as an index.
+    index: Option<usize>,
 }
 
 impl Builder {
@@ -58,19 +65,27 @@ impl Builder {
         Self::default()
     }
 
-    /// Creates a [`Builder`] instance with a given row capacity.
+    /// Creates a [`Builder`] instance with a capacity reserved
+    /// for a given number of records and columns.
+    ///
+    /// It's useful to reduce reallocations when a lot of records is pushed.
+    /// The capacity doesn't affect a shape of a built table,
+    /// so it's fine to push less records or columns than reserved.
     ///
     /// ```
     /// use tabled::builder::Builder;
     ///
-    /// let mut builder = Builder::with_capacity(2, 3);
+    /// let mut builder = Builder::with_capacity(3, 4);
     /// builder.push_record((0..3).map(|i| i.to_string()));
     /// builder.push_record(["i", "surname", "lastname"]);
+    ///
+    /// assert_eq!(builder.count_records(), 2);
+    /// assert_eq!(builder.count_columns(), 3);
     /// ```
     pub fn with_capacity(count_records: usize, count_columns: usize) -> Self {
         let mut builder = Self::new();
         builder.data = Vec::with_capacity(count_records);
-        builder.count_columns = count_columns;
+        builder.capacity_columns = count_columns;
 
         builder
     }
@@ -93,7 +108,9 @@ impl Builder {
         Self {
             data,
             count_columns,
+            capacity_columns: 0,
             empty_text: Text::default(),
+            index: None,
         }
     }
 
@@ -131,6 +148,7 @@ impl Builder {
     /// Add an index to the [`Table`].
     ///
     /// Default index is a range 0-N where N is amount of records.
+    /// A column marked by [`Builder::set_index`] is used as an index instead.
     ///
     /// # Example
     ///
@@ -156,6 +174,92 @@ impl Builder {
         IndexBuilder::from(self)
     }
 
+    /// Marks a column as an index column, which is a column of row labels.
+    ///
+    /// The column is used as an index by [`Builder::index`],
+    /// the same way [`IndexBuilder::column`] does.
+    ///
+    /// The column is not moved and the mark follows it
+    /// when columns are inserted or removed.
+    ///
+    /// An out of range column is ignored.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "id"]);
+    /// builder.push_record(["tabled", "0"]);
+    /// builder.set_index(1);
+    /// builder.insert_column(0, ["lang", "rust"]);
+    ///
+    /// assert_eq!(builder.get_index(), Some(2));
+    /// assert_eq!(
+    ///     builder.index().build().to_string(),
+    ///     "+----+------+--------+\n\
+    ///      |    | lang | name   |\n\
+    ///      +----+------+--------+\n\
+    ///      | id |      |        |\n\
+    ///      +----+------+--------+\n\
+    ///      | 0  | rust | tabled |\n\
+    ///      +----+------+--------+"
+    /// );
+    /// ```
+    pub fn set_index(&mut self, column: usize) {
+        if column < self.count_columns {
+            self.index = Some(column);
+        }
+    }
+
+    /// Returns a column which is marked as an index column.
+    ///
+    /// See [`Builder::set_index`].
+    pub fn get_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Moves a column to the front, including its header,
+    /// and marks it as an index column.
+    ///
+    /// So a built [`Table`] has it as the first column,
+    /// which can be targeted by [`Columns::first`].
+    /// See [`Builder::set_index`] for how the mark is used.
+    ///
+    /// An out of range column is ignored.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "lang", "id"]);
+    /// builder.push_record(["tabled", "rust", "0"]);
+    /// builder.move_index(2);
+    ///
+    /// assert_eq!(builder.get_index(), Some(0));
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+----+--------+------+\n\
+    ///      | id | name   | lang |\n\
+    ///      +----+--------+------+\n\
+    ///      | 0  | tabled | rust |\n\
+    ///      +----+--------+------+"
+    /// );
+    /// ```
+    ///
+    /// [`Columns::first`]: crate::settings::object::Columns::first
+    pub fn move_index(&mut self, column: usize) {
+        if column >= self.count_columns {
+            return;
+        }
+
+        for row in &mut self.data {
+            let text = row.remove(column);
+            row.insert(0, text);
+        }
+
+        self.index = Some(0);
+    }
+
     /// Adds a row to a [`Table`].
     ///
     /// ```
@@ -170,7 +274,12 @@ impl Builder {
         R: IntoIterator,
         R::Item: Into<String>,
     {
-        let list = create_row(record, self.count_columns, &self.empty_text);
+        let list = create_row(
+            record,
+            self.count_columns,
+            self.capacity_columns,
+            &self.empty_text,
+        );
         let list_length = list.len();
 
         if !is_size_eq(self.count_columns, list_length) {
@@ -192,7 +301,12 @@ impl Builder {
         R: IntoIterator,
         R::Item: Into<String>,
     {
-        let list = create_row(record, self.count_columns, &self.empty_text);
+        let list = create_row(
+            record,
+            self.count_columns,
+            self.capacity_columns,
+            &self.empty_text,
+        );
         let list_length = list.len();
 
         if !is_size_eq(self.count_columns, list_length) {
@@ -228,6 +342,16 @@ impl Builder {
     /// )
     /// ```
     pub fn clean(&mut self) {
+        self.index = self.index.and_then(|col| {
+            if is_empty_column(&self.data, col) {
+                return None;
+            }
+
+            let removed = (0..col).filter(|&i| is_empty_column(&self.data, i)).count();
+
+            Some(col - removed)
+        });
+
         self.count_columns -= remove_empty_columns(&mut self.data, self.count_columns);
         remove_empty_rows(&mut self.data, self.count_columns);
     }
@@ -257,10 +381,47 @@ impl Builder {
             let _ = row.remove(index);
         }
 
+        self.index = match self.index {
+            Some(col) if col == index => None,
+            Some(col) if col > index => Some(col - 1),
+            col => col,
+        };
+
         self.count_columns -= 1;
     }
 
-    /// Push a column.
+    /// Push a column, appending it as the rightmost one.
+    ///
+    /// The first element of the column goes to the first record,
+    /// which is a header of a built [`Table`].
+    ///
+    /// If the column is shorter than the amount of records, the rest is filled with an empty text
+    /// (see [`Builder::set_empty`]).
+    /// If it's longer, new records are added, which are empty except for the new column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["name", "lang"]);
+    /// builder.push_record(["tabled", "rust"]);
+    /// builder.push_column(["stars", "2k", "?"]);
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+------+-------+\n\
+    ///      | name   | lang | stars |\n\
+    ///      +--------+------+-------+\n\
+    ///      | tabled | rust | 2k    |\n\
+    ///      +--------+------+-------+\n\
+    ///      |        |      | ?     |\n\
+    ///      +--------+------+-------+"
+    /// );
+    /// ```
     pub fn push_column<I>(&mut self, column: I)
     where
         I: IntoIterator,
@@ -332,6 +493,12 @@ impl Builder {
             }
         }
 
+        if let Some(col) = self.index.as_mut() {
+            if *col >= index {
+                *col += 1;
+            }
+        }
+
         self.count_columns += 1;
     }
 
@@ -339,6 +506,7 @@ impl Builder {
     pub fn clear(&mut self) {
         self.data.clear();
         self.count_columns = 0;
+        self.index = None;
     }
 
     /// Returns an amount of columns which would be present in a built table.
@@ -365,6 +533,21 @@ impl From<Builder> for Vec<Vec<String>> {
     }
 }
 
+impl From<Builder> for FlatRecords {
+    fn from(builder: Builder) -> Self {
+        let count_rows = builder.data.len();
+        let count_columns = builder.count_columns;
+        let data = builder
+            .data
+            .into_iter()
+            .flat_map(|row| row.into_iter().map(Text::into_inner))
+            .collect();
+
+        FlatRecords::new(data, count_rows, count_columns)
+            .expect("a builder is expected to keep records rectangular")
+    }
+}
+
 impl From<Builder> for Vec<Vec<Text<String>>> {
     fn from(builder: Builder) -> Self {
         builder.data
@@ -465,7 +648,9 @@ impl From<Vec<Vec<String>>> for Builder {
         Self {
             data,
             count_columns,
+            capacity_columns: 0,
             empty_text: Text::default(),
+            index: None,
         }
     }
 }
@@ -477,17 +662,104 @@ impl From<Vec<Vec<Text<String>>>> for Builder {
         Self {
             data,
             count_columns,
+            capacity_columns: 0,
             empty_text: Text::default(),
+            index: None,
+        }
+    }
+}
+
+/// Deserializes a [`Builder`] from a logical data of a table,
+/// which is produced by serialization of a [`Table`].
+///
+/// ```json
+/// { "header": ["name", "age"], "rows": [["Tom", "8"]] }
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Builder {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Table", &["header", "rows"], BuilderVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct BuilderVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for BuilderVisitor {
+    type Value = Builder;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a table with a header and rows")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let header: Vec<String> = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let rows: Vec<Vec<String>> = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        Ok(build_from_parts(header, rows))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::{Error, IgnoredAny};
+
+        let mut header: Option<Vec<String>> = None;
+        let mut rows: Option<Vec<Vec<String>>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "header" if header.is_some() => return Err(Error::duplicate_field("header")),
+                "rows" if rows.is_some() => return Err(Error::duplicate_field("rows")),
+                "header" => header = Some(map.next_value()?),
+                "rows" => rows = Some(map.next_value()?),
+                _ => {
+                    let _ = map.next_value::<IgnoredAny>()?;
+                }
+            }
         }
+
+        let header = header.ok_or_else(|| Error::missing_field("header"))?;
+        let rows = rows.ok_or_else(|| Error::missing_field("rows"))?;
+
+        Ok(build_from_parts(header, rows))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn build_from_parts(header: Vec<String>, rows: Vec<Vec<String>>) -> Builder {
+    let mut builder = Builder::with_capacity(rows.len() + 1, header.len());
+    if !header.is_empty() {
+        builder.push_record(header);
     }
+
+    for row in rows {
+        builder.push_record(row);
+    }
+
+    builder
 }
 
-fn create_row<R>(row: R, size: usize, default: &Text<String>) -> Vec<Text<String>>
+fn create_row<R>(row: R, size: usize, capacity: usize, default: &Text<String>) -> Vec<Text<String>>
 where
     R: IntoIterator,
     R::Item: Into<String>,
 {
-    let mut list = Vec::with_capacity(size);
+    let mut list = Vec::with_capacity(cmp::max(size, capacity));
     for text in row {
         let text = text.into();
         let text = Text::new(text);
@@ -509,16 +781,7 @@ fn remove_empty_columns(data: &mut [Vec<Text<String>>], count_columns: usize) ->
     for col in 0..count_columns {
         let col = col - deleted;
 
-        let mut is_empty_column = true;
-        for row in data.iter() {
-            let text = &row[col];
-            if !text.as_ref().is_empty() {
-                is_empty_column = false;
-                break;
-            }
-        }
-
-        if is_empty_column {
+        if is_empty_column(data, col) {
             for row in data.iter_mut() {
                 let _ = row.remove(col);
             }
@@ -530,6 +793,10 @@ fn remove_empty_columns(data: &mut [Vec<Text<String>>], count_columns: usize) ->
     deleted
 }
 
+fn is_empty_column(data: &[Vec<Text<String>>], col: usize) -> bool {
+    data.iter().all(|row| row[col].as_ref().is_empty())
+}
+
 fn remove_empty_rows(data: &mut Vec<Vec<Text<String>>>, count_columns: usize) {
     let mut deleted = 0;
 
@@ -537,8 +804,7 @@ fn remove_empty_rows(data: &mut Vec<Vec<Text<String>>>, count_columns: usize) {
         let row = row - deleted;
 
         let mut is_empty_row = true;
-        for col in 0..count_columns {
-            let cell = &data[row][col];
+        for cell in data[row].iter().take(count_columns) {
             if !cell.as_ref().is_empty() {
                 is_empty_row = false;
                 break;
@@ -589,7 +855,7 @@ fn equalize_row_length(data: &mut Vec<Vec<Text<String>>>) -> usize {
     let (count_columns, is_consistent) = data.iter().fold(init, |mut acc, cur| {
         let length = cur.len();
         acc.1 = acc.1 && acc.0 == length;
-        acc.0 = std::cmp::max(acc.0, length);
+        acc.0 = cmp::max(acc.0, length);
         acc
     });
 
@@ -15,6 +15,20 @@
 /// struct SomeOtherType;
 /// ```
 ///
+/// Fields of generic types get the needed bounds automatically,
+/// `Display` for a displayed field and `Tabled` for an inlined one.
+///
+/// ```
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Wrapper<T> {
+///     value: T,
+/// }
+///
+/// assert_eq!(Wrapper { value: 1 }.fields(), ["1"]);
+/// ```
+///
 /// Bellow you'll find available options for it.
 ///
 /// ### Override a column name
@@ -68,6 +82,23 @@
 /// }
 /// ```
 ///
+/// A field can also be moved to the beginning or the end by `#[tabled(order = "first")]` and `#[tabled(order = "last")]`.
+/// Numeric orders take their exact positions first,
+/// then the rest of the positions are filled by `first` fields, not ordered fields and `last` fields,
+/// keeping a declaration order among each of them.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Person {
+///    #[tabled(order = "last")]
+///    id: u8,
+///    number: String,
+///    name: String,
+/// }
+/// ```
+///
 /// ### Format fields
 ///
 /// As was said already, using `#[derive(Tabled)]` is possible only when all fields implement a `Display` trait.
@@ -119,6 +150,57 @@
 /// }
 /// ```
 ///
+/// By default a function gets a reference to a field.
+/// Add a `by_value` flag to pass a copy of it instead (the field type must implement `Clone`).
+/// The flag can't be used without `display_with`.
+///
+/// ```rust,compile_fail
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// pub struct Port {
+///     #[tabled(by_value)]
+///     pub number: u16,
+/// }
+/// ```
+///
+/// A function can also get a [`FieldContext`] with a column header and index,
+/// by adding `context` as the last argument, like `#[tabled(display_with("some_function", context))]`.
+///
+/// ```rust,no_run
+/// use tabled::{FieldContext, Tabled};
+///
+/// #[derive(Tabled)]
+/// pub struct MyRecord {
+///     pub id: i64,
+///     #[tabled(display_with("display_valid", context))]
+///     pub valid: Option<bool>
+/// }
+///
+/// fn display_valid(o: &Option<bool>, ctx: FieldContext) -> String {
+///     format!("{} #{} = {:?}", ctx.header(), ctx.index(), o)
+/// }
+/// ```
+///
+/// A value returned by a function is formatted into a new `String`.
+/// If a function returns a `&str`, add `borrow` as the last argument to use it as is, without an allocation.
+///
+/// ```rust,no_run
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// pub struct MyRecord {
+///     #[tabled(display_with("str::trim", borrow))]
+///     pub name: String,
+///     #[tabled(display_with("display_valid", borrow))]
+///     pub valid: bool,
+/// }
+///
+/// fn display_valid(valid: &bool) -> &'static str {
+///     if *valid { "yes" } else { "no" }
+/// }
+/// ```
+///
 /// There's also a probably more suitable way for formatting, if your format is constant.
 /// Using `#[tabled(format = "{}")]` and `#[tabled(format("{}"))]` and proving a general formatting string.
 ///
@@ -153,10 +235,31 @@
 /// }
 /// ```
 ///
+/// The casing can be chosen at runtime as well,
+/// by `Tabled::headers_cased` with a [`CasingStyle`].
+/// An explicit `rename` is still kept as is.
+///
+/// ```
+/// use tabled::{CasingStyle, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Person {
+///     id: u8,
+///     #[tabled(rename = "Full Name")]
+///     full_name: String,
+///     middle_name: String,
+/// }
+///
+/// assert_eq!(Person::headers_cased(CasingStyle::Upper), ["ID", "Full Name", "MIDDLENAME"]);
+/// assert_eq!(Person::headers_cased(CasingStyle::Kebab), ["id", "Full Name", "middle-name"]);
+/// ```
+///
 /// ### Inline
 ///
 /// It's possible to inline internal data if it implements the `Tabled` trait using `#[tabled(inline)]`.
 /// You can also set a prefix which will be used for all inlined elements by `#[tabled(inline("prefix>>"))]`.
+/// A suffix and a separator can be set as well by `#[tabled(inline(prefix = "a", suffix = "b", sep = "."))]`;
+/// if only a separator is set, a field name is used as a prefix, so headers look like `ed.uni`.
 ///
 /// ```rust,no_run
 /// use tabled::Tabled;
@@ -201,4 +304,120 @@
 ///     price: f32,
 /// }
 /// ```
+///
+/// A nesting of inlined structs can be limited by `#[tabled(inline(depth = N))]`,
+/// where `N` is from 1 to [`MAX_INLINE_DEPTH`](crate::MAX_INLINE_DEPTH).
+/// Structs which are deeper than `N` are put into a single column,
+/// with their fields joined by `", "`.
+///
+/// ```
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Company {
+///     name: &'static str,
+///     #[tabled(inline(depth = 1))]
+///     owner: Person,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Person {
+///     name: &'static str,
+///     #[tabled(inline)]
+///     address: Address,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Address {
+///     city: &'static str,
+///     street: &'static str,
+/// }
+///
+/// let company = Company {
+///     name: "Tabled",
+///     owner: Person {
+///         name: "Maxim",
+///         address: Address { city: "Minsk", street: "Lenina" },
+///     },
+/// };
+///
+/// assert_eq!(Company::headers(), ["name", "name", "address"]);
+/// assert_eq!(company.fields(), ["Tabled", "Maxim", "Minsk, Lenina"]);
+/// ```
+///
+/// ### Crate path
+///
+/// By default the generated code refers to the trait as `::tabled::Tabled`.
+/// If `tabled` is re-exported by another crate or module use `#[tabled(crate = "path")]`
+/// to point to it, then the trait is referred as `path::Tabled`
+/// (and [`FieldContext`] as `path::FieldContext`).
+///
+/// As the path may point to another trait, only the required items of the trait are implemented,
+/// so `Tabled::headers_cased` keeps headers unchanged.
+/// The exception is `#[tabled(inline(depth = N))]`,
+/// which also needs [`HeadersOptions`] and [`MAX_INLINE_DEPTH`] to be available by the path.
+///
+/// ```rust,no_run
+/// mod shim {
+///     pub mod tabled {
+///         pub use ::tabled::{FieldContext, Tabled};
+///     }
+/// }
+///
+/// #[derive(shim::tabled::Tabled)]
+/// #[tabled(crate = "shim::tabled")]
+/// struct Language {
+///     name: &'static str,
+///     designed_by: &'static str,
+/// }
+/// ```
+///
+/// [`CasingStyle`]: crate::CasingStyle
+/// [`HeadersOptions`]: crate::HeadersOptions
+/// [`MAX_INLINE_DEPTH`]: crate::MAX_INLINE_DEPTH
 pub use tabled_derive::Tabled;
+
+/// A context of a field which is passed to a `display_with` function
+/// if a `context` argument is set, like `#[tabled(display_with("some_function", context))]`.
+///
+/// A function receives a reference to a field and the context.
+///
+/// ```
+/// use tabled::{FieldContext, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Record {
+///     #[tabled(display_with("with_column", context))]
+///     id: u8,
+/// }
+///
+/// fn with_column(id: &u8, ctx: FieldContext) -> String {
+///     format!("{}={} ({})", ctx.header(), id, ctx.index())
+/// }
+///
+/// assert_eq!(Record { id: 1 }.fields(), ["id=1 (0)"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldContext {
+    header: &'static str,
+    index: usize,
+}
+
+impl FieldContext {
+    /// Creates a new context.
+    pub const fn new(header: &'static str, index: usize) -> Self {
+        Self { header, index }
+    }
+
+    /// Returns a header of a column.
+    ///
+    /// Notice that an inlining prefix of an outer type isn't included.
+    pub const fn header(&self) -> &'static str {
+        self.header
+    }
+
+    /// Returns an index of a column among the columns of a type.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
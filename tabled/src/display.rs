@@ -0,0 +1,189 @@
+//! This module contains functions which can be used to display common types,
+//! which don't implement [`Display`] or which [`Display`] doesn't suit a table.
+//!
+//! They are supposed to be used with a `#[tabled(display_with = "...")]` attribute.
+//!
+//! Notice that they don't depend on any 3rd party crates.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use std::time::Duration;
+//! use tabled::{Table, Tabled};
+//!
+//! #[derive(Tabled)]
+//! struct Job {
+//!     name: &'static str,
+//!     #[tabled(display_with = "tabled::display::duration")]
+//!     took: Duration,
+//!     #[tabled(display_with = "tabled::display::duration_precise::<2>")]
+//!     took_exactly: Duration,
+//! }
+//!
+//! let jobs = [
+//!     Job { name: "build", took: Duration::from_secs(3723), took_exactly: Duration::from_secs(3723) },
+//!     Job { name: "test", took: Duration::from_millis(1500), took_exactly: Duration::from_millis(1500) },
+//! ];
+//!
+//! let table = Table::new(jobs).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+-------+----------+--------------+\n\
+//!      | name  | took     | took_exactly |\n\
+//!      +-------+----------+--------------+\n\
+//!      | build | 1h 2m 3s | 1h 2m 3.00s  |\n\
+//!      +-------+----------+--------------+\n\
+//!      | test  | 1s       | 1.50s        |\n\
+//!      +-------+----------+--------------+"
+//! );
+//! ```
+//!
+//! [`Display`]: std::fmt::Display
+
+use std::{
+    fmt::Write,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Formats a [`Duration`] like `1h 2m 3s`.
+///
+/// Leading zero units are omitted, and fractions of a second are truncated.
+/// Use [`duration_precise`] to keep them.
+///
+/// ```
+/// use std::time::Duration;
+/// use tabled::display::duration;
+///
+/// assert_eq!(duration(&Duration::from_secs(3723)), "1h 2m 3s");
+/// assert_eq!(duration(&Duration::from_secs(60)), "1m 0s");
+/// assert_eq!(duration(&Duration::from_millis(999)), "0s");
+/// ```
+pub fn duration(duration: &Duration) -> String {
+    format_duration(duration, 0)
+}
+
+/// Formats a [`Duration`] like `1h 2m 3.250s`,
+/// with `PRECISION` digits of a fraction of a second (up to 9).
+///
+/// ```
+/// use std::time::Duration;
+/// use tabled::display::duration_precise;
+///
+/// assert_eq!(duration_precise::<3>(&Duration::from_millis(62250)), "1m 2.250s");
+/// assert_eq!(duration_precise::<1>(&Duration::from_millis(999)), "0.9s");
+/// ```
+pub fn duration_precise<const PRECISION: usize>(duration: &Duration) -> String {
+    format_duration(duration, PRECISION)
+}
+
+/// Formats a [`SystemTime`] as an ISO-8601 date and time in UTC, like `2024-01-02T03:04:05Z`.
+///
+/// Fractions of a second are truncated.
+/// Use [`system_time_precise`] to keep them.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use tabled::display::system_time;
+///
+/// let time = UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+///
+/// assert_eq!(system_time(&time), "2024-01-02T03:04:05Z");
+/// ```
+pub fn system_time(time: &SystemTime) -> String {
+    format_system_time(time, 0)
+}
+
+/// Formats a [`SystemTime`] as an ISO-8601 date and time in UTC, like `2024-01-02T03:04:05.250Z`,
+/// with `PRECISION` digits of a fraction of a second (up to 9).
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use tabled::display::system_time_precise;
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(1_704_164_645_250);
+///
+/// assert_eq!(system_time_precise::<3>(&time), "2024-01-02T03:04:05.250Z");
+/// ```
+pub fn system_time_precise<const PRECISION: usize>(time: &SystemTime) -> String {
+    format_system_time(time, PRECISION)
+}
+
+fn format_duration(duration: &Duration, precision: usize) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+    let mut buf = String::new();
+    if hours > 0 {
+        let _ = write!(buf, "{}h ", hours);
+    }
+
+    if hours > 0 || minutes > 0 {
+        let _ = write!(buf, "{}m ", minutes);
+    }
+
+    let _ = write!(buf, "{}", seconds);
+    write_fraction(&mut buf, duration.subsec_nanos(), precision);
+    buf.push('s');
+
+    buf
+}
+
+fn format_system_time(time: &SystemTime, precision: usize) -> String {
+    let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
+        Err(err) => {
+            let d = err.duration();
+            match d.subsec_nanos() {
+                0 => (-(d.as_secs() as i64), 0),
+                nanos => (-(d.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+
+    let days = secs.div_euclid(86400);
+    let secs = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+
+    let mut buf = String::new();
+    let _ = write!(
+        buf,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+    write_fraction(&mut buf, nanos, precision);
+    buf.push('Z');
+
+    buf
+}
+
+fn write_fraction(buf: &mut String, nanos: u32, precision: usize) {
+    let precision = precision.min(9);
+    if precision == 0 {
+        return;
+    }
+
+    let fraction = nanos / 10u32.pow(9 - precision as u32);
+    let _ = write!(buf, ".{:0width$}", fraction, width = precision);
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month as u32, day as u32)
+}
@@ -0,0 +1,53 @@
+//! The module contains a [`TableError`] which is reported by [`TableOption::validate`].
+//!
+//! [`TableOption::validate`]: crate::settings::TableOption::validate
+
+use core::fmt::{self, Display};
+
+use crate::grid::config::Position;
+
+/// An error which reports that a setting can't be applied to a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum TableError {
+    /// A position is out of a table bounds.
+    OutOfBounds(Position),
+    /// A span at a position intersects with an existing span.
+    SpanIntersection(Position),
+    /// A row has a different amount of columns than a table.
+    ColumnCount {
+        /// An amount of columns of a table.
+        expected: usize,
+        /// An amount of columns of a row.
+        found: usize,
+    },
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::OutOfBounds(pos) => {
+                write!(
+                    f,
+                    "position ({}, {}) is out of bounds",
+                    pos.row(),
+                    pos.col()
+                )
+            }
+            TableError::SpanIntersection(pos) => write!(
+                f,
+                "span at ({}, {}) intersects with an existing span",
+                pos.row(),
+                pos.col()
+            ),
+            TableError::ColumnCount { expected, found } => write!(
+                f,
+                "expected a row with {} columns but it has {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TableError {}
@@ -57,6 +57,78 @@ impl ColoredConfig {
         self
     }
 
+    /// Removes settings and colors of a column, shifting ones of the following columns to the left.
+    ///
+    /// See [`SpannedConfig::remove_column`].
+    pub fn remove_column(&mut self, col: usize) -> &mut Self {
+        self.config.remove_column(col);
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.remove_column(col);
+        }
+
+        self
+    }
+
+    /// Removes settings and colors of a row, shifting ones of the following rows up.
+    ///
+    /// See [`SpannedConfig::remove_row`].
+    pub fn remove_row(&mut self, row: usize) -> &mut Self {
+        self.config.remove_row(row);
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.remove_row(row);
+        }
+
+        self
+    }
+
+    /// Shifts settings and colors of a column and the following columns to the right.
+    ///
+    /// See [`SpannedConfig::insert_column`].
+    pub fn insert_column(&mut self, col: usize) -> &mut Self {
+        self.config.insert_column(col);
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.insert_column(col);
+        }
+
+        self
+    }
+
+    /// Shifts settings and colors of a row and the following rows down.
+    ///
+    /// See [`SpannedConfig::insert_row`].
+    pub fn insert_row(&mut self, row: usize) -> &mut Self {
+        self.config.insert_row(row);
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.insert_row(row);
+        }
+
+        self
+    }
+
+    /// Copies settings and colors of a row to another row.
+    ///
+    /// See [`SpannedConfig::copy_row`].
+    pub fn copy_row(&mut self, from: usize, to: usize) -> &mut Self {
+        self.config.copy_row(from, to);
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.copy_row(from, to);
+        }
+
+        self
+    }
+
+    /// Mirrors settings and colors of columns.
+    ///
+    /// See [`SpannedConfig::reverse_columns`].
+    pub fn reverse_columns(&mut self, count_columns: usize) -> &mut Self {
+        self.config.reverse_columns(count_columns);
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.reverse_columns(count_columns);
+        }
+
+        self
+    }
+
     /// Returns a list of colors.
     pub fn get_colors(&self) -> &ColorMap {
         &self.colors
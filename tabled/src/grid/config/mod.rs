@@ -16,7 +16,7 @@ pub use papergrid::config::{
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub use spanned_config::{EntityMap, Offset, SpannedConfig};
+pub use spanned_config::{EntityMap, IntersectionResolver, Offset, SpanError, SpannedConfig};
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -1 +1,3 @@
-pub use papergrid::config::spanned::{EntityMap, Offset, SpannedConfig};
+pub use papergrid::config::spanned::{
+    EntityMap, IntersectionResolver, Offset, SpanError, SpannedConfig,
+};
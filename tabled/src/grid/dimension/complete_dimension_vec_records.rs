@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use crate::grid::{
-    config::{ColoredConfig, SpannedConfig},
+    config::{ColoredConfig, Position, SpannedConfig},
     dimension::{Dimension, Estimate, SpannedVecRecordsDimension},
     records::vec_records::{Cell, VecRecords},
 };
@@ -80,6 +80,43 @@ impl CompleteDimensionVecRecords<'_> {
         self.height = None;
     }
 
+    /// Re-estimates a column and a row of a changed cell.
+    ///
+    /// Only the affected column and row are measured again, the rest of values are kept.
+    /// If there's any span set all values are dropped, so they will be estimated from scratch.
+    pub fn invalidate_cell<T>(
+        &mut self,
+        records: &VecRecords<T>,
+        cfg: &SpannedConfig,
+        pos: Position,
+    ) where
+        T: AsRef<str> + Cell,
+    {
+        if cfg.has_column_spans() || cfg.has_row_spans() {
+            self.clear_width();
+            self.clear_height();
+            return;
+        }
+
+        if let Some(widths) = self.width.as_mut() {
+            if pos.col() < widths.len() {
+                let width = SpannedVecRecordsDimension::column_width(records, cfg, pos.col());
+                widths.to_mut()[pos.col()] = width;
+            } else {
+                self.width = None;
+            }
+        }
+
+        if let Some(heights) = self.height.as_mut() {
+            if pos.row() < heights.len() {
+                let height = SpannedVecRecordsDimension::row_height(records, cfg, pos.row());
+                heights.to_mut()[pos.row()] = height;
+            } else {
+                self.height = None;
+            }
+        }
+    }
+
     /// Copies a reference from self.
     pub fn from_origin(&self) -> CompleteDimensionVecRecords<'_> {
         let width = self.width.as_deref().map(Cow::Borrowed);
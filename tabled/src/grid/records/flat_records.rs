@@ -0,0 +1,253 @@
+//! The module contains [`FlatRecords`] which keeps all cells in a single buffer.
+
+use crate::grid::{
+    config::Position,
+    records::{ExactRecords, PeekableRecords, Records},
+};
+
+/// A [`Records`] implementation which keeps all cells in a single contiguous buffer.
+///
+/// The cells are stored row by row,
+/// so it's a more compact alternative to [`VecRecords`] for large dense tables.
+///
+/// The records are always rectangular.
+///
+/// ```
+/// use tabled::{grid::records::FlatRecords, tables::IterTable};
+///
+/// let records = FlatRecords::from_rows(vec![
+///     vec![String::from("Hello"), String::from("World")],
+///     vec![String::from("1"), String::from("2")],
+/// ])
+/// .unwrap();
+///
+/// let table = IterTable::new(&records).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-------+-------+\n\
+///      | Hello | World |\n\
+///      +-------+-------+\n\
+///      | 1     | 2     |\n\
+///      +-------+-------+"
+/// );
+///
+/// assert!(FlatRecords::from_rows(vec![vec![String::from("1")], vec![]]).is_none());
+/// ```
+///
+/// [`VecRecords`]: crate::grid::records::vec_records::VecRecords
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FlatRecords {
+    data: Vec<String>,
+    count_rows: usize,
+    count_columns: usize,
+}
+
+impl FlatRecords {
+    /// Creates a new [`FlatRecords`] from a buffer of cells stored row by row.
+    ///
+    /// Returns [`None`] if the buffer length doesn't match a given shape.
+    pub fn new(data: Vec<String>, count_rows: usize, count_columns: usize) -> Option<Self> {
+        if count_rows.checked_mul(count_columns) != Some(data.len()) {
+            return None;
+        }
+
+        Some(Self {
+            data,
+            count_rows,
+            count_columns,
+        })
+    }
+
+    /// Creates a new [`FlatRecords`] from a list of rows.
+    ///
+    /// Returns [`None`] if the rows have different length.
+    pub fn from_rows<I, R>(rows: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = String>,
+    {
+        let mut data = Vec::new();
+        let mut count_rows = 0;
+        let mut count_columns = None;
+        for row in rows {
+            let len = data.len();
+            data.extend(row);
+
+            let row_length = data.len() - len;
+            match count_columns {
+                Some(count) if count != row_length => return None,
+                Some(_) => {}
+                None => count_columns = Some(row_length),
+            }
+
+            count_rows += 1;
+        }
+
+        Self::new(data, count_rows, count_columns.unwrap_or(0))
+    }
+
+    /// Returns a text of a given cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    pub fn get_text(&self, pos: Position) -> &str {
+        &self.data[self.index(pos)]
+    }
+
+    /// Sets a text of a given cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    pub fn set_text(&mut self, pos: Position, text: String) {
+        let i = self.index(pos);
+        self.data[i] = text;
+    }
+
+    /// Returns all cells stored row by row.
+    pub fn as_slice(&self) -> &[String] {
+        &self.data
+    }
+
+    /// Returns an iterator over rows.
+    pub fn rows(&self) -> FlatRows<'_> {
+        FlatRows {
+            data: &self.data,
+            count_rows: self.count_rows,
+            count_columns: self.count_columns,
+            row: 0,
+        }
+    }
+
+    fn index(&self, pos: Position) -> usize {
+        assert!(
+            pos.is_covered((self.count_rows, self.count_columns).into()),
+            "position ({}, {}) is out of bounds",
+            pos.row(),
+            pos.col(),
+        );
+
+        pos.row() * self.count_columns + pos.col()
+    }
+}
+
+impl Records for FlatRecords {
+    type Iter = FlatIntoRows;
+
+    fn iter_rows(self) -> FlatIntoRows {
+        FlatIntoRows {
+            iter: self.data.into_iter(),
+            count_rows: self.count_rows,
+            count_columns: self.count_columns,
+        }
+    }
+
+    fn count_columns(&self) -> usize {
+        self.count_columns
+    }
+
+    fn hint_count_rows(&self) -> Option<usize> {
+        Some(self.count_rows)
+    }
+}
+
+impl<'a> Records for &'a FlatRecords {
+    type Iter = FlatRows<'a>;
+
+    fn iter_rows(self) -> FlatRows<'a> {
+        self.rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.count_columns
+    }
+
+    fn hint_count_rows(&self) -> Option<usize> {
+        Some(self.count_rows)
+    }
+}
+
+impl ExactRecords for FlatRecords {
+    fn count_rows(&self) -> usize {
+        self.count_rows
+    }
+}
+
+impl PeekableRecords for FlatRecords {
+    fn get_text(&self, pos: Position) -> &str {
+        FlatRecords::get_text(self, pos)
+    }
+}
+
+impl<'a> IntoIterator for &'a FlatRecords {
+    type Item = &'a [String];
+    type IntoIter = FlatRows<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows()
+    }
+}
+
+impl From<FlatRecords> for Vec<String> {
+    fn from(records: FlatRecords) -> Self {
+        records.data
+    }
+}
+
+/// A row iterator of [`FlatRecords`].
+#[derive(Debug, Clone)]
+pub struct FlatRows<'a> {
+    data: &'a [String],
+    count_rows: usize,
+    count_columns: usize,
+    row: usize,
+}
+
+impl<'a> Iterator for FlatRows<'a> {
+    type Item = &'a [String];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row == self.count_rows {
+            return None;
+        }
+
+        let start = self.row * self.count_columns;
+        self.row += 1;
+
+        Some(&self.data[start..start + self.count_columns])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.count_rows - self.row;
+        (left, Some(left))
+    }
+}
+
+/// An owned row iterator of [`FlatRecords`].
+#[derive(Debug)]
+pub struct FlatIntoRows {
+    iter: std::vec::IntoIter<String>,
+    count_rows: usize,
+    count_columns: usize,
+}
+
+impl Iterator for FlatIntoRows {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count_rows == 0 {
+            return None;
+        }
+
+        self.count_rows -= 1;
+
+        let row = self.iter.by_ref().take(self.count_columns).collect();
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count_rows, Some(self.count_rows))
+    }
+}
@@ -7,50 +7,75 @@ use crate::{
 
 /// A records iterator which truncates all cells to a given width.
 #[derive(Debug)]
-pub struct TruncateContent<I, D> {
+pub struct TruncateContent<I, D, S = &'static str> {
     records: I,
     dimension: D,
+    suffix: S,
 }
 
 impl TruncateContent<(), ()> {
     /// Creates new [`TruncateContent`] object.
     pub fn new<I, D>(records: I, dimension: D) -> TruncateContent<I, D> {
-        TruncateContent { records, dimension }
+        TruncateContent {
+            records,
+            dimension,
+            suffix: "",
+        }
+    }
+}
+
+impl<I, D, S> TruncateContent<I, D, S> {
+    /// Sets a suffix which is appended to a truncated cell.
+    ///
+    /// The suffix is considered to be a part of a width.
+    pub fn suffix<T>(self, suffix: T) -> TruncateContent<I, D, T>
+    where
+        T: AsRef<str>,
+    {
+        TruncateContent {
+            records: self.records,
+            dimension: self.dimension,
+            suffix,
+        }
     }
 }
 
-impl<I, D> IntoRecords for TruncateContent<I, D>
+impl<I, D, S> IntoRecords for TruncateContent<I, D, S>
 where
     I: IntoRecords,
     I::Cell: AsRef<str>,
     D: Clone + Dimension,
+    S: Clone + AsRef<str>,
 {
     type Cell = EitherString<I::Cell>;
-    type IterColumns = TruncateContentColumnsIter<<I::IterColumns as IntoIterator>::IntoIter, D>;
-    type IterRows = TruncateContentIter<<I::IterRows as IntoIterator>::IntoIter, D>;
+    type IterColumns = TruncateContentColumnsIter<<I::IterColumns as IntoIterator>::IntoIter, D, S>;
+    type IterRows = TruncateContentIter<<I::IterRows as IntoIterator>::IntoIter, D, S>;
 
     fn iter_rows(self) -> Self::IterRows {
         TruncateContentIter {
             iter: self.records.iter_rows().into_iter(),
             dimension: self.dimension.clone(),
+            suffix: self.suffix,
         }
     }
 }
 
 /// A row iterator for [`TruncateContent`].
 #[derive(Debug)]
-pub struct TruncateContentIter<I, D> {
+pub struct TruncateContentIter<I, D, S = &'static str> {
     iter: I,
     dimension: D,
+    suffix: S,
 }
 
-impl<I, D> Iterator for TruncateContentIter<I, D>
+impl<I, D, S> Iterator for TruncateContentIter<I, D, S>
 where
     I: Iterator,
     I::Item: IntoIterator,
     D: Clone,
+    S: Clone,
 {
-    type Item = TruncateContentColumnsIter<<I::Item as IntoIterator>::IntoIter, D>;
+    type Item = TruncateContentColumnsIter<<I::Item as IntoIterator>::IntoIter, D, S>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let iter = self.iter.next()?;
@@ -58,6 +83,7 @@ where
             iter: iter.into_iter(),
             iter_column: 0,
             dimension: self.dimension.clone(),
+            suffix: self.suffix.clone(),
         };
 
         Some(iter)
@@ -66,17 +92,19 @@ where
 
 /// A column iterator for [`TruncateContent`].
 #[derive(Debug)]
-pub struct TruncateContentColumnsIter<I, D> {
+pub struct TruncateContentColumnsIter<I, D, S = &'static str> {
     iter: I,
     dimension: D,
+    suffix: S,
     iter_column: usize,
 }
 
-impl<I, D> Iterator for TruncateContentColumnsIter<I, D>
+impl<I, D, S> Iterator for TruncateContentColumnsIter<I, D, S>
 where
     I: Iterator,
     I::Item: AsRef<str>,
     D: Dimension,
+    S: AsRef<str>,
 {
     type Item = EitherString<I::Item>;
 
@@ -92,7 +120,7 @@ where
         if is_small {
             Some(EitherString::Some(text))
         } else {
-            let text = Truncate::truncate(text_ref, width);
+            let text = Truncate::truncate_with_suffix(text_ref, width, self.suffix.as_ref());
             let text = text.into_owned();
             Some(EitherString::Owned(text))
         }
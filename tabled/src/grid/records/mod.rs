@@ -4,12 +4,17 @@
 //! Also it provides a list of helpers for a user built [`Records`] via [`into_records`].
 
 mod empty_records;
+#[cfg(feature = "std")]
+mod flat_records;
 mod records_mut;
 mod resizable;
 
 pub mod into_records;
 
 pub use empty_records::EmptyRecords;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use flat_records::{FlatIntoRows, FlatRecords, FlatRows};
 pub use papergrid::records::{ExactRecords, IntoRecords, IterRecords, PeekableRecords, Records};
 pub use records_mut::RecordsMut;
 pub use resizable::Resizable;
@@ -1,6 +1,9 @@
 use crate::grid::config::Position;
 #[cfg(feature = "std")]
-use crate::grid::records::vec_records::{Text, VecRecords};
+use crate::grid::records::{
+    vec_records::{Text, VecRecords},
+    FlatRecords,
+};
 
 /// A [`Records`] representation which can modify cell by (row, column) index.
 ///
@@ -32,3 +35,17 @@ impl RecordsMut<&str> for VecRecords<Text<String>> {
         self[p.row()][p.col()] = Text::new(text.to_string());
     }
 }
+
+#[cfg(feature = "std")]
+impl RecordsMut<String> for FlatRecords {
+    fn set(&mut self, pos: Position, text: String) {
+        self.set_text(pos, text);
+    }
+}
+
+#[cfg(feature = "std")]
+impl RecordsMut<&str> for FlatRecords {
+    fn set(&mut self, pos: Position, text: &str) {
+        self.set_text(pos, text.to_string());
+    }
+}
@@ -167,6 +167,7 @@
 //! - `derive`  - Used by default. A support for `Tabled` derive macro.
 //! - `ansi`    - A support for ANSI sequences.
 //! - `macros`  - A support for `row!`, `col!` macro.
+//! - `serde`   - A support for serialization of [`Table`] data and of a `Theme`.
 //!
 //! # Advanced
 //!
@@ -276,6 +277,7 @@
 )]
 #![allow(clippy::uninlined_format_args)]
 
+mod error;
 mod util;
 
 #[cfg(feature = "derive")]
@@ -288,15 +290,23 @@ mod tabled;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod builder;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod display;
 pub mod grid;
 pub mod iter;
 pub mod settings;
 pub mod tables;
 
+pub use crate::error::TableError;
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub use crate::{tabled::Tabled, tables::Table};
+pub use crate::{
+    tabled::{CasingStyle, HeadersOptions, Tabled, MAX_INLINE_DEPTH},
+    tables::Table,
+};
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use derive::Tabled;
+pub use derive::{FieldContext, Tabled};
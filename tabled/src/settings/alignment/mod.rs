@@ -80,6 +80,7 @@ use crate::grid::config::ColoredConfig;
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Alignment {
     inner: AlignmentInner,
+    per_line: bool,
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -137,6 +138,49 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Makes a horizontal alignment to be applied to each line of a cell separately,
+    /// rather than to a cell content as a whole.
+    ///
+    /// It's the same as using [`AlignmentStrategy::PerLine`] along with the alignment.
+    /// It's ignored for a vertical alignment.
+    ///
+    /// Notice that lines are aligned including their spaces,
+    /// so in case of [`Width::wrap`], which fills lines with spaces,
+    /// you may need to use [`TrimStrategy`] as well.
+    ///
+    #[cfg_attr(feature = "std", doc = "```")]
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    /// use tabled::{Table, settings::{Alignment, Style}};
+    ///
+    /// let data = [["A long line\nshort"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::modern()).with(Alignment::center().per_line());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "┌─────────────┐\n\
+    ///      │      0      │\n\
+    ///      ├─────────────┤\n\
+    ///      │ A long line │\n\
+    ///      │    short    │\n\
+    ///      └─────────────┘"
+    /// );
+    /// ```
+    ///
+    /// [`AlignmentStrategy::PerLine`]: crate::settings::formatting::AlignmentStrategy::PerLine
+    /// [`Width::wrap`]: crate::settings::Width::wrap
+    /// [`TrimStrategy`]: crate::settings::formatting::TrimStrategy
+    pub const fn per_line(mut self) -> Self {
+        self.per_line = true;
+        self
+    }
+
+    /// Verifies whether an alignment is applied to each line separately.
+    pub const fn is_per_line(&self) -> bool {
+        self.per_line
+    }
+
     /// Convert alignment to horizontal.
     pub const fn as_horizontal(self) -> Option<AlignmentHorizontal> {
         match self.inner {
@@ -164,7 +208,10 @@ impl Alignment {
     }
 
     const fn new(inner: AlignmentInner) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            per_line: false,
+        }
     }
 }
 
@@ -172,7 +219,13 @@ impl Alignment {
 impl<R> crate::settings::CellOption<R, ColoredConfig> for Alignment {
     fn change(self, _: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
         match self.inner {
-            Horizontal(a) => cfg.set_alignment_horizontal(entity, a),
+            Horizontal(a) => {
+                cfg.set_alignment_horizontal(entity, a);
+
+                if self.per_line {
+                    cfg.set_line_alignment(entity, true);
+                }
+            }
             Vertical(a) => cfg.set_alignment_vertical(entity, a),
         }
     }
@@ -180,11 +233,13 @@ impl<R> crate::settings::CellOption<R, ColoredConfig> for Alignment {
 
 #[cfg(feature = "std")]
 impl<R, D> TableOption<R, ColoredConfig, D> for Alignment {
-    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
-        match self.inner {
-            Horizontal(a) => cfg.set_alignment_horizontal(Entity::Global, a),
-            Vertical(a) => cfg.set_alignment_vertical(Entity::Global, a),
-        }
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        <Self as crate::settings::CellOption<R, ColoredConfig>>::change(
+            self,
+            records,
+            cfg,
+            Entity::Global,
+        )
     }
 
     fn hint_change(&self) -> Option<Entity> {
@@ -207,7 +262,15 @@ impl<R, D> TableOption<R, CompactConfig, D> for Alignment {
 impl<R, D> TableOption<R, CompactMultilineConfig, D> for Alignment {
     fn change(self, _: &mut R, cfg: &mut CompactMultilineConfig, _: &mut D) {
         match self.inner {
-            Horizontal(a) => cfg.set_alignment_horizontal(a),
+            Horizontal(a) => {
+                cfg.set_alignment_horizontal(a);
+
+                if self.per_line {
+                    let mut f = cfg.get_formatting();
+                    f.allow_lines_alignment = true;
+                    cfg.set_formatting(f);
+                }
+            }
             Vertical(a) => cfg.set_alignment_vertical(a),
         }
     }
@@ -0,0 +1,158 @@
+//! This module contains an [`AutoAlign`] option for a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::{AutoAlign, Alignment, Style}};
+//!
+//! let data = [("apple", 3, true), ("kiwi", 120, false)];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Style::markdown());
+//! table.with(AutoAlign::new().header(Alignment::center()));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "| &str  | i32 | bool  |\n",
+//!         "|-------|-----|-------|\n",
+//!         "| apple |   3 | true  |\n",
+//!         "| kiwi  | 120 | false |",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::str::FromStr;
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity, Position},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{Alignment, CellOption, TableOption},
+};
+
+/// AutoAlign sets a horizontal alignment of each column by a type of its data.
+///
+/// A column where every cell is a finite number is aligned right,
+/// a column where every cell is a boolean is centered,
+/// and any other column, including a mixed one, is aligned left.
+/// Empty cells are ignored, and a column of only empty cells is aligned left.
+///
+/// The first row is considered a header;
+/// it's not inspected and is aligned as its column unless [`AutoAlign::header`] is set.
+///
+/// It doesn't support spans; a type is decided by the cells of the columns only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoAlign {
+    header: Option<Alignment>,
+    keep_manual: bool,
+}
+
+impl AutoAlign {
+    /// Creates a new [`AutoAlign`].
+    pub const fn new() -> Self {
+        Self {
+            header: None,
+            keep_manual: false,
+        }
+    }
+
+    /// Sets an alignment of a header row, independent of the alignment of the columns.
+    pub const fn header(mut self, alignment: Alignment) -> Self {
+        self.header = Some(alignment);
+        self
+    }
+
+    /// Leave columns which were aligned manually untouched.
+    ///
+    /// A column is considered aligned manually if any of its cells (except the header)
+    /// have a horizontal alignment different from the global one.
+    pub const fn keep_manual(mut self, on: bool) -> Self {
+        self.keep_manual = on;
+        self
+    }
+}
+
+impl Default for AutoAlign {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for AutoAlign
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        for col in 0..count_columns {
+            if self.keep_manual && is_aligned_manually(cfg, count_rows, col) {
+                continue;
+            }
+
+            let alignment = match column_kind(records, col) {
+                Kind::Number => AlignmentHorizontal::Right,
+                Kind::Bool => AlignmentHorizontal::Center,
+                Kind::Text => AlignmentHorizontal::Left,
+            };
+
+            cfg.set_alignment_horizontal(Entity::Column(col), alignment);
+        }
+
+        if let Some(alignment) = self.header {
+            CellOption::change(alignment, records, cfg, Entity::Row(0));
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Number,
+    Bool,
+    Text,
+}
+
+fn column_kind<R>(records: &R, col: usize) -> Kind
+where
+    R: ExactRecords + PeekableRecords,
+{
+    let mut kind = None;
+    for row in 1..records.count_rows() {
+        let text = records.get_text(Position::new(row, col)).trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let cell_kind = if f64::from_str(text).is_ok_and(f64::is_finite) {
+            Kind::Number
+        } else if bool::from_str(text).is_ok() {
+            Kind::Bool
+        } else {
+            return Kind::Text;
+        };
+
+        match kind {
+            Some(kind) if kind != cell_kind => return Kind::Text,
+            _ => kind = Some(cell_kind),
+        }
+    }
+
+    kind.unwrap_or(Kind::Text)
+}
+
+fn is_aligned_manually(cfg: &ColoredConfig, count_rows: usize, col: usize) -> bool {
+    let global = *cfg.get_alignment_horizontal_default();
+    (1..count_rows).any(|row| *cfg.get_alignment_horizontal(Position::new(row, col)) != global)
+}
@@ -0,0 +1,128 @@
+//! This module contains a [`Bar`] option for a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::Bar};
+//!
+//! let data = [["rust", "50"], ["go", "20"], ["zig", "100"]];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Bar::column(1, 100.0).width(10));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     "+------+------------+\n\
+//!      | 0    | 1          |\n\
+//!      +------+------------+\n\
+//!      | rust | █████░░░░░ |\n\
+//!      +------+------------+\n\
+//!      | go   | ██░░░░░░░░ |\n\
+//!      +------+------------+\n\
+//!      | zig  | ██████████ |\n\
+//!      +------+------------+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::TableOption,
+};
+
+/// Bar replaces numeric values of a column with a horizontal bar scaled to a given maximum.
+///
+/// A header (first row) and cells which can't be parsed as [`f64`] are left as they are.
+/// Values are clamped into `0..=max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    column: usize,
+    max: f64,
+    width: usize,
+    filled: char,
+    empty: char,
+}
+
+impl Bar {
+    /// Creates a [`Bar`] for a given column, where `max` corresponds to a full bar.
+    ///
+    /// The default width is 10 characters.
+    pub fn column(index: usize, max: f64) -> Self {
+        Self {
+            column: index,
+            max,
+            width: 10,
+            filled: '█',
+            empty: '░',
+        }
+    }
+
+    /// Sets a width of a bar.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets characters used for filled and empty parts of a bar.
+    pub fn symbols(mut self, filled: char, empty: char) -> Self {
+        self.filled = filled;
+        self.empty = empty;
+        self
+    }
+
+    /// Renders a bar for a given value.
+    ///
+    /// ```
+    /// use tabled::settings::Bar;
+    ///
+    /// assert_eq!(Bar::column(0, 100.0).width(10).render(50.0), "█████░░░░░");
+    /// ```
+    pub fn render(&self, value: f64) -> String {
+        let filled = self.count_filled(value);
+
+        let mut buf = String::with_capacity(self.width * self.filled.len_utf8());
+        buf.extend(std::iter::repeat_n(self.filled, filled));
+        buf.extend(std::iter::repeat_n(self.empty, self.width - filled));
+
+        buf
+    }
+
+    fn count_filled(&self, value: f64) -> usize {
+        if self.max.is_nan() || self.max <= 0.0 || value.is_nan() {
+            return 0;
+        }
+
+        let ratio = (value / self.max).clamp(0.0, 1.0);
+        let filled = (ratio * self.width as f64).round() as usize;
+
+        filled.min(self.width)
+    }
+}
+
+impl<R, C, D> TableOption<R, C, D> for Bar
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, _: &mut D) {
+        if self.column >= records.count_columns() {
+            return;
+        }
+
+        for row in 1..records.count_rows() {
+            let pos = (row, self.column).into();
+            let value = match records.get_text(pos).trim().parse::<f64>() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            let bar = self.render(value);
+            records.set(pos, bar);
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        Some(Entity::Column(self.column))
+    }
+}
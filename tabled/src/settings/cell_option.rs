@@ -1,6 +1,9 @@
-use crate::grid::{
-    config::Entity,
-    records::{ExactRecords, Records, RecordsMut},
+use crate::{
+    grid::{
+        config::Entity,
+        records::{ExactRecords, Records, RecordsMut},
+    },
+    TableError,
 };
 
 /// A trait for configuring a single cell.
@@ -33,6 +36,18 @@ pub trait CellOption<R, C> {
     fn hint_change(&self) -> Option<Entity> {
         Some(Entity::Global)
     }
+
+    /// Checks whether the [`CellOption`] can be applied to a given [`Entity`].
+    ///
+    /// By default any option is considered to be valid.
+    ///
+    /// See [`TableOption::validate`].
+    ///
+    /// [`TableOption::validate`]: crate::settings::TableOption::validate
+    fn validate(&self, records: &R, cfg: &C, entity: Entity) -> Result<(), TableError> {
+        let _ = (records, cfg, entity);
+        Ok(())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -97,6 +112,16 @@ macro_rules! tuple_trait_impl {
 
                 crate::settings::table_option::hint_change_list(&list)
             }
+
+            fn validate(&self, records: &R, cfg: &C, entity: Entity) -> Result<(), TableError> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = &self;
+                $(
+                    $name::validate($name, records, cfg, entity)?;
+                )+
+
+                Ok(())
+            }
         }
     };
 }
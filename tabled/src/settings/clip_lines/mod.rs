@@ -0,0 +1,142 @@
+//! This module contains a [`ClipLines`] option for a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::ClipLines};
+//!
+//! let data = [["Hello", "World"], ["Something", "Else"]];
+//!
+//! let mut table = Table::new(data);
+//! table.with(ClipLines::new(17, ">"));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     "+-----------+--->\n\
+//!      | 0         | 1 >\n\
+//!      +-----------+--->\n\
+//!      | Hello     | Wo>\n\
+//!      +-----------+--->\n\
+//!      | Something | El>\n\
+//!      +-----------+--->"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        colors::NoColors,
+        config::{ColoredConfig, Entity, SpannedConfig},
+        dimension::{CompleteDimensionVecRecords, Estimate},
+        records::{
+            vec_records::{Text, VecRecords},
+            ExactRecords, Records,
+        },
+        util::string::{get_line_width, get_lines},
+        PeekableGrid,
+    },
+    settings::TableOption,
+    util::string::cut_str,
+};
+
+/// ClipLines truncates each rendered line of a [`Table`] to a given width,
+/// putting a marker at the end of a line which was cut.
+///
+/// It's a last resort for a table which doesn't fit in any way;
+/// the structure of rows is kept, while lines are just cut, like `cut -c` does.
+///
+/// The option renders the table,
+/// so the table becomes a single cell with no borders containing the clipped lines.
+/// Therefore it supposed to be applied last.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipLines {
+    width: usize,
+    marker: String,
+}
+
+impl ClipLines {
+    /// Creates a [`ClipLines`] option with a maximum line width and a marker used at the cut.
+    ///
+    /// The marker is counted into the line width.
+    pub fn new<S>(width: usize, marker: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            width,
+            marker: marker.into(),
+        }
+    }
+}
+
+impl TableOption<VecRecords<Text<String>>, ColoredConfig, CompleteDimensionVecRecords<'_>>
+    for ClipLines
+{
+    fn change(
+        self,
+        records: &mut VecRecords<Text<String>>,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        if records.count_rows() == 0 || records.count_columns() == 0 {
+            return;
+        }
+
+        let text = render_table(records, cfg, dims);
+        let text = clip_lines(&text, self.width, &self.marker);
+
+        *records = VecRecords::new(vec![vec![Text::new(text)]]);
+        *cfg = ColoredConfig::new(SpannedConfig::default());
+        dims.clear_width();
+        dims.clear_height();
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        Some(Entity::Global)
+    }
+}
+
+fn render_table(
+    records: &VecRecords<Text<String>>,
+    cfg: &ColoredConfig,
+    dims: &CompleteDimensionVecRecords<'_>,
+) -> String {
+    let mut dims = dims.from_origin();
+    dims.estimate(records, cfg.as_ref());
+
+    let colors = cfg.get_colors();
+    if colors.is_empty() {
+        PeekableGrid::new(records, cfg.as_ref(), &dims, NoColors).to_string()
+    } else {
+        PeekableGrid::new(records, cfg.as_ref(), &dims, colors).to_string()
+    }
+}
+
+fn clip_lines(text: &str, width: usize, marker: &str) -> String {
+    let marker_width = get_line_width(marker);
+
+    let mut buf = String::with_capacity(text.len());
+    for (i, line) in get_lines(text).enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        if get_line_width(&line) <= width {
+            buf.push_str(&line);
+            continue;
+        }
+
+        if marker_width >= width {
+            buf.push_str(&cut_str(marker, width));
+            continue;
+        }
+
+        buf.push_str(&cut_str(&line, width - marker_width));
+        buf.push_str(marker);
+    }
+
+    buf
+}
@@ -31,6 +31,11 @@ use crate::{
 ///
 /// [`Concat`] DOES NOT handle style merge and other configuration of 2nd table,
 /// it just uses 1st one as a bases.
+/// Spans are not supported either;
+/// spans of the 1st table are left as they are while spans of the 2nd one are ignored.
+///
+/// By default headers of both tables are kept,
+/// see [`Concat::dedup_header`] to change it.
 ///
 /// # Example
 ///
@@ -85,6 +90,7 @@ pub struct Concat {
     table: Table,
     mode: ConcatMode,
     default_cell: Cow<'static, str>,
+    dedup_header: bool,
 }
 
 #[derive(Debug)]
@@ -99,6 +105,7 @@ impl Concat {
             table,
             mode,
             default_cell: Cow::Borrowed(""),
+            dedup_header: false,
         }
     }
 
@@ -117,6 +124,40 @@ impl Concat {
         self.default_cell = cell.into();
         self
     }
+
+    /// Sets whether the header (1st row) of the 2nd table is dropped
+    /// in case it's the same as the header of the 1st table.
+    ///
+    /// It affects only vertical concatenation,
+    /// horizontally the headers are on the same row anyway.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Concat, Style}};
+    ///
+    /// let mut table = Table::new([(0, "a"), (1, "b")]);
+    /// table
+    ///     .with(Concat::vertical(Table::new([(2, "c")])).dedup_header(true))
+    ///     .with(Style::modern());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "┌─────┬──────┐\n",
+    ///         "│ i32 │ &str │\n",
+    ///         "├─────┼──────┤\n",
+    ///         "│ 0   │ a    │\n",
+    ///         "├─────┼──────┤\n",
+    ///         "│ 1   │ b    │\n",
+    ///         "├─────┼──────┤\n",
+    ///         "│ 2   │ c    │\n",
+    ///         "└─────┴──────┘",
+    ///     )
+    /// )
+    /// ```
+    pub fn dedup_header(mut self, on: bool) -> Self {
+        self.dedup_header = on;
+        self
+    }
 }
 
 impl<R, D, C> TableOption<R, C, D> for Concat
@@ -151,7 +192,9 @@ where
                 }
             }
             ConcatMode::Vertical => {
-                for _ in 0..rhs.count_rows() {
+                let skip = usize::from(self.dedup_header && is_same_header(records, rhs));
+
+                for _ in skip..rhs.count_rows() {
                     records.push_row();
                 }
 
@@ -163,10 +206,10 @@ where
                     }
                 }
 
-                for row in 0..rhs.shape().0 {
+                for row in skip..rhs.shape().0 {
                     for col in 0..rhs.shape().1 {
                         let text = rhs.get_records().get_text(pos(row, col)).to_string();
-                        let row = row + count_rows;
+                        let row = row - skip + count_rows;
                         records.set(pos(row, col), text);
                     }
                 }
@@ -174,3 +217,19 @@ where
         }
     }
 }
+
+fn is_same_header<R>(records: &R, table: &Table) -> bool
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    let count_columns = records.count_columns();
+    if records.count_rows() == 0
+        || table.count_rows() == 0
+        || count_columns != table.count_columns()
+    {
+        return false;
+    }
+
+    (0..count_columns)
+        .all(|col| records.get_text(pos(0, col)) == table.get_records().get_text(pos(0, col)))
+}
@@ -0,0 +1,110 @@
+//! This module contains a [`Direction`] setting which changes a direction of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::Direction};
+//!
+//! let data = [("שלום", 1), ("עולם", 2)];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Direction::rtl());
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Position},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    },
+    settings::TableOption,
+};
+
+/// Direction sets an order in which columns are laid out.
+///
+/// A right-to-left direction mirrors the table:
+/// columns are reversed, left and right paddings are swapped
+/// and horizontal alignments (the default and the set ones) are flipped.
+/// Spans are moved along with their cells.
+///
+/// Borders and lines are not changed,
+/// which keeps the frame of a symmetric style as it is.
+///
+/// Notice that it mirrors the current state of a table,
+/// so applying [`Direction::rtl`] twice restores the original layout.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::Direction};
+///
+/// let data = [("Hello", 1), ("World", 22)];
+///
+/// let mut table = Table::new(data);
+/// table.with(Direction::rtl());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-----+-------+\n\
+///      | i32 |  &str |\n\
+///      +-----+-------+\n\
+///      |   1 | Hello |\n\
+///      +-----+-------+\n\
+///      |  22 | World |\n\
+///      +-----+-------+"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Direction {
+    rtl: bool,
+}
+
+impl Direction {
+    /// Creates a left-to-right direction, which leaves a table as it is.
+    pub const fn ltr() -> Self {
+        Self { rtl: false }
+    }
+
+    /// Creates a right-to-left direction, which mirrors a table.
+    pub const fn rtl() -> Self {
+        Self { rtl: true }
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Direction
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String> + Resizable,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if !self.rtl {
+            return;
+        }
+
+        let count_columns = records.count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        for col in 0..count_columns / 2 {
+            records.swap_column(col, count_columns - 1 - col);
+        }
+
+        // a content of a spanned cell must be moved to the new origin of a span
+        let spans = cfg.get_column_spans();
+        let _ = cfg.reverse_columns(count_columns);
+
+        for (pos, span) in spans {
+            if span < 2 || pos.col() + span > count_columns {
+                continue;
+            }
+
+            let from = Position::new(pos.row(), count_columns - 1 - pos.col());
+            let to = Position::new(pos.row(), count_columns - pos.col() - span);
+
+            let text = records.get_text(from).to_string();
+            records.set(from, String::new());
+            records.set(to, text);
+        }
+    }
+}
@@ -14,14 +14,18 @@
 use std::marker::PhantomData;
 
 use crate::{
-    grid::records::{ExactRecords, Records, Resizable},
+    grid::{
+        config::{ColoredConfig, CompactConfig, CompactMultilineConfig, SpannedConfig},
+        records::{ExactRecords, Records, Resizable},
+    },
     settings::{location::Location, TableOption},
 };
 
 /// Remove removes particular rows/columns from a [`Table`].
 ///
-/// It tries to keeps track of style changes which may occur.
-/// But it's not guaranteed will be the way you would expect it to be.
+/// Settings of the following rows/columns are shifted together with their content,
+/// so the ones applied before and after the removal refer to the same cells.
+/// Borders and lines set for particular cells are not moved though.
 ///
 /// Generally you should avoid use of [`Remove`] because it's a slow function and modifies the underlying records.
 /// Providing correct data right away is better.
@@ -152,8 +156,9 @@ impl<L, R, D, C> TableOption<R, C, D> for Remove<L, TargetColumn>
 where
     L: Location<R, Coordinate = usize>,
     R: Records + Resizable,
+    C: RemovableConfig,
 {
-    fn change(mut self, records: &mut R, _: &mut C, _: &mut D) {
+    fn change(mut self, records: &mut R, cfg: &mut C, _: &mut D) {
         let columns = self.locator.locate(records).into_iter().collect::<Vec<_>>();
 
         let mut shift = 0;
@@ -163,11 +168,9 @@ where
             }
 
             records.remove_column(col - shift);
+            cfg.remove_column(col - shift);
             shift += 1;
         }
-
-        // fixme: I am pretty sure that we violate span constrains by removing rows/cols
-        //        Because span may be bigger then the max number of rows/cols
     }
 }
 
@@ -175,8 +178,9 @@ impl<L, R, D, C> TableOption<R, C, D> for Remove<L, TargetRow>
 where
     L: Location<R, Coordinate = usize>,
     R: ExactRecords + Resizable,
+    C: RemovableConfig,
 {
-    fn change(mut self, records: &mut R, _: &mut C, _: &mut D) {
+    fn change(mut self, records: &mut R, cfg: &mut C, _: &mut D) {
         let rows = self.locator.locate(records).into_iter().collect::<Vec<_>>();
 
         let mut shift = 0;
@@ -186,10 +190,50 @@ where
             }
 
             records.remove_row(row - shift);
+            cfg.remove_row(row - shift);
             shift += 1;
         }
+    }
+}
+
+/// A config which settings are shifted by [`Remove`] together with the records.
+///
+/// Configs which don't keep settings for particular rows and columns do nothing.
+pub trait RemovableConfig {
+    /// Removes settings of a row, shifting ones of the following rows up.
+    fn remove_row(&mut self, row: usize);
+    /// Removes settings of a column, shifting ones of the following columns to the left.
+    fn remove_column(&mut self, col: usize);
+}
+
+impl RemovableConfig for ColoredConfig {
+    fn remove_row(&mut self, row: usize) {
+        let _ = ColoredConfig::remove_row(self, row);
+    }
 
-        // fixme: I am pretty sure that we violate span constrains by removing rows/cols
-        //        Because span may be bigger then the max number of rows/cols
+    fn remove_column(&mut self, col: usize) {
+        let _ = ColoredConfig::remove_column(self, col);
     }
 }
+
+impl RemovableConfig for SpannedConfig {
+    fn remove_row(&mut self, row: usize) {
+        SpannedConfig::remove_row(self, row);
+    }
+
+    fn remove_column(&mut self, col: usize) {
+        SpannedConfig::remove_column(self, col);
+    }
+}
+
+impl RemovableConfig for CompactConfig {
+    fn remove_row(&mut self, _: usize) {}
+
+    fn remove_column(&mut self, _: usize) {}
+}
+
+impl RemovableConfig for CompactMultilineConfig {
+    fn remove_row(&mut self, _: usize) {}
+
+    fn remove_column(&mut self, _: usize) {}
+}
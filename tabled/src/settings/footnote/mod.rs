@@ -0,0 +1,140 @@
+//! This module contains a [`Footnote`] option for a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::Footnote};
+//!
+//! let data = [["Revenue", "120"], ["Costs", "80"]];
+//!
+//! let footnotes = Footnote::new()
+//!     .attach((1, 1), "Unaudited")
+//!     .attach((2, 0), "Includes taxes");
+//!
+//! let table = Table::new(data).with(footnotes).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+----------+-------+\n\
+//!      | 0        | 1     |\n\
+//!      +----------+-------+\n\
+//!      | Revenue  | 120¹  |\n\
+//!      +----------+-------+\n\
+//!      | Costs²   | 80    |\n\
+//!      +----------+-------+\n\
+//!      | ¹ Unaudited      |\n\
+//!      | ² Includes taxes |\n\
+//!      +----------+-------+"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::config::{AlignmentHorizontal, ColoredConfig, Entity, Position},
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    settings::{Panel, TableOption},
+};
+
+/// Footnote attaches numbered markers to cells
+/// and renders a list of the notes in a left aligned spanned row at the bottom of a [`Table`].
+///
+/// The notes are numbered in the order they were attached.
+/// A note attached to a cell out of the table bounds is still listed but no marker is added.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Footnote {
+    notes: Vec<(Position, String)>,
+}
+
+impl Footnote {
+    /// Creates an empty [`Footnote`] list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches a note to a given cell.
+    ///
+    /// A marker like `¹` will be appended to the cell content.
+    pub fn attach<P, S>(mut self, pos: P, text: S) -> Self
+    where
+        P: Into<Position>,
+        S: Into<String>,
+    {
+        self.notes.push((pos.into(), text.into()));
+        self
+    }
+
+    /// Returns a number of attached notes.
+    pub fn count(&self) -> usize {
+        self.notes.len()
+    }
+
+    /// Returns a marker which will be used for a note with a given number (starting from 1).
+    pub fn marker(number: usize) -> String {
+        number
+            .to_string()
+            .chars()
+            .map(|c| SUPERSCRIPT_DIGITS[c.to_digit(10).unwrap_or(0) as usize])
+            .collect()
+    }
+
+    /// Renders a footnote block, a note per line.
+    ///
+    /// It's the same text which is put into the last row of a table.
+    ///
+    /// ```
+    /// use tabled::settings::Footnote;
+    ///
+    /// let footnotes = Footnote::new()
+    ///     .attach((1, 1), "Unaudited")
+    ///     .attach((2, 0), "Includes taxes");
+    ///
+    /// assert_eq!(footnotes.render(), "¹ Unaudited\n² Includes taxes");
+    /// ```
+    pub fn render(&self) -> String {
+        let mut buf = String::new();
+        for (i, (_, text)) in self.notes.iter().enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+
+            buf.push_str(&Self::marker(i + 1));
+            buf.push(' ');
+            buf.push_str(text);
+        }
+
+        buf
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Footnote
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String> + Resizable,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dimension: &mut D) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        if self.notes.is_empty() || count_rows == 0 || count_cols == 0 {
+            return;
+        }
+
+        for (i, (pos, _)) in self.notes.iter().enumerate() {
+            if !pos.is_covered((count_rows, count_cols).into()) {
+                continue;
+            }
+
+            let mut text = records.get_text(*pos).to_owned();
+            text.push_str(&Self::marker(i + 1));
+            records.set(*pos, text);
+        }
+
+        Panel::footer(self.render()).change(records, cfg, dimension);
+
+        let pos = Entity::Cell(records.count_rows() - 1, 0);
+        cfg.set_alignment_horizontal(pos, AlignmentHorizontal::Left);
+    }
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
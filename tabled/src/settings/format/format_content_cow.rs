@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// A lambda which formats cell content, returning a [`Cow`].
+///
+/// A cell is left untouched when the lambda returns the content it was given as it is,
+/// so no allocation is done for it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FormatContentCow<F> {
+    f: F,
+    multiline: bool,
+}
+
+impl<F> FormatContentCow<F> {
+    pub(crate) fn new(f: F) -> Self {
+        Self {
+            f,
+            multiline: false,
+        }
+    }
+}
+
+impl<F> FormatContentCow<F> {
+    /// Multiline a helper function for changing multiline content of cell.
+    /// Using this formatting applied for all rows not to a string as a whole.
+    ///
+    /// ```rust,no_run
+    /// use std::borrow::Cow;
+    /// use tabled::{Table, settings::{Format, object::Segment, Modify}};
+    ///
+    /// let data: Vec<&'static str> = Vec::new();
+    /// let table = Table::new(&data)
+    ///     .with(Modify::new(Segment::all()).with(Format::content_cow(|s| Cow::Borrowed(s)).multiline()))
+    ///     .to_string();
+    /// ```
+    pub fn multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+}
+
+impl<F, R, D, C> TableOption<R, C, D> for FormatContentCow<F>
+where
+    F: for<'a> FnMut(&'a str) -> Cow<'a, str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<F, R, C> CellOption<R, C> for FormatContentCow<F>
+where
+    F: for<'a> FnMut(&'a str) -> Cow<'a, str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            if !pos.is_covered((count_rows, count_cols).into()) {
+                continue;
+            }
+
+            let content = records.get_text(pos);
+            let content = if self.multiline {
+                format_multiline(&mut self.f, content)
+            } else {
+                format_text(&mut self.f, content)
+            };
+
+            if let Some(content) = content {
+                records.set(pos, content);
+            }
+        }
+    }
+}
+
+fn format_text<F>(f: &mut F, text: &str) -> Option<String>
+where
+    F: for<'a> FnMut(&'a str) -> Cow<'a, str>,
+{
+    match f(text) {
+        Cow::Borrowed(s) if is_same_str(s, text) => None,
+        Cow::Borrowed(s) => Some(s.to_owned()),
+        Cow::Owned(s) => Some(s),
+    }
+}
+
+fn format_multiline<F>(f: &mut F, text: &str) -> Option<String>
+where
+    F: for<'a> FnMut(&'a str) -> Cow<'a, str>,
+{
+    let mut is_changed = false;
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let formatted = f(line);
+        is_changed |= match &formatted {
+            Cow::Borrowed(s) => !is_same_str(s, line),
+            Cow::Owned(_) => true,
+        };
+        lines.push(formatted);
+    }
+
+    if !is_changed {
+        return None;
+    }
+
+    Some(lines.join("\n"))
+}
+
+// A borrowed string may be a part of the original one or any other string,
+// so it's compared by identity rather than by length.
+fn is_same_str(s: &str, origin: &str) -> bool {
+    s.as_ptr() == origin.as_ptr() && s.len() == origin.len()
+}
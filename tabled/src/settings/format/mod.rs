@@ -4,10 +4,12 @@
 
 mod format_config;
 mod format_content;
+mod format_content_cow;
 mod format_positioned;
 
 pub use format_config::FormatConfig;
 pub use format_content::FormatContent;
+pub use format_content_cow::FormatContentCow;
 pub use format_positioned::FormatContentPositioned;
 use papergrid::config::Position;
 
@@ -59,6 +61,51 @@ impl Format {
         FormatContent::new(f)
     }
 
+    /// This function creates a new [`FormatContentCow`] instance, so
+    /// it can be used as a grid setting.
+    ///
+    /// It's different from [`Format::content`] as the function returns a [`Cow`],
+    /// so a cell which is not changed is not allocated again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use tabled::{Table, settings::{Format, object::Rows}};
+    ///
+    /// let data = vec![(0, "Grodno"), (1, "minsk"), (2, "Brest")];
+    ///
+    /// let table = Table::new(&data)
+    ///                .modify(Rows::new(1..), Format::content_cow(|s| {
+    ///                    match s.starts_with(char::is_lowercase) {
+    ///                        true => Cow::Owned(s.to_uppercase()),
+    ///                        false => Cow::Borrowed(s),
+    ///                    }
+    ///                }))
+    ///                .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-----+--------+\n\
+    ///      | i32 | &str   |\n\
+    ///      +-----+--------+\n\
+    ///      | 0   | Grodno |\n\
+    ///      +-----+--------+\n\
+    ///      | 1   | MINSK  |\n\
+    ///      +-----+--------+\n\
+    ///      | 2   | Brest  |\n\
+    ///      +-----+--------+"
+    /// );
+    /// ```
+    ///
+    /// [`Cow`]: std::borrow::Cow
+    pub fn content_cow<F>(f: F) -> FormatContentCow<F>
+    where
+        F: for<'a> FnMut(&'a str) -> std::borrow::Cow<'a, str>,
+    {
+        FormatContentCow::new(f)
+    }
+
     /// This function creates a new [`FormatContentPositioned`], so
     /// it can be used as a grid setting.
     ///
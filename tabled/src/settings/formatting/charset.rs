@@ -40,7 +40,7 @@ impl Charset {
     ///
     /// [`TabSize`]: crate::settings::formatting::TabSize
     pub fn clean() -> CleanCharset {
-        CleanCharset
+        CleanCharset::new()
     }
 }
 
@@ -74,9 +74,78 @@ impl Charset {
 /// )
 /// ```
 #[derive(Debug, Default, Clone)]
-pub struct CleanCharset;
+pub struct CleanCharset {
+    tab_size: Option<usize>,
+    placeholder: Option<char>,
+}
 
 impl CleanCharset {
+    /// Creates a [`CleanCharset`] which removes all `\t` and `\r` occurrences.
+    pub const fn new() -> Self {
+        Self {
+            tab_size: None,
+            placeholder: None,
+        }
+    }
+
+    /// Expands each `\t` into a given number of spaces instead of removing it,
+    /// the same way [`TabSize`] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::formatting::Charset};
+    ///
+    /// let data = [["id", "name"], ["1\t", "Mi\tke\r"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Charset::clean().tab_size(2));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+--------+\n\
+    ///      | 0   | 1      |\n\
+    ///      +-----+--------+\n\
+    ///      | id  | name   |\n\
+    ///      +-----+--------+\n\
+    ///      | 1   | Mi  ke |\n\
+    ///      +-----+--------+"
+    /// )
+    /// ```
+    ///
+    /// [`TabSize`]: crate::settings::formatting::TabSize
+    pub const fn tab_size(mut self, size: usize) -> Self {
+        self.tab_size = Some(size);
+        self
+    }
+
+    /// Replaces control characters other then `\n`, `\r` and `\t` with a given character
+    /// instead of removing them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::formatting::Charset};
+    ///
+    /// let mut table = Table::new(["bell\u{7}", "\u{1b}escape"]);
+    /// table.with(Charset::clean().placeholder('?'));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---------+\n\
+    ///      | &str    |\n\
+    ///      +---------+\n\
+    ///      | bell?   |\n\
+    ///      +---------+\n\
+    ///      | ?escape |\n\
+    ///      +---------+"
+    /// )
+    /// ```
+    pub const fn placeholder(mut self, c: char) -> Self {
+        self.placeholder = Some(c);
+        self
+    }
+
     /// Removes all symbols which may break the layout such as `\t`, `\r` and more.
     ///
     /// Notice that tab is just removed rather then being replaced with spaces.
@@ -92,7 +161,7 @@ impl CleanCharset {
     /// )
     /// ```
     pub fn clean(s: &str) -> Cow<'_, str> {
-        Cow::Owned(clean_charset(s))
+        Cow::Owned(clean_charset(s, None, None))
     }
 }
 
@@ -105,7 +174,7 @@ where
             for col in 0..records.count_columns() {
                 let pos = pos(row, col);
                 let text = records.get_text(pos);
-                let text = clean_charset(text);
+                let text = clean_charset(text, self.tab_size, self.placeholder);
                 records.set(pos, text);
             }
         }
@@ -121,14 +190,30 @@ where
         let count_cols = records.count_columns();
         for pos in entity.iter(count_rows, count_cols) {
             let text = records.get_text(pos);
-            let text = clean_charset(text);
+            let text = clean_charset(text, self.tab_size, self.placeholder);
             records.set(pos, text);
         }
     }
 }
 
-fn clean_charset(text: &str) -> String {
-    // It's enough for covering '\t' and '\r'
-    // as well as a list of other unwanted escapes.
-    text.replace(|c| c != '\n' && c < ' ', "")
+fn clean_charset(text: &str, tab_size: Option<usize>, placeholder: Option<char>) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        // It's enough for covering '\t' and '\r'
+        // as well as a list of other unwanted escapes.
+        let is_control = c != '\n' && c < ' ';
+        if !is_control {
+            buf.push(c);
+            continue;
+        }
+
+        match (c, tab_size, placeholder) {
+            ('\t', Some(size), _) => buf.extend(std::iter::repeat_n(' ', size)),
+            ('\t' | '\r', _, _) => {}
+            (_, _, Some(placeholder)) => buf.push(placeholder),
+            _ => {}
+        }
+    }
+
+    buf
 }
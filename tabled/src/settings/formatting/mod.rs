@@ -4,17 +4,20 @@
 //! - [`TabSize`] sets a default tab size.
 //! - [`Charset`] responsible for special char treatment.
 //! - [`Justification`] responsible for justification space of content.
+//! - [`Strike`] overlays a character across a cell body.
 //!
 //! [`Alignment`]: crate::settings::Alignment
 
 mod alignment_strategy;
 mod charset;
 mod justification;
+mod strike;
 mod tab_size;
 mod trim_strategy;
 
 pub use alignment_strategy::AlignmentStrategy;
 pub use charset::{Charset, CleanCharset};
 pub use justification::Justification;
+pub use strike::Strike;
 pub use tab_size::TabSize;
 pub use trim_strategy::TrimStrategy;
@@ -0,0 +1,79 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    settings::{CellOption, TableOption},
+};
+
+/// Strike overlays a character across a cell body.
+///
+/// The whole cell area inside of borders (including padding and every line of a multiline cell)
+/// is rendered with the character, while the content is left untouched.
+/// It may be handy to mark a cell as disabled or not applicable.
+///
+/// # Examples
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{Modify, formatting::Strike},
+/// };
+///
+/// let mut table = Table::new(&[("Hello", "N/A"), ("", "World")]);
+/// table.with(Modify::new((1, 1)).with(Strike::diagonal()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+-------+\n\
+///      | &str  | &str  |\n\
+///      +-------+-------+\n\
+///      | Hello |╱╱╱╱╱╱╱|\n\
+///      +-------+-------+\n\
+///      |       | World |\n\
+///      +-------+-------+"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Strike {
+    c: Option<char>,
+}
+
+impl Strike {
+    /// Creates new [`Strike`] object which fills a cell with a given character.
+    pub fn new(c: char) -> Self {
+        Self { c: Some(c) }
+    }
+
+    /// Creates a [`Strike`] with a diagonal pattern `╱`.
+    pub fn diagonal() -> Self {
+        Self::new('╱')
+    }
+
+    /// Creates a [`Strike`] with a cross pattern `╳`.
+    pub fn cross() -> Self {
+        Self::new('╳')
+    }
+
+    /// Creates a [`Strike`] which removes a previously set one.
+    pub fn none() -> Self {
+        Self { c: None }
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Strike {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.set_fill_override(Entity::Global, self.c);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for Strike {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        cfg.set_fill_override(entity, self.c);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
@@ -0,0 +1,227 @@
+//! This module contains a [`Heatmap`] setting which colors a numeric column of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::{Color, Heatmap}};
+//!
+//! let data = [("apple", 3), ("kiwi", 12), ("orange", 7)];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Heatmap::column(1).colors(Color::rgb_bg(0, 0, 255), Color::rgb_bg(255, 0, 0)));
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        ansi::ANSIBuf,
+        config::{ColoredConfig, Entity, Position, Sides},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{Color, TableOption},
+};
+
+/// Heatmap sets a background color of each numeric cell of a column,
+/// interpolating between 2 colors by its value.
+///
+/// Cells which can't be parsed as a number (like a header) are skipped.
+/// If a range is not set, the minimum and maximum values of the column are used.
+/// Values out of the range get the closest color.
+///
+/// The colors must be either 24 bit RGB, 8 bit or 4 bit ANSI colors
+/// (foreground or background); otherwise nothing is changed.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Color, Heatmap}};
+///
+/// let data = [("a", 1), ("b", 2), ("c", 3)];
+///
+/// let mut table = Table::new(data);
+/// table.with(Heatmap::column(1).colors(Color::rgb_bg(0, 0, 0), Color::rgb_bg(255, 255, 255)));
+///
+/// let table = table.to_string();
+///
+/// assert!(table.contains("\u{1b}[48;2;0;0;0m"));
+/// assert!(table.contains("\u{1b}[48;2;128;128;128m"));
+/// assert!(table.contains("\u{1b}[48;2;255;255;255m"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap {
+    column: usize,
+    range: Option<(f64, f64)>,
+    low: Color,
+    high: Color,
+}
+
+impl Heatmap {
+    /// Creates a [`Heatmap`] for a given column, using a green to red gradient.
+    pub fn column(column: usize) -> Self {
+        Self {
+            column,
+            range: None,
+            low: Color::rgb_bg(0, 255, 0),
+            high: Color::rgb_bg(255, 0, 0),
+        }
+    }
+
+    /// Sets a range of values which is mapped to the colors.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Sets colors used for the minimum and the maximum values.
+    pub fn colors(mut self, low: Color, high: Color) -> Self {
+        self.low = low;
+        self.high = high;
+        self
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Heatmap
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self.column >= records.count_columns() {
+            return;
+        }
+
+        let (low, high) = match (color_to_rgb(&self.low), color_to_rgb(&self.high)) {
+            (Some(low), Some(high)) => (low, high),
+            _ => return,
+        };
+
+        let values = (0..records.count_rows())
+            .map(|row| Position::new(row, self.column))
+            .filter(|&pos| cfg.is_cell_visible(pos))
+            .filter_map(|pos| parse_number(records.get_text(pos)).map(|value| (pos, value)))
+            .collect::<Vec<_>>();
+
+        let (min, max) = match self.range {
+            Some(range) => range,
+            None => {
+                let min = values.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min);
+                let max = values
+                    .iter()
+                    .map(|(_, v)| *v)
+                    .fold(f64::NEG_INFINITY, f64::max);
+                (min, max)
+            }
+        };
+
+        for (pos, value) in values {
+            let ratio = if max > min {
+                ((value - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            let (r, g, b) = interpolate(low, high, ratio);
+            let color = ANSIBuf::from(Color::rgb_bg(r, g, b));
+
+            let entity = Entity::Cell(pos.row(), pos.col());
+            let _ = cfg.set_color(entity, color.clone());
+            cfg.set_justification_color(entity, Some(color.clone()));
+            let padding = Sides::new(
+                Some(color.clone()),
+                Some(color.clone()),
+                Some(color.clone()),
+                Some(color),
+            );
+            cfg.set_padding_color(entity, padding);
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+type Rgb = (u8, u8, u8);
+
+fn parse_number(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn interpolate(low: Rgb, high: Rgb, ratio: f64) -> Rgb {
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * ratio).round() as u8;
+    (mix(low.0, high.0), mix(low.1, high.1), mix(low.2, high.2))
+}
+
+fn color_to_rgb(color: &Color) -> Option<Rgb> {
+    use ansitok::{parse_ansi, parse_ansi_sgr, AnsiColor, ElementKind, Output, VisualAttribute};
+
+    let prefix = color.get_prefix();
+    for el in parse_ansi(prefix) {
+        if el.kind() != ElementKind::Sgr {
+            continue;
+        }
+
+        for attr in parse_ansi_sgr(&prefix[el.start()..el.end()]) {
+            let color = match attr {
+                Output::Escape(VisualAttribute::BgColor(color)) => color,
+                Output::Escape(VisualAttribute::FgColor(color)) => color,
+                _ => continue,
+            };
+
+            return match color {
+                AnsiColor::Bit24 { r, g, b } => Some((r, g, b)),
+                AnsiColor::Bit8(index) => Some(bit8_to_rgb(index)),
+                AnsiColor::Bit4(code) => bit4_to_rgb(code),
+            };
+        }
+    }
+
+    None
+}
+
+// xterm default palette
+const PALETTE: [Rgb; 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+fn bit4_to_rgb(code: u8) -> Option<Rgb> {
+    let index = match code {
+        30..=37 => code - 30,
+        40..=47 => code - 40,
+        90..=97 => code - 90 + 8,
+        100..=107 => code - 100 + 8,
+        _ => return None,
+    };
+
+    Some(PALETTE[index as usize])
+}
+
+fn bit8_to_rgb(index: u8) -> Rgb {
+    match index {
+        0..=15 => PALETTE[index as usize],
+        16..=231 => {
+            let level = |i: u8| if i == 0 { 0 } else { 55 + i * 40 };
+            let i = index - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
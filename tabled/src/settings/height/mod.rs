@@ -3,6 +3,7 @@
 mod cell_height_increase;
 mod cell_height_limit;
 mod height_list;
+mod table_height_fill;
 mod table_height_increase;
 mod table_height_limit;
 mod util;
@@ -12,6 +13,7 @@ use crate::settings::measurement::Measurement;
 pub use cell_height_increase::CellHeightIncrease;
 pub use cell_height_limit::CellHeightLimit;
 pub use height_list::HeightList;
+pub use table_height_fill::TableHeightFill;
 pub use table_height_increase::TableHeightIncrease;
 pub use table_height_limit::TableHeightLimit;
 
@@ -193,6 +195,42 @@ impl Height {
         CellHeightLimit::new(height)
     }
 
+    /// Create [`TableHeightFill`] to fill a table height by a vertical padding of cells.
+    ///
+    /// Unlike [`Height::increase`] extra lines are spread evenly among all rows,
+    /// and a padding which is already set is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Height};
+    ///
+    /// let data = vec![("Some data", "here"), ("Some data on a next", "line")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Height::fill(12))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---------------------+------+\n\
+    ///      |                     |      |\n\
+    ///      | &str                | &str |\n\
+    ///      |                     |      |\n\
+    ///      +---------------------+------+\n\
+    ///      |                     |      |\n\
+    ///      | Some data           | here |\n\
+    ///      |                     |      |\n\
+    ///      +---------------------+------+\n\
+    ///      | Some data on a next | line |\n\
+    ///      |                     |      |\n\
+    ///      +---------------------+------+",
+    /// )
+    /// ```
+    pub fn fill<W: Measurement<Height>>(height: W) -> TableHeightFill<W> {
+        TableHeightFill::new(height)
+    }
+
     /// Create [`HeightList`] to set a table height to a constant list of row heights.
     ///
     /// Notice if you provide a list with `.len()` less than `Table::count_rows` then it will have no affect.
@@ -0,0 +1,83 @@
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity, Position},
+        records::{ExactRecords, IntoRecords, PeekableRecords, Records},
+    },
+    settings::{measurement::Measurement, Height, TableOption},
+};
+
+use super::util::get_table_height;
+
+/// A modification of a table to fill a given height by a vertical padding.
+///
+/// Extra lines are spread evenly among rows,
+/// where the first rows get a line more if it can't be split equally.
+/// Within a row the lines are split between a top and a bottom padding,
+/// so a content is kept in a middle.
+///
+/// A padding which is already set is kept, the lines are added on top of it.
+/// A table which is already higher than the height is left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TableHeightFill<W = usize> {
+    height: W,
+}
+
+impl<W> TableHeightFill<W> {
+    /// Creates a new object.
+    pub fn new(height: W) -> Self
+    where
+        W: Measurement<Height>,
+    {
+        Self { height }
+    }
+}
+
+impl<R, W, D> TableOption<R, ColoredConfig, D> for TableHeightFill<W>
+where
+    W: Measurement<Height>,
+    R: Records + ExactRecords + PeekableRecords,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let height = self.height.measure(&*records, cfg);
+        let (total, _) = get_table_height(&*records, cfg);
+        if total >= height {
+            return;
+        }
+
+        let extra = height - total;
+        for row in 0..count_rows {
+            let mut lines = extra / count_rows;
+            if row < extra % count_rows {
+                lines += 1;
+            }
+
+            if lines == 0 {
+                continue;
+            }
+
+            let top = lines / 2;
+            let bottom = lines - top;
+
+            for col in 0..count_columns {
+                let pos = Position::new(row, col);
+                if !cfg.is_cell_visible(pos) {
+                    continue;
+                }
+
+                let mut padding = cfg.get_padding(pos);
+                padding.top.size += top;
+                padding.bottom.size += bottom;
+
+                cfg.set_padding(Entity::Cell(row, col), padding);
+            }
+        }
+    }
+}
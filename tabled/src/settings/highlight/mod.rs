@@ -9,7 +9,7 @@ use crate::{
     grid::{
         ansi::ANSIBuf,
         config::{Border, ColoredConfig, Entity, Position, SpannedConfig},
-        records::{ExactRecords, Records},
+        records::{ExactRecords, PeekableRecords, Records},
     },
     settings::{
         object::Object,
@@ -127,6 +127,80 @@ impl<O> Highlight<O> {
     }
 }
 
+impl Highlight<()> {
+    /// Build a new instance of [`Highlight`],
+    /// targeting every cell which text matches a given predicate.
+    ///
+    /// Notice that a border or a color must be set, otherwise nothing is changed.
+    ///
+    /// A predicate is given a whole text of a cell,
+    /// so multiline cells can be checked line by line via [`str::lines`].
+    /// Cells which are hidden by a span are skipped,
+    /// a spanned cell is checked by its own text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Highlight, Border, Style}};
+    ///
+    /// let data = [["0", "ERROR"], ["1", "OK"], ["2", "ERROR"]];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::ascii())
+    ///     .with(Highlight::by(|text| text == "ERROR").border(Border::filled('#')))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---+-------+\n",
+    ///         "| 0 | 1     |\n",
+    ///         "+---#########\n",
+    ///         "| 0 # ERROR #\n",
+    ///         "+---#########\n",
+    ///         "| 1 | OK    |\n",
+    ///         "+---#########\n",
+    ///         "| 2 # ERROR #\n",
+    ///         "+---#########",
+    ///     ),
+    /// );
+    /// ```
+    pub const fn by<F>(f: F) -> HighlightBy<F>
+    where
+        F: Fn(&str) -> bool,
+    {
+        HighlightBy {
+            f,
+            border: None,
+            color: None,
+        }
+    }
+}
+
+/// HighlightBy is a [`Highlight`] of every cell which text matches a predicate.
+///
+/// It's created by [`Highlight::by`].
+#[derive(Debug)]
+pub struct HighlightBy<F> {
+    f: F,
+    border: Option<Border<char>>,
+    color: Option<Border<ANSIBuf>>,
+}
+
+impl<F> HighlightBy<F> {
+    /// Set a border for a [`HighlightBy`].
+    pub fn border<T, B, L, R>(mut self, border: ConstBorder<T, B, L, R>) -> Self {
+        self.border = Some(border.into_inner());
+        self
+    }
+
+    /// Set a border color for a [`HighlightBy`].
+    pub fn color(mut self, border: BorderColor) -> Self {
+        self.color = Some(border.into_inner().convert());
+        self
+    }
+}
+
 impl<O, R, D> TableOption<R, ColoredConfig, D> for Highlight<O>
 where
     O: Object<R>,
@@ -139,27 +213,44 @@ where
         let cells = self.target.cells(records);
         let segments = split_segments(cells, count_rows, count_cols);
 
-        match (self.border, self.color) {
-            (None, Some(color)) => {
-                for sector in segments {
-                    set_border_color(cfg, &sector, &color);
-                }
-            }
-            (Some(border), None) => {
-                for sector in segments {
-                    set_border(cfg, &sector, border);
+        highlight_segments(cfg, segments, self.border, self.color);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<F, R, D> TableOption<R, ColoredConfig, D> for HighlightBy<F>
+where
+    F: Fn(&str) -> bool,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self.border.is_none() && self.color.is_none() {
+            return;
+        }
+
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        let mut cells = Vec::new();
+        for row in 0..count_rows {
+            for col in 0..count_cols {
+                let pos = Position::new(row, col);
+                if !cfg.is_cell_visible(pos) {
+                    continue;
                 }
-            }
-            (Some(border), Some(color)) => {
-                for sector in segments {
-                    set_border(cfg, &sector, border);
-                    set_border_color(cfg, &sector, &color);
+
+                if (self.f)(records.get_text(pos)) {
+                    cells.push(Entity::Cell(row, col));
                 }
             }
-            (None, None) => {
-                // noop
-            }
         }
+
+        let segments = split_segments(cells.into_iter(), count_rows, count_cols);
+
+        highlight_segments(cfg, segments, self.border, self.color);
     }
 
     fn hint_change(&self) -> Option<Entity> {
@@ -167,6 +258,35 @@ where
     }
 }
 
+fn highlight_segments(
+    cfg: &mut SpannedConfig,
+    segments: Vec<HashSet<Position>>,
+    border: Option<Border<char>>,
+    color: Option<Border<ANSIBuf>>,
+) {
+    match (border, color) {
+        (None, Some(color)) => {
+            for sector in segments {
+                set_border_color(cfg, &sector, &color);
+            }
+        }
+        (Some(border), None) => {
+            for sector in segments {
+                set_border(cfg, &sector, border);
+            }
+        }
+        (Some(border), Some(color)) => {
+            for sector in segments {
+                set_border(cfg, &sector, border);
+                set_border_color(cfg, &sector, &color);
+            }
+        }
+        (None, None) => {
+            // noop
+        }
+    }
+}
+
 fn set_border_color(cfg: &mut SpannedConfig, sector: &HashSet<Position>, border: &Border<ANSIBuf>) {
     if sector.is_empty() {
         return;
@@ -9,8 +9,35 @@ use crate::{
 /// A name is considered be a value in a first row.
 ///
 /// So even if in reality there's no header, the first row will be considered to be one.
+///
+/// By default all columns with a given name are selected.
+/// If there's no such column nothing is selected.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{builder::Builder, settings::{location::ByColumnName, Alignment}};
+///
+/// let data = [["name", "is_active", "is_active"], ["Tom", "yes", "no"]];
+///
+/// let mut table = Builder::from_iter(data).build();
+/// table.modify(ByColumnName::new("is_active").first(), Alignment::right());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+-----------+-----------+\n\
+///      | name | is_active | is_active |\n\
+///      +------+-----------+-----------+\n\
+///      | Tom  |       yes | no        |\n\
+///      +------+-----------+-----------+"
+/// );
+/// ```
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ByColumnName<S>(S);
+pub struct ByColumnName<S> {
+    text: S,
+    first: bool,
+}
 
 impl<S> ByColumnName<S> {
     /// Constructs a new object of the structure.
@@ -18,7 +45,36 @@ impl<S> ByColumnName<S> {
     where
         S: AsRef<str>,
     {
-        Self(text)
+        Self { text, first: false }
+    }
+
+    /// Select only the first column with the name,
+    /// in case there are a few of them.
+    pub fn first(mut self) -> Self {
+        self.first = true;
+        self
+    }
+}
+
+impl<S> ByColumnName<S>
+where
+    S: AsRef<str>,
+{
+    fn columns<R>(&self, records: &R) -> Vec<usize>
+    where
+        R: Records + ExactRecords + PeekableRecords,
+    {
+        if records.count_rows() == 0 {
+            return Vec::new();
+        }
+
+        let limit = if self.first { 1 } else { usize::MAX };
+
+        // todo: can be optimized by creating Iterator
+        (0..records.count_columns())
+            .filter(|col| records.get_text((0, *col).into()) == self.text.as_ref())
+            .take(limit)
+            .collect()
     }
 }
 
@@ -31,10 +87,7 @@ where
     type IntoIter = Vec<usize>;
 
     fn locate(&mut self, records: &R) -> Self::IntoIter {
-        // todo: can be optimized by creating Iterator
-        (0..records.count_columns())
-            .filter(|col| records.get_text((0, *col).into()) == self.0.as_ref())
-            .collect::<Vec<_>>()
+        self.columns(records)
     }
 }
 
@@ -46,9 +99,8 @@ where
     type Iter = std::vec::IntoIter<Entity>;
 
     fn cells(&self, records: &R) -> Self::Iter {
-        // todo: can be optimized by creating Iterator
-        (0..records.count_columns())
-            .filter(|col| records.get_text((0, *col).into()) == self.0.as_ref())
+        self.columns(records)
+            .into_iter()
             .map(Entity::Column)
             .collect::<Vec<_>>()
             .into_iter()
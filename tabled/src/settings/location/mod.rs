@@ -247,6 +247,11 @@ mod tests {
         );
         assert_eq!(cells(by_colname("1"), &data[4]), [Column(1), Column(2)]);
         assert_eq!(cells(by_colname("1"), &data[5]), []);
+
+        assert_eq!(cells(by_colname("1").first(), &data[2]), [Column(0)]);
+        assert_eq!(cells(by_colname("1").first(), &data[4]), [Column(1)]);
+        assert_eq!(cells(by_colname("1").first(), &data[5]), []);
+        assert_eq!(cells(by_colname("1"), &[]), []);
     }
 
     #[test]
@@ -23,6 +23,9 @@ use crate::{
 #[cfg(feature = "std")]
 use crate::grid::config::ColoredConfig;
 
+#[cfg(feature = "ansi")]
+use crate::{grid::ansi::ANSIBuf, settings::Color};
+
 /// Margin is responsible for a left/right/top/bottom outer indent of a grid.
 ///
 /// # Example
@@ -53,6 +56,8 @@ use crate::grid::config::ColoredConfig;
 #[derive(Debug, Clone)]
 pub struct Margin {
     indent: Sides<Indent>,
+    #[cfg(feature = "ansi")]
+    color: Option<Color>,
 }
 
 impl Margin {
@@ -68,6 +73,8 @@ impl Margin {
                 Indent::spaced(top),
                 Indent::spaced(bottom),
             ),
+            #[cfg(feature = "ansi")]
+            color: None,
         }
     }
 
@@ -79,6 +86,43 @@ impl Margin {
         self.indent.bottom.fill = bottom;
         self
     }
+
+    /// The function, sets the same character for the margin on all sides.
+    pub const fn fill_all(self, c: char) -> Self {
+        self.fill(c, c, c, c)
+    }
+
+    /// The function, sets the same color for the margin on all sides.
+    ///
+    /// It's an analog of [`MarginColor::filled`] which is kept together with the margin.
+    /// It's applied only to a [`Table`].
+    ///
+    /// ```
+    /// use tabled::{settings::{Color, Margin, Style}, Table};
+    ///
+    /// let mut table = Table::new(["Hello"]);
+    /// table
+    ///     .with(Style::markdown())
+    ///     .with(Margin::new(1, 1, 0, 0).fill_all('#').color(Color::FG_RED));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "\u{1b}[31m#\u{1b}[39m| &str  |\u{1b}[31m#\u{1b}[39m\n",
+    ///         "\u{1b}[31m#\u{1b}[39m|-------|\u{1b}[31m#\u{1b}[39m\n",
+    ///         "\u{1b}[31m#\u{1b}[39m| Hello |\u{1b}[31m#\u{1b}[39m",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`MarginColor::filled`]: crate::settings::MarginColor::filled
+    /// [`Table`]: crate::Table
+    #[cfg(feature = "ansi")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ansi")))]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
 }
 
 impl From<Margin> for Sides<Indent> {
@@ -89,7 +133,11 @@ impl From<Margin> for Sides<Indent> {
 
 impl From<Sides<Indent>> for Margin {
     fn from(indent: Sides<Indent>) -> Self {
-        Self { indent }
+        Self {
+            indent,
+            #[cfg(feature = "ansi")]
+            color: None,
+        }
     }
 }
 
@@ -99,6 +147,18 @@ impl<R, D> TableOption<R, ColoredConfig, D> for Margin {
         let indent = self.indent;
         let margin = Sides::new(indent.left, indent.right, indent.top, indent.bottom);
         cfg.set_margin(margin);
+
+        #[cfg(feature = "ansi")]
+        if let Some(color) = self.color {
+            let color: ANSIBuf = color.into();
+            let colors = Sides::new(
+                Some(color.clone()),
+                Some(color.clone()),
+                Some(color.clone()),
+                Some(color),
+            );
+            cfg.set_margin_color(colors);
+        }
     }
 }
 
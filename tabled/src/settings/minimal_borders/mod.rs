@@ -0,0 +1,165 @@
+//! This module contains a [`MinimalBorders`] option for a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::{object::Columns, Alignment, MinimalBorders}};
+//!
+//! let data = [("apple", 120, "fresh"), ("kiwi", 7, "old")];
+//!
+//! let mut table = Table::new(data);
+//! table.modify(Columns::single(1), Alignment::right());
+//! table.with(MinimalBorders::new());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " &str    i32 │ &str  \n",
+//!         "─────────────┼───────\n",
+//!         " apple   120 │ fresh \n",
+//!         " kiwi      7 │ old   ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::{
+            AlignmentHorizontal, ColoredConfig, HorizontalLine, Position, SpannedConfig,
+            VerticalLine,
+        },
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::get_text_width,
+    },
+    settings::{Style, TableOption},
+};
+
+/// MinimalBorders sets a blank style with a header line,
+/// adding vertical separators only where adjacent columns could run together.
+///
+/// It's a heuristic which inspects alignment and content of the cells (except the header).
+/// A column edge is considered dense when every non empty cell touches it,
+/// which is the case for a cell aligned to this edge or a cell which fills the whole column.
+/// A separator is added between 2 columns when the right edge of the left column
+/// and the left edge of the right column are both dense;
+/// for example right aligned numbers followed by left aligned text.
+///
+/// It doesn't support spans; a separator is decided by the cells of the columns only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinimalBorders {
+    vertical: char,
+    horizontal: char,
+    intersection: char,
+}
+
+impl MinimalBorders {
+    /// Creates a [`MinimalBorders`] which uses `│`, `─` and `┼` characters.
+    pub const fn new() -> Self {
+        Self {
+            vertical: '│',
+            horizontal: '─',
+            intersection: '┼',
+        }
+    }
+
+    /// Sets characters used for a separator, a header line and their intersection.
+    pub const fn chars(mut self, vertical: char, horizontal: char, intersection: char) -> Self {
+        self.vertical = vertical;
+        self.horizontal = horizontal;
+        self.intersection = intersection;
+        self
+    }
+}
+
+impl Default for MinimalBorders {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for MinimalBorders
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        Style::blank().change(records, cfg, dims);
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        if count_rows > 1 {
+            let line = HorizontalLine::new(Some(self.horizontal), None, None, None);
+            cfg.insert_horizontal_line(1, line);
+        }
+
+        let widths = (0..count_columns)
+            .map(|col| column_width(records, col))
+            .collect::<Vec<_>>();
+
+        for col in 1..count_columns {
+            let is_left_dense = is_edge_dense(records, cfg, col - 1, widths[col - 1], Edge::Right);
+            let is_right_dense = is_edge_dense(records, cfg, col, widths[col], Edge::Left);
+
+            // a blank line is set as well so the header line is not broken at the intersection
+            let line = match is_left_dense && is_right_dense {
+                true => VerticalLine::new(Some(self.vertical), Some(self.intersection), None, None),
+                false => VerticalLine::new(Some(' '), Some(self.horizontal), None, None),
+            };
+
+            cfg.insert_vertical_line(col, line);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Left,
+    Right,
+}
+
+fn column_width<R>(records: &R, col: usize) -> usize
+where
+    R: ExactRecords + PeekableRecords,
+{
+    (0..records.count_rows())
+        .map(|row| get_text_width(records.get_text(Position::new(row, col))))
+        .max()
+        .unwrap_or(0)
+}
+
+fn is_edge_dense<R>(records: &R, cfg: &SpannedConfig, col: usize, width: usize, edge: Edge) -> bool
+where
+    R: ExactRecords + PeekableRecords,
+{
+    let mut has_content = false;
+    for row in 1..records.count_rows() {
+        let pos = Position::new(row, col);
+        let text_width = get_text_width(records.get_text(pos));
+        if text_width == 0 {
+            continue;
+        }
+
+        has_content = true;
+
+        if text_width == width {
+            continue;
+        }
+
+        let alignment = *cfg.get_alignment_horizontal(pos);
+        let is_aligned = match edge {
+            Edge::Left => alignment == AlignmentHorizontal::Left,
+            Edge::Right => alignment == AlignmentHorizontal::Right,
+        };
+
+        if !is_aligned {
+            return false;
+        }
+    }
+
+    has_content
+}
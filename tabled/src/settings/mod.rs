@@ -57,12 +57,28 @@ mod rotate;
 #[cfg(feature = "std")]
 mod modify;
 
+#[cfg(feature = "std")]
+mod auto_align;
+#[cfg(feature = "std")]
+mod bar;
+#[cfg(feature = "std")]
+mod clip_lines;
 #[cfg(feature = "std")]
 mod color;
 #[cfg(feature = "std")]
 mod concat;
 #[cfg(feature = "std")]
+mod direction;
+#[cfg(feature = "std")]
 mod duplicate;
+#[cfg(feature = "std")]
+mod footnote;
+#[cfg(feature = "ansi")]
+mod heatmap;
+#[cfg(feature = "std")]
+mod minimal_borders;
+#[cfg(feature = "std")]
+mod repeat_header;
 
 pub mod style;
 
@@ -122,6 +138,8 @@ pub mod width;
 pub use cell_option::CellOption;
 pub use settings_list::{EmptySettings, Settings};
 pub use table_option::TableOption;
+#[cfg(feature = "std")]
+pub use table_option::TableOptionDyn;
 
 pub use self::{
     alignment::Alignment, extract::Extract, margin::Margin, margin_color::MarginColor,
@@ -132,18 +150,29 @@ pub use self::{
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
+    auto_align::AutoAlign,
+    bar::Bar,
+    clip_lines::ClipLines,
     color::Color,
     concat::Concat,
+    direction::Direction,
     disable::Remove,
     duplicate::Dup,
+    footnote::Footnote,
     format::Format,
     height::Height,
     highlight::Highlight,
     merge::Merge,
+    minimal_borders::MinimalBorders,
     modify::{Modify, ModifyList},
     panel::Panel,
+    repeat_header::RepeatHeader,
     shadow::Shadow,
     span::Span,
     themes::Theme,
     width::Width,
 };
+
+#[cfg(feature = "ansi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ansi")))]
+pub use self::heatmap::Heatmap;
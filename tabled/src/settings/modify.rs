@@ -1,9 +1,10 @@
 use crate::{
     grid::{
-        config::Entity,
+        config::{Entity, Position},
         records::{ExactRecords, Records},
     },
     settings::{object::Object, CellOption, Settings, TableOption},
+    TableError,
 };
 
 /// Modify structure provide an abstraction, to be able to apply
@@ -11,6 +12,11 @@ use crate::{
 ///
 /// Be aware that the settings are applied all to a cell at a time.
 /// So sometimes you may need to make a several calls of [`Modify`] in order to achieve the desired affect.
+///
+/// An object (or its part) which is out of the table boundaries produces no cells,
+/// so for example modifying a 10th column of a 3 column table does nothing.
+/// The only exception is the one past the end row and column,
+/// which are kept so the bottom and the right borders could be changed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Modify<O> {
     obj: O,
@@ -120,6 +126,10 @@ where
 {
     fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
         for entity in self.obj.cells(records) {
+            if out_of_bounds(records, entity).is_some() {
+                continue;
+            }
+
             self.modifiers.clone().change(records, cfg, entity);
         }
     }
@@ -127,4 +137,39 @@ where
     fn hint_change(&self) -> Option<Entity> {
         self.modifiers.hint_change()
     }
+
+    fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        // cells of an object are cut by the table boundaries so it's checked separately
+        self.obj.validate_bounds(records)?;
+
+        for entity in self.obj.cells(records) {
+            if let Some(pos) = out_of_bounds(records, entity) {
+                return Err(TableError::OutOfBounds(pos));
+            }
+
+            self.modifiers.validate(records, cfg, entity)?;
+        }
+
+        Ok(())
+    }
+}
+
+// A cell is not cut by the table boundaries by an object itself so it's checked here.
+//
+// The one past the end row and column are kept as it's where the last borders go.
+fn out_of_bounds<R>(records: &R, entity: Entity) -> Option<Position>
+where
+    R: Records + ExactRecords,
+{
+    let pos = match entity {
+        Entity::Cell(row, col) => Position::new(row, col),
+        _ => return None,
+    };
+
+    let shape = (records.count_rows() + 1, records.count_columns() + 1).into();
+    if pos.is_covered(shape) {
+        return None;
+    }
+
+    Some(pos)
 }
@@ -1,6 +1,7 @@
 use crate::{
     grid::config::{Entity, Position},
-    settings::object::Object,
+    grid::records::{ExactRecords, Records},
+    settings::object::{Object, SectorIter},
 };
 
 /// Cell denotes a particular cell on a [`Table`].
@@ -69,6 +70,73 @@ impl<I> Object<I> for (usize, usize) {
     }
 }
 
+/// Cells denotes a rectangular block of cells on a [`Table`].
+///
+/// Both corners are included.
+/// The corners can be given in any order,
+/// and the cells out of a table are ignored.
+///
+/// ```
+/// use tabled::{Table, settings::{object::Cells, Alignment, Style}};
+///
+/// let data = [["one", "two", "three"], ["four", "five", "six"]];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::markdown())
+///     .modify(Cells::rect((2, 2), (1, 1)), Alignment::right());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "| 0    | 1    | 2     |\n\
+///      |------|------|-------|\n\
+///      | one  |  two | three |\n\
+///      | four | five |   six |"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cells {
+    start: Position,
+    end: Position,
+}
+
+impl Cells {
+    /// Creates a block of cells between 2 corners.
+    pub fn rect<P1, P2>(corner1: P1, corner2: P2) -> Self
+    where
+        P1: Into<Position>,
+        P2: Into<Position>,
+    {
+        let a = corner1.into();
+        let b = corner2.into();
+
+        let start = Position::new(a.row().min(b.row()), a.col().min(b.col()));
+        let end = Position::new(a.row().max(b.row()), a.col().max(b.col()));
+
+        Self { start, end }
+    }
+}
+
+impl<I> Object<I> for Cells
+where
+    I: Records + ExactRecords,
+{
+    type Iter = SectorIter;
+
+    fn cells(&self, records: &I) -> Self::Iter {
+        let rows_end = self.end.row().saturating_add(1).min(records.count_rows());
+        let cols_end = self
+            .end
+            .col()
+            .saturating_add(1)
+            .min(records.count_columns());
+
+        SectorIter::new(self.start.row(), rows_end, self.start.col(), cols_end)
+    }
+}
+
 /// An [`Iterator`] which returns an entity once.
 #[derive(Debug)]
 pub struct EntityOnce {
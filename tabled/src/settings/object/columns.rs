@@ -1,12 +1,13 @@
 use std::ops::{Add, RangeBounds, Sub};
 
 use crate::{
-    grid::config::Entity,
+    grid::config::{Entity, Position},
     grid::records::{ExactRecords, Records},
     settings::object::{cell::EntityOnce, Object},
+    TableError,
 };
 
-use super::util::bounds_to_usize;
+use super::util::{bounds_exceeded, bounds_to_usize};
 
 /// Column denotes a set of cells on given columns on a [`Table`].
 ///
@@ -19,40 +20,52 @@ pub struct Columns<R> {
 impl<R> Columns<R> {
     /// Returns a new instance of [`Columns`] for a range of columns.
     ///
-    /// If the boundaries are exceeded it may panic.
-    pub fn new(range: R) -> Self
+    /// Columns out of the boundaries produce no cells, except the one past the end column
+    /// which is kept as it's where the right border goes.
+    pub const fn new(range: R) -> Self
     where
         R: RangeBounds<usize>,
     {
         Self { range }
     }
 
-    pub(crate) fn get_range(&self) -> &R {
+    pub(crate) const fn get_range(&self) -> &R {
         &self.range
     }
 }
 
 impl Columns<()> {
-    /// Returns a new instance of [`Columns`] for a single column.
+    /// Returns a new instance of [`Columns`] with a single column.
     ///
-    /// If the boundaries are exceeded it may panic.
-    pub fn single(index: usize) -> Column {
+    /// Columns out of the boundaries produce no cells, except the one past the end column
+    /// which is kept as it's where the right border goes.
+    pub const fn single(index: usize) -> Column {
         Column(index)
     }
 
-    /// Returns a new instance of [`Columns`] for a first column.
+    /// Returns a first column [`Object`].
     ///
-    /// If the boundaries are exceeded the object will produce no cells.
-    pub fn first() -> FirstColumn {
+    /// If the table has 0 columns returns an empty set of cells.
+    pub const fn first() -> FirstColumn {
         FirstColumn
     }
 
-    /// Returns a new instance of [`Columns`] for a last column.
+    /// Returns a last column [`Object`].
     ///
-    /// If the boundaries are exceeded the object will produce no cells.
-    pub fn last() -> LastColumn {
+    /// If the table has 0 columns returns an empty set of cells.
+    pub const fn last() -> LastColumn {
         LastColumn
     }
+
+    /// Returns a column [`Object`] with a given offset from the last column.
+    ///
+    /// So `Columns::from_end(0)` is the last column and `Columns::from_end(1)` is the one before it.
+    /// It's the same as `Columns::last() - offset`.
+    ///
+    /// If the offset exceeds the boundaries the object will produce no cells.
+    pub const fn from_end(offset: usize) -> LastColumnOffset {
+        LastColumnOffset { offset }
+    }
 }
 
 impl<I, R> Object<I> for Columns<R>
@@ -70,6 +83,15 @@ where
 
         ColumnsIter::new(x, y)
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        let start = self.range.start_bound();
+        let end = self.range.end_bound();
+        match bounds_exceeded(start, end, records.count_columns()) {
+            Some(i) => Err(TableError::OutOfBounds(Position::new(0, i))),
+            None => Ok(()),
+        }
+    }
 }
 
 /// `FirstColumn` represents the first column on a grid.
@@ -131,12 +153,28 @@ impl Sub<usize> for LastColumn {
 #[derive(Debug, Clone, Copy)]
 pub struct Column(usize);
 
-impl<I> Object<I> for Column {
+impl<I> Object<I> for Column
+where
+    I: Records + ExactRecords,
+{
     type Iter = EntityOnce;
 
-    fn cells(&self, _: &I) -> Self::Iter {
+    fn cells(&self, records: &I) -> Self::Iter {
+        // the one past the end column is kept as it's where the right border goes
+        if records.count_rows() == 0 || self.0 > records.count_columns() {
+            return EntityOnce::new(None);
+        }
+
         EntityOnce::new(Some(Entity::Column(self.0)))
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        if self.0 > records.count_columns() {
+            return Err(TableError::OutOfBounds(Position::new(0, self.0)));
+        }
+
+        Ok(())
+    }
 }
 
 impl From<usize> for Column {
@@ -9,6 +9,57 @@ use crate::{
 #[derive(Debug)]
 pub struct Frame;
 
+impl Frame {
+    /// Returns the cells of a top edge of a frame, which is the first row.
+    ///
+    /// Corner cells are included in both adjacent edges.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Frame, Format}};
+    ///
+    /// let data = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// let mut table = Table::new(data);
+    /// table.modify(Frame::top(), Format::content(|s| format!("[{s}]")));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+-----+-----+\n\
+    ///      | [0] | [1] | [2] |\n\
+    ///      +-----+-----+-----+\n\
+    ///      | 1   | 2   | 3   |\n\
+    ///      +-----+-----+-----+\n\
+    ///      | 4   | 5   | 6   |\n\
+    ///      +-----+-----+-----+\n\
+    ///      | 7   | 8   | 9   |\n\
+    ///      +-----+-----+-----+"
+    /// );
+    /// ```
+    pub const fn top() -> FrameSide {
+        FrameSide::new(Side::Top)
+    }
+
+    /// Returns the cells of a bottom edge of a frame, which is the last row.
+    ///
+    /// Corner cells are included in both adjacent edges.
+    pub const fn bottom() -> FrameSide {
+        FrameSide::new(Side::Bottom)
+    }
+
+    /// Returns the cells of a left edge of a frame, which is the first column.
+    ///
+    /// Corner cells are included in both adjacent edges.
+    pub const fn left() -> FrameSide {
+        FrameSide::new(Side::Left)
+    }
+
+    /// Returns the cells of a right edge of a frame, which is the last column.
+    ///
+    /// Corner cells are included in both adjacent edges.
+    pub const fn right() -> FrameSide {
+        FrameSide::new(Side::Right)
+    }
+}
+
 impl<I> Object<I> for Frame
 where
     I: Records + ExactRecords,
@@ -67,3 +118,87 @@ impl Iterator for FrameIter {
         Some(Entity::Cell(row, col))
     }
 }
+
+/// A single edge of a [`Frame`].
+///
+/// It can be created by [`Frame::top`], [`Frame::bottom`], [`Frame::left`] and [`Frame::right`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameSide {
+    side: Side,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl FrameSide {
+    const fn new(side: Side) -> Self {
+        Self { side }
+    }
+}
+
+impl<I> Object<I> for FrameSide
+where
+    I: Records + ExactRecords,
+{
+    type Iter = FrameSideIter;
+
+    fn cells(&self, records: &I) -> Self::Iter {
+        FrameSideIter::new(self.side, records.count_rows(), records.count_columns())
+    }
+}
+
+/// An [`Iterator`] which goes over cells of a single edge of a [`Frame`].
+#[derive(Debug)]
+pub struct FrameSideIter {
+    side: Side,
+    rows: usize,
+    cols: usize,
+    i: usize,
+}
+
+impl FrameSideIter {
+    const fn new(side: Side, count_rows: usize, count_columns: usize) -> Self {
+        Self {
+            side,
+            rows: count_rows,
+            cols: count_columns,
+            i: 0,
+        }
+    }
+}
+
+impl Iterator for FrameSideIter {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cols == 0 || self.rows == 0 {
+            return None;
+        }
+
+        let length = match self.side {
+            Side::Top | Side::Bottom => self.cols,
+            Side::Left | Side::Right => self.rows,
+        };
+
+        if self.i == length {
+            return None;
+        }
+
+        let i = self.i;
+        self.i += 1;
+
+        let pos = match self.side {
+            Side::Top => (0, i),
+            Side::Bottom => (self.rows - 1, i),
+            Side::Left => (i, 0),
+            Side::Right => (i, self.cols - 1),
+        };
+
+        Some(Entity::Cell(pos.0, pos.1))
+    }
+}
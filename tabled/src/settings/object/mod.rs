@@ -9,6 +9,7 @@ mod frame;
 mod iterator;
 mod rows;
 mod segment;
+mod threshold;
 
 pub(crate) mod util;
 
@@ -19,18 +20,20 @@ use self::segment::SectorCellsIter;
 use crate::{
     grid::config::{Entity, EntityIterator},
     grid::records::{ExactRecords, Records},
+    TableError,
 };
 
-pub use cell::{Cell, EntityOnce};
+pub use cell::{Cell, Cells, EntityOnce};
 pub use columns::{Column, Columns, ColumnsIter, FirstColumn, LastColumn, LastColumnOffset};
-pub use frame::{Frame, FrameIter};
+pub use frame::{Frame, FrameIter, FrameSide, FrameSideIter};
 pub use iterator::{
     FilterObject, FilterObjectIter, ObjectIterator, SkipObject, SkipObjectIter, StepByObject,
     StepByObjectIter,
 };
 use papergrid::config::Position;
-pub use rows::{FirstRow, LastRow, LastRowOffset, Row, Rows, RowsIter};
+pub use rows::{FirstRow, LastRow, LastRowOffset, Row, RowBands, RowBandsIter, Rows, RowsIter};
 pub use segment::{SectorIter, Segment, SegmentAll};
+pub use threshold::Threshold;
 
 /// Object helps to locate a necessary part of a [`Table`].
 ///
@@ -42,6 +45,18 @@ pub trait Object<R> {
     /// Cells returns a set of coordinates of cells.
     fn cells(&self, records: &R) -> Self::Iter;
 
+    /// Checks whether the [`Object`] is within the table boundaries,
+    /// returning [`TableError::OutOfBounds`] with the first position which is out of them.
+    ///
+    /// The one past the end row and column are considered to be within the boundaries,
+    /// as they're where the bottom and the right borders go.
+    ///
+    /// By default any object is considered to be valid.
+    fn validate_bounds(&self, records: &R) -> Result<(), TableError> {
+        let _ = records;
+        Ok(())
+    }
+
     /// Combines cells.
     /// It doesn't repeat cells.
     fn and<O>(self, rhs: O) -> UnionCombination<Self, O, R>
@@ -112,6 +127,11 @@ where
 
         UnionIter::new(lhs, rhs, records.count_rows(), records.count_columns())
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        self.lhs.validate_bounds(records)?;
+        self.rhs.validate_bounds(records)
+    }
 }
 
 /// Difference struct used for chaining [`Object`]'s.
@@ -148,6 +168,10 @@ where
 
         DiffIter::new(lhs, rhs, records.count_rows(), records.count_columns())
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        self.lhs.validate_bounds(records)
+    }
 }
 
 /// Intersection struct used for chaining [`Object`]'s.
@@ -185,6 +209,11 @@ where
 
         IntersectIter::new(lhs, rhs, records.count_rows(), records.count_columns())
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        self.lhs.validate_bounds(records)?;
+        self.rhs.validate_bounds(records)
+    }
 }
 
 /// Inversion struct used for chaining [`Object`]'s.
@@ -550,10 +579,9 @@ mod tests {
         assert_eq!(vec_cells(Columns::first() + 0, 5, 2), [Entity::Column(0)]);
         assert_eq!(vec_cells(Columns::first() + 1, 5, 2), [Entity::Column(1)]);
         assert_eq!(vec_cells(Columns::first() + 2, 5, 2), [Entity::Column(2)]);
-        assert_eq!(
-            vec_cells(Columns::first() + 100, 5, 2),
-            [Entity::Column(100)]
-        );
+        assert_eq!(vec_cells(Columns::first() + 3, 5, 2), []);
+        assert_eq!(vec_cells(Columns::first() + 100, 5, 2), []);
+        assert_eq!(vec_cells(Columns::first() + 1, 0, 2), []);
     }
 
     #[test]
@@ -565,6 +593,15 @@ mod tests {
         assert_eq!(vec_cells(Rows::new(1..), 2, 3), [Entity::Row(1)]);
         assert_eq!(vec_cells(Rows::new(2..), 2, 3), []);
         assert_eq!(vec_cells(Rows::new(2..), 0, 0), []);
+        assert_eq!(
+            vec_cells(Rows::new(1..10), 2, 3),
+            [Entity::Row(1), Entity::Row(2)]
+        );
+        assert_eq!(vec_cells(Rows::new(5..10), 2, 3), []);
+        assert_eq!(
+            vec_cells(Rows::new(..=usize::MAX), 1, 3),
+            [Entity::Row(0), Entity::Row(1)]
+        );
         assert_eq!(vec_cells(Rows::new(0..1), 2, 3), [Entity::Row(0)],);
         assert_eq!(vec_cells(Rows::new(1..2), 2, 3), [Entity::Row(1)],);
         assert_eq!(vec_cells(Rows::new(..), 0, 0), []);
@@ -617,10 +654,11 @@ mod tests {
         assert_eq!(vec_cells(Rows::first() + 3, 5, 2), [Entity::Row(3)]);
         assert_eq!(vec_cells(Rows::first() + 4, 5, 2), [Entity::Row(4)]);
         assert_eq!(vec_cells(Rows::first() + 5, 5, 2), [Entity::Row(5)]);
-        assert_eq!(vec_cells(Rows::first() + 100, 5, 2), [Entity::Row(100)]);
-        assert_eq!(vec_cells(Rows::first() + 1, 0, 0), [Entity::Row(1)]);
-        assert_eq!(vec_cells(Rows::first() + 1, 5, 0), [Entity::Row(1)]);
-        assert_eq!(vec_cells(Rows::first() + 1, 0, 2), [Entity::Row(1)]);
+        assert_eq!(vec_cells(Rows::first() + 6, 5, 2), []);
+        assert_eq!(vec_cells(Rows::first() + 100, 5, 2), []);
+        assert_eq!(vec_cells(Rows::first() + 1, 0, 0), []);
+        assert_eq!(vec_cells(Rows::first() + 1, 5, 0), []);
+        assert_eq!(vec_cells(Rows::first() + 1, 0, 2), []);
     }
 
     #[test]
@@ -641,6 +679,70 @@ mod tests {
         assert_eq!(vec_cells(Frame, 0, 2), []);
     }
 
+    #[test]
+    fn frame_side_test() {
+        assert_eq!(
+            vec_cells(Frame::top(), 3, 3),
+            [Entity::Cell(0, 0), Entity::Cell(0, 1), Entity::Cell(0, 2)]
+        );
+        assert_eq!(
+            vec_cells(Frame::bottom(), 3, 3),
+            [Entity::Cell(2, 0), Entity::Cell(2, 1), Entity::Cell(2, 2)]
+        );
+        assert_eq!(
+            vec_cells(Frame::left(), 3, 3),
+            [Entity::Cell(0, 0), Entity::Cell(1, 0), Entity::Cell(2, 0)]
+        );
+        assert_eq!(
+            vec_cells(Frame::right(), 3, 3),
+            [Entity::Cell(0, 2), Entity::Cell(1, 2), Entity::Cell(2, 2)]
+        );
+        assert_eq!(vec_cells(Frame::right(), 1, 1), [Entity::Cell(0, 0)]);
+        assert_eq!(vec_cells(Frame::top(), 0, 0), []);
+        assert_eq!(vec_cells(Frame::left(), 2, 0), []);
+        assert_eq!(vec_cells(Frame::bottom(), 0, 2), []);
+    }
+
+    #[test]
+    fn cells_test() {
+        assert_eq!(
+            vec_cells(Cells::rect((0, 1), (1, 2)), 3, 3),
+            [
+                Entity::Cell(0, 1),
+                Entity::Cell(0, 2),
+                Entity::Cell(1, 1),
+                Entity::Cell(1, 2)
+            ]
+        );
+        assert_eq!(
+            vec_cells(Cells::rect((1, 2), (0, 1)), 3, 3),
+            vec_cells(Cells::rect((0, 1), (1, 2)), 3, 3)
+        );
+        assert_eq!(
+            vec_cells(Cells::rect((0, 2), (1, 1)), 3, 3),
+            vec_cells(Cells::rect((0, 1), (1, 2)), 3, 3)
+        );
+        assert_eq!(
+            vec_cells(Cells::rect((1, 1), (100, 100)), 3, 3),
+            [
+                Entity::Cell(1, 1),
+                Entity::Cell(1, 2),
+                Entity::Cell(2, 1),
+                Entity::Cell(2, 2)
+            ]
+        );
+        assert_eq!(
+            vec_cells(Cells::rect((2, 2), (2, 2)), 3, 3),
+            [Entity::Cell(2, 2)]
+        );
+        assert_eq!(vec_cells(Cells::rect((3, 0), (5, 2)), 3, 3), []);
+        assert_eq!(vec_cells(Cells::rect((0, 0), (1, 1)), 0, 0), []);
+        assert_eq!(
+            vec_cells(Cells::rect((2, 2), (usize::MAX, usize::MAX)), 3, 3),
+            [Entity::Cell(2, 2)]
+        );
+    }
+
     #[test]
     fn segment_test() {
         assert_eq!(
@@ -763,6 +865,29 @@ mod tests {
         assert_eq!(vec_cells(Rows::first().inverse(), 0, 0), []);
     }
 
+    #[test]
+    fn validate_bounds_test() {
+        let data = vec![vec![String::default(); 3]; 2];
+        let records = VecRecords::new(data);
+        let err = |row, col| Err(TableError::OutOfBounds(Position::new(row, col)));
+
+        assert_eq!(Rows::single(2).validate_bounds(&records), Ok(()));
+        assert_eq!(Rows::single(3).validate_bounds(&records), err(3, 0));
+        assert_eq!(Rows::new(..).validate_bounds(&records), Ok(()));
+        assert_eq!(Rows::new(1..3).validate_bounds(&records), Ok(()));
+        assert_eq!(Rows::new(1..10).validate_bounds(&records), err(3, 0));
+        assert_eq!(Rows::new(5..).validate_bounds(&records), Ok(()));
+        assert_eq!(Rows::new(5..7).validate_bounds(&records), err(5, 0));
+        assert_eq!(Columns::single(4).validate_bounds(&records), err(0, 4));
+        assert_eq!(Columns::new(..=4).validate_bounds(&records), err(0, 4));
+        assert_eq!(
+            Columns::single(0)
+                .and(Rows::single(10))
+                .validate_bounds(&records),
+            err(10, 0)
+        );
+    }
+
     fn vec_cells<O: Object<VecRecords<String>>>(
         o: O,
         count_rows: usize,
@@ -1,12 +1,13 @@
-use std::ops::{Add, RangeBounds, Sub};
+use std::ops::{Add, Range, RangeBounds, Sub};
 
 use crate::{
-    grid::config::Entity,
+    grid::config::{Entity, Position},
     grid::records::{ExactRecords, Records},
     settings::object::{cell::EntityOnce, Object},
+    TableError,
 };
 
-use super::util::bounds_to_usize;
+use super::util::{bounds_exceeded, bounds_to_usize};
 
 /// Row denotes a set of cells on given rows on a [`Table`].
 ///
@@ -19,8 +20,9 @@ pub struct Rows<R> {
 impl<R> Rows<R> {
     /// Returns a new instance of [`Rows`] for a range of rows.
     ///
-    /// If the boundaries are exceeded it may panic.
-    pub fn new(range: R) -> Self
+    /// Rows out of the boundaries produce no cells, except the one past the end row
+    /// which is kept as it's where the bottom border goes.
+    pub const fn new(range: R) -> Self
     where
         R: RangeBounds<usize>,
     {
@@ -35,7 +37,8 @@ impl<R> Rows<R> {
 impl Rows<()> {
     /// Returns a new instance of [`Rows`] with a single row.
     ///
-    /// If the boundaries are exceeded it may panic.
+    /// Rows out of the boundaries produce no cells, except the one past the end row
+    /// which is kept as it's where the bottom border goes.
     pub const fn single(index: usize) -> Row {
         Row { index }
     }
@@ -53,6 +56,29 @@ impl Rows<()> {
     pub const fn last() -> LastRow {
         LastRow
     }
+
+    /// Returns a row [`Object`] with a given offset from the last row.
+    ///
+    /// So `Rows::from_end(0)` is the last row and `Rows::from_end(1)` is the one before it.
+    /// It's the same as `Rows::last() - offset`.
+    ///
+    /// If the offset exceeds the boundaries the object will produce no cells.
+    pub const fn from_end(offset: usize) -> LastRowOffset {
+        LastRowOffset::sub(offset)
+    }
+
+    /// Returns a band of `size` rows with a given index.
+    ///
+    /// It's the same as `Rows::new(index * size..(index + 1) * size)`,
+    /// so `Rows::band(1, 3)` is a range of rows `3..6`.
+    ///
+    /// Rows out of the boundaries produce no cells, except the one past the end row
+    /// which is kept as it's where the bottom border goes.
+    pub const fn band(index: usize, size: usize) -> Rows<Range<usize>> {
+        Rows {
+            range: index * size..(index + 1) * size,
+        }
+    }
 }
 
 impl<I, R> Object<I> for Rows<R>
@@ -70,6 +96,137 @@ where
 
         RowsIter::new(x, y)
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        let start = self.range.start_bound();
+        let end = self.range.end_bound();
+        match bounds_exceeded(start, end, records.count_rows()) {
+            Some(i) => Err(TableError::OutOfBounds(Position::new(i, 0))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// RowBands denotes every other band of `size` rows on a [`Table`].
+///
+/// It can be used for grouped striping, by applying 2 different colors to even and odd bands.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{object::RowBands, Format}};
+///
+/// let data = [[0], [1], [2], [3], [4], [5]];
+///
+/// let mut table = Table::new(data);
+/// table.modify(RowBands::odd(2).offset(1), Format::content(|s| format!("*{s}")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+----+\n\
+///      | 0  |\n\
+///      +----+\n\
+///      | 0  |\n\
+///      +----+\n\
+///      | 1  |\n\
+///      +----+\n\
+///      | *2 |\n\
+///      +----+\n\
+///      | *3 |\n\
+///      +----+\n\
+///      | 4  |\n\
+///      +----+\n\
+///      | 5  |\n\
+///      +----+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy)]
+pub struct RowBands {
+    size: usize,
+    offset: usize,
+    odd: bool,
+}
+
+impl RowBands {
+    /// Returns even bands (1st, 3rd, ...) of `size` rows.
+    pub const fn even(size: usize) -> Self {
+        Self {
+            size,
+            offset: 0,
+            odd: false,
+        }
+    }
+
+    /// Returns odd bands (2nd, 4th, ...) of `size` rows.
+    pub const fn odd(size: usize) -> Self {
+        Self {
+            size,
+            offset: 0,
+            odd: true,
+        }
+    }
+
+    /// Sets a number of rows the bands start after.
+    ///
+    /// These rows are not selected, so it can be used to skip a header.
+    pub const fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<I> Object<I> for RowBands
+where
+    I: ExactRecords,
+{
+    type Iter = RowBandsIter;
+
+    fn cells(&self, records: &I) -> Self::Iter {
+        RowBandsIter::new(*self, records.count_rows())
+    }
+}
+
+/// An [`Iterator`] which goes over rows of [`RowBands`].
+#[derive(Debug)]
+pub struct RowBandsIter {
+    bands: RowBands,
+    row: usize,
+    end: usize,
+}
+
+impl RowBandsIter {
+    const fn new(bands: RowBands, end: usize) -> Self {
+        Self {
+            row: bands.offset,
+            bands,
+            end,
+        }
+    }
+}
+
+impl Iterator for RowBandsIter {
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bands.size == 0 {
+            return None;
+        }
+
+        while self.row < self.end {
+            let row = self.row;
+            self.row += 1;
+
+            let band = (row - self.bands.offset) / self.bands.size;
+            let is_odd = band % 2 == 1;
+            if is_odd == self.bands.odd {
+                return Some(Entity::Row(row));
+            }
+        }
+
+        None
+    }
 }
 
 /// A row which is located by an offset from the first row.
@@ -78,12 +235,28 @@ pub struct Row {
     index: usize,
 }
 
-impl<I> Object<I> for Row {
+impl<I> Object<I> for Row
+where
+    I: Records + ExactRecords,
+{
     type Iter = EntityOnce;
 
-    fn cells(&self, _: &I) -> Self::Iter {
+    fn cells(&self, records: &I) -> Self::Iter {
+        // the one past the end row is kept as it's where the bottom border goes
+        if records.count_columns() == 0 || self.index > records.count_rows() {
+            return EntityOnce::new(None);
+        }
+
         EntityOnce::new(Some(Entity::Row(self.index)))
     }
+
+    fn validate_bounds(&self, records: &I) -> Result<(), TableError> {
+        if self.index > records.count_rows() {
+            return Err(TableError::OutOfBounds(Position::new(self.index, 0)));
+        }
+
+        Ok(())
+    }
 }
 
 impl From<Row> for usize {
@@ -176,7 +349,7 @@ pub struct LastRowOffset {
 }
 
 impl LastRowOffset {
-    fn sub(offset: usize) -> Self {
+    const fn sub(offset: usize) -> Self {
         Self {
             offset,
             sign: false,
@@ -83,7 +83,12 @@ pub struct SectorIter {
 }
 
 impl SectorIter {
-    const fn new(rows_start: usize, rows_end: usize, cols_start: usize, cols_end: usize) -> Self {
+    pub(crate) const fn new(
+        rows_start: usize,
+        rows_end: usize,
+        cols_start: usize,
+        cols_end: usize,
+    ) -> Self {
         Self {
             iter: SectorCellsIter::new(rows_start, rows_end, cols_start, cols_end),
         }
@@ -0,0 +1,142 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::object::Object,
+};
+
+/// Threshold selects cells of a column by comparing their numeric value.
+///
+/// Each cell is parsed as [`f64`];
+/// a header (first row) and cells which can't be parsed are skipped.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{object::Threshold, Format}};
+///
+/// let data = [["Apple", "150"], ["Banana", "30"], ["Cherry", "n/a"], ["Kiwi", "101.5"]];
+///
+/// let mut table = Table::new(data);
+/// table.modify(Threshold::column(1).greater_than(100.0), Format::content(|s| format!("{s}!")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+--------+\n\
+///      | 0      | 1      |\n\
+///      +--------+--------+\n\
+///      | Apple  | 150!   |\n\
+///      +--------+--------+\n\
+///      | Banana | 30     |\n\
+///      +--------+--------+\n\
+///      | Cherry | n/a    |\n\
+///      +--------+--------+\n\
+///      | Kiwi   | 101.5! |\n\
+///      +--------+--------+"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    column: usize,
+    min: Option<Bound>,
+    max: Option<Bound>,
+}
+
+impl Threshold {
+    /// Create a threshold for a given column.
+    ///
+    /// Without any bounds set it selects all numeric cells of the column.
+    pub fn column(index: usize) -> Self {
+        Self {
+            column: index,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Select cells with a value strictly greater than a given one.
+    pub fn greater_than(mut self, value: f64) -> Self {
+        self.min = Some(Bound::exclusive(value));
+        self
+    }
+
+    /// Select cells with a value strictly less than a given one.
+    pub fn less_than(mut self, value: f64) -> Self {
+        self.max = Some(Bound::exclusive(value));
+        self
+    }
+
+    /// Select cells with a value in a given range, bounds included.
+    pub fn between(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(Bound::inclusive(min));
+        self.max = Some(Bound::inclusive(max));
+        self
+    }
+
+    fn is_matched(&self, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+
+        let min = self.min.is_none_or(|b| match b.inclusive {
+            true => value >= b.value,
+            false => value > b.value,
+        });
+
+        let max = self.max.is_none_or(|b| match b.inclusive {
+            true => value <= b.value,
+            false => value < b.value,
+        });
+
+        min && max
+    }
+}
+
+impl<R> Object<R> for Threshold
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let mut out = vec![];
+        if self.column >= records.count_columns() {
+            return out.into_iter();
+        }
+
+        for row in 1..records.count_rows() {
+            let text = records.get_text((row, self.column).into());
+            let value = match text.trim().parse::<f64>() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            if self.is_matched(value) {
+                out.push(Entity::Cell(row, self.column));
+            }
+        }
+
+        out.into_iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bound {
+    value: f64,
+    inclusive: bool,
+}
+
+impl Bound {
+    fn inclusive(value: f64) -> Self {
+        Self {
+            value,
+            inclusive: true,
+        }
+    }
+
+    fn exclusive(value: f64) -> Self {
+        Self {
+            value,
+            inclusive: false,
+        }
+    }
+}
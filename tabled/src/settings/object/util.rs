@@ -5,13 +5,44 @@ pub(super) fn bounds_to_usize(
     left: Bound<&usize>,
     right: Bound<&usize>,
     count_elements: usize,
+) -> (usize, usize) {
+    let (start, end) = range_to_usize(left, right, count_elements);
+
+    // a range out of the boundaries is cut, so it produces no cells for the exceeded part;
+    // though the one past the end index is kept as it's where the last border goes
+    let max = count_elements.saturating_add(1);
+    (start.min(max), end.min(max))
+}
+
+/// Returns the first index of a range which is out of the boundaries if there's any.
+///
+/// The one past the end index is considered to be within the boundaries.
+pub(super) fn bounds_exceeded(
+    left: Bound<&usize>,
+    right: Bound<&usize>,
+    count_elements: usize,
+) -> Option<usize> {
+    let (start, end) = range_to_usize(left, right, count_elements);
+    let max = count_elements.saturating_add(1);
+
+    if start >= end || end <= max {
+        None
+    } else {
+        Some(start.max(max))
+    }
+}
+
+fn range_to_usize(
+    left: Bound<&usize>,
+    right: Bound<&usize>,
+    count_elements: usize,
 ) -> (usize, usize) {
     match (left, right) {
-        (Bound::Included(x), Bound::Included(y)) => (*x, y + 1),
+        (Bound::Included(x), Bound::Included(y)) => (*x, y.saturating_add(1)),
         (Bound::Included(x), Bound::Excluded(y)) => (*x, *y),
         (Bound::Included(x), Bound::Unbounded) => (*x, count_elements),
         (Bound::Unbounded, Bound::Unbounded) => (0, count_elements),
-        (Bound::Unbounded, Bound::Included(y)) => (0, y + 1),
+        (Bound::Unbounded, Bound::Included(y)) => (0, y.saturating_add(1)),
         (Bound::Unbounded, Bound::Excluded(y)) => (0, *y),
         (Bound::Excluded(_), Bound::Unbounded)
         | (Bound::Excluded(_), Bound::Included(_))
@@ -103,6 +103,16 @@ impl Padding {
         self
     }
 
+    /// The function, sets the same character for the padding on all sides.
+    ///
+    /// The character is used only for the padding,
+    /// the content justification is set separately by [`Justification`].
+    ///
+    /// [`Justification`]: crate::settings::formatting::Justification
+    pub const fn fill_all(self, c: char) -> Self {
+        self.fill(c, c, c, c)
+    }
+
     #[cfg(feature = "std")]
     /// Construct's an PaddingExpand object.
     pub const fn expand(horizontal: bool) -> PaddingExpand {
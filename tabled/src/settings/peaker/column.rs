@@ -0,0 +1,27 @@
+use super::{content::is_peakable, Peaker, PriorityByContent};
+
+/// A Peaker which goes over a given column first,
+/// and then over the widest columns like [`PriorityByContent`].
+///
+/// A column content never becomes narrower than 1.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Hash)]
+pub struct PriorityColumn {
+    col: usize,
+}
+
+impl PriorityColumn {
+    /// Creates a new [`PriorityColumn`] object targeting a given column.
+    pub const fn new(col: usize) -> Self {
+        Self { col }
+    }
+}
+
+impl Peaker for PriorityColumn {
+    fn peak(&mut self, mins: &[usize], widths: &[usize]) -> Option<usize> {
+        if self.col < widths.len() && is_peakable(mins, widths, self.col) {
+            return Some(self.col);
+        }
+
+        PriorityByContent::new().peak(mins, widths)
+    }
+}
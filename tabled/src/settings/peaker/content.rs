@@ -0,0 +1,36 @@
+use super::Peaker;
+
+/// A Peaker which goes over the widest column first,
+/// but never makes a column content narrower than 1.
+///
+/// When columns have equal widths the right most one is peaked.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Hash)]
+pub struct PriorityByContent;
+
+impl PriorityByContent {
+    /// Creates a new [`PriorityByContent`] object.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Peaker for PriorityByContent {
+    fn peak(&mut self, mins: &[usize], widths: &[usize]) -> Option<usize> {
+        (0..widths.len())
+            .filter(|&i| is_peakable(mins, widths, i))
+            .max_by_key(|&i| widths[i])
+    }
+}
+
+// A column is expected to keep at least 1 character of its content.
+//
+// No minimums are given when a width is increased or a height is limited,
+// so any column is fine unless it's already empty.
+pub(super) fn is_peakable(mins: &[usize], widths: &[usize], col: usize) -> bool {
+    if mins.is_empty() {
+        return widths[col] != 0;
+    }
+
+    let min = mins.get(col).copied().unwrap_or(0);
+    widths[col] > min + 1
+}
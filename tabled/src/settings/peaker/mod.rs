@@ -4,12 +4,16 @@
 //! [`Width`]: crate::settings::width::Width
 //! [`Height`]: crate::settings::height::Height
 
+mod column;
+mod content;
 mod left;
 mod max;
 mod min;
 mod none;
 mod right;
 
+pub use column::PriorityColumn;
+pub use content::PriorityByContent;
 pub use left::PriorityLeft;
 pub use max::PriorityMax;
 pub use min::PriorityMin;
@@ -266,4 +270,92 @@ impl Priority {
     pub fn right() -> PriorityRight {
         PriorityRight::new()
     }
+
+    /// Returns a [`Peaker`] which goes over list peacking the widest value first,
+    /// and prioritizing a right side when equal values are met.
+    ///
+    /// Unlike [`Priority::max`] it never shrinks a column content below 1 character.
+    ///
+    /// ```
+    /// # use tabled::{Table, settings::{Style, peaker::Priority, Width}};
+    /// # use testing_table::assert_table;
+    /// #
+    /// let data = [
+    ///     ("1", "Hello", 100),
+    ///     ("2", "World", 1000),
+    /// ];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::modern());
+    /// table.with(Width::wrap(15).priority(Priority::by_content()));
+    ///
+    /// let output = table.to_string();
+    ///
+    /// assert_table!(
+    ///     output,
+    ///     "┌────┬────┬───┐"
+    ///     "│ &s │ &s │ i │"
+    ///     "│ tr │ tr │ 3 │"
+    ///     "│    │    │ 2 │"
+    ///     "├────┼────┼───┤"
+    ///     "│ 1  │ He │ 1 │"
+    ///     "│    │ ll │ 0 │"
+    ///     "│    │ o  │ 0 │"
+    ///     "├────┼────┼───┤"
+    ///     "│ 2  │ Wo │ 1 │"
+    ///     "│    │ rl │ 0 │"
+    ///     "│    │ d  │ 0 │"
+    ///     "│    │    │ 0 │"
+    ///     "└────┴────┴───┘"
+    /// );
+    /// ```
+    pub fn by_content() -> PriorityByContent {
+        PriorityByContent::new()
+    }
+
+    /// Returns a [`Peaker`] which goes over a given column first,
+    /// and then over the widest values like [`Priority::by_content`].
+    ///
+    /// It never shrinks a column content below 1 character.
+    ///
+    /// ```
+    /// # use tabled::{Table, settings::{Style, peaker::Priority, Width}};
+    /// # use testing_table::assert_table;
+    /// #
+    /// let data = [
+    ///     ("1", "Hello", 100),
+    ///     ("2", "World", 1000),
+    /// ];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::modern());
+    /// table.with(Width::wrap(15).priority(Priority::column(1)));
+    ///
+    /// let output = table.to_string();
+    ///
+    /// assert_table!(
+    ///     output,
+    ///     "┌────┬───┬────┐"
+    ///     "│ &s │ & │ i3 │"
+    ///     "│ tr │ s │ 2  │"
+    ///     "│    │ t │    │"
+    ///     "│    │ r │    │"
+    ///     "├────┼───┼────┤"
+    ///     "│ 1  │ H │ 10 │"
+    ///     "│    │ e │ 0  │"
+    ///     "│    │ l │    │"
+    ///     "│    │ l │    │"
+    ///     "│    │ o │    │"
+    ///     "├────┼───┼────┤"
+    ///     "│ 2  │ W │ 10 │"
+    ///     "│    │ o │ 00 │"
+    ///     "│    │ r │    │"
+    ///     "│    │ l │    │"
+    ///     "│    │ d │    │"
+    ///     "└────┴───┴────┘"
+    /// );
+    /// ```
+    pub fn column(column: usize) -> PriorityColumn {
+        PriorityColumn::new(column)
+    }
 }
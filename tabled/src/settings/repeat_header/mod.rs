@@ -0,0 +1,110 @@
+//! This module contains a [`RepeatHeader`] option for a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::{RepeatHeader, Style}};
+//!
+//! let data = [1, 2, 3, 4, 5];
+//!
+//! let table = Table::new(data)
+//!     .with(Style::rounded())
+//!     .with(RepeatHeader::every(2))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "╭─────╮\n\
+//!      │ i32 │\n\
+//!      ├─────┤\n\
+//!      │ 1   │\n\
+//!      │ 2   │\n\
+//!      ├─────┤\n\
+//!      │ i32 │\n\
+//!      ├─────┤\n\
+//!      │ 3   │\n\
+//!      │ 4   │\n\
+//!      ├─────┤\n\
+//!      │ i32 │\n\
+//!      ├─────┤\n\
+//!      │ 5   │\n\
+//!      ╰─────╯"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::config::ColoredConfig,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    settings::TableOption,
+};
+
+/// RepeatHeader repeats a header (the first row) of a [`Table`] every N data rows.
+///
+/// A copy of the header keeps its settings, like alignment, padding, colors and spans.
+/// A horizontal line set right below the header is put around each copy as well.
+///
+/// A copy is never put inside a row span, it's moved down to the end of the span instead.
+/// The header is not repeated after the last block, even if it's a full one.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RepeatHeader {
+    every: usize,
+}
+
+impl RepeatHeader {
+    /// Creates a [`RepeatHeader`] which puts a header after each `n` data rows.
+    ///
+    /// `0` means that the header is not repeated.
+    pub const fn every(n: usize) -> Self {
+        Self { every: n }
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for RepeatHeader
+where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self.every == 0 || records.count_rows() == 0 {
+            return;
+        }
+
+        let header = (0..records.count_columns())
+            .map(|col| records.get_text((0, col).into()).to_owned())
+            .collect::<Vec<_>>();
+        let line = cfg.get_horizontal_line(1).cloned();
+
+        let mut row = 1 + self.every;
+        loop {
+            while row < records.count_rows() && is_covered_by_row_span(cfg, row, header.len()) {
+                row += 1;
+            }
+
+            if row >= records.count_rows() {
+                break;
+            }
+
+            records.insert_row(row);
+            let _ = cfg.insert_row(row);
+            let _ = cfg.copy_row(0, row);
+
+            for (col, text) in header.iter().enumerate() {
+                records.set((row, col).into(), text.clone());
+            }
+
+            if let Some(line) = line {
+                cfg.insert_horizontal_line(row, line);
+                cfg.insert_horizontal_line(row + 1, line);
+            }
+
+            row += 1 + self.every;
+        }
+    }
+}
+
+fn is_covered_by_row_span(cfg: &ColoredConfig, row: usize, count_columns: usize) -> bool {
+    (0..count_columns).any(|col| cfg.is_cell_covered_by_row_span((row, col).into()))
+}
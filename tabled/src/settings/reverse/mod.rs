@@ -4,6 +4,37 @@ use crate::{
 };
 
 /// Reverse data on the table.
+///
+/// A number of rows (columns) at the beginning and at the end can be skipped,
+/// so for example a header can be kept on top by `Reverse::rows(1, 0)`.
+///
+/// Notice that a header is just a first row of a table,
+/// so reversing columns moves the headers along with their columns.
+///
+/// Be aware that spans are not moved,
+/// so a table with spans may look broken afterwards.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Reverse, Style}};
+///
+/// let data = [(1, "first"), (2, "second"), (3, "third")];
+///
+/// let table = Table::new(data)
+///     .with(Style::markdown())
+///     .with(Reverse::rows(1, 0))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| i32 | &str   |\n\
+///      |-----|--------|\n\
+///      | 3   | third  |\n\
+///      | 2   | second |\n\
+///      | 1   | first  |"
+/// );
+/// ```
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Reverse {
     columns: bool,
@@ -12,12 +43,14 @@ pub struct Reverse {
 }
 
 impl Reverse {
-    /// Reverse columns.
+    /// Reverse columns,
+    /// skipping `start` columns at the beginning and `end` columns at the end.
     pub const fn columns(start: usize, end: usize) -> Self {
         Self::new(true, start, end)
     }
 
-    /// Reverse rows.
+    /// Reverse rows,
+    /// skipping `start` rows at the beginning and `end` rows at the end.
     pub const fn rows(start: usize, end: usize) -> Self {
         Self::new(false, start, end)
     }
@@ -72,8 +105,8 @@ where
         return;
     }
 
-    for (i, row) in (start..end / 2).enumerate() {
-        data.swap_row(row, end - i - 1);
+    for i in 0..count_rows / 2 {
+        data.swap_row(start + i, end - i - 1);
     }
 }
 
@@ -86,8 +119,8 @@ where
         return;
     }
 
-    for (i, col) in (start..end / 2).enumerate() {
-        data.swap_column(col, end - i - 1);
+    for i in 0..count_columns / 2 {
+        data.swap_column(start + i, end - i - 1);
     }
 }
 
@@ -114,6 +147,16 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_reverse_rows_range() {
+        let mut data = vec![vec![0], vec![1], vec![2], vec![3], vec![4], vec![5]];
+        reverse_rows(&mut data, 1, 5);
+        assert_eq!(
+            data,
+            vec![vec![0], vec![4], vec![3], vec![2], vec![1], vec![5]]
+        );
+    }
+
     fn rev_rows(mut data: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
         let end = data.len();
         reverse_rows(&mut data, 0, end);
@@ -1,4 +1,4 @@
-use crate::{grid::config::Entity, settings::TableOption};
+use crate::{grid::config::Entity, settings::TableOption, TableError};
 
 #[cfg(feature = "std")]
 use crate::settings::CellOption;
@@ -51,6 +51,11 @@ where
             (value, None) => value,
         }
     }
+
+    fn validate(&self, records: &R, cfg: &C, entity: Entity) -> Result<(), TableError> {
+        self.0.validate(records, cfg, entity)?;
+        self.1.validate(records, cfg, entity)
+    }
 }
 
 impl<R, D, C, A, B> TableOption<R, C, D> for Settings<A, B>
@@ -71,6 +76,11 @@ where
             (value, None) => value,
         }
     }
+
+    fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        self.0.validate(records, cfg)?;
+        self.1.validate(records, cfg)
+    }
 }
 
 /// A marker structure to be able to create an empty [`Settings`].
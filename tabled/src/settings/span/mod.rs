@@ -11,9 +11,11 @@
 //! ```
 
 mod column;
+mod object;
 mod row;
 
 pub use column::ColumnSpan;
+pub use object::ObjectSpan;
 pub use row::RowSpan;
 
 /// Span represent a horizontal/column span setting for any cell on a [`Table`].
@@ -65,4 +67,44 @@ impl Span {
     pub fn row(size: isize) -> RowSpan {
         RowSpan::new(size)
     }
+
+    /// Constructs a horizontal/column [`Span`] for each cell of an object.
+    ///
+    /// Content of the covered cells is removed.
+    /// A span which overlaps another span is ignored,
+    /// and a span which exceeds the number of columns is cut.
+    ///
+    /// ```
+    /// use tabled::{settings::{Span, object::Rows}, Table};
+    ///
+    /// let data = [["Hello", "World", "!"], ["Tabled", "is", "nice"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Span::column_at(Rows::first(), 3));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+--------+-------+------+\n",
+    ///         "| 0                     |\n",
+    ///         "+--------+-------+------+\n",
+    ///         "| Hello  | World | !    |\n",
+    ///         "+--------+-------+------+\n",
+    ///         "| Tabled | is    | nice |\n",
+    ///         "+--------+-------+------+",
+    ///     )
+    /// )
+    /// ```
+    pub fn column_at<O>(target: O, size: usize) -> ObjectSpan<O> {
+        ObjectSpan::column(target, size)
+    }
+
+    /// Constructs a vertical/row [`Span`] for each cell of an object.
+    ///
+    /// Content of the covered cells is removed.
+    /// A span which overlaps another span is ignored,
+    /// and a span which exceeds the number of rows is cut.
+    pub fn row_at<O>(target: O, size: usize) -> ObjectSpan<O> {
+        ObjectSpan::row(target, size)
+    }
 }
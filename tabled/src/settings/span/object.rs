@@ -0,0 +1,117 @@
+use std::cmp;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Position, SpannedConfig},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{object::Object, TableOption},
+};
+
+/// A span set for each cell of an [`Object`].
+///
+/// A size is cut to the table bounds.
+/// A span which overlaps an existing one (including the ones set by this option) is ignored.
+/// Content of the cells covered by a span is removed.
+///
+/// It's created by [`Span::column_at`] and [`Span::row_at`].
+///
+/// [`Span::column_at`]: crate::settings::Span::column_at
+/// [`Span::row_at`]: crate::settings::Span::row_at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObjectSpan<O> {
+    target: O,
+    size: usize,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Direction {
+    Column,
+    Row,
+}
+
+impl<O> ObjectSpan<O> {
+    pub(crate) const fn column(target: O, size: usize) -> Self {
+        Self {
+            target,
+            size,
+            direction: Direction::Column,
+        }
+    }
+
+    pub(crate) const fn row(target: O, size: usize) -> Self {
+        Self {
+            target,
+            size,
+            direction: Direction::Row,
+        }
+    }
+}
+
+impl<O, R, D> TableOption<R, ColoredConfig, D> for ObjectSpan<O>
+where
+    O: Object<R>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        let shape = (count_rows, count_cols).into();
+
+        let mut spanned: Vec<Position> = Vec::new();
+        for entity in self.target.cells(records) {
+            for pos in entity.iter(count_rows, count_cols) {
+                if !pos.is_covered(shape) || spanned.contains(&pos) {
+                    continue;
+                }
+
+                let span = match self.direction {
+                    Direction::Column => cmp::min(self.size, count_cols - pos.col()),
+                    Direction::Row => cmp::min(self.size, count_rows - pos.row()),
+                };
+
+                if span < 2 || has_intersections(cfg, pos, span, self.direction) {
+                    continue;
+                }
+
+                for p in covered_cells(pos, span, self.direction).skip(1) {
+                    records.set(p, String::new());
+                }
+
+                match self.direction {
+                    Direction::Column => cfg.set_column_span(pos, span),
+                    Direction::Row => cfg.set_row_span(pos, span),
+                }
+
+                spanned.push(pos);
+            }
+        }
+    }
+}
+
+fn covered_cells(
+    pos: Position,
+    span: usize,
+    direction: Direction,
+) -> impl Iterator<Item = Position> {
+    (0..span).map(move |i| match direction {
+        Direction::Column => Position::new(pos.row(), pos.col() + i),
+        Direction::Row => Position::new(pos.row() + i, pos.col()),
+    })
+}
+
+fn has_intersections(
+    cfg: &SpannedConfig,
+    pos: Position,
+    span: usize,
+    direction: Direction,
+) -> bool {
+    if !cfg.is_cell_visible(pos) {
+        return true;
+    }
+
+    covered_cells(pos, span, direction).skip(1).any(|p| {
+        !cfg.is_cell_visible(p) || cfg.get_column_span(p).is_some() || cfg.get_row_span(p).is_some()
+    })
+}
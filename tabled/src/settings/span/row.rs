@@ -6,6 +6,7 @@ use crate::{
         records::{ExactRecords, PeekableRecords, Records, RecordsMut},
     },
     settings::CellOption,
+    TableError,
 };
 
 /// Row (vertical) span.
@@ -51,6 +52,27 @@ where
 
         remove_false_spans(cfg);
     }
+
+    fn validate(&self, records: &R, cfg: &ColoredConfig, entity: Entity) -> Result<(), TableError> {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        let shape: Position = (count_rows, count_cols).into();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            if !pos.is_covered(shape) {
+                return Err(TableError::OutOfBounds(pos));
+            }
+
+            if self.size > 0 {
+                let span = cmp::min(self.size as usize, shape.row() - pos.row());
+                if span_has_intersections(cfg, pos, span) {
+                    return Err(TableError::SpanIntersection(pos));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 fn set_span<R>(recs: &mut R, cfg: &mut SpannedConfig, span: isize, pos: Position, shape: Position)
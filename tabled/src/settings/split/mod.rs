@@ -3,6 +3,8 @@
 //!
 //! [`Table`]: crate::Table
 
+mod split_width;
+
 use core::ops::Range;
 
 use crate::grid::{
@@ -12,6 +14,8 @@ use crate::grid::{
 
 use super::TableOption;
 
+pub use split_width::SplitWidth;
+
 #[derive(Debug, Clone, Copy)]
 enum Direction {
     Column,
@@ -239,6 +243,16 @@ impl Split {
         }
     }
 
+    /// Returns a [`SplitWidth`] which splits a cell content into lines of a given width.
+    ///
+    /// ```rust,no_run
+    /// # use tabled::settings::split::Split;
+    /// Split::by_width(10);
+    /// ```
+    pub fn by_width(width: usize) -> SplitWidth {
+        SplitWidth::new(width)
+    }
+
     /// Returns a split [`Table`] with the redistributed cells pushed to the back of the new shape.
     ///
     /// ```text
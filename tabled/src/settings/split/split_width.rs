@@ -0,0 +1,152 @@
+//! This module contains a [`SplitWidth`] setting.
+
+use std::ops::Range;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+        util::string::{get_char_width, get_text_width},
+    },
+    settings::CellOption,
+};
+
+/// Splits a cell content into multiple lines so each of them fits a given width.
+///
+/// Unlike [`Width::wrap`] it doesn't set a column width,
+/// it only changes a content and the column width gets estimated from it.
+///
+/// Whitespace at a split point is dropped.
+/// A character wider than the given width is kept on its own line.
+/// Existing new lines are kept and ANSI sequences are not split if `ansi` feature is on.
+/// A zero width is ignored.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{settings::split::Split, Table};
+/// use testing_table::assert_table;
+///
+/// let mut table = Table::new([["Hello World!", "1"]]);
+/// table.modify((1, 0), Split::by_width(5));
+///
+/// assert_table!(
+///     table,
+///     "+-------+---+"
+///     "| 0     | 1 |"
+///     "+-------+---+"
+///     "| Hello | 1 |"
+///     "| World |   |"
+///     "| !     |   |"
+///     "+-------+---+"
+/// );
+/// ```
+///
+/// [`Width::wrap`]: crate::settings::Width::wrap
+#[derive(Debug, Clone, Copy)]
+pub struct SplitWidth {
+    width: usize,
+}
+
+impl SplitWidth {
+    /// Creates a new [`SplitWidth`] with a given width.
+    pub fn new(width: usize) -> Self {
+        Self { width }
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for SplitWidth
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut ColoredConfig, entity: Entity) {
+        if self.width == 0 {
+            return;
+        }
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_columns) {
+            if !pos.is_covered((count_rows, count_columns).into()) {
+                continue;
+            }
+
+            let text = records.get_text(pos);
+            if get_text_width(text) <= self.width {
+                continue;
+            }
+
+            let text = split_text(text, self.width);
+            records.set(pos, text);
+        }
+    }
+}
+
+fn split_text(text: &str, width: usize) -> String {
+    #[cfg(feature = "ansi")]
+    let stripped = ansi_str::AnsiStr::ansi_strip(text);
+    #[cfg(not(feature = "ansi"))]
+    let stripped = std::borrow::Cow::Borrowed(text);
+
+    let mut buf = String::with_capacity(text.len());
+    for (i, range) in split_ranges(&stripped, width).into_iter().enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        #[cfg(feature = "ansi")]
+        buf.push_str(&ansi_str::AnsiStr::ansi_cut(text, range));
+        #[cfg(not(feature = "ansi"))]
+        buf.push_str(&text[range]);
+    }
+
+    buf
+}
+
+// Returns byte ranges of the lines the text is split into.
+//
+// Whitespace around a split point is dropped,
+// so it doesn't end up at the beginning or the end of a line.
+fn split_ranges(text: &str, width: usize) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    let mut line_width = 0;
+    let mut is_split = false;
+
+    for (i, c) in text.char_indices() {
+        if c == '\n' {
+            ranges.push(start..end);
+            start = i + 1;
+            end = start;
+            line_width = 0;
+            is_split = false;
+            continue;
+        }
+
+        let c_width = get_char_width(c);
+        if line_width > 0 && line_width + c_width > width {
+            let trimmed = text[start..end].trim_end().len();
+            ranges.push(start..start + trimmed);
+            start = i;
+            line_width = 0;
+            is_split = true;
+        }
+
+        if is_split && line_width == 0 && c.is_whitespace() {
+            start = i + c.len_utf8();
+            end = start;
+            continue;
+        }
+
+        line_width += c_width;
+        end = i + c.len_utf8();
+    }
+
+    if !is_split || start < end {
+        ranges.push(start..end);
+    }
+
+    ranges
+}
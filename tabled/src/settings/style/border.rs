@@ -7,9 +7,10 @@ use crate::{
 
 #[cfg(feature = "std")]
 use crate::{
-    grid::config::{ColoredConfig, Entity},
+    grid::config::{ColoredConfig, Entity, Position},
     grid::records::{ExactRecords, Records},
     settings::{CellOption, TableOption},
+    Table,
 };
 
 /// Border represents a border of a Cell.
@@ -121,6 +122,46 @@ impl<T, B, L, R> Border<T, B, L, R> {
     }
 }
 
+#[cfg(feature = "std")]
+impl Border<(), (), (), ()> {
+    /// Fetches a border of a cell from a table,
+    /// so it can be applied to other cells.
+    ///
+    /// The sides which are not set are returned as [`None`],
+    /// and they're left unchanged when the border is applied.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Cell, style::Border, Highlight, Style}};
+    ///
+    /// let data = [[1, 2], [3, 4]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::ascii());
+    /// table.with(Highlight::outline(Cell::new(1, 0), '*'));
+    ///
+    /// let border = Border::inherit_cell(&table, (1, 0));
+    /// table.modify(Cell::new(2, 1), border);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+---+\n\
+    ///      | 0 | 1 |\n\
+    ///      *****---+\n\
+    ///      * 1 * 2 |\n\
+    ///      *********\n\
+    ///      | 3 * 4 *\n\
+    ///      +---*****"
+    /// );
+    /// ```
+    pub fn inherit_cell<P>(table: &Table, pos: P) -> Self
+    where
+        P: Into<Position>,
+    {
+        let border = table.get_config().get_border(pos.into(), table.shape());
+        Self::from_border(border)
+    }
+}
+
 impl<T, B, L, R> Border<T, B, L, R> {
     /// Set a top border character.
     pub const fn top(mut self, c: char) -> Border<On, B, L, R> {
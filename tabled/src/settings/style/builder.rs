@@ -145,6 +145,36 @@ impl Style<(), (), (), (), (), (), 0, 0> {
         )
     }
 
+    /// This is a style which draws only an outer frame of a table.
+    ///
+    /// It has no inner horizontal and vertical lines,
+    /// though they can be added back e.g. by [`Style::horizontals`].
+    ///
+    /// It's not named `frame` as [`Style::frame`] is already used to set an outer border of a style.
+    ///
+    /// ```text
+    ///     +---------------------------------------------+
+    ///     | id  distribution            link            |
+    ///     | 0      Fedora      https://getfedora.org/   |
+    ///     | 2     OpenSUSE    https://www.opensuse.org/ |
+    ///     | 3   Endeavouros   https://endeavouros.com/  |
+    ///     +---------------------------------------------+
+    /// ```
+    pub const fn framed() -> Style<On, On, On, On, (), (), 0, 0> {
+        Style::new(
+            create_borders(
+                HLine::new(Some('-'), None, Some('+'), Some('+')),
+                HLine::new(Some('-'), None, Some('+'), Some('+')),
+                HLine::empty(),
+                Some('|'),
+                Some('|'),
+                None,
+            ),
+            [],
+            [],
+        )
+    }
+
     /// This is a style which relays only on ASCII charset.
     ///
     /// It has horizontal and vertical lines.
@@ -1229,6 +1259,39 @@ impl<B, R, H, V, const HN: usize, const VN: usize> Style<On, B, On, R, H, V, HN,
     }
 }
 
+impl<H, V, const HN: usize, const VN: usize> Style<On, On, On, On, H, V, HN, VN> {
+    /// Sets rounded outer corners (`╭`, `╮`, `╰`, `╯`),
+    /// while intersections are left as they are.
+    ///
+    /// ```
+    /// use tabled::{settings::Style, Table};
+    /// use testing_table::assert_table;
+    ///
+    /// let data = [["Hello", "World"], ["Rust", "Tabled"]];
+    /// let mut table = Table::new(data);
+    /// table.with(Style::ascii().rounded_corners());
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "╭-------+--------╮"
+    ///     "| 0     | 1      |"
+    ///     "+-------+--------+"
+    ///     "| Hello | World  |"
+    ///     "+-------+--------+"
+    ///     "| Rust  | Tabled |"
+    ///     "╰-------+--------╯"
+    /// );
+    /// ```
+    pub const fn rounded_corners(mut self) -> Self {
+        self.borders.top_left = Some('╭');
+        self.borders.top_right = Some('╮');
+        self.borders.bottom_left = Some('╰');
+        self.borders.bottom_right = Some('╯');
+
+        Style::new(self.borders, self.horizontals, self.verticals)
+    }
+}
+
 impl<B, L, H, V, const HN: usize, const VN: usize> Style<On, B, L, On, H, V, HN, VN> {
     /// Sets a top right corner.
     pub const fn corner_top_right(mut self, c: char) -> Self {
@@ -1422,6 +1485,50 @@ impl<T, B, L, R, H, const HN: usize, const VN: usize> Style<T, B, L, R, H, On, H
 
         Style::new(self.borders, horizontals, self.verticals)
     }
+
+    /// Keeps only a single vertical split line at a given index,
+    /// removing all other inner vertical lines.
+    ///
+    /// The line inherits its characters from the style.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "std", doc = "```")]
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    /// use tabled::{settings::style::Style, Table};
+    ///
+    /// let data = [["Name", "Jan", "Feb"], ["Rent", "100", "200"]];
+    /// let table = Table::new(data).with(Style::modern().only_vertical(1)).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┌──────┬──────────┐\n",
+    ///         "│ 0    │ 1    2   │\n",
+    ///         "├──────┼──────────┤\n",
+    ///         "│ Name │ Jan  Feb │\n",
+    ///         "├──────┼──────────┤\n",
+    ///         "│ Rent │ 100  200 │\n",
+    ///         "└──────┴──────────┘",
+    ///     )
+    /// )
+    /// ```
+    pub const fn only_vertical(self, line: usize) -> Style<T, B, L, R, H, (), HN, 1>
+    where
+        R: Copy,
+        L: Copy,
+    {
+        let vertical = VLine::new(
+            self.borders.vertical,
+            self.borders.intersection,
+            self.borders.top_intersection,
+            self.borders.bottom_intersection,
+        );
+
+        let style = self.remove_vertical();
+
+        Style::new(style.borders, style.horizontals, [(line, vertical)])
+    }
 }
 
 impl<H, V, const HN: usize, const VN: usize> Style<On, On, On, On, H, V, HN, VN> {
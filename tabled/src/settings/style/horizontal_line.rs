@@ -5,7 +5,38 @@ use crate::grid::config::VerticalLine;
 use crate::settings::style::On;
 use crate::settings::Style;
 
+#[cfg(feature = "std")]
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    grid::records::{ExactRecords, Records},
+    settings::CellOption,
+};
+
 /// A horizontal split line which can be used to set a border.
+///
+/// It can also be used as a [`CellOption`] to set a line under a row.
+/// Only whole rows are considered, so columns and cells are ignored.
+///
+/// ```
+/// use tabled::{Table, settings::{style::{HorizontalLine, Style}, object::Rows}};
+///
+/// let data = [[1, 2], [3, 4]];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::modern().remove_horizontal())
+///     .modify(Rows::first(), HorizontalLine::full('━', '┿', '┝', '┥'));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "┌───┬───┐\n\
+///      │ 0 │ 1 │\n\
+///      ┝━━━┿━━━┥\n\
+///      │ 1 │ 2 │\n\
+///      │ 3 │ 4 │\n\
+///      └───┴───┘"
+/// );
+/// ```
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct HorizontalLine<L, R, I> {
     line: Line<char>,
@@ -186,6 +217,28 @@ impl<L, R, I> From<Line<char>> for HorizontalLine<L, R, I> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<L, R, I, Data> CellOption<Data, ColoredConfig> for HorizontalLine<L, R, I>
+where
+    Data: Records + ExactRecords,
+{
+    fn change(self, records: &mut Data, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+
+        match entity {
+            Entity::Row(row) if row < count_rows => {
+                cfg.insert_horizontal_line(row + 1, self.line);
+            }
+            Entity::Global => {
+                for row in 0..count_rows {
+                    cfg.insert_horizontal_line(row + 1, self.line);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 impl<T, B, I> From<HorizontalLine<T, B, I>> for VerticalLine<char> {
     fn from(value: HorizontalLine<T, B, I>) -> Self {
         VerticalLine::new(
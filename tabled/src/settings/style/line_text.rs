@@ -56,6 +56,11 @@ impl<Line> LineText<Line> {
     /// (`line >= 0 && line <= count_rows`)
     /// (`line >= 0 && line <= count_columns`).
     ///
+    /// A column makes the text go down a vertical line,
+    /// a character per each line of a table, intersections included.
+    /// A text longer than the table height is truncated,
+    /// a shorter one leaves the rest of the border as it is.
+    ///
     /// ```
     /// use tabled::{Table, settings::style::LineText, settings::object::Columns};
     ///
@@ -1,4 +1,4 @@
-use crate::grid::config::Entity;
+use crate::{grid::config::Entity, TableError};
 
 /// A trait which is responsible for configuration of a [`Table`].
 ///
@@ -24,6 +24,106 @@ pub trait TableOption<R, C, D> {
     fn hint_change(&self) -> Option<Entity> {
         Some(Entity::Global)
     }
+
+    /// Checks whether the [`TableOption`] can be applied to a table.
+    ///
+    /// It's called by [`Table::try_with`] before [`TableOption::change`],
+    /// so an invalid configuration is reported instead of being silently ignored.
+    ///
+    /// By default any option is considered to be valid.
+    ///
+    /// [`Table::try_with`]: crate::Table::try_with
+    fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        let _ = (records, cfg);
+        Ok(())
+    }
+}
+
+/// An object safe version of [`TableOption`].
+///
+/// It's implemented for any [`TableOption`],
+/// so options can be boxed and chosen at runtime,
+/// while a boxed option is a [`TableOption`] itself.
+///
+/// ```
+/// use tabled::{
+///     grid::{config::ColoredConfig, dimension::CompleteDimensionVecRecords},
+///     grid::records::vec_records::{Text, VecRecords},
+///     settings::{Alignment, Style, TableOptionDyn},
+///     Table,
+/// };
+///
+/// type Setting = Box<
+///     dyn for<'a> TableOptionDyn<
+///         VecRecords<Text<String>>,
+///         ColoredConfig,
+///         CompleteDimensionVecRecords<'a>,
+///     >,
+/// >;
+///
+/// let align_right = true;
+///
+/// let mut settings: Vec<Setting> = vec![Box::new(Style::markdown())];
+/// if align_right {
+///     settings.push(Box::new(Alignment::right()));
+/// }
+///
+/// let table = Table::new([1, 20]).with(settings).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| i32 |\n\
+///      |-----|\n\
+///      |   1 |\n\
+///      |  20 |"
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub trait TableOptionDyn<R, C, D> {
+    /// The same as [`TableOption::change`] but for a boxed option.
+    fn change_boxed(self: Box<Self>, records: &mut R, cfg: &mut C, dimension: &mut D);
+
+    /// The same as [`TableOption::hint_change`].
+    fn hint_change_dyn(&self) -> Option<Entity>;
+
+    /// The same as [`TableOption::validate`].
+    fn validate_dyn(&self, records: &R, cfg: &C) -> Result<(), TableError>;
+}
+
+#[cfg(feature = "std")]
+impl<T, R, C, D> TableOptionDyn<R, C, D> for T
+where
+    T: TableOption<R, C, D>,
+{
+    fn change_boxed(self: Box<Self>, records: &mut R, cfg: &mut C, dimension: &mut D) {
+        T::change(*self, records, cfg, dimension)
+    }
+
+    fn hint_change_dyn(&self) -> Option<Entity> {
+        T::hint_change(self)
+    }
+
+    fn validate_dyn(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        T::validate(self, records, cfg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, R, C, D> TableOption<R, C, D> for Box<T>
+where
+    T: TableOptionDyn<R, C, D> + ?Sized,
+{
+    fn change(self, records: &mut R, cfg: &mut C, dimension: &mut D) {
+        T::change_boxed(self, records, cfg, dimension)
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        T::hint_change_dyn(self)
+    }
+
+    fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        T::validate_dyn(self, records, cfg)
+    }
 }
 
 // todo: probably we could add one more hint but it likely require Vec<Entity>,
@@ -56,6 +156,10 @@ where
             opt.change(records, cfg, dimension)
         }
     }
+
+    fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        self.iter().try_for_each(|opt| opt.validate(records, cfg))
+    }
 }
 
 #[cfg(feature = "std")]
@@ -68,6 +172,10 @@ where
             opt.change(records, cfg, dimension)
         }
     }
+
+    fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+        self.iter().try_for_each(|opt| opt.validate(records, cfg))
+    }
 }
 
 #[cfg(feature = "std")]
@@ -93,6 +201,16 @@ macro_rules! tuple_trait_impl {
 
                 hint_change_list(&list)
             }
+
+            fn validate(&self, records: &R, cfg: &C) -> Result<(), TableError> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = &self;
+                $(
+                    $name::validate($name, records, cfg)?;
+                )+
+
+                Ok(())
+            }
         }
     };
 }
@@ -1,7 +1,8 @@
 use crate::{
     grid::{
         ansi::ANSIBuf,
-        config::{ColoredConfig, Entity, Sides},
+        colors::Colors,
+        config::{ColoredConfig, Entity, Position, Sides},
         records::{ExactRecords, Records},
     },
     settings::{object::Object, Color, TableOption},
@@ -40,6 +41,8 @@ use crate::{
 pub struct Colorization {
     pattern: ColorizationPattern,
     colors: Vec<Color>,
+    skip_header: bool,
+    keep_colors: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -222,13 +225,50 @@ impl Colorization {
         Self::new(colors, ColorizationPattern::ByColumn)
     }
 
+    /// Sets whether a header (the first row) is left as it is.
+    ///
+    /// If it's set a pattern starts from the second row.
+    ///
+    /// ```
+    /// use std::iter::FromIterator;
+    ///
+    /// use tabled::builder::Builder;
+    /// use tabled::settings::{themes::Colorization, Color, Style};
+    ///
+    /// let data = [["Hello", "World"], ["Hi", "World"], ["Halo", "World"]];
+    ///
+    /// let mut table = Builder::from_iter(data).build();
+    /// table
+    ///     .with(Style::empty())
+    ///     .with(Colorization::rows([Color::FG_RED, Color::FG_BLUE]).skip_header(true));
+    ///
+    /// println!("{table}");
+    /// ```
+    pub fn skip_header(mut self, on: bool) -> Self {
+        self.skip_header = on;
+        self
+    }
+
+    /// Sets whether cells which already have a color are left as they are.
+    ///
+    /// By default such colors are overridden.
+    pub fn keep_colors(mut self, on: bool) -> Self {
+        self.keep_colors = on;
+        self
+    }
+
     fn new<I>(colors: I, pattern: ColorizationPattern) -> Self
     where
         I: IntoIterator,
         I::Item: Into<Color>,
     {
         let colors = colors.into_iter().map(Into::into).collect();
-        Self { colors, pattern }
+        Self {
+            colors,
+            pattern,
+            skip_header: false,
+            keep_colors: false,
+        }
     }
 }
 
@@ -244,75 +284,95 @@ where
         let count_columns = records.count_columns();
         let count_rows = records.count_rows();
 
+        let target = Target {
+            first_row: usize::from(self.skip_header),
+            count_rows,
+            count_columns,
+            keep_colors: self.keep_colors,
+        };
+
         match self.pattern {
-            ColorizationPattern::Column => colorize_columns(&self.colors, count_columns, cfg),
-            ColorizationPattern::Row => colorize_rows(&self.colors, count_rows, cfg),
-            ColorizationPattern::ByRow => {
-                colorize_by_row(&self.colors, count_rows, count_columns, cfg)
-            }
-            ColorizationPattern::ByColumn => {
-                colorize_by_column(&self.colors, count_rows, count_columns, cfg)
+            ColorizationPattern::Column => colorize_columns(&self.colors, target, cfg),
+            ColorizationPattern::Row => colorize_rows(&self.colors, target, cfg),
+            ColorizationPattern::ByRow => colorize_by_row(&self.colors, target, cfg),
+            ColorizationPattern::ByColumn => colorize_by_column(&self.colors, target, cfg),
+            ColorizationPattern::Chess => colorize_diogonals(&self.colors, target, cfg),
+        }
+    }
+}
+
+// A part of a table which is colorized.
+#[derive(Debug, Clone, Copy)]
+struct Target {
+    first_row: usize,
+    count_rows: usize,
+    count_columns: usize,
+    keep_colors: bool,
+}
+
+impl Target {
+    // It goes cell by cell so a color of a cell is overridden as well.
+    fn colorize(&self, color: &Color, pos: Entity, cfg: &mut ColoredConfig) {
+        for pos in pos.iter(self.count_rows, self.count_columns) {
+            if pos.row() < self.first_row {
+                continue;
             }
-            ColorizationPattern::Chess => {
-                colorize_diogonals(&self.colors, count_rows, count_columns, cfg)
+
+            if self.keep_colors && has_color(cfg, pos) {
+                continue;
             }
+
+            colorize_entity(color, pos.into(), cfg);
         }
     }
 }
 
-fn colorize_columns(colors: &[Color], count_columns: usize, cfg: &mut ColoredConfig) {
-    for (col, color) in (0..count_columns).zip(colors.iter().cycle()) {
-        colorize_entity(color, Entity::Column(col), cfg);
+fn has_color(cfg: &ColoredConfig, pos: Position) -> bool {
+    matches!(cfg.get_colors().get_color(pos), Some(color) if !color.is_empty())
+}
+
+fn colorize_columns(colors: &[Color], target: Target, cfg: &mut ColoredConfig) {
+    for (col, color) in (0..target.count_columns).zip(colors.iter().cycle()) {
+        target.colorize(color, Entity::Column(col), cfg);
     }
 }
 
-fn colorize_rows(colors: &[Color], count_rows: usize, cfg: &mut ColoredConfig) {
-    for (row, color) in (0..count_rows).zip(colors.iter().cycle()) {
-        colorize_entity(color, Entity::Row(row), cfg);
+fn colorize_rows(colors: &[Color], target: Target, cfg: &mut ColoredConfig) {
+    for (row, color) in (target.first_row..target.count_rows).zip(colors.iter().cycle()) {
+        target.colorize(color, Entity::Row(row), cfg);
     }
 }
 
-fn colorize_by_row(
-    colors: &[Color],
-    count_rows: usize,
-    count_columns: usize,
-    cfg: &mut ColoredConfig,
-) {
+fn colorize_by_row(colors: &[Color], target: Target, cfg: &mut ColoredConfig) {
     let mut color_peek = colors.iter().cycle();
-    for row in 0..count_rows {
-        for col in 0..count_columns {
+    for row in target.first_row..target.count_rows {
+        for col in 0..target.count_columns {
             let color = color_peek.next().unwrap();
-            colorize_entity(color, Entity::Cell(row, col), cfg);
+            target.colorize(color, Entity::Cell(row, col), cfg);
         }
     }
 }
 
-fn colorize_by_column(
-    colors: &[Color],
-    count_rows: usize,
-    count_columns: usize,
-    cfg: &mut ColoredConfig,
-) {
+fn colorize_by_column(colors: &[Color], target: Target, cfg: &mut ColoredConfig) {
     let mut color_peek = colors.iter().cycle();
-    for col in 0..count_columns {
-        for row in 0..count_rows {
+    for col in 0..target.count_columns {
+        for row in target.first_row..target.count_rows {
             let color = color_peek.next().unwrap();
-            colorize_entity(color, Entity::Cell(row, col), cfg);
+            target.colorize(color, Entity::Cell(row, col), cfg);
         }
     }
 }
 
-fn colorize_diogonals(
-    colors: &[Color],
-    count_rows: usize,
-    count_columns: usize,
-    cfg: &mut ColoredConfig,
-) {
+fn colorize_diogonals(colors: &[Color], target: Target, cfg: &mut ColoredConfig) {
+    let first_row = target.first_row;
+    let count_rows = target.count_rows.saturating_sub(first_row);
+    let count_columns = target.count_columns;
+
     let mut color_peek = colors.iter().cycle();
     for mut row in 0..count_rows {
         let color = color_peek.next().unwrap();
         for col in 0..count_columns {
-            colorize_entity(color, Entity::Cell(row, col), cfg);
+            target.colorize(color, Entity::Cell(first_row + row, col), cfg);
 
             row += 1;
             if row == count_rows {
@@ -326,7 +386,7 @@ fn colorize_diogonals(
     for mut col in 1..count_columns {
         let color = color_peek.next().unwrap();
         for row in 0..count_rows {
-            colorize_entity(color, Entity::Cell(row, col), cfg);
+            target.colorize(color, Entity::Cell(first_row + row, col), cfg);
 
             col += 1;
             if col == count_columns {
@@ -278,7 +278,7 @@ fn set_column_text(
     let widths = names
         .iter()
         .enumerate()
-        .map(|(col, name)| (cmp::max(get_line_width(name), dims.get_width(col))))
+        .map(|(col, name)| cmp::max(get_line_width(name), dims.get_width(col)))
         .collect::<Vec<_>>();
 
     dims.set_widths(widths.clone());
@@ -314,7 +314,7 @@ fn set_row_text(
     let heights = names
         .iter()
         .enumerate()
-        .map(|(row, name)| (cmp::max(get_line_width(name), dims.get_height(row))))
+        .map(|(row, name)| cmp::max(get_line_width(name), dims.get_height(row)))
         .collect::<Vec<_>>();
 
     dims.set_heights(heights.clone());
@@ -353,7 +353,7 @@ fn vec_set_size(mut data: Vec<String>, size: usize) -> Vec<String> {
         cmp::Ordering::Equal => {}
         cmp::Ordering::Less => {
             let additional_size = size - data.len();
-            data.extend(std::iter::repeat(String::new()).take(additional_size));
+            data.extend(std::iter::repeat_n(String::new(), additional_size));
         }
         cmp::Ordering::Greater => {
             data.truncate(size);
@@ -11,12 +11,16 @@
 
 use std::collections::HashMap;
 
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
+
 use crate::{
     grid::config::{
-        Border, Borders, ColoredConfig, CompactConfig, CompactMultilineConfig, HorizontalLine,
-        VerticalLine,
+        AlignmentHorizontal, AlignmentVertical, Border, Borders, ColoredConfig, CompactConfig,
+        CompactMultilineConfig, Entity, HorizontalLine, Indent, Sides, VerticalLine,
     },
     settings::{style::Style, Color, TableOption},
+    Table,
 };
 
 /// A raw style data, which can be produced safely from [`Style`].
@@ -29,6 +33,9 @@ pub struct Theme {
     lines_horizontals: Option<HashMap<usize, HorizontalLine<char>>>,
     lines_verticals: Option<HashMap<usize, VerticalLine<char>>>,
     lines_horizontal1: Option<HorizontalLine<char>>,
+    padding: Option<Sides<Indent>>,
+    alignment_horizontal: Option<AlignmentHorizontal>,
+    alignment_vertical: Option<AlignmentVertical>,
 }
 
 impl Theme {
@@ -50,6 +57,31 @@ impl Theme {
         Self::gen(chars, Borders::empty(), None, None, hlines1)
     }
 
+    /// Build a theme out of a table configuration.
+    ///
+    /// It captures borders, their colors, horizontal and vertical lines,
+    /// as well as default padding and alignment.
+    /// So applying the theme to another table reproduces a look of the original one.
+    ///
+    /// Settings of particular cells (like a border or padding of a cell) are not captured.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Style, themes::Theme}};
+    ///
+    /// let mut table = Table::new([(1, "a")]);
+    /// table.with(Style::modern());
+    ///
+    /// let theme = Theme::extract(&table);
+    ///
+    /// let mut other = Table::new([(1, "a")]);
+    /// other.with(theme);
+    ///
+    /// assert_eq!(table.to_string(), other.to_string());
+    /// ```
+    pub fn extract(table: &Table) -> Self {
+        Self::from(table.get_config().clone())
+    }
+
     /// Returns an outer border of the style.
     pub fn set_frame(&mut self, frame: Border<char>) {
         self.chars.top = frame.top;
@@ -291,6 +323,36 @@ impl Theme {
         None
     }
 
+    /// Set a padding which is applied to all cells.
+    pub fn set_padding(&mut self, padding: Sides<Indent>) {
+        self.padding = Some(padding);
+    }
+
+    /// Get a padding if any set.
+    pub const fn get_padding(&self) -> Option<&Sides<Indent>> {
+        self.padding.as_ref()
+    }
+
+    /// Set a horizontal alignment which is applied to all cells.
+    pub fn set_alignment_horizontal(&mut self, alignment: AlignmentHorizontal) {
+        self.alignment_horizontal = Some(alignment);
+    }
+
+    /// Get a horizontal alignment if any set.
+    pub const fn get_alignment_horizontal(&self) -> Option<AlignmentHorizontal> {
+        self.alignment_horizontal
+    }
+
+    /// Set a vertical alignment which is applied to all cells.
+    pub fn set_alignment_vertical(&mut self, alignment: AlignmentVertical) {
+        self.alignment_vertical = Some(alignment);
+    }
+
+    /// Get a vertical alignment if any set.
+    pub const fn get_alignment_vertical(&self) -> Option<AlignmentVertical> {
+        self.alignment_vertical
+    }
+
     /// Verifies if borders has left line set on the frame.
     pub const fn borders_has_left(&self) -> bool {
         self.chars.has_left()
@@ -334,6 +396,9 @@ impl Theme {
             lines_horizontals: horizontals,
             lines_verticals: verticals,
             lines_horizontal1: horizontal1,
+            padding: None,
+            alignment_horizontal: None,
+            alignment_vertical: None,
         }
     }
 }
@@ -366,6 +431,18 @@ impl<R, D> TableOption<R, ColoredConfig, D> for Theme {
             self.lines_horizontal1,
         );
         cfg_set_borders(cfg, self.chars, self.colors);
+
+        if let Some(padding) = self.padding {
+            cfg.set_padding(Entity::Global, padding);
+        }
+
+        if let Some(alignment) = self.alignment_horizontal {
+            cfg.set_alignment_horizontal(Entity::Global, alignment);
+        }
+
+        if let Some(alignment) = self.alignment_vertical {
+            cfg.set_alignment_vertical(Entity::Global, alignment);
+        }
     }
 }
 
@@ -396,7 +473,12 @@ impl From<ColoredConfig> for Theme {
         let horizontals = cfg.get_horizontal_lines().into_iter().collect();
         let verticals = cfg.get_vertical_lines().into_iter().collect();
 
-        Self::gen(borders, colors, Some(horizontals), Some(verticals), None)
+        let mut theme = Self::gen(borders, colors, Some(horizontals), Some(verticals), None);
+        theme.padding = Some(cfg.get_padding_default());
+        theme.alignment_horizontal = Some(*cfg.get_alignment_horizontal_default());
+        theme.alignment_vertical = Some(*cfg.get_alignment_vertical_default());
+
+        theme
     }
 }
 
@@ -626,3 +708,345 @@ const fn hlines_find<const N: usize>(
 
     line
 }
+
+/// Serializes a [`Theme`] into a map of its parts,
+/// where borders and lines are maps of their set characters.
+///
+/// ```json
+/// { "chars": { "top": "-", "vertical": "|" }, "colors": {}, "alignment_horizontal": "left" }
+/// ```
+///
+/// A color is represented by a pair of its prefix and suffix.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Theme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let colors = serde_borders::to_map(&self.colors)
+            .into_iter()
+            .map(|(key, color)| (key, (color.get_prefix(), color.get_suffix())))
+            .collect::<BTreeMap<_, _>>();
+        let horizontals = self.lines_horizontals.as_ref().map(|lines| {
+            lines
+                .iter()
+                .map(|(i, line)| (*i, serde_horizontal_line::to_map(line)))
+                .collect::<BTreeMap<_, _>>()
+        });
+        let verticals = self.lines_verticals.as_ref().map(|lines| {
+            lines
+                .iter()
+                .map(|(i, line)| (*i, serde_vertical_line::to_map(line)))
+                .collect::<BTreeMap<_, _>>()
+        });
+        let horizontal1 = self
+            .lines_horizontal1
+            .as_ref()
+            .map(serde_horizontal_line::to_map);
+        let padding = self.padding.as_ref().map(serde_padding::to_map);
+        let halignment = self.alignment_horizontal.map(halignment_to_str);
+        let valignment = self.alignment_vertical.map(valignment_to_str);
+
+        let mut theme = serializer.serialize_struct("Theme", THEME_FIELDS.len())?;
+        theme.serialize_field("chars", &serde_borders::to_map(&self.chars))?;
+        theme.serialize_field("colors", &colors)?;
+        theme.serialize_field("horizontal_lines", &horizontals)?;
+        theme.serialize_field("vertical_lines", &verticals)?;
+        theme.serialize_field("horizontal_line1", &horizontal1)?;
+        theme.serialize_field("padding", &padding)?;
+        theme.serialize_field("alignment_horizontal", &halignment)?;
+        theme.serialize_field("alignment_vertical", &valignment)?;
+        theme.end()
+    }
+}
+
+/// Deserializes a [`Theme`] from a map produced by its serialization.
+///
+/// All fields are optional and unknown ones are rejected.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Theme {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Theme", THEME_FIELDS, ThemeVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+const THEME_FIELDS: &[&str] = &[
+    "chars",
+    "colors",
+    "horizontal_lines",
+    "vertical_lines",
+    "horizontal_line1",
+    "padding",
+    "alignment_horizontal",
+    "alignment_vertical",
+];
+
+#[cfg(feature = "serde")]
+type CharMap = BTreeMap<String, char>;
+
+#[cfg(feature = "serde")]
+struct ThemeVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ThemeVisitor {
+    type Value = Theme;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a theme")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut theme = Theme::new();
+        let mut seen = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let field = THEME_FIELDS
+                .iter()
+                .find(|field| **field == key)
+                .ok_or_else(|| Error::unknown_field(&key, THEME_FIELDS))?;
+            if seen.contains(field) {
+                return Err(Error::duplicate_field(field));
+            }
+
+            seen.push(*field);
+
+            match *field {
+                "chars" => theme.chars = serde_borders::from_map(map.next_value::<CharMap>()?)?,
+                "colors" => {
+                    let colors = map
+                        .next_value::<BTreeMap<String, (String, String)>>()?
+                        .into_iter()
+                        .map(|(key, (prefix, suffix))| (key, Color::new(prefix, suffix)))
+                        .collect();
+                    theme.colors = serde_borders::from_map(colors)?;
+                }
+                "horizontal_lines" => {
+                    theme.lines_horizontals = map
+                        .next_value::<Option<BTreeMap<usize, CharMap>>>()?
+                        .map(|lines| {
+                            lines
+                                .into_iter()
+                                .map(|(i, line)| Ok((i, serde_horizontal_line::from_map(line)?)))
+                                .collect::<Result<_, A::Error>>()
+                        })
+                        .transpose()?;
+                }
+                "vertical_lines" => {
+                    theme.lines_verticals = map
+                        .next_value::<Option<BTreeMap<usize, CharMap>>>()?
+                        .map(|lines| {
+                            lines
+                                .into_iter()
+                                .map(|(i, line)| Ok((i, serde_vertical_line::from_map(line)?)))
+                                .collect::<Result<_, A::Error>>()
+                        })
+                        .transpose()?;
+                }
+                "horizontal_line1" => {
+                    theme.lines_horizontal1 = map
+                        .next_value::<Option<CharMap>>()?
+                        .map(serde_horizontal_line::from_map)
+                        .transpose()?;
+                }
+                "padding" => {
+                    theme.padding = map
+                        .next_value::<Option<BTreeMap<String, (char, usize)>>>()?
+                        .map(serde_padding::from_map)
+                        .transpose()?;
+                }
+                "alignment_horizontal" => {
+                    theme.alignment_horizontal = map
+                        .next_value::<Option<String>>()?
+                        .map(|s| halignment_from_str(&s))
+                        .transpose()?;
+                }
+                "alignment_vertical" => {
+                    theme.alignment_vertical = map
+                        .next_value::<Option<String>>()?
+                        .map(|s| valignment_from_str(&s))
+                        .transpose()?;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+#[cfg(feature = "serde")]
+macro_rules! serde_line_map {
+    ($module:ident, $line:ident, $($field:ident),* $(,)?) => {
+        mod $module {
+            use std::collections::BTreeMap;
+
+            use crate::grid::config::$line;
+
+            const FIELDS: &[&str] = &[$(stringify!($field)),*];
+
+            pub(super) fn to_map<T>(line: &$line<T>) -> BTreeMap<&'static str, &T> {
+                let mut map = BTreeMap::new();
+                $(
+                    if let Some(value) = &line.$field {
+                        let _ = map.insert(stringify!($field), value);
+                    }
+                )*
+
+                map
+            }
+
+            pub(super) fn from_map<T, E>(map: BTreeMap<String, T>) -> Result<$line<T>, E>
+            where
+                E: serde::de::Error,
+            {
+                let mut line = $line::empty();
+                for (key, value) in map {
+                    match key.as_str() {
+                        $(stringify!($field) => line.$field = Some(value),)*
+                        _ => return Err(E::unknown_field(&key, FIELDS)),
+                    }
+                }
+
+                Ok(line)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+serde_line_map!(
+    serde_borders,
+    Borders,
+    top,
+    top_left,
+    top_right,
+    top_intersection,
+    bottom,
+    bottom_left,
+    bottom_right,
+    bottom_intersection,
+    horizontal,
+    vertical,
+    intersection,
+    left,
+    left_intersection,
+    right,
+    right_intersection,
+);
+
+#[cfg(feature = "serde")]
+serde_line_map!(
+    serde_horizontal_line,
+    HorizontalLine,
+    main,
+    intersection,
+    left,
+    right
+);
+
+#[cfg(feature = "serde")]
+serde_line_map!(
+    serde_vertical_line,
+    VerticalLine,
+    main,
+    intersection,
+    top,
+    bottom
+);
+
+#[cfg(feature = "serde")]
+mod serde_padding {
+    use std::collections::BTreeMap;
+
+    use crate::grid::config::{Indent, Sides};
+
+    const FIELDS: &[&str] = &["top", "bottom", "left", "right"];
+
+    pub(super) fn to_map(padding: &Sides<Indent>) -> BTreeMap<&'static str, (char, usize)> {
+        let sides = [padding.top, padding.bottom, padding.left, padding.right];
+
+        FIELDS
+            .iter()
+            .zip(sides)
+            .map(|(key, indent)| (*key, (indent.fill, indent.size)))
+            .collect()
+    }
+
+    pub(super) fn from_map<E>(mut map: BTreeMap<String, (char, usize)>) -> Result<Sides<Indent>, E>
+    where
+        E: serde::de::Error,
+    {
+        if let Some(key) = map.keys().find(|key| !FIELDS.contains(&key.as_str())) {
+            return Err(E::unknown_field(key, FIELDS));
+        }
+
+        let mut side = |key: &'static str| {
+            map.remove(key)
+                .map(|(fill, size)| Indent::new(size, fill))
+                .ok_or_else(|| E::missing_field(key))
+        };
+
+        Ok(Sides {
+            top: side("top")?,
+            bottom: side("bottom")?,
+            left: side("left")?,
+            right: side("right")?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+fn halignment_to_str(alignment: AlignmentHorizontal) -> &'static str {
+    match alignment {
+        AlignmentHorizontal::Left => "left",
+        AlignmentHorizontal::Center => "center",
+        AlignmentHorizontal::Right => "right",
+    }
+}
+
+#[cfg(feature = "serde")]
+fn valignment_to_str(alignment: AlignmentVertical) -> &'static str {
+    match alignment {
+        AlignmentVertical::Top => "top",
+        AlignmentVertical::Center => "center",
+        AlignmentVertical::Bottom => "bottom",
+    }
+}
+
+#[cfg(feature = "serde")]
+fn halignment_from_str<E>(s: &str) -> Result<AlignmentHorizontal, E>
+where
+    E: serde::de::Error,
+{
+    match s {
+        "left" => Ok(AlignmentHorizontal::Left),
+        "center" => Ok(AlignmentHorizontal::Center),
+        "right" => Ok(AlignmentHorizontal::Right),
+        _ => Err(E::unknown_variant(s, &["left", "center", "right"])),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn valignment_from_str<E>(s: &str) -> Result<AlignmentVertical, E>
+where
+    E: serde::de::Error,
+{
+    match s {
+        "top" => Ok(AlignmentVertical::Top),
+        "center" => Ok(AlignmentVertical::Center),
+        "bottom" => Ok(AlignmentVertical::Bottom),
+        _ => Err(E::unknown_variant(s, &["top", "center", "bottom"])),
+    }
+}
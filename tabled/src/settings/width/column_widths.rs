@@ -0,0 +1,137 @@
+use std::{borrow::Cow, cmp::max, iter::FromIterator};
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        dimension::CompleteDimensionVecRecords,
+        records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{
+        width::{
+            util::{get_table_widths, range_width},
+            Truncate,
+        },
+        CellOption, TableOption,
+    },
+};
+
+/// ColumnWidths sets an exact width for each column of a [`Table`].
+///
+/// A width includes padding, so it can't be less than the padding.
+/// Bigger cells are truncated (with an optional suffix),
+/// while smaller ones are justified as usual.
+///
+/// If the list is shorter than a number of columns only the first columns are changed;
+/// extra values are ignored.
+/// A spanned cell gets a sum of widths of the columns it covers.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::width::ColumnWidths};
+///
+/// let data = [["Hello", "World"], ["Something", "Else"]];
+///
+/// let mut table = Table::new(data);
+/// table.with(ColumnWidths::new([6, 9]).suffix("~"));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+------+---------+\n\
+///      | 0    | 1       |\n\
+///      +------+---------+\n\
+///      | Hel~ | World   |\n\
+///      +------+---------+\n\
+///      | Som~ | Else    |\n\
+///      +------+---------+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnWidths<'a> {
+    list: Vec<usize>,
+    suffix: Cow<'a, str>,
+}
+
+impl ColumnWidths<'static> {
+    /// Creates a new object from a list of widths.
+    pub fn new<I>(list: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            list: list.into_iter().collect(),
+            suffix: Cow::Borrowed(""),
+        }
+    }
+}
+
+impl<'a> ColumnWidths<'a> {
+    /// Sets a suffix which is used when a cell is truncated.
+    pub fn suffix<S>(self, suffix: S) -> ColumnWidths<'a>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        ColumnWidths {
+            list: self.list,
+            suffix: suffix.into(),
+        }
+    }
+}
+
+impl FromIterator<usize> for ColumnWidths<'static> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for ColumnWidths<'_>
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(
+        self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 || self.list.is_empty() {
+            return;
+        }
+
+        let mut widths = get_table_widths(&*records, cfg);
+        let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_columns), cfg);
+        let count_set = self.list.len().min(count_columns);
+        for (col, &width) in self.list.iter().take(count_set).enumerate() {
+            widths[col] = max(width, min_widths[col]);
+        }
+
+        for row in 0..count_rows {
+            for col in 0..count_set {
+                let pos = (row, col).into();
+                if !cfg.is_cell_visible(pos) {
+                    continue;
+                }
+
+                let span = cfg.get_column_span(pos).unwrap_or(1);
+                let end = (col + span).min(count_columns);
+
+                let width = range_width(cfg, &widths, col, end, count_columns);
+                let pad = cfg.get_padding(pos);
+                let width = width.saturating_sub(pad.left.size + pad.right.size);
+
+                let truncate = Truncate::new(width)
+                    .suffix(self.suffix.as_ref())
+                    .multiline(true);
+                CellOption::change(truncate, records, cfg, Entity::Cell(row, col));
+            }
+        }
+
+        dims.set_widths(widths);
+    }
+}
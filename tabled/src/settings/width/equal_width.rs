@@ -0,0 +1,153 @@
+//! This module contains [`EqualWidth`] structure, used to set the same width to each column.
+
+use std::cmp::max;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        dimension::CompleteDimensionVecRecords,
+        records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{
+        width::{
+            util::{get_table_widths, get_table_widths_with_total, range_width},
+            Wrap,
+        },
+        CellOption, TableOption,
+    },
+};
+
+/// EqualWidth sets all columns of a [`Table`] to the same width.
+///
+/// By default the width of the widest column is used.
+/// If a total width is set it's split among the columns,
+/// and the leftmost columns get the remainder.
+///
+/// Bigger cells are wrapped, while smaller ones are justified as usual.
+/// A spanned cell gets a sum of widths of the columns it covers.
+///
+/// Notice that a column can't be less than its padding,
+/// so in such a case widths may differ.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::Width};
+///
+/// let data = [["Hello", "World"], ["Something", "Else"]];
+///
+/// let mut table = Table::new(data);
+/// table.with(Width::equal());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-----------+-----------+\n\
+///      | 0         | 1         |\n\
+///      +-----------+-----------+\n\
+///      | Hello     | World     |\n\
+///      +-----------+-----------+\n\
+///      | Something | Else      |\n\
+///      +-----------+-----------+"
+/// );
+///
+/// table.with(Width::equal().total(20));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+---------+--------+\n\
+///      | 0       | 1      |\n\
+///      +---------+--------+\n\
+///      | Hello   | World  |\n\
+///      +---------+--------+\n\
+///      | Somethi | Else   |\n\
+///      | ng      |        |\n\
+///      +---------+--------+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EqualWidth {
+    total: Option<usize>,
+}
+
+impl EqualWidth {
+    /// Creates a new object which uses the widest column width.
+    pub const fn new() -> Self {
+        Self { total: None }
+    }
+
+    /// Sets a total width of a table which is split among the columns.
+    ///
+    /// The width includes borders and margin.
+    pub const fn total(mut self, width: usize) -> Self {
+        self.total = Some(width);
+        self
+    }
+}
+
+impl<R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for EqualWidth
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(
+        self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let (widths, total) = get_table_widths_with_total(&*records, cfg);
+        let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_columns), cfg);
+
+        let widths = match self.total {
+            Some(total_width) => {
+                let borders = total - widths.iter().sum::<usize>();
+                let available = total_width.saturating_sub(borders);
+                let width = available / count_columns;
+                let rest = available % count_columns;
+
+                (0..count_columns)
+                    .map(|col| width + usize::from(col < rest))
+                    .collect::<Vec<_>>()
+            }
+            None => {
+                let width = widths.iter().copied().max().unwrap_or(0);
+                vec![width; count_columns]
+            }
+        };
+
+        let widths = widths
+            .into_iter()
+            .zip(min_widths)
+            .map(|(width, min)| max(width, min))
+            .collect::<Vec<_>>();
+
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let pos = (row, col).into();
+                if !cfg.is_cell_visible(pos) {
+                    continue;
+                }
+
+                let span = cfg.get_column_span(pos).unwrap_or(1);
+                let end = (col + span).min(count_columns);
+
+                let width = range_width(cfg, &widths, col, end, count_columns);
+                let pad = cfg.get_padding(pos);
+                let width = width.saturating_sub(pad.left.size + pad.right.size);
+
+                CellOption::change(Wrap::new(width), records, cfg, Entity::Cell(row, col));
+            }
+        }
+
+        dims.set_widths(widths);
+    }
+}
@@ -87,9 +87,29 @@ impl<W, P> MinWidth<W, P> {
     /// - [`PriorityNone`] which inc the columns one after another.
     /// - [`PriorityMax`] inc the biggest columns first.
     /// - [`PriorityMin`] inc the lowest columns first.
+    /// - [`PriorityColumn`] inc a given column, so it absorbs all the extra space.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{peaker::Priority, Style, Width}};
+    ///
+    /// let data = [(0, "Hello"), (1, "World")];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::markdown());
+    /// table.with(Width::increase(20).priority(Priority::column(1)));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "| i32 | &str       |\n\
+    ///      |-----|------------|\n\
+    ///      | 0   | Hello      |\n\
+    ///      | 1   | World      |"
+    /// );
+    /// ```
     ///
     /// [`PriorityMax`]: crate::settings::peaker::PriorityMax
     /// [`PriorityMin`]: crate::settings::peaker::PriorityMin
+    /// [`PriorityColumn`]: crate::settings::peaker::PriorityColumn
     pub fn priority<PP: Peaker>(self, peacker: PP) -> MinWidth<W, PP> {
         MinWidth {
             fill: self.fill,
@@ -191,7 +211,7 @@ where
 
 fn increase_width(s: &str, width: usize, fill_with: char) -> String {
     use crate::grid::util::string::get_line_width;
-    use std::{borrow::Cow, iter::repeat};
+    use std::{borrow::Cow, iter::repeat_n};
 
     get_lines(s)
         .map(|line| {
@@ -200,7 +220,7 @@ fn increase_width(s: &str, width: usize, fill_with: char) -> String {
             if length < width {
                 let mut line = line.into_owned();
                 let remain = width - length;
-                line.extend(repeat(fill_with).take(remain));
+                line.extend(repeat_n(fill_with, remain));
                 Cow::Owned(line)
             } else {
                 line
@@ -3,6 +3,8 @@
 //! - [`Truncate`] cuts a cell content to limit width.
 //! - [`Wrap`] split the content via new lines in order to fit max width.
 //! - [`Justify`] sets columns width to the same value.
+//! - [`ColumnWidths`] sets an exact width for each column.
+//! - [`EqualWidth`] sets all columns to the same width.
 //!
 //! To set a a table width, a combination of [`Width::truncate`] or [`Width::wrap`] and [`Width::increase`] can be used.
 //!
@@ -32,6 +34,8 @@
 //! );
 //! ```
 
+mod column_widths;
+mod equal_width;
 mod justify;
 mod min_width;
 mod truncate;
@@ -42,6 +46,8 @@ mod wrap;
 use crate::settings::measurement::Measurement;
 
 pub use self::{
+    column_widths::ColumnWidths,
+    equal_width::EqualWidth,
     justify::Justify,
     min_width::MinWidth,
     truncate::{SuffixLimit, Truncate},
@@ -124,6 +130,11 @@ impl Width {
         Justify::new(width)
     }
 
+    /// Returns an [`EqualWidth`] structure.
+    pub fn equal() -> EqualWidth {
+        EqualWidth::new()
+    }
+
     /// Create [`WidthList`] to set a table width to a constant list of column widths.
     ///
     /// Notice if you provide a list with `.len()` smaller than `Table::count_columns` then it will have no affect.
@@ -102,12 +102,37 @@ where
 impl<'a, W, P> Truncate<'a, W, P> {
     /// Sets a suffix which will be appended to a resultant string.
     ///
-    /// The suffix is used in 3 circumstances:
+    /// The suffix is considered to be a part of a width.
+    ///
+    /// The suffix is used in 2 circumstances:
     ///     1. If original string is *bigger* than the suffix.
     ///        We cut more of the original string and append the suffix.
     ///     2. If suffix is bigger than the original string.
     ///        We cut the suffix to fit in the width by default.
     ///        But you can peak the behaviour by using [`Truncate::suffix_limit`]
+    ///
+    /// A different suffix can be set for each column by [`Modify`].
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Columns, Modify, Style, Width}};
+    ///
+    /// let data = [["Hello World", "Hello World"]];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::markdown())
+    ///     .with(Modify::new(Columns::first()).with(Width::truncate(6).suffix("..")))
+    ///     .with(Modify::new(Columns::last()).with(Width::truncate(6).suffix("~")))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "| 0      | 1      |\n\
+    ///      |--------|--------|\n\
+    ///      | Hell.. | Hello~ |"
+    /// );
+    /// ```
+    ///
+    /// [`Modify`]: crate::settings::Modify
     pub fn suffix<S: Into<Cow<'a, str>>>(self, suffix: S) -> Truncate<'a, W, P> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.text = suffix.into();
@@ -182,6 +207,23 @@ impl Truncate<'_, (), ()> {
     pub fn truncate(text: &str, width: usize) -> Cow<'_, str> {
         truncate_text(text, width, "", false)
     }
+
+    /// Truncate a given string, appending a suffix to it.
+    ///
+    /// The suffix is considered to be a part of a width,
+    /// so if it's bigger than the width the suffix itself is cut.
+    pub fn truncate_with_suffix<'a>(text: &'a str, width: usize, suffix: &str) -> Cow<'a, str> {
+        if suffix.is_empty() {
+            return Self::truncate(text, width);
+        }
+
+        let suffix_width = get_line_width(suffix);
+        if width > suffix_width {
+            truncate_text(text, width - suffix_width, suffix, false)
+        } else {
+            Cow::Owned(cut_str(suffix, width).into_owned())
+        }
+    }
 }
 
 impl<W, P, R> CellOption<R, ColoredConfig> for Truncate<'_, W, P>
@@ -295,7 +337,7 @@ fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str
             (suffix, 0)
         }
         SuffixLimit::Replace(c) => {
-            let suffix = Cow::Owned(iter::repeat(c).take(width).collect());
+            let suffix = Cow::Owned(iter::repeat_n(c, width).collect());
             (suffix, 0)
         }
     }
@@ -22,7 +22,22 @@ where
     (widths, total_width)
 }
 
-fn get_table_total_width(list: &[usize], cfg: &SpannedConfig) -> usize {
+pub(crate) fn range_width(
+    cfg: &SpannedConfig,
+    widths: &[usize],
+    start: usize,
+    end: usize,
+    count_columns: usize,
+) -> usize {
+    let borders = (start..end)
+        .skip(1)
+        .filter(|&i| cfg.has_vertical(i, count_columns))
+        .count();
+
+    widths[start..end].iter().sum::<usize>() + borders
+}
+
+pub(crate) fn get_table_total_width(list: &[usize], cfg: &SpannedConfig) -> usize {
     let margin = cfg.get_margin();
     list.iter().sum::<usize>()
         + cfg.count_vertical(list.len())
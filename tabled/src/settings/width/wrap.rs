@@ -7,7 +7,7 @@ use crate::{
     grid::{
         config::SpannedConfig,
         config::{ColoredConfig, Entity},
-        dimension::CompleteDimensionVecRecords,
+        dimension::{CompleteDimensionVecRecords, SpannedGridDimension},
         records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
         util::string::{get_char_width, get_text_width},
     },
@@ -22,7 +22,7 @@ use crate::{
 #[cfg(not(feature = "ansi"))]
 use crate::grid::util::string::get_string_width;
 
-use super::util::{get_table_widths, get_table_widths_with_total};
+use super::util::{get_table_total_width, get_table_widths};
 use crate::util::string::split_at_width;
 
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
@@ -47,6 +47,7 @@ use crate::util::string::split_at_width;
 pub struct Wrap<W = usize, P = PriorityNone> {
     width: W,
     keep_words: bool,
+    word_boundary: fn(char) -> bool,
     priority: P,
 }
 
@@ -59,6 +60,7 @@ impl<W> Wrap<W> {
         Wrap {
             width,
             keep_words: false,
+            word_boundary: no_word_boundary,
             priority: PriorityNone::new(),
         }
     }
@@ -81,6 +83,7 @@ impl<W, P> Wrap<W, P> {
         Wrap {
             width: self.width,
             keep_words: self.keep_words,
+            word_boundary: self.word_boundary,
             priority,
         }
     }
@@ -93,6 +96,39 @@ impl<W, P> Wrap<W, P> {
         self.keep_words = on;
         self
     }
+
+    /// Set the keep words option with additional word boundaries.
+    ///
+    /// It works as [`Wrap::keep_words`] but a word is also allowed to be broken
+    /// right after any character for which the predicate returns `true`.
+    /// The character itself stays at the end of a line.
+    ///
+    /// It's handy for long URLs, paths or `snake_case` names.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Segment, width::Width, Modify}};
+    ///
+    /// let data = ["https://example.com/some/path"];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Modify::new(Segment::all()).with(Width::wrap(14).keep_words_with(|c| c == '/')));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----------------+\n\
+    ///      | &str           |\n\
+    ///      +----------------+\n\
+    ///      | https://       |\n\
+    ///      | example.com/   |\n\
+    ///      | some/path      |\n\
+    ///      +----------------+"
+    /// );
+    /// ```
+    pub fn keep_words_with(mut self, is_boundary: fn(char) -> bool) -> Self {
+        self.keep_words = true;
+        self.word_boundary = is_boundary;
+        self
+    }
 }
 
 impl Wrap<(), ()> {
@@ -121,14 +157,16 @@ where
         }
 
         let width = self.width.measure(&*records, cfg);
-        let (widths, total) = get_table_widths_with_total(&*records, cfg);
-        if width >= total {
+
+        let mut priority = self.priority;
+        let peak = |mins: &[usize], widths: &[usize]| priority.peak(mins, widths);
+        let widths = SpannedGridDimension::width_within(&*records, cfg, width, peak);
+        if get_table_total_width(&widths, cfg) < width {
             return;
         }
 
-        let priority = self.priority;
-        let keep_words = self.keep_words;
-        let widths = wrap_total_width(records, cfg, widths, total, width, keep_words, priority);
+        let keep_words = self.keep_words.then_some(self.word_boundary);
+        let widths = wrap_total_width(records, cfg, widths, keep_words);
 
         dims.set_widths(widths);
     }
@@ -158,58 +196,63 @@ where
                 continue;
             }
 
-            let wrapped = wrap_text(text, width, self.keep_words);
+            let keep_words = self.keep_words.then_some(self.word_boundary);
+            let wrapped = wrap_text_with(text, width, keep_words);
             records.set(pos, wrapped);
         }
     }
 }
 
-fn wrap_total_width<R, P>(
+fn wrap_total_width<R>(
     records: &mut R,
     cfg: &mut ColoredConfig,
-    mut widths: Vec<usize>,
-    total_width: usize,
-    width: usize,
-    keep_words: bool,
-    priority: P,
+    widths: Vec<usize>,
+    keep_words: Option<fn(char) -> bool>,
 ) -> Vec<usize>
 where
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
-    P: Peaker,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
 {
     let shape = (records.count_rows(), records.count_columns());
     let min_widths = get_table_widths(EmptyRecords::from(shape), cfg);
 
-    decrease_widths(&mut widths, &min_widths, total_width, width, priority);
-
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, shape);
 
     for ((row, col), width) in points {
         let mut wrap = Wrap::new(width);
-        wrap.keep_words = keep_words;
+        if let Some(is_boundary) = keep_words {
+            wrap = wrap.keep_words_with(is_boundary);
+        }
         <Wrap as CellOption<_, _>>::change(wrap, records, cfg, (row, col).into());
     }
 
     widths
 }
 
-#[cfg(not(feature = "ansi"))]
 pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+    let boundary: fn(char) -> bool = no_word_boundary;
+    wrap_text_with(text, width, keep_words.then_some(boundary))
+}
+
+fn no_word_boundary(_: char) -> bool {
+    false
+}
+
+#[cfg(not(feature = "ansi"))]
+fn wrap_text_with(text: &str, width: usize, keep_words: Option<fn(char) -> bool>) -> String {
     if width == 0 {
         return String::new();
     }
 
-    if keep_words {
-        split_keeping_words(text, width, "\n")
-    } else {
-        chunks(text, width).join("\n")
+    match keep_words {
+        Some(is_boundary) => split_keeping_words_with(text, width, "\n", is_boundary),
+        None => chunks(text, width).join("\n"),
     }
 }
 
 #[cfg(feature = "ansi")]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+fn wrap_text_with(text: &str, width: usize, keep_words: Option<fn(char) -> bool>) -> String {
     use crate::util::string::strip_osc;
 
     if width == 0 {
@@ -219,10 +262,9 @@ pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
     let (text, url): (String, Option<String>) = strip_osc(text);
     let (prefix, suffix) = build_link_prefix_suffix(url);
 
-    if keep_words {
-        split_keeping_words(&text, width, &prefix, &suffix)
-    } else {
-        chunks(&text, width, &prefix, &suffix).join("\n")
+    match keep_words {
+        Some(is_boundary) => split_keeping_words_with(&text, width, &prefix, &suffix, is_boundary),
+        None => chunks(&text, width, &prefix, &suffix).join("\n"),
     }
 }
 
@@ -269,7 +311,7 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
         let c_width = get_char_width(c);
         if i + c_width > width {
             let count_unknowns = width - i;
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            buf.extend(std::iter::repeat_n(REPLACEMENT, count_unknowns));
             i += count_unknowns;
         } else {
             buf.push(c);
@@ -350,7 +392,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
             line_width += get_text_width(lhs);
 
             const REPLACEMENT: char = '\u{FFFD}';
-            line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+            line.extend(std::iter::repeat_n(REPLACEMENT, unknowns));
             line_width += unknowns;
 
             if line_width == width {
@@ -377,8 +419,18 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
     list
 }
 
-#[cfg(not(feature = "ansi"))]
+#[cfg(all(test, not(feature = "ansi")))]
 fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
+    split_keeping_words_with(s, width, sep, no_word_boundary)
+}
+
+#[cfg(not(feature = "ansi"))]
+fn split_keeping_words_with(
+    s: &str,
+    width: usize,
+    sep: &str,
+    is_boundary: fn(char) -> bool,
+) -> String {
     const REPLACEMENT: char = '\u{FFFD}';
 
     let mut lines = Vec::new();
@@ -401,63 +453,77 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             is_first_word = false;
         }
 
-        let word_width = get_string_width(word);
-
-        let line_has_space = line_width + word_width <= width;
-        if line_has_space {
-            line.push_str(word);
-            line_width += word_width;
-            continue;
-        }
-
-        if word_width <= width {
-            // the word can be fit to 'width' so we put it on new line
+        // a word may be broken further right after a boundary character
+        for part in word.split_inclusive(is_boundary) {
+            let part_width = get_string_width(part);
 
-            line.extend(std::iter::repeat(' ').take(width - line_width));
-            lines.push(line);
+            let line_has_space = line_width + part_width <= width;
+            if line_has_space {
+                line.push_str(part);
+                line_width += part_width;
+                continue;
+            }
 
-            line = String::with_capacity(width);
-            line_width = 0;
+            if part_width <= width {
+                // the part can be fit to 'width' so we put it on new line
 
-            line.push_str(word);
-            line_width += word_width;
-            is_first_word = false;
-        } else {
-            // the word is too long any way so we split it
+                line.extend(std::iter::repeat_n(' ', width - line_width));
+                lines.push(line);
 
-            let mut word_part = word;
-            while !word_part.is_empty() {
-                let available_space = width - line_width;
-                let (lhs, rhs, (unknowns, split_char)) =
-                    split_string_at(word_part, available_space);
+                line = String::with_capacity(width);
+                line_width = 0;
 
-                word_part = &rhs[split_char..];
-                line_width += get_string_width(lhs) + unknowns;
+                line.push_str(part);
+                line_width += part_width;
                 is_first_word = false;
-
-                line.push_str(lhs);
-                line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
-
-                if line_width == width {
-                    lines.push(line);
-                    line = String::with_capacity(width);
-                    line_width = 0;
-                    is_first_word = true;
+            } else {
+                // the part is too long any way so we split it
+
+                let mut word_part = part;
+                while !word_part.is_empty() {
+                    let available_space = width - line_width;
+                    let (lhs, rhs, (unknowns, split_char)) =
+                        split_string_at(word_part, available_space);
+
+                    word_part = &rhs[split_char..];
+                    line_width += get_string_width(lhs) + unknowns;
+                    is_first_word = false;
+
+                    line.push_str(lhs);
+                    line.extend(std::iter::repeat_n(REPLACEMENT, unknowns));
+
+                    if line_width == width {
+                        lines.push(line);
+                        line = String::with_capacity(width);
+                        line_width = 0;
+                        is_first_word = true;
+                    }
                 }
             }
         }
     }
 
     if line_width > 0 {
-        line.extend(std::iter::repeat(' ').take(width - line_width));
+        line.extend(std::iter::repeat_n(' ', width - line_width));
         lines.push(line);
     }
 
     lines.join(sep)
 }
 
-#[cfg(feature = "ansi")]
+#[cfg(all(test, feature = "ansi"))]
 fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) -> String {
+    split_keeping_words_with(text, width, prefix, suffix, no_word_boundary)
+}
+
+#[cfg(feature = "ansi")]
+fn split_keeping_words_with(
+    text: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    is_boundary: fn(char) -> bool,
+) -> String {
     if text.is_empty() || width == 0 {
         return String::new();
     }
@@ -482,6 +548,15 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                 word_chars = 0;
                 word_width = 0;
             }
+            _ if is_boundary(c) => {
+                // the boundary is a part of a word so it's kept on the line
+                word_width += get_char_width(c);
+                word_chars += 1;
+
+                parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 0);
+                word_chars = 0;
+                word_width = 0;
+            }
             _ => {
                 word_width += get_char_width(c);
                 word_chars += 1;
@@ -489,9 +564,16 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
         }
     }
 
+    let ends_with_boundary = stripped_text
+        .chars()
+        .last()
+        .is_some_and(|c| c != ' ' && c != '\n' && is_boundary(c));
+
     if word_chars > 0 {
         parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 0);
         buf.finish_line(&blocks);
+    } else if ends_with_boundary {
+        buf.finish_line(&blocks);
     }
 
     buf.into_string()
@@ -808,46 +890,6 @@ fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
     (lhs, rhs, (count_unknowns, split_char_size))
 }
 
-fn decrease_widths<F>(
-    widths: &mut [usize],
-    min_widths: &[usize],
-    total_width: usize,
-    mut width: usize,
-    mut peeaker: F,
-) where
-    F: Peaker,
-{
-    let mut empty_list = 0;
-    for col in 0..widths.len() {
-        if widths[col] == 0 || widths[col] <= min_widths[col] {
-            empty_list += 1;
-        }
-    }
-
-    while width != total_width {
-        if empty_list == widths.len() {
-            break;
-        }
-
-        let col = match peeaker.peak(min_widths, widths) {
-            Some(col) => col,
-            None => break,
-        };
-
-        if widths[col] == 0 || widths[col] <= min_widths[col] {
-            continue;
-        }
-
-        widths[col] -= 1;
-
-        if widths[col] == 0 || widths[col] <= min_widths[col] {
-            empty_list += 1;
-        }
-
-        width += 1;
-    }
-}
-
 fn get_decrease_cell_list(
     cfg: &SpannedConfig,
     widths: &[usize],
@@ -1182,6 +1224,35 @@ mod tests {
         assert_eq!(split_keeping_words("12345678", 2,), "12\n34\n56\n78");
     }
 
+    #[test]
+    fn split_keeping_words_with_boundary_test() {
+        #[cfg(not(feature = "ansi"))]
+        let split = |text, width| split_keeping_words_with(text, width, "\n", |c| c == '/');
+        #[cfg(feature = "ansi")]
+        let split = |text, width| split_keeping_words_with(text, width, "", "", |c| c == '/');
+
+        assert_eq!(split("path/to/file", 5), "path/\nto/  \nfile ");
+        assert_eq!(split("a//b", 2), "a/\n/b");
+        assert_eq!(split("/abc", 3), "/  \nabc");
+        assert_eq!(split("/abc", 4), "/abc");
+        assert_eq!(split("abc/", 3), "abc\n/  ");
+        assert_eq!(split("abc/", 5), "abc/ ");
+        assert_eq!(split("long/path name", 6), "long/ \npath  \nname  ");
+        assert_eq!(split("verylongpart/x", 4), "very\nlong\npart\n/x  ");
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn split_keeping_words_with_boundary_color_test() {
+        let split = |text, width| split_keeping_words_with(text, width, "", "", |c| c == '/');
+
+        let text = "\u{1b}[36mpath/to\u{1b}[39m/\u{1b}[31mfile\u{1b}[39m";
+        assert_eq!(
+            split(text, 5),
+            "\u{1b}[36mpath/\u{1b}[39m\n\u{1b}[36mto\u{1b}[39m/  \n\u{1b}[31mfile\u{1b}[39m "
+        );
+    }
+
     #[cfg(feature = "ansi")]
     #[test]
     fn chunks_test_with_prefix_and_suffix() {
@@ -1,5 +1,11 @@
 use std::borrow::Cow;
 
+/// A number of inline depths which lengths are known at compile time,
+/// see [`Tabled::LENGTH_LIMITED`].
+///
+/// It's also the biggest depth accepted by `#[tabled(inline(depth = N))]`.
+pub const MAX_INLINE_DEPTH: usize = 8;
+
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
 /// It's urgent that `header` len is equal to `fields` len.
@@ -10,14 +16,139 @@ use std::borrow::Cow;
 pub trait Tabled {
     /// A length of fields and headers,
     /// which must be the same.
+    ///
+    /// It's a constant so it can be used before any value exists,
+    /// for example to pre-size a buffer.
+    ///
+    /// ```
+    /// use tabled::Tabled;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Person {
+    ///     name: &'static str,
+    ///     age: u8,
+    /// }
+    ///
+    /// const COLUMNS: usize = <Person as Tabled>::LENGTH;
+    ///
+    /// assert_eq!(COLUMNS, 2);
+    /// ```
     const LENGTH: usize;
 
+    /// A length of fields and headers limited by an inline depth,
+    /// see [`Tabled::fields_limited`].
+    ///
+    /// An element at index `n` is a length for a depth `n`.
+    /// By default the length is not affected by a depth.
+    const LENGTH_LIMITED: [usize; MAX_INLINE_DEPTH] = [Self::LENGTH; MAX_INLINE_DEPTH];
+
     /// Fields method must return a list of cells.
     ///
     /// The cells will be placed in the same row, preserving the order.
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+
+    /// Returns a list of column names converted to a given casing.
+    ///
+    /// The [`Tabled`] derive converts names of fields and variants,
+    /// while renamed ones are kept as is.
+    /// By default the headers are returned unchanged.
+    fn headers_cased(casing: CasingStyle) -> Vec<Cow<'static, str>> {
+        Self::headers_with(HeadersOptions::new().casing(casing))
+    }
+
+    /// Returns a list of column names built according to given [`HeadersOptions`].
+    ///
+    /// It's what [`Tabled::headers_cased`] relies on,
+    /// and it must match [`Tabled::fields_limited`] when a depth is set,
+    /// so it's the one to be implemented.
+    /// By default the headers are returned unchanged.
+    fn headers_with(options: HeadersOptions) -> Vec<Cow<'static, str>> {
+        let _ = options;
+        Self::headers()
+    }
+
+    /// Returns a list of cells where inlined fields are expanded up to a given depth.
+    ///
+    /// An inlined field which is deeper than `depth` is put into a single cell.
+    /// The [`Tabled`] derive implements it for structs,
+    /// and uses it for fields marked as `#[tabled(inline(depth = N))]`.
+    /// By default the fields are returned unchanged.
+    fn fields_limited(&self, depth: usize) -> Vec<Cow<'_, str>> {
+        let _ = depth;
+        self.fields()
+    }
+}
+
+/// A set of options used by [`Tabled::headers_with`].
+///
+/// ```
+/// use tabled::{CasingStyle, HeadersOptions, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Person {
+///     first_name: &'static str,
+///     #[tabled(inline)]
+///     address: Address,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Address {
+///     street_name: &'static str,
+/// }
+///
+/// let options = HeadersOptions::new().casing(CasingStyle::Kebab);
+/// assert_eq!(Person::headers_with(options), ["first-name", "street-name"]);
+///
+/// let options = HeadersOptions::new().depth(0);
+/// assert_eq!(Person::headers_with(options), ["first_name", "address"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeadersOptions {
+    depth: usize,
+    casing: Option<CasingStyle>,
+}
+
+impl HeadersOptions {
+    /// Creates options which keep headers as they are.
+    pub const fn new() -> Self {
+        Self {
+            depth: usize::MAX,
+            casing: None,
+        }
+    }
+
+    /// Sets a depth up to which inlined fields are expanded,
+    /// like in [`Tabled::fields_limited`].
+    pub const fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets a casing which names of fields and variants are converted to.
+    pub const fn casing(mut self, casing: CasingStyle) -> Self {
+        self.casing = Some(casing);
+        self
+    }
+
+    /// Returns a depth up to which inlined fields are expanded.
+    ///
+    /// It's [`usize::MAX`] if it was not set.
+    pub const fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns a casing if it was set.
+    pub const fn get_casing(&self) -> Option<CasingStyle> {
+        self.casing
+    }
+}
+
+impl Default for HeadersOptions {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Tabled for &T
@@ -25,6 +156,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const LENGTH_LIMITED: [usize; MAX_INLINE_DEPTH] = T::LENGTH_LIMITED;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
@@ -32,6 +164,14 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+
+    fn headers_with(options: HeadersOptions) -> Vec<Cow<'static, str>> {
+        T::headers_with(options)
+    }
+
+    fn fields_limited(&self, depth: usize) -> Vec<Cow<'_, str>> {
+        T::fields_limited(self, depth)
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -39,6 +179,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const LENGTH_LIMITED: [usize; MAX_INLINE_DEPTH] = T::LENGTH_LIMITED;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         T::fields(self)
@@ -46,6 +187,14 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+
+    fn headers_with(options: HeadersOptions) -> Vec<Cow<'static, str>> {
+        T::headers_with(options)
+    }
+
+    fn fields_limited(&self, depth: usize) -> Vec<Cow<'_, str>> {
+        T::fields_limited(self, depth)
+    }
 }
 
 impl<T> Tabled for Option<T>
@@ -53,6 +202,7 @@ where
     T: Tabled,
 {
     const LENGTH: usize = T::LENGTH;
+    const LENGTH_LIMITED: [usize; MAX_INLINE_DEPTH] = T::LENGTH_LIMITED;
 
     fn fields(&self) -> Vec<Cow<'_, str>> {
         match self {
@@ -64,12 +214,39 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+
+    fn headers_with(options: HeadersOptions) -> Vec<Cow<'static, str>> {
+        T::headers_with(options)
+    }
+
+    fn fields_limited(&self, depth: usize) -> Vec<Cow<'_, str>> {
+        match self {
+            Some(value) => Tabled::fields_limited(value, depth),
+            None => {
+                let length = Self::LENGTH_LIMITED
+                    .get(depth)
+                    .copied()
+                    .unwrap_or_else(|| T::headers_with(HeadersOptions::new().depth(depth)).len());
+                vec![Cow::Borrowed(""); length]
+            }
+        }
+    }
 }
 
 macro_rules! tuple_table {
     ( $($name:ident)+ ) => {
         impl<$($name: Tabled),+> Tabled for ($($name,)+){
             const LENGTH: usize = $($name::LENGTH+)+ 0;
+            const LENGTH_LIMITED: [usize; MAX_INLINE_DEPTH] = {
+                let mut out = [0; MAX_INLINE_DEPTH];
+                let mut depth = 0;
+                while depth < MAX_INLINE_DEPTH {
+                    out[depth] = $($name::LENGTH_LIMITED[depth]+)+ 0;
+                    depth += 1;
+                }
+
+                out
+            };
 
             fn fields(&self) -> Vec<Cow<'_, str>> {
                 #![allow(non_snake_case)]
@@ -84,6 +261,20 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn headers_with(options: HeadersOptions) -> Vec<Cow<'static, str>> {
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name::headers_with(options));)+
+                fields
+            }
+
+            fn fields_limited(&self, depth: usize) -> Vec<Cow<'_, str>> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name.fields_limited(depth));)+
+                fields
+            }
         }
     };
 }
@@ -94,6 +285,16 @@ tuple_table! { A B C }
 tuple_table! { A B C D }
 tuple_table! { A B C D E }
 tuple_table! { A B C D E F }
+tuple_table! { A B C D E F G }
+tuple_table! { A B C D E F G H }
+tuple_table! { A B C D E F G H I }
+tuple_table! { A B C D E F G H I J }
+tuple_table! { A B C D E F G H I J K }
+tuple_table! { A B C D E F G H I J K L }
+tuple_table! { A B C D E F G H I J K L M }
+tuple_table! { A B C D E F G H I J K L M N }
+tuple_table! { A B C D E F G H I J K L M N O }
+tuple_table! { A B C D E F G H I J K L M N O P }
 
 macro_rules! default_table {
     ( $t:ty ) => {
@@ -166,3 +367,127 @@ where
         (0..N).map(|i| Cow::Owned(format!("{i}"))).collect()
     }
 }
+
+/// A casing which is applied to headers by [`Tabled::headers_cased`].
+///
+/// It follows the same rules as `#[tabled(rename_all = "")]`.
+///
+/// ```
+/// use tabled::CasingStyle;
+///
+/// assert_eq!(CasingStyle::ScreamingSnake.cast("middle_name"), "MIDDLE_NAME");
+/// assert_eq!(CasingStyle::Camel.cast("middle_name"), "middleName");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CasingStyle {
+    /// Indicate word boundaries with uppercase letter, excluding the first word.
+    Camel,
+    /// Keep all letters lowercase and indicate word boundaries with hyphens.
+    Kebab,
+    /// Indicate word boundaries with uppercase letter, including the first word.
+    Pascal,
+    /// Keep all letters uppercase and indicate word boundaries with underscores.
+    ScreamingSnake,
+    /// Keep all letters lowercase and indicate word boundaries with underscores.
+    Snake,
+    /// Keep all letters lowercase and remove word boundaries.
+    Lower,
+    /// Keep all letters uppercase and remove word boundaries.
+    Upper,
+    /// Use the original name defined in the code.
+    Verbatim,
+}
+
+impl CasingStyle {
+    /// Converts a name to the casing.
+    pub fn cast(self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            Self::Camel => {
+                let mut words = words.into_iter();
+                let first = words.next().map(str::to_lowercase).unwrap_or_default();
+                words.fold(first, |mut buf, word| {
+                    buf.push_str(&capitalize(word));
+                    buf
+                })
+            }
+            Self::Kebab => join_words(&words, "-", str::to_lowercase),
+            Self::Pascal => join_words(&words, "", capitalize),
+            Self::ScreamingSnake => join_words(&words, "_", str::to_uppercase),
+            Self::Snake => join_words(&words, "_", str::to_lowercase),
+            Self::Lower => join_words(&words, "", str::to_lowercase),
+            Self::Upper => join_words(&words, "", str::to_uppercase),
+            Self::Verbatim => name.to_string(),
+        }
+    }
+}
+
+// The words are split the same way as `#[tabled(rename_all = "")]` does it,
+// by non alphanumeric characters and by a change of a letter case,
+// so "HTTPServer_name" is split into "HTTP", "Server" and "name".
+fn split_words(s: &str) -> Vec<&str> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Mode {
+        Boundary,
+        Lowercase,
+        Uppercase,
+    }
+
+    let mut words = Vec::new();
+    for word in s.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = word.char_indices().peekable();
+        let mut start = 0;
+        let mut mode = Mode::Boundary;
+        while let Some((i, c)) = chars.next() {
+            let (next_i, next) = match chars.peek() {
+                Some(&next) => next,
+                None => {
+                    words.push(&word[start..]);
+                    break;
+                }
+            };
+
+            let next_mode = if c.is_lowercase() {
+                Mode::Lowercase
+            } else if c.is_uppercase() {
+                Mode::Uppercase
+            } else {
+                mode
+            };
+
+            if next_mode == Mode::Lowercase && next.is_uppercase() {
+                words.push(&word[start..next_i]);
+                start = next_i;
+                mode = Mode::Boundary;
+            } else if mode == Mode::Uppercase && c.is_uppercase() && next.is_lowercase() {
+                words.push(&word[start..i]);
+                start = i;
+                mode = Mode::Boundary;
+            } else {
+                mode = next_mode;
+            }
+        }
+    }
+
+    words
+}
+
+fn join_words(words: &[&str], sep: &str, f: fn(&str) -> String) -> String {
+    words
+        .iter()
+        .map(|word| f(word))
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) => c
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
@@ -11,14 +11,19 @@ use crate::{
             into_records::{LimitColumns, LimitRows},
             IntoRecords, IterRecords,
         },
-        util::string::get_line_width,
+        util::string::{count_lines, get_text_width},
         CompactGrid,
     },
     settings::{style::Style, TableOption},
 };
 
+#[cfg(feature = "std")]
+use crate::grid::records::into_records::TruncateContent;
+
 /// A table which consumes an [`IntoRecords`] iterator.
-/// It assumes that the content has only single line.
+/// A multiline content is printed line by line when a row height is big enough,
+/// otherwise only its first line is printed.
+/// Notice that multiline content requires `std` and such a row gets buffered.
 ///
 /// In contrast to [`Table`] [`CompactTable`] does no allocations but it consumes an iterator.
 /// It's useful when you don't want to re/allocate a buffer for your data.
@@ -91,6 +96,7 @@ pub struct CompactTable<I, D> {
     dims: D,
     count_columns: usize,
     count_rows: Option<usize>,
+    truncate: Option<&'static str>,
 }
 
 impl<I> CompactTable<I, ConstDimension<0, 0>> {
@@ -104,11 +110,61 @@ impl<I> CompactTable<I, ConstDimension<0, 0>> {
             cfg: create_config(),
             count_columns: 0,
             count_rows: None,
+            truncate: None,
             dims: ConstDimension::new(ConstSize::Value(2), ConstSize::Value(1)),
         }
     }
 }
 
+impl<I, const COLS: usize> CompactTable<I, ConstDimension<COLS, 0>> {
+    /// Creates a new [`CompactTable`] structure with a given width of each column.
+    ///
+    /// Widths don't include padding, and a number of columns is set to the length of the list.
+    ///
+    /// Notice that no estimation is done, so the table is built in a single pass.
+    /// Bigger cells are truncated (with `std` feature) and smaller ones are padded.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "std", doc = "```")]
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    /// use tabled::tables::CompactTable;
+    ///
+    /// let data = [
+    ///     ["FreeBSD", "1993", "William and Lynne Jolitz"],
+    ///     ["OpenBSD", "1995", "Theo de Raadt"],
+    /// ];
+    ///
+    /// let table = CompactTable::with_widths(data, [7, 4, 10])
+    ///     .truncate("...")
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---------+------+------------+\n\
+    ///      | FreeBSD | 1993 | William... |\n\
+    ///      |---------+------+------------|\n\
+    ///      | OpenBSD | 1995 | Theo de... |\n\
+    ///      +---------+------+------------+"
+    /// );
+    /// ```
+    pub fn with_widths(iter: I, widths: [usize; COLS]) -> Self
+    where
+        I: IntoRecords,
+    {
+        // add padding
+        let mut list = widths;
+        for w in &mut list {
+            *w += 2;
+        }
+
+        let dims = ConstDimension::new(ConstSize::List(list), ConstSize::Value(1));
+        let mut table = Self::with_dimension(iter, dims).columns(COLS);
+        table.truncate = Some("");
+        table
+    }
+}
+
 impl<I, const ROWS: usize, const COLS: usize> CompactTable<I, ConstDimension<COLS, ROWS>> {
     /// Set a height for each row.
     pub fn height<S: Into<ConstSize<COUNT_ROWS>>, const COUNT_ROWS: usize>(
@@ -122,6 +178,7 @@ impl<I, const ROWS: usize, const COLS: usize> CompactTable<I, ConstDimension<COL
             cfg: self.cfg,
             count_columns: self.count_columns,
             count_rows: self.count_rows,
+            truncate: self.truncate,
         }
     }
 
@@ -137,6 +194,7 @@ impl<I, const ROWS: usize, const COLS: usize> CompactTable<I, ConstDimension<COL
             cfg: self.cfg,
             count_columns: self.count_columns,
             count_rows: self.count_rows,
+            truncate: self.truncate,
         }
     }
 }
@@ -157,6 +215,7 @@ impl<I, D> CompactTable<I, D> {
             cfg: create_config(),
             count_columns: 0,
             count_rows: None,
+            truncate: None,
         }
     }
 
@@ -183,6 +242,17 @@ impl<I, D> CompactTable<I, D> {
         self
     }
 
+    /// Truncate cells which are wider than a column, appending a suffix to them.
+    ///
+    /// The suffix is considered to be a part of a width.
+    /// By default the content is not truncated.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const fn truncate(mut self, suffix: &'static str) -> Self {
+        self.truncate = Some(suffix);
+        self
+    }
+
     /// Returns a table config.
     pub fn get_config(&self) -> &CompactConfig {
         &self.cfg
@@ -208,6 +278,7 @@ impl<I, D> CompactTable<I, D> {
             self.cfg,
             self.count_columns,
             self.count_rows,
+            self.truncate,
         )
     }
 
@@ -222,8 +293,7 @@ impl<I, D> CompactTable<I, D> {
         W: std::io::Write,
     {
         let writer = crate::util::utf8_writer::UTF8Writer::new(writer);
-        self.fmt(writer)
-            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
+        self.fmt(writer).map_err(std::io::Error::other)
     }
 
     /// Build a string.
@@ -253,11 +323,13 @@ where
 {
     fn from(mat: [[T; COLS]; ROWS]) -> Self {
         let mut width = [0; COLS];
-        for row in mat.iter() {
-            for (col, text) in row.iter().enumerate() {
+        let mut height = [1; ROWS];
+        for (row, cells) in mat.iter().enumerate() {
+            for (col, text) in cells.iter().enumerate() {
                 let text = text.as_ref();
-                let text_width = get_line_width(text);
+                let text_width = get_text_width(text);
                 width[col] = max(width[col], text_width);
+                height[row] = max(height[row], count_lines(text));
             }
         }
 
@@ -266,7 +338,7 @@ where
             *w += 2;
         }
 
-        let dims = ConstDimension::new(ConstSize::List(width), ConstSize::Value(1));
+        let dims = ConstDimension::new(ConstSize::List(width), ConstSize::List(height));
         Self::with_dimension(mat, dims).columns(COLS).rows(ROWS)
     }
 }
@@ -278,6 +350,38 @@ fn build_grid<W, I, D>(
     config: CompactConfig,
     cols: usize,
     rows: Option<usize>,
+    truncate: Option<&str>,
+) -> Result<(), fmt::Error>
+where
+    W: fmt::Write,
+    I: IntoRecords,
+    I::Cell: AsRef<str>,
+    D: Dimension,
+{
+    // a truncated text is allocated, so it's done only with `std`
+    #[cfg(not(feature = "std"))]
+    let _ = truncate;
+
+    #[cfg(feature = "std")]
+    if let Some(suffix) = truncate {
+        let pad = config.get_padding();
+        let pad = pad.left.size + pad.right.size;
+        let width = ContentWidth::new(&dims, pad);
+        let records = TruncateContent::new(records, width).suffix(suffix);
+
+        return print_grid(writer, records, &dims, config, cols, rows);
+    }
+
+    print_grid(writer, records, dims, config, cols, rows)
+}
+
+fn print_grid<W, I, D>(
+    writer: W,
+    records: I,
+    dims: D,
+    config: CompactConfig,
+    cols: usize,
+    rows: Option<usize>,
 ) -> Result<(), fmt::Error>
 where
     W: fmt::Write,
@@ -300,6 +404,42 @@ where
     }
 }
 
+/// A content width of a column, which is a column width without padding.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct ContentWidth<'a, D> {
+    dims: &'a D,
+    padding: usize,
+}
+
+#[cfg(feature = "std")]
+impl<'a, D> ContentWidth<'a, D> {
+    fn new(dims: &'a D, padding: usize) -> Self {
+        Self { dims, padding }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D> Clone for ContentWidth<'_, D> {
+    fn clone(&self) -> Self {
+        Self::new(self.dims, self.padding)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D> Dimension for ContentWidth<'_, D>
+where
+    D: Dimension,
+{
+    fn get_width(&self, column: usize) -> usize {
+        self.dims.get_width(column).saturating_sub(self.padding)
+    }
+
+    fn get_height(&self, _row: usize) -> usize {
+        unreachable!("A height method is not supposed to be called");
+    }
+}
+
 const fn create_config() -> CompactConfig {
     let mut cfg = CompactConfig::new();
     cfg.set_padding(Sides::new(
@@ -1,7 +1,8 @@
 //! This module contains an [`ExtendedTable`] structure which is useful in cases where
 //! a structure has a lot of fields.
 
-use crate::grid::util::string::get_line_width;
+use crate::grid::util::string::{get_line_width, get_text_width};
+use crate::settings::width::Wrap;
 use crate::Tabled;
 use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
@@ -137,6 +138,73 @@ impl ExtendedTable {
         true
     }
 
+    /// Wraps values so a table fits into a set width.
+    /// It returns a success inticator, where `false` means it's not possible to set the table width,
+    /// because fields don't leave space for values.
+    ///
+    /// A wrapped value continues on the next lines with an empty field name.
+    ///
+    /// ```
+    /// use tabled::tables::ExtendedTable;
+    ///
+    /// let data = vec![
+    ///     vec!["name", "description"],
+    ///     vec!["Rust", "A language empowering everyone"],
+    ///     vec!["Go", "Build simple, secure, scalable systems"],
+    /// ];
+    /// let data = data
+    ///     .into_iter()
+    ///     .map(|row| row.into_iter().map(String::from).collect())
+    ///     .collect::<Vec<Vec<String>>>();
+    ///
+    /// let mut table = ExtendedTable::from(data);
+    /// assert!(table.wrap(30, true));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "-[ RECORD 0 ]-----------------\n\
+    ///      name        | Rust\n\
+    ///      description | A language\n\
+    ///      \x20           | empowering\n\
+    ///      \x20           | everyone\n\
+    ///      -[ RECORD 1 ]-----------------\n\
+    ///      name        | Go\n\
+    ///      description | Build simple,\n\
+    ///      \x20           | secure, scalable\n\
+    ///      \x20           | systems"
+    /// );
+    /// ```
+    pub fn wrap(&mut self, max: usize, keep_words: bool) -> bool {
+        let fields_max_width = self
+            .fields
+            .iter()
+            .map(|s| get_line_width(s))
+            .max()
+            .unwrap_or_default();
+
+        // 3 is a space for ' | '
+        if max <= fields_max_width + 3 {
+            return false;
+        }
+
+        let max = max - fields_max_width - 3;
+        for record in &mut self.records {
+            for text in record {
+                if get_line_width(text) <= max {
+                    continue;
+                }
+
+                // keeping words leaves spaces on either side of a line
+                // (depending on whether `ansi` is on), which are not needed here
+                let wrapped = Wrap::wrap(text, max, keep_words);
+                let trim: fn(&str) -> &str = if keep_words { str::trim } else { str::trim_end };
+                *text = wrapped.lines().map(trim).collect::<Vec<_>>().join("\n");
+            }
+        }
+
+        true
+    }
+
     /// Sets the template for a record.
     pub fn template<F>(mut self, template: F) -> Self
     where
@@ -186,7 +254,7 @@ impl Display for ExtendedTable {
         let max_values_length = self
             .records
             .iter()
-            .map(|record| record.iter().map(|s| get_line_width(s)).max())
+            .map(|record| record.iter().map(|s| get_text_width(s)).max())
             .max()
             .unwrap_or_default()
             .unwrap_or_default();
@@ -254,13 +322,12 @@ fn write_header_template(
             let part2 = rest_to_print - part1 - 1;
 
             template.extend(
-                std::iter::repeat('-')
-                    .take(part1)
+                std::iter::repeat_n('-', part1)
                     .chain(std::iter::once('+'))
-                    .chain(std::iter::repeat('-').take(part2)),
+                    .chain(std::iter::repeat_n('-', part2)),
             );
         } else {
-            template.extend(std::iter::repeat('-').take(rest_to_print));
+            template.extend(std::iter::repeat_n('-', rest_to_print));
         }
     }
 
@@ -275,7 +342,16 @@ fn write_record(
     value: &str,
     max_field_width: usize,
 ) -> fmt::Result {
-    write!(f, "{field:max_field_width$} | {value}")
+    let mut lines = value.lines();
+
+    let first = lines.next().unwrap_or_default();
+    write!(f, "{field:max_field_width$} | {first}")?;
+
+    for line in lines {
+        write!(f, "\n{:max_field_width$} | {line}", "")?;
+    }
+
+    Ok(())
 }
 
 fn truncate(text: &mut String, max: usize, suffix: &str) {
@@ -93,8 +93,11 @@ struct Settings {
     sniff: usize,
     count_columns: Option<usize>,
     count_rows: Option<usize>,
+    limit_rows: Option<usize>,
     width: Option<usize>,
+    widths: Option<Vec<usize>>,
     height: Option<usize>,
+    suffix: String,
 }
 
 impl<I> IterTable<I> {
@@ -110,8 +113,11 @@ impl<I> IterTable<I> {
                 sniff: 1000,
                 count_columns: None,
                 count_rows: None,
+                limit_rows: None,
                 height: None,
                 width: None,
+                widths: None,
+                suffix: String::new(),
             },
         }
     }
@@ -141,6 +147,37 @@ impl<I> IterTable<I> {
         self
     }
 
+    /// Limit a number of rows to be shown, adding a row of `…` if there are more.
+    ///
+    /// The `…` row spans all columns.
+    /// Only first N rows are used for dimension estimations,
+    /// and at most N + 1 rows are read from the iterator to find out whether there are more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{grid::records::IterRecords, tables::IterTable};
+    ///
+    /// let data = (0..100).map(|i| vec![i.to_string(), (i * i).to_string()]);
+    /// let records = IterRecords::new(data, 2, None);
+    /// let table = IterTable::new(records).limit_rows(2);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+---+\n\
+    ///      | 0 | 0 |\n\
+    ///      +---+---+\n\
+    ///      | 1 | 1 |\n\
+    ///      +---+---+\n\
+    ///      | …     |\n\
+    ///      +---+---+",
+    /// );
+    /// ```
+    pub fn limit_rows(mut self, count_rows: usize) -> Self {
+        self.table.limit_rows = Some(count_rows);
+        self
+    }
+
     /// Limit an amount of rows will be read for dimension estimations.
     ///
     /// By default it's 1000.
@@ -161,6 +198,61 @@ impl<I> IterTable<I> {
         self
     }
 
+    /// Set a width of each column individually.
+    ///
+    /// In such case no records are buffered and the table is built in a single pass.
+    /// Bigger cells are truncated and smaller ones are padded.
+    ///
+    /// Widths don't include padding.
+    /// Unless [`IterTable::columns`] is set, a number of columns is the length of the list;
+    /// columns without a width are considered to be empty.
+    ///
+    /// It takes precedence over [`IterTable::width`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{grid::records::IterRecords, tables::IterTable};
+    ///
+    /// let data = vec![
+    ///     vec!["First", "row"],
+    ///     vec!["Second", "row"],
+    ///     vec!["Third", "big row"],
+    /// ];
+    ///
+    /// let records = IterRecords::new(data, 2, None);
+    /// let table = IterTable::new(records).widths([4, 5]).suffix("~");
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-------+\n\
+    ///      | Fir~ | row   |\n\
+    ///      +------+-------+\n\
+    ///      | Sec~ | row   |\n\
+    ///      +------+-------+\n\
+    ///      | Thi~ | big ~ |\n\
+    ///      +------+-------+",
+    /// );
+    /// ```
+    pub fn widths<W>(mut self, list: W) -> Self
+    where
+        W: IntoIterator<Item = usize>,
+    {
+        self.table.widths = Some(list.into_iter().collect());
+        self
+    }
+
+    /// Set a suffix which is appended to a truncated cell.
+    ///
+    /// The suffix is considered to be a part of a width.
+    pub fn suffix<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.table.suffix = suffix.into();
+        self
+    }
+
     /// Build a string.
     ///
     /// We can't implement [`std::string::ToString`] cause it does takes `&self` reference.
@@ -185,8 +277,7 @@ impl<I> IterTable<I> {
         I::Cell: AsRef<str>,
     {
         let writer = UTF8Writer::new(writer);
-        self.fmt(writer)
-            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+        self.fmt(writer).map_err(io::Error::other)
     }
 
     /// Format table into [fmt::Write]er.
@@ -207,7 +298,9 @@ where
     I::Cell: AsRef<str>,
 {
     let width_config = opts.width.is_some() && opts.count_columns.is_some();
-    if width_config {
+    if opts.widths.is_some() {
+        build_table_with_widths(f, iter, cfg, opts)
+    } else if width_config {
         build_table_with_static_dims(f, iter, cfg, opts)
     } else if opts.width.is_some() {
         build_table_sniffing_with_width(f, iter, cfg, opts)
@@ -237,17 +330,31 @@ where
     let dims = StaticDimension::new(w, h);
     let cfg = SpannedConfig::from(cfg);
 
-    match opts.count_rows {
-        Some(limit) => {
-            let records = LimitRows::new(iter, limit);
-            let records = build_records(records, contentw, count_columns, Some(limit));
-            Grid::new(records, dims, cfg, NoColors).build(f)
-        }
-        None => {
-            let records = build_records(iter, contentw, count_columns, None);
-            Grid::new(records, dims, cfg, NoColors).build(f)
-        }
-    }
+    print_records(f, iter, contentw, count_columns, dims, cfg, &opts)
+}
+
+fn build_table_with_widths<W, I>(f: W, iter: I, cfg: CompactConfig, opts: Settings) -> fmt::Result
+where
+    W: fmt::Write,
+    I: IntoRecords,
+    I::Cell: AsRef<str>,
+{
+    let mut widths = opts.widths.clone().unwrap_or_default();
+    let count_columns = opts.count_columns.unwrap_or(widths.len());
+    widths.resize(count_columns, 0);
+
+    let height = opts.height.unwrap_or(1);
+    let pad = cfg.get_padding();
+    let padh = pad.left.size + pad.right.size;
+    let padv = pad.top.size + pad.bottom.size;
+
+    let w = DimensionValue::List(widths.iter().map(|w| w + padh).collect());
+    let h = DimensionValue::Exact(height + padv);
+    let dims = StaticDimension::new(w, h);
+    let contentw = WidthDimension::List(widths);
+    let cfg = SpannedConfig::from(cfg);
+
+    print_records(f, iter, contentw, count_columns, dims, cfg, &opts)
 }
 
 fn build_table_sniffing<W, I>(f: W, iter: I, cfg: CompactConfig, opts: Settings) -> fmt::Result
@@ -256,7 +363,7 @@ where
     I: IntoRecords,
     I::Cell: AsRef<str>,
 {
-    let records = BufRecords::new(iter, opts.sniff);
+    let records = BufRecords::new(iter, get_sniff(&opts));
 
     let count_columns = get_count_columns(&opts, records.as_slice());
 
@@ -270,10 +377,8 @@ where
     let pad = padding.left.size + padding.right.size;
     let padv = padding.top.size + padding.bottom.size;
 
-    if opts.sniff == 0 {
-        width = std::iter::repeat(pad)
-            .take(count_columns)
-            .collect::<Vec<_>>();
+    if get_sniff(&opts) == 0 {
+        width = std::iter::repeat_n(pad, count_columns).collect::<Vec<_>>();
     }
 
     let content_width = WidthDimension::List(width.iter().map(|i| i.saturating_sub(pad)).collect());
@@ -289,17 +394,7 @@ where
     let dims = StaticDimension::new(dims_width, dims_height);
     let cfg = SpannedConfig::from(cfg);
 
-    match opts.count_rows {
-        Some(limit) => {
-            let records = LimitRows::new(records, limit);
-            let records = build_records(records, content_width, count_columns, Some(limit));
-            Grid::new(records, dims, cfg, NoColors).build(f)
-        }
-        None => {
-            let records = build_records(records, content_width, count_columns, None);
-            Grid::new(records, dims, cfg, NoColors).build(f)
-        }
-    }
+    print_records(f, records, content_width, count_columns, dims, cfg, &opts)
 }
 
 fn build_table_sniffing_with_width<W, I>(
@@ -313,7 +408,7 @@ where
     I: IntoRecords,
     I::Cell: AsRef<str>,
 {
-    let records = BufRecords::new(iter, opts.sniff);
+    let records = BufRecords::new(iter, get_sniff(&opts));
 
     let count_columns = get_count_columns(&opts, records.as_slice());
 
@@ -331,16 +426,65 @@ where
 
     let cfg = SpannedConfig::from(cfg);
 
-    match opts.count_rows {
-        Some(limit) => {
-            let records = LimitRows::new(records, limit);
-            let records = build_records(records, contentw, count_columns, Some(limit));
+    print_records(f, records, contentw, count_columns, dims, cfg, &opts)
+}
+
+fn print_records<W, I>(
+    f: W,
+    records: I,
+    width: WidthDimension,
+    count_columns: usize,
+    dims: StaticDimension,
+    mut cfg: SpannedConfig,
+    opts: &Settings,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    I: IntoRecords,
+    I::Cell: AsRef<str>,
+{
+    match (opts.count_rows, opts.limit_rows) {
+        (Some(count_rows), Some(limit)) => {
+            let records = LimitRows::new(records, count_rows);
+            let records = EllipsisRows::new(records, limit, count_columns);
+            set_ellipsis_span(&mut cfg, limit, count_columns);
+            let records = build_records(records, width, &opts.suffix, count_columns, None);
             Grid::new(records, dims, cfg, NoColors).build(f)
         }
-        None => {
-            let records = build_records(records, contentw, count_columns, None);
+        (None, Some(limit)) => {
+            let records = EllipsisRows::new(records, limit, count_columns);
+            set_ellipsis_span(&mut cfg, limit, count_columns);
+            let records = build_records(records, width, &opts.suffix, count_columns, None);
             Grid::new(records, dims, cfg, NoColors).build(f)
         }
+        (Some(count_rows), None) => {
+            let records = LimitRows::new(records, count_rows);
+            let records = build_records(
+                records,
+                width,
+                &opts.suffix,
+                count_columns,
+                Some(count_rows),
+            );
+            Grid::new(records, dims, cfg, NoColors).build(f)
+        }
+        (None, None) => {
+            let records = build_records(records, width, &opts.suffix, count_columns, None);
+            Grid::new(records, dims, cfg, NoColors).build(f)
+        }
+    }
+}
+
+fn set_ellipsis_span(cfg: &mut SpannedConfig, row: usize, count_columns: usize) {
+    if count_columns > 1 {
+        cfg.set_column_span((row, 0).into(), count_columns);
+    }
+}
+
+fn get_sniff(opts: &Settings) -> usize {
+    match opts.limit_rows {
+        Some(limit) => opts.sniff.min(limit),
+        None => opts.sniff,
     }
 }
 
@@ -367,13 +511,14 @@ const fn create_config() -> CompactConfig {
 fn build_records<I>(
     records: I,
     width: WidthDimension,
+    suffix: &str,
     count_columns: usize,
     count_rows: Option<usize>,
-) -> IterRecords<LimitColumns<TruncateContent<I, WidthDimension>>>
+) -> IterRecords<LimitColumns<TruncateContent<I, WidthDimension, &str>>>
 where
     I: IntoRecords,
 {
-    let records = TruncateContent::new(records, width);
+    let records = TruncateContent::new(records, width).suffix(suffix);
     let records = LimitColumns::new(records, count_columns);
     IterRecords::new(records, count_columns, count_rows)
 }
@@ -397,3 +542,126 @@ impl Dimension for WidthDimension {
         unreachable!("A height method is not supposed to be called");
     }
 }
+
+/// A records iterator which limits amount of rows, adding a row of `…` if there are more.
+#[derive(Debug)]
+struct EllipsisRows<I> {
+    records: I,
+    limit: usize,
+    count_columns: usize,
+}
+
+impl<I> EllipsisRows<I> {
+    fn new(records: I, limit: usize, count_columns: usize) -> Self {
+        Self {
+            records,
+            limit,
+            count_columns,
+        }
+    }
+}
+
+impl<I> IntoRecords for EllipsisRows<I>
+where
+    I: IntoRecords,
+{
+    type Cell = EllipsisCell<I::Cell>;
+    type IterColumns = EllipsisColumns<<I::IterColumns as IntoIterator>::IntoIter>;
+    type IterRows = EllipsisRowsIter<<I::IterRows as IntoIterator>::IntoIter>;
+
+    fn iter_rows(self) -> Self::IterRows {
+        EllipsisRowsIter {
+            iter: self.records.iter_rows().into_iter(),
+            limit: self.limit,
+            count_columns: self.count_columns,
+            is_finished: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct EllipsisRowsIter<I> {
+    iter: I,
+    limit: usize,
+    count_columns: usize,
+    is_finished: bool,
+}
+
+impl<I> Iterator for EllipsisRowsIter<I>
+where
+    I: Iterator,
+    I::Item: IntoIterator,
+{
+    type Item = EllipsisColumns<<I::Item as IntoIterator>::IntoIter>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+
+        if self.limit > 0 {
+            self.limit -= 1;
+
+            let columns = self.iter.next();
+            self.is_finished = columns.is_none();
+
+            return columns.map(|columns| EllipsisColumns::Row(columns.into_iter()));
+        }
+
+        // we only check whether there's a next row, it's not rendered
+        self.is_finished = true;
+        let _ = self.iter.next()?;
+
+        Some(EllipsisColumns::Ellipsis {
+            col: 0,
+            count_columns: self.count_columns,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum EllipsisColumns<I> {
+    Row(I),
+    Ellipsis { col: usize, count_columns: usize },
+}
+
+impl<I> Iterator for EllipsisColumns<I>
+where
+    I: Iterator,
+{
+    type Item = EllipsisCell<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            EllipsisColumns::Row(iter) => iter.next().map(EllipsisCell::Cell),
+            EllipsisColumns::Ellipsis { col, count_columns } => {
+                if *col == *count_columns {
+                    return None;
+                }
+
+                let text = if *col == 0 { "…" } else { "" };
+                *col += 1;
+
+                Some(EllipsisCell::Text(text))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+enum EllipsisCell<T> {
+    Cell(T),
+    Text(&'static str),
+}
+
+impl<T> AsRef<str> for EllipsisCell<T>
+where
+    T: AsRef<str>,
+{
+    fn as_ref(&self) -> &str {
+        match self {
+            EllipsisCell::Cell(cell) => cell.as_ref(),
+            EllipsisCell::Text(text) => text,
+        }
+    }
+}
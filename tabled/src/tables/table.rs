@@ -1,25 +1,31 @@
 //! This module contains a main table representation [`Table`].
 
 use core::ops::DerefMut;
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    hash::BuildHasher,
+    iter::FromIterator,
+};
 
 use crate::{
     builder::Builder,
     grid::{
         colors::NoColors,
         config::{
-            AlignmentHorizontal, ColorMap, ColoredConfig, CompactConfig, Entity, Indent, Sides,
-            SpannedConfig,
+            AlignmentHorizontal, ColorMap, ColoredConfig, CompactConfig, Entity, Indent, Position,
+            Sides, SpannedConfig,
         },
         dimension::{CompleteDimensionVecRecords, Dimension, Estimate, PeekableDimension},
         records::{
             vec_records::{Text, VecRecords},
-            ExactRecords, Records,
+            ExactRecords, PeekableRecords, Records, RecordsMut,
         },
         PeekableGrid,
     },
-    settings::{object::Object, CellOption, Style, TableOption},
-    Tabled,
+    settings::{object::Object, CellOption, Color, Style, TableOption},
+    TableError, Tabled,
 };
 
 /// The structure provides an interface for building a table for types that implements [`Tabled`].
@@ -88,6 +94,8 @@ pub struct Table {
     records: VecRecords<Text<String>>,
     config: ColoredConfig,
     dimension: CompleteDimensionVecRecords<'static>,
+    newline: Cow<'static, str>,
+    empty_text: Option<String>,
 }
 
 impl Table {
@@ -235,6 +243,112 @@ impl Table {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            newline: Cow::Borrowed("\n"),
+            empty_text: None,
+        }
+    }
+
+    /// Creates a new [`Table`] from a list of results.
+    ///
+    /// An [`Ok`] value is rendered as a regular row,
+    /// while an [`Err`] is rendered as a red row with an error message,
+    /// which is spanned over all columns.
+    /// If `T` has no fields a single empty column is used for the errors.
+    ///
+    /// ```
+    /// use tabled::{Table, Tabled};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Request {
+    ///     method: &'static str,
+    ///     status: u16,
+    /// }
+    ///
+    /// let data = vec![
+    ///     Ok(Request { method: "GET", status: 200 }),
+    ///     Err("connection reset"),
+    ///     Ok(Request { method: "POST", status: 201 }),
+    /// ];
+    ///
+    /// let table = Table::try_from_iter(data).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+---------+--------+\n\
+    ///      | method  | status |\n\
+    ///      +---------+--------+\n\
+    ///      | GET     | 200    |\n\
+    ///      +---------+--------+\n\
+    ///      | \u{1b}[31mconnection reset\u{1b}[39m |\n\
+    ///      +---------+--------+\n\
+    ///      | POST    | 201    |\n\
+    ///      +---------+--------+"
+    /// );
+    /// ```
+    pub fn try_from_iter<I, T, E>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+        T: Tabled,
+        E: fmt::Display,
+    {
+        let mut header = Vec::with_capacity(T::LENGTH);
+        for text in T::headers() {
+            let text = text.into_owned();
+            let cell = Text::new(text);
+            header.push(cell);
+        }
+
+        let count_columns = header.len();
+
+        let mut errors = Vec::new();
+        let mut records = vec![header];
+        for row in iter.into_iter() {
+            let mut list = Vec::with_capacity(count_columns);
+            match row {
+                Ok(row) => {
+                    for text in row.fields().into_iter() {
+                        let text = text.into_owned();
+                        let cell = Text::new(text);
+
+                        list.push(cell);
+                    }
+                }
+                Err(err) => {
+                    errors.push(records.len());
+
+                    list.push(Text::new(err.to_string()));
+                    list.resize(count_columns.max(1), Text::default());
+                }
+            }
+
+            records.push(list);
+        }
+
+        // an error takes a column, so a type with no fields gets an empty one
+        if count_columns == 0 && !errors.is_empty() {
+            for row in &mut records {
+                row.resize(1, Text::default());
+            }
+        }
+
+        let records = VecRecords::new(records);
+
+        let mut config = ColoredConfig::new(configure_grid());
+        for row in errors {
+            if count_columns > 1 {
+                config.set_column_span(Position::new(row, 0), count_columns);
+            }
+
+            let color = Color::FG_RED.into();
+            let _ = config.set_color(Entity::Cell(row, 0), color);
+        }
+
+        Self {
+            records,
+            config,
+            dimension: CompleteDimensionVecRecords::default(),
+            newline: Cow::Borrowed("\n"),
+            empty_text: None,
         }
     }
 
@@ -376,9 +490,110 @@ impl Table {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            newline: Cow::Borrowed("\n"),
+            empty_text: None,
         }
     }
 
+    /// Creates a 2 column [`Table`] instance from a list of key-value pairs.
+    ///
+    /// The columns are named `key` and `value`;
+    /// see [`Table::pairs_with_header`] to use different names.
+    ///
+    /// The order of pairs is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    /// use testing_table::assert_table;
+    ///
+    /// let list = vec![("HOME", "/root"), ("SHELL", "/bin/bash")];
+    ///
+    /// let table = Table::pairs(list);
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "+-------+-----------+"
+    ///     "| key   | value     |"
+    ///     "+-------+-----------+"
+    ///     "| HOME  | /root     |"
+    ///     "+-------+-----------+"
+    ///     "| SHELL | /bin/bash |"
+    ///     "+-------+-----------+"
+    /// );
+    /// ```
+    pub fn pairs<I, K, V>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: fmt::Display,
+        V: fmt::Display,
+    {
+        Self::pairs_with_header(iter, "key", "value")
+    }
+
+    /// Creates a 2 column [`Table`] instance from a list of key-value pairs,
+    /// with the given column names.
+    ///
+    /// The order of pairs is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::Table;
+    /// use testing_table::assert_table;
+    ///
+    /// let list = vec![("timeout", 30), ("retries", 3)];
+    ///
+    /// let table = Table::pairs_with_header(list, "option", "setting");
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "+---------+---------+"
+    ///     "| option  | setting |"
+    ///     "+---------+---------+"
+    ///     "| timeout | 30      |"
+    ///     "+---------+---------+"
+    ///     "| retries | 3       |"
+    ///     "+---------+---------+"
+    /// );
+    /// ```
+    pub fn pairs_with_header<I, K, V, H1, H2>(iter: I, key: H1, value: H2) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: fmt::Display,
+        V: fmt::Display,
+        H1: Into<String>,
+        H2: Into<String>,
+    {
+        let iter = iter.into_iter();
+
+        let mut builder = Builder::with_capacity(iter.size_hint().0 + 1, 2);
+        builder.push_record([key.into(), value.into()]);
+
+        for (k, v) in iter {
+            builder.push_record([k.to_string(), v.to_string()]);
+        }
+
+        builder.build()
+    }
+
+    /// Creates an empty [`Table`] with a capacity reserved for a given number of rows and columns.
+    ///
+    /// See [`Builder::with_capacity`].
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::with_capacity(100, 3);
+    ///
+    /// assert_eq!(table.shape(), (0, 0));
+    /// assert_eq!(table.to_string(), "");
+    /// ```
+    pub fn with_capacity(count_rows: usize, count_columns: usize) -> Self {
+        Self::from(Builder::with_capacity(count_rows, count_columns))
+    }
+
     /// Creates a builder from a data set given.
     ///
     /// # Example
@@ -437,7 +652,7 @@ impl Table {
         builder.push_record(T::headers());
 
         for row in iter {
-            builder.push_record(row.fields().into_iter());
+            builder.push_record(row.fields());
         }
 
         builder
@@ -462,6 +677,34 @@ impl Table {
         self
     }
 
+    /// It's a generic function which applies options to the [`Table`],
+    /// unless an option reports that it can't be applied.
+    ///
+    /// The option is checked by [`TableOption::validate`] beforehand,
+    /// so in case of an error the [`Table`] is left untouched.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{Modify, Span},
+    ///     Table, TableError,
+    /// };
+    ///
+    /// let mut table = Table::new([["a", "b", "c"]]);
+    /// table.modify((1, 0), Span::column(2));
+    ///
+    /// let result = table.try_with(Modify::new((1, 1)).with(Span::column(2)));
+    /// assert!(matches!(result, Err(TableError::SpanIntersection(_))));
+    /// ```
+    pub fn try_with<O>(&mut self, option: O) -> Result<&mut Self, TableError>
+    where
+        for<'a> O:
+            TableOption<VecRecords<Text<String>>, ColoredConfig, CompleteDimensionVecRecords<'a>>,
+    {
+        option.validate(&self.records, &self.config)?;
+
+        Ok(self.with(option))
+    }
+
     /// It's a generic function which applies options to particular cells on the [`Table`].
     /// Target cells using [`Object`]s such as [`Cell`], [`Rows`], [`Location`] and more.
     ///
@@ -486,6 +729,145 @@ impl Table {
         self
     }
 
+    /// Changes every cell of the [`Table`] by a given function.
+    ///
+    /// The function gets a cell position and its current text and returns a new text.
+    ///
+    /// ```
+    /// use tabled::Table;
+    /// use testing_table::assert_table;
+    ///
+    /// let data = [["hello", "world"], ["hi", "!"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.map_cells(|pos, text| match pos.row() {
+    ///     0 => format!("#{}", text),
+    ///     _ => text.to_uppercase(),
+    /// });
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "+-------+-------+"
+    ///     "| #0    | #1    |"
+    ///     "+-------+-------+"
+    ///     "| HELLO | WORLD |"
+    ///     "+-------+-------+"
+    ///     "| HI    | !     |"
+    ///     "+-------+-------+"
+    /// );
+    /// ```
+    pub fn map_cells<F>(&mut self, mut f: F) -> &mut Self
+    where
+        F: FnMut(Position, &str) -> String,
+    {
+        let (count_rows, count_columns) = self.shape();
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let pos = Position::new(row, col);
+                let text = f(pos, self.records.get_text(pos));
+                self.records.set(pos, text);
+            }
+        }
+
+        dimension_reastimate_likely(&mut self.dimension, Some(Entity::Global));
+
+        self
+    }
+
+    /// Sets a text of a given cell.
+    ///
+    /// Only the column and the row of the cell are measured again,
+    /// which makes it cheaper than re-estimating a whole table on frequent edits.
+    ///
+    /// A position out of the table bounds is ignored.
+    ///
+    /// ```
+    /// use tabled::Table;
+    /// use testing_table::assert_table;
+    ///
+    /// let mut table = Table::new([["Hello", "World"]]);
+    /// table.set_cell((1, 1), "Everyone");
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "+-------+----------+"
+    ///     "| 0     | 1        |"
+    ///     "+-------+----------+"
+    ///     "| Hello | Everyone |"
+    ///     "+-------+----------+"
+    /// );
+    /// ```
+    pub fn set_cell<P, S>(&mut self, pos: P, text: S) -> &mut Self
+    where
+        P: Into<Position>,
+        S: Into<String>,
+    {
+        let pos = pos.into();
+        if !pos.is_covered(self.shape().into()) {
+            return self;
+        }
+
+        self.records.set(pos, text.into());
+        self.dimension
+            .invalidate_cell(&self.records, self.config.as_ref(), pos);
+
+        self
+    }
+
+    /// Appends a row built from a [`Tabled`] value to the end of the table.
+    ///
+    /// The settings which were applied are kept,
+    /// and the table is measured again on the next render.
+    ///
+    /// An error is returned in case the amount of fields doesn't match the amount of columns,
+    /// so the table is left untouched.
+    ///
+    /// ```
+    /// use tabled::{settings::Style, Table};
+    /// use testing_table::assert_table;
+    ///
+    /// let mut table = Table::new([("Hello", 1)]);
+    /// table.with(Style::markdown());
+    ///
+    /// table.push_row(("World", 2)).unwrap();
+    /// assert!(table.push_row(("!", 3, 4)).is_err());
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "| &str  | i32 |"
+    ///     "|-------|-----|"
+    ///     "| Hello | 1   |"
+    ///     "| World | 2   |"
+    /// );
+    /// ```
+    pub fn push_row<T>(&mut self, record: T) -> Result<&mut Self, TableError>
+    where
+        T: Tabled,
+    {
+        let fields = record.fields();
+        let count_columns = self.count_columns();
+        if !self.is_empty() && fields.len() != count_columns {
+            return Err(TableError::ColumnCount {
+                expected: count_columns,
+                found: fields.len(),
+            });
+        }
+
+        let row = fields
+            .into_iter()
+            .map(|text| Text::new(text.into_owned()))
+            .collect();
+
+        let records = std::mem::take(&mut self.records);
+        let mut data: Vec<Vec<_>> = records.into();
+        data.push(row);
+        self.records = VecRecords::new(data);
+
+        dimension_reastimate_likely(&mut self.dimension, Some(Entity::Global));
+
+        Ok(self)
+    }
+
     /// Returns a table shape (count rows, count columns).
     pub fn shape(&self) -> (usize, usize) {
         (self.count_rows(), self.count_columns())
@@ -537,6 +919,205 @@ impl Table {
         total + countv + margin.left.size + margin.right.size
     }
 
+    /// Returns column widths and row heights which will be used while rendering.
+    ///
+    /// It reflects applied settings, like [`Width`] or [`Padding`].
+    /// Widths include padding, but not borders or margin.
+    ///
+    /// Cached values are reused and only missing ones are estimated.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Columns, Width}};
+    ///
+    /// let mut table = Table::new([["Hello", "World!!!"]]);
+    /// table.modify(Columns::last(), Width::truncate(5));
+    ///
+    /// let dims = table.get_dimension();
+    ///
+    /// // a content is truncated, while padding is still there
+    /// assert_eq!(dims.get_widths(), Some(&[7, 7][..]));
+    /// assert_eq!(dims.get_heights(), Some(&[1, 1][..]));
+    /// ```
+    ///
+    /// [`Width`]: crate::settings::Width
+    /// [`Padding`]: crate::settings::Padding
+    pub fn get_dimension(&self) -> CompleteDimensionVecRecords<'_> {
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        dims
+    }
+
+    /// Estimates column widths and row heights and keeps them,
+    /// so they are not measured again on each render.
+    ///
+    /// It's useful together with [`Table::set_cell`] in case a table is edited and printed often.
+    pub fn cache_dimension(&mut self) -> &mut Self {
+        self.dimension.estimate(&self.records, self.config.as_ref());
+        self
+    }
+
+    /// Renders a table into a given writer.
+    ///
+    /// It produces the same output as [`ToString::to_string`],
+    /// but the grid is written directly, without building an intermediate [`String`].
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use tabled::Table;
+    ///
+    /// struct Bytes(Vec<u8>);
+    ///
+    /// impl fmt::Write for Bytes {
+    ///     fn write_str(&mut self, s: &str) -> fmt::Result {
+    ///         self.0.extend_from_slice(s.as_bytes());
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let table = Table::new([["Hello", "World"]]);
+    ///
+    /// let mut buf = Bytes(Vec::new());
+    /// table.fmt_to(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf.0, table.to_string().into_bytes());
+    /// ```
+    pub fn fmt_to<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        if let Some(table) = self.build_empty_table() {
+            return table.fmt_to(w);
+        }
+
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        print_table(w, self, self.config.as_ref())
+    }
+
+    /// Sets a line separator which is used between the lines of a table.
+    ///
+    /// By default it's `\n`.
+    /// It could be set to `\r\n` in case the output goes to a tool which expects CRLF.
+    ///
+    /// It affects only the rendered output,
+    /// so widths and heights of a table are unchanged.
+    /// Multiline cells are printed line by line,
+    /// so their lines are separated the same way.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let mut table = Table::new([["Hello", "World"]]);
+    /// table.newline("\r\n");
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-------+\r\n\
+    ///      | 0     | 1     |\r\n\
+    ///      +-------+-------+\r\n\
+    ///      | Hello | World |\r\n\
+    ///      +-------+-------+"
+    /// );
+    /// ```
+    pub fn newline<S>(&mut self, newline: S) -> &mut Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.newline = newline.into();
+        self
+    }
+
+    /// Returns a line separator which is used between the lines of a table.
+    pub fn get_newline(&self) -> &str {
+        &self.newline
+    }
+
+    /// Sets a text which is rendered when a table has no data.
+    ///
+    /// The first row is considered to be a header,
+    /// so the text is put below it in a cell spanned over all columns.
+    /// If there's no header either the text is rendered as a single cell.
+    /// In both cases the text is surrounded by a frame of a table.
+    ///
+    /// ```
+    /// use tabled::{Table, Tabled, settings::Style};
+    ///
+    /// #[derive(Tabled)]
+    /// struct Language {
+    ///     name: &'static str,
+    ///     designed_by: &'static str,
+    /// }
+    ///
+    /// let data: Vec<Language> = Vec::new();
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::modern())
+    ///     .empty_text("no data")
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "┌──────┬─────────────┐\n\
+    ///      │ name │ designed_by │\n\
+    ///      ├──────┼─────────────┤\n\
+    ///      │ no data            │\n\
+    ///      └──────┴─────────────┘"
+    /// );
+    /// ```
+    pub fn empty_text<S>(&mut self, text: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.empty_text = Some(text.into());
+        self
+    }
+
+    /// Returns a text which is rendered when a table has no data.
+    pub fn get_empty_text(&self) -> Option<&str> {
+        self.empty_text.as_deref()
+    }
+
+    // A copy of the table with an empty text in it,
+    // if the text is set and the table has no data.
+    fn build_empty_table(&self) -> Option<Table> {
+        let text = self.empty_text.as_ref()?;
+        if self.count_rows() > 1 {
+            return None;
+        }
+
+        let mut table = self.clone();
+        table.empty_text = None;
+        table.dimension = CompleteDimensionVecRecords::default();
+
+        let text = Text::new(text.clone());
+
+        if self.is_empty() {
+            table.records = VecRecords::new(vec![vec![text]]);
+            return Some(table);
+        }
+
+        let count_columns = self.count_columns();
+
+        let mut row = vec![Text::default(); count_columns];
+        row[0] = text;
+
+        let records = std::mem::take(&mut table.records);
+        let mut data: Vec<Vec<_>> = records.into();
+        data.push(row);
+        table.records = VecRecords::new(data);
+
+        if count_columns > 1 {
+            table
+                .config
+                .set_column_span(Position::new(1, 0), count_columns);
+        }
+
+        Some(table)
+    }
+
     /// Returns a table config.
     pub fn get_config(&self) -> &ColoredConfig {
         &self.config
@@ -548,14 +1129,87 @@ impl Table {
     }
 
     /// Returns a used records.
+    ///
+    /// A cell text can be read by [`PeekableRecords::get_text`].
+    ///
+    /// ```
+    /// use tabled::{grid::records::PeekableRecords, Table};
+    ///
+    /// let table = Table::new([["Hello", "World"]]);
+    ///
+    /// assert_eq!(table.get_records().get_text((1, 1).into()), "World");
+    /// ```
+    ///
+    /// [`PeekableRecords::get_text`]: crate::grid::records::PeekableRecords::get_text
     pub fn get_records(&self) -> &VecRecords<Text<String>> {
         &self.records
     }
 
-    /// Returns a used records.
+    /// Returns a used records, so they could be modified.
+    ///
+    /// A cell text can be changed by [`RecordsMut::set`].
+    ///
+    /// A cached dimension is dropped,
+    /// so the table is measured again on the next render.
+    ///
+    /// ```
+    /// use tabled::{grid::records::RecordsMut, Table};
+    /// use testing_table::assert_table;
+    ///
+    /// let mut table = Table::new([["Hello", "World"]]);
+    /// table.get_records_mut().set((1, 1).into(), "Everyone");
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "+-------+----------+"
+    ///     "| 0     | 1        |"
+    ///     "+-------+----------+"
+    ///     "| Hello | Everyone |"
+    ///     "+-------+----------+"
+    /// );
+    /// ```
+    ///
+    /// [`RecordsMut::set`]: crate::grid::records::RecordsMut::set
     pub fn get_records_mut(&mut self) -> &mut VecRecords<Text<String>> {
+        dimension_reastimate_likely(&mut self.dimension, Some(Entity::Global));
+
         &mut self.records
     }
+
+    /// Converts a table back into a [`Builder`], so its data could be edited and built again.
+    ///
+    /// Only the records are kept, all settings (style, alignment, spans, etc.) are lost.
+    /// Notice that the cells hidden by a span keep their content,
+    /// so it will become visible in a rebuilt table.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = [("Hello", 1), ("World", 2)];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(tabled::settings::Style::modern());
+    ///
+    /// let mut builder = table.into_builder();
+    /// builder.remove_record(1);
+    /// builder.push_record(["Rust", "3"]);
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+-----+\n\
+    ///      | &str  | i32 |\n\
+    ///      +-------+-----+\n\
+    ///      | World | 2   |\n\
+    ///      +-------+-----+\n\
+    ///      | Rust  | 3   |\n\
+    ///      +-------+-----+"
+    /// )
+    /// ```
+    pub fn into_builder(self) -> Builder {
+        Builder::from(self)
+    }
 }
 
 impl Default for Table {
@@ -564,30 +1218,25 @@ impl Default for Table {
             records: VecRecords::default(),
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            newline: Cow::Borrowed("\n"),
+            empty_text: None,
         }
     }
 }
 
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(table) = self.build_empty_table() {
+            return table.fmt(f);
+        }
+
         if self.is_empty() {
             return Ok(());
         }
 
         let config = use_format_configuration(f, self);
-        let colors = self.config.get_colors();
 
-        if !self.dimension.is_empty() {
-            let mut dims = self.dimension.clone();
-            dims.estimate(&self.records, config.as_ref());
-
-            print_grid(f, &self.records, &config, &dims, colors)
-        } else {
-            let mut dims = PeekableDimension::default();
-            dims.estimate(&self.records, &config);
-
-            print_grid(f, &self.records, &config, &dims, colors)
-        }
+        print_table(f, self, &config)
     }
 }
 
@@ -601,6 +1250,24 @@ where
     }
 }
 
+/// Appends rows to a table, see [`Table::push_row`].
+///
+/// # Panics
+///
+/// Panics if the amount of fields of a record doesn't match the amount of columns.
+impl<T> Extend<T> for Table
+where
+    T: Tabled,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for record in iter {
+            if let Err(err) = self.push_row(record) {
+                panic!("{}", err);
+            }
+        }
+    }
+}
+
 impl From<Builder> for Table {
     fn from(builder: Builder) -> Self {
         let data = builder.into();
@@ -610,10 +1277,43 @@ impl From<Builder> for Table {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            newline: Cow::Borrowed("\n"),
+            empty_text: None,
         }
     }
 }
 
+impl<K, V> From<BTreeMap<K, V>> for Table
+where
+    K: fmt::Display,
+    V: fmt::Display,
+{
+    /// Creates a 2 column table of `key` and `value` like [`Table::pairs`],
+    /// keeping the map ordering.
+    fn from(map: BTreeMap<K, V>) -> Self {
+        Table::pairs(map)
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for Table
+where
+    K: fmt::Display,
+    V: fmt::Display,
+    S: BuildHasher,
+{
+    /// Creates a 2 column table of `key` and `value` like [`Table::pairs`],
+    /// with the rows sorted by a key string.
+    fn from(map: HashMap<K, V, S>) -> Self {
+        let mut pairs: Vec<_> = map
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Table::pairs(pairs)
+    }
+}
+
 impl From<Table> for Builder {
     fn from(val: Table) -> Self {
         let data = val.records.into();
@@ -621,6 +1321,62 @@ impl From<Table> for Builder {
     }
 }
 
+/// Serializes a logical data of a table, rather than its rendered form.
+///
+/// The first row is considered a header.
+///
+/// ```json
+/// { "header": ["name", "age"], "rows": [["Tom", "8"]] }
+/// ```
+///
+/// It can be deserialized back into a [`Builder`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Table {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let (header, rows): (&[_], &[_]) = match self.records.split_first() {
+            Some((header, rows)) => (header, rows),
+            None => (&[], &[]),
+        };
+
+        let mut table = serializer.serialize_struct("Table", 2)?;
+        table.serialize_field("header", &SerializeRow(header))?;
+        table.serialize_field("rows", &SerializeRows(rows))?;
+        table.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+struct SerializeRow<'a>(&'a [Text<String>]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SerializeRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter().map(|text| text.as_ref()))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct SerializeRows<'a>(&'a [Vec<Text<String>>]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SerializeRows<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter().map(|row| SerializeRow(row)))
+    }
+}
+
 impl<R, D> TableOption<R, ColoredConfig, D> for CompactConfig {
     fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
         *cfg.deref_mut() = self.into();
@@ -743,6 +1499,57 @@ fn set_width_table(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig, table: &Tabl
     }
 }
 
+fn print_table<F: fmt::Write>(f: &mut F, table: &Table, cfg: &SpannedConfig) -> fmt::Result {
+    if table.newline != "\n" {
+        let mut f = NewlineWriter::new(f, &table.newline);
+        return print_table_lines(&mut f, table, cfg);
+    }
+
+    print_table_lines(f, table, cfg)
+}
+
+fn print_table_lines<F: fmt::Write>(f: &mut F, table: &Table, cfg: &SpannedConfig) -> fmt::Result {
+    let colors = table.config.get_colors();
+
+    if !table.dimension.is_empty() {
+        let mut dims = table.dimension.clone();
+        dims.estimate(&table.records, cfg);
+
+        print_grid(f, &table.records, cfg, &dims, colors)
+    } else {
+        let mut dims = PeekableDimension::default();
+        dims.estimate(&table.records, cfg);
+
+        print_grid(f, &table.records, cfg, &dims, colors)
+    }
+}
+
+// A grid writes line separators as a single '\n' char,
+// so a content which is written as a string is left untouched.
+struct NewlineWriter<'a, F> {
+    f: &'a mut F,
+    newline: &'a str,
+}
+
+impl<'a, F> NewlineWriter<'a, F> {
+    fn new(f: &'a mut F, newline: &'a str) -> Self {
+        Self { f, newline }
+    }
+}
+
+impl<F: fmt::Write> fmt::Write for NewlineWriter<'_, F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.f.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '\n' => self.f.write_str(self.newline),
+            c => self.f.write_char(c),
+        }
+    }
+}
+
 fn print_grid<F: fmt::Write, D: Dimension>(
     f: &mut F,
     records: &VecRecords<Text<String>>,
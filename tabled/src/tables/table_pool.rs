@@ -111,6 +111,7 @@ pub struct PoolTable {
     config: CompactMultilineConfig,
     dims: PoolTableDimension,
     value: TableValue,
+    alignment_columns: Vec<AlignmentHorizontal>,
 }
 
 impl PoolTable {
@@ -137,6 +138,7 @@ impl PoolTable {
             config: configure_grid(),
             dims: PoolTableDimension::new(DimensionPriority::List, DimensionPriority::List),
             value,
+            alignment_columns: Vec::new(),
         }
     }
 
@@ -181,19 +183,59 @@ impl PoolTable {
     }
 }
 
+impl PoolTable {
+    /// Sets a horizontal alignment of cells by their index in a row.
+    ///
+    /// Cells which are not covered by the list use the alignment set globally.
+    /// Notice that a row inside a column counts its cells on its own.
+    ///
+    /// ```
+    /// use tabled::{grid::config::AlignmentHorizontal, tables::PoolTable};
+    ///
+    /// let data = vec![vec!["Hello", "World"], vec!["1", "2"]];
+    ///
+    /// let table = PoolTable::new(data)
+    ///     .alignment_columns([AlignmentHorizontal::Right])
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-------+-------+\n\
+    ///      | Hello | World |\n\
+    ///      +-------+-------+\n\
+    ///      |     1 | 2     |\n\
+    ///      +-------+-------+"
+    /// )
+    /// ```
+    pub fn alignment_columns<I>(&mut self, list: I) -> &mut Self
+    where
+        I: IntoIterator<Item = AlignmentHorizontal>,
+    {
+        self.alignment_columns = list.into_iter().collect();
+        self
+    }
+}
+
 impl From<TableValue> for PoolTable {
     fn from(value: TableValue) -> Self {
         Self {
             config: configure_grid(),
             dims: PoolTableDimension::new(DimensionPriority::List, DimensionPriority::List),
             value,
+            alignment_columns: Vec::new(),
         }
     }
 }
 
 impl Display for PoolTable {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        print::build_table(&self.value, &self.config, self.dims).fmt(f)
+        print::build_table(
+            &self.value,
+            &self.config,
+            self.dims,
+            &self.alignment_columns,
+        )
+        .fmt(f)
     }
 }
 
@@ -237,7 +279,7 @@ impl<R, D> TableOption<R, CompactMultilineConfig, D> for CompactMultilineConfig
 }
 
 mod print {
-    use std::{cmp::max, collections::HashMap, iter::repeat};
+    use std::{cmp::max, collections::HashMap, iter::repeat_n};
 
     use crate::{
         builder::Builder,
@@ -258,8 +300,10 @@ mod print {
     use super::TableValue;
 
     #[derive(Debug, Default)]
-    struct PrintContext {
+    struct PrintContext<'a> {
         pos: usize,
+        column: usize,
+        alignment_columns: &'a [AlignmentHorizontal],
         is_last_col: bool,
         is_last_row: bool,
         is_first_col: bool,
@@ -299,9 +343,11 @@ mod print {
         val: &TableValue,
         cfg: &CompactMultilineConfig,
         dims_priority: PoolTableDimension,
+        alignment_columns: &[AlignmentHorizontal],
     ) -> String {
         let dims = collect_table_dimensions(val, cfg);
         let ctx = PrintContext {
+            alignment_columns,
             is_last_col: true,
             is_last_row: true,
             is_first_col: true,
@@ -331,7 +377,7 @@ mod print {
         cfg: &CompactMultilineConfig,
         dims: &Dimensions,
         priority: PoolTableDimension,
-        ctx: PrintContext,
+        ctx: PrintContext<'_>,
     ) -> CellData {
         match val {
             TableValue::Cell(text) => generate_value_cell(text, cfg, ctx),
@@ -357,7 +403,7 @@ mod print {
         cfg: &CompactMultilineConfig,
         dims: &Dimensions,
         priority: PoolTableDimension,
-        ctx: PrintContext,
+        ctx: PrintContext<'_>,
     ) -> CellData {
         let array_dims = dims.arrays.get(&ctx.pos).unwrap();
 
@@ -406,6 +452,8 @@ mod print {
             let is_prev_list_not_first = ctx.list && !ctx.list_is_first;
             let valctx = PrintContext {
                 pos: val_pos,
+                column: ctx.column,
+                alignment_columns: ctx.alignment_columns,
                 is_last_col: ctx.is_last_col,
                 is_last_row: ctx.is_last_row && i + 1 == list.len(),
                 is_first_col: ctx.is_first_col,
@@ -446,7 +494,7 @@ mod print {
         cfg: &CompactMultilineConfig,
         dims: &Dimensions,
         priority: PoolTableDimension,
-        ctx: PrintContext,
+        ctx: PrintContext<'_>,
     ) -> CellData {
         let array_dims = dims.arrays.get(&ctx.pos).unwrap();
 
@@ -495,6 +543,8 @@ mod print {
             let is_prev_list_not_first = ctx.list && !ctx.list_is_first;
             let valctx = PrintContext {
                 pos: val_pos,
+                column: i,
+                alignment_columns: ctx.alignment_columns,
                 is_first_col: ctx.is_first_col && i == 0,
                 is_last_col: ctx.is_last_col && i + 1 == list.len(),
                 is_last_row: ctx.is_last_row,
@@ -537,7 +587,7 @@ mod print {
     fn generate_value_cell(
         text: &str,
         cfg: &CompactMultilineConfig,
-        ctx: PrintContext,
+        ctx: PrintContext<'_>,
     ) -> CellData {
         let width = ctx.size.width;
         let height = ctx.size.height;
@@ -548,13 +598,16 @@ mod print {
     fn generate_value_table(
         text: &str,
         cfg: &CompactMultilineConfig,
-        mut ctx: PrintContext,
+        mut ctx: PrintContext<'_>,
     ) -> String {
         if ctx.size.width == 0 || ctx.size.height == 0 {
             return String::new();
         }
 
-        let halignment = cfg.get_alignment_horizontal();
+        let halignment = match ctx.alignment_columns.get(ctx.column) {
+            Some(alignment) => *alignment,
+            None => cfg.get_alignment_horizontal(),
+        };
         let valignment = cfg.get_alignment_vertical();
         let pad = cfg.get_padding();
         let pad_color = convert_border_colors(*cfg.get_padding_color());
@@ -643,9 +696,9 @@ mod print {
                 }
 
                 print_chars(&mut buf, pad.left.fill, pad_color.left, pad.left.size);
-                buf.extend(repeat(' ').take(left));
+                buf.extend(repeat_n(' ', left));
                 buf.push_str(&line);
-                buf.extend(repeat(' ').take(right));
+                buf.extend(repeat_n(' ', right));
                 print_chars(&mut buf, pad.right.fill, pad_color.right, pad.right.size);
 
                 if border.has_right() {
@@ -675,9 +728,9 @@ mod print {
                 }
 
                 print_chars(&mut buf, pad.left.fill, pad_color.left, pad.left.size);
-                buf.extend(repeat(' ').take(left));
+                buf.extend(repeat_n(' ', left));
                 buf.push_str(&line);
-                buf.extend(repeat(' ').take(right));
+                buf.extend(repeat_n(' ', right));
                 print_chars(&mut buf, pad.right.fill, pad_color.right, pad.right.size);
 
                 if border.has_right() {
@@ -731,10 +784,10 @@ mod print {
         match color {
             Some(color) => {
                 buf.push_str(color.get_prefix());
-                buf.extend(repeat(c).take(width));
+                buf.extend(repeat_n(c, width));
                 buf.push_str(color.get_suffix());
             }
-            None => buf.extend(repeat(c).take(width)),
+            None => buf.extend(repeat_n(c, width)),
         }
     }
 
@@ -813,7 +866,7 @@ mod print {
 
         let c = border.top.unwrap_or(' ');
         if splits.is_empty() {
-            buf.extend(repeat(c).take(width));
+            buf.extend(repeat_n(c, width));
         } else {
             let mut splits = splits;
             for i in 0..width {
@@ -891,7 +944,7 @@ mod print {
         }
 
         let c = border.bottom.unwrap_or(' ');
-        buf.extend(repeat(c).take(width));
+        buf.extend(repeat_n(c, width));
 
         if border.has_right() {
             if let Some(color) = color.right_bottom_corner {
@@ -933,7 +986,7 @@ mod print {
         }
     }
 
-    fn config_borders(borders: &mut Borders<char>, ctx: &PrintContext) {
+    fn config_borders(borders: &mut Borders<char>, ctx: &PrintContext<'_>) {
         // set top_left
         {
             if ctx.kv && ctx.kv_is_first {
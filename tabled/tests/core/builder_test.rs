@@ -2,7 +2,12 @@
 
 use std::iter::FromIterator;
 
-use tabled::builder::Builder;
+use tabled::{
+    builder::Builder,
+    grid::records::{FlatRecords, RecordsMut},
+    settings::{object::Columns, Alignment},
+    tables::IterTable,
+};
 
 use testing_table::test_table;
 
@@ -167,6 +172,67 @@ test_table!(
     "+---+---+---+---+--+"
 );
 
+test_table!(
+    push_column_to_3x2,
+    {
+        let mut b = Builder::default();
+        b.push_record(["a", "b"]);
+        b.push_record(["c", "d"]);
+        b.push_record(["e", "f"]);
+        b.push_column(["1", "2", "3"]);
+        b.build()
+    },
+    "+---+---+---+"
+    "| a | b | 1 |"
+    "+---+---+---+"
+    "| c | d | 2 |"
+    "+---+---+---+"
+    "| e | f | 3 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    push_column_to_3x2_longer,
+    {
+        let mut b = Builder::default();
+        b.set_empty("-");
+        b.push_record(["a", "b"]);
+        b.push_record(["c", "d"]);
+        b.push_record(["e", "f"]);
+        b.push_column(["1", "2", "3", "4"]);
+        b.build()
+    },
+    "+---+---+---+"
+    "| a | b | 1 |"
+    "+---+---+---+"
+    "| c | d | 2 |"
+    "+---+---+---+"
+    "| e | f | 3 |"
+    "+---+---+---+"
+    "| - | - | 4 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    push_column_to_3x2_shorter,
+    {
+        let mut b = Builder::default();
+        b.set_empty("-");
+        b.push_record(["a", "b"]);
+        b.push_record(["c", "d"]);
+        b.push_record(["e", "f"]);
+        b.push_column(["1"]);
+        b.build()
+    },
+    "+---+---+---+"
+    "| a | b | 1 |"
+    "+---+---+---+"
+    "| c | d | - |"
+    "+---+---+---+"
+    "| e | f | - |"
+    "+---+---+---+"
+);
+
 test_table!(
     push_column_4,
     {
@@ -907,6 +973,150 @@ test_table!(
     "+------+---------+-----------+"
 );
 
+#[test]
+fn builder_with_capacity() {
+    let records = (0..1000).map(|i| [i.to_string(), format!("name {i}"), (i * 2).to_string()]);
+
+    let mut b = Builder::with_capacity(1000, 3);
+    for record in records.clone() {
+        b.push_record(record);
+    }
+
+    let mut expected = Builder::default();
+    for record in records {
+        expected.push_record(record);
+    }
+
+    assert_eq!(b.count_records(), 1000);
+    assert_eq!(b.count_columns(), 3);
+    assert_eq!(b.build().to_string(), expected.build().to_string());
+}
+
+#[test]
+fn builder_with_capacity_bigger_than_data() {
+    let mut b = Builder::with_capacity(10, 5);
+    b.push_record(["1", "2"]);
+    b.push_record(["3"]);
+
+    assert_eq!(b.count_records(), 2);
+    assert_eq!(b.count_columns(), 2);
+
+    let table = b.build();
+
+    assert_eq!(table.shape(), (2, 2));
+    assert_eq!(
+        table.to_string(),
+        "+---+---+\n\
+         | 1 | 2 |\n\
+         +---+---+\n\
+         | 3 |   |\n\
+         +---+---+"
+    );
+}
+
+#[test]
+fn builder_into_flat_records() {
+    let data = [["1", "2", "3"], ["a", "", "c"], ["d", "e", "f"]];
+
+    let mut records: FlatRecords = Builder::from_iter(data).into();
+    let expected = Builder::from_iter(data).build().to_string();
+
+    assert_eq!(IterTable::new(&records).to_string(), expected);
+
+    records.set((1, 1).into(), "b");
+    assert_eq!(records.get_text((1, 1).into()), "b");
+}
+
+#[test]
+fn builder_into_flat_records_with_ragged_records() {
+    let mut b = Builder::default();
+    b.push_record(["1", "2", "3"]);
+    b.push_record(["a"]);
+
+    let records: FlatRecords = b.clone().into();
+    let expected = IterTable::new(Vec::<Vec<String>>::from(b)).to_string();
+
+    assert_eq!(IterTable::new(&records).to_string(), expected);
+}
+
+#[test]
+fn flat_records_reject_ragged_rows() {
+    let rows = vec![vec![String::from("1"), String::from("2")], vec![]];
+    assert!(FlatRecords::from_rows(rows).is_none());
+    assert!(FlatRecords::new(vec![String::new(); 5], 2, 3).is_none());
+}
+
+test_table!(
+    builder_move_index,
+    {
+        let mut b = Builder::default();
+        b.push_record(["name", "lang", "id"]);
+        b.push_record(["tabled", "rust", "0"]);
+        b.push_record(["pandas", "python", "1"]);
+        b.move_index(2);
+        assert_eq!(b.get_index(), Some(0));
+
+        let mut table = b.build();
+        table.modify(Columns::first(), Alignment::right());
+        table
+    },
+    "+----+--------+--------+"
+    "| id | name   | lang   |"
+    "+----+--------+--------+"
+    "|  0 | tabled | rust   |"
+    "+----+--------+--------+"
+    "|  1 | pandas | python |"
+    "+----+--------+--------+"
+);
+
+#[test]
+fn builder_index_follows_columns() {
+    let mut b = Builder::default();
+    b.push_record(["a", "b", "c"]);
+    b.push_record(["1", "", "3"]);
+    assert_eq!(b.get_index(), None);
+
+    b.set_index(3);
+    assert_eq!(b.get_index(), None);
+
+    b.set_index(2);
+    b.insert_column(0, ["x", "0"]);
+    assert_eq!(b.get_index(), Some(3));
+
+    b.push_column(["y", "4"]);
+    assert_eq!(b.get_index(), Some(3));
+
+    b.remove_column(1);
+    assert_eq!(b.get_index(), Some(2));
+
+    b.remove_record(0);
+    b.clean();
+    assert_eq!(b.get_index(), Some(1));
+
+    b.remove_column(1);
+    assert_eq!(b.get_index(), None);
+
+    b.set_index(0);
+    b.clear();
+    assert_eq!(b.get_index(), None);
+}
+
+#[test]
+fn builder_set_index_is_used_by_index_builder() {
+    let mut b = Builder::default();
+    b.push_record(["name", "lang", "id"]);
+    b.push_record(["tabled", "rust", "0"]);
+    b.push_record(["pandas", "python", "1"]);
+
+    let expected = b.clone().index().column(2).build().to_string();
+
+    b.set_index(2);
+    assert_eq!(b.clone().index().build().to_string(), expected);
+
+    b.move_index(2);
+    assert_eq!(b.index().build().to_string(), expected);
+}
+
 fn clean(mut b: Builder) -> String {
     b.clean();
     b.build().to_string()
@@ -117,3 +117,80 @@ test_table!(
     "| 2-0 | 2-1 | 2-2 |"
     "+--+--+--+--+--+"
 );
+
+test_table!(
+    compact_with_widths,
+    CompactTable::with_widths(Matrix::with_no_frame(3, 3).to_vec(), [1, 3, 5]).to_string(),
+    "+---+-----+-------+"
+    "| 0 | 0-1 | 0-2   |"
+    "|---+-----+-------|"
+    "| 1 | 1-1 | 1-2   |"
+    "|---+-----+-------|"
+    "| 2 | 2-1 | 2-2   |"
+    "+---+-----+-------+"
+);
+
+test_table!(
+    compact_with_widths_truncate_suffix,
+    CompactTable::with_widths([["Hello", "World"], ["Some text", "x"]], [5, 4])
+        .truncate("..")
+        .to_string(),
+    "+-------+------+"
+    "| Hello | Wo.. |"
+    "|-------+------|"
+    "| Som.. | x    |"
+    "+-------+------+"
+);
+
+test_table!(
+    compact_width_truncate,
+    CompactTable::new(Matrix::with_no_frame(3, 3).to_vec())
+        .columns(3)
+        .width(4)
+        .truncate("")
+        .to_string(),
+    "+----+----+----+"
+    "| 0- | 0- | 0- |"
+    "|----+----+----|"
+    "| 1- | 1- | 1- |"
+    "|----+----+----|"
+    "| 2- | 2- | 2- |"
+    "+----+----+----+"
+);
+
+test_table!(
+    compact_multiline_from,
+    CompactTable::from([["1", "Hello\nWorld", "3"], ["4", "5", "6"]]).to_string(),
+    "+---+-------+---+"
+    "| 1 | Hello | 3 |"
+    "|   | World |   |"
+    "|---+-------+---|"
+    "| 4 | 5     | 6 |"
+    "+---+-------+---+"
+);
+
+test_table!(
+    compact_multiline_with_dimension,
+    {
+        let data = vec![vec!["1", "2\n22", "3"], vec!["4\n\n4", "5", "6"]];
+        let mut dims = CompactGridDimension::default();
+        dims.estimate(IterRecords::new(&data, 3, None), &CompactConfig::default());
+        CompactTable::with_dimension(data, dims).columns(3).to_string()
+    },
+    "+---+----+---+"
+    "| 1 | 2  | 3 |"
+    "|   | 22 |   |"
+    "|---+----+---|"
+    "| 4 | 5  | 6 |"
+    "|   |    |   |"
+    "| 4 |    |   |"
+    "+---+----+---+"
+);
+
+test_table!(
+    compact_multiline_height_1,
+    CompactTable::new([["1", "Hello\nWorld", "3"]]).columns(3).width(7).to_string(),
+    "+-------+-------+-------+"
+    "| 1     | Hello | 3     |"
+    "+-------+-------+-------+"
+);
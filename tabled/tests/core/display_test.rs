@@ -0,0 +1,58 @@
+#![cfg(feature = "std")]
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use tabled::display::{duration, duration_precise, system_time, system_time_precise};
+
+#[test]
+fn display_duration() {
+    assert_eq!(duration(&Duration::ZERO), "0s");
+    assert_eq!(duration(&Duration::from_secs(59)), "59s");
+    assert_eq!(duration(&Duration::from_secs(61)), "1m 1s");
+    assert_eq!(duration(&Duration::from_secs(3600)), "1h 0m 0s");
+    assert_eq!(duration(&Duration::from_secs(3723)), "1h 2m 3s");
+    assert_eq!(duration(&Duration::from_secs(90061)), "25h 1m 1s");
+    assert_eq!(duration(&Duration::from_millis(1999)), "1s");
+}
+
+#[test]
+fn display_duration_precise() {
+    let d = Duration::new(3723, 123_456_789);
+
+    assert_eq!(duration_precise::<0>(&d), "1h 2m 3s");
+    assert_eq!(duration_precise::<1>(&d), "1h 2m 3.1s");
+    assert_eq!(duration_precise::<3>(&d), "1h 2m 3.123s");
+    assert_eq!(duration_precise::<9>(&d), "1h 2m 3.123456789s");
+    assert_eq!(duration_precise::<12>(&d), "1h 2m 3.123456789s");
+    assert_eq!(duration_precise::<3>(&Duration::from_micros(5)), "0.000s");
+    assert_eq!(
+        duration_precise::<6>(&Duration::from_micros(5)),
+        "0.000005s"
+    );
+}
+
+#[test]
+fn display_system_time() {
+    assert_eq!(system_time(&UNIX_EPOCH), "1970-01-01T00:00:00Z");
+    assert_eq!(
+        system_time(&(UNIX_EPOCH + Duration::from_secs(951_827_696))),
+        "2000-02-29T12:34:56Z"
+    );
+    assert_eq!(
+        system_time(&(UNIX_EPOCH + Duration::from_secs(4_102_444_799))),
+        "2099-12-31T23:59:59Z"
+    );
+    assert_eq!(
+        system_time(&(UNIX_EPOCH - Duration::from_secs(1))),
+        "1969-12-31T23:59:59Z"
+    );
+}
+
+#[test]
+fn display_system_time_precise() {
+    let time = UNIX_EPOCH + Duration::new(951_827_696, 5_000_000);
+    assert_eq!(system_time_precise::<3>(&time), "2000-02-29T12:34:56.005Z");
+
+    let time = UNIX_EPOCH - Duration::from_millis(250);
+    assert_eq!(system_time_precise::<3>(&time), "1969-12-31T23:59:59.750Z");
+}
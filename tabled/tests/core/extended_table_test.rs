@@ -177,7 +177,7 @@ fn display_dynamic_header_template() {
     {
         build_tabled_type!(TestType, 3, ["22"], ["11111111111"]);
         assert_expanded_display!(
-            std::iter::repeat(TestType).take(11),
+            std::iter::repeat_n(TestType, 11),
             static_table!(
                 "-[ RECORD 0 ]---"
                 "11111111111 | 22"
@@ -486,3 +486,42 @@ fn record_template() {
         )
     );
 }
+
+test_table!(
+    display_with_wrap,
+    {
+        let data = Matrix::new(2, 2).insert((1, 1).into(), "a long string").to_vec();
+        let mut table = ExtendedTable::from(data);
+        assert!(table.wrap(16, false));
+        table.to_string()
+    },
+    "-[ RECORD 0 ]---"
+    "N        | 0"
+    "column 0 | a lon"
+    "         | g str"
+    "         | ing"
+    "column 1 | 0-1"
+    "-[ RECORD 1 ]---"
+    "N        | 1"
+    "column 0 | 1-0"
+    "column 1 | 1-1"
+);
+
+#[test]
+fn wrap_too_small() {
+    let data = Matrix::new(2, 2)
+        .insert((1, 1).into(), "a long string")
+        .to_vec();
+    let mut table = ExtendedTable::from(data);
+    let expected = table.to_string();
+
+    assert!(!table.wrap(11, false));
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn wrap_empty() {
+    let mut table = ExtendedTable::new(Vec::<(String, String)>::new());
+    assert!(table.wrap(16, true));
+    assert_eq!(table.to_string(), "");
+}
@@ -212,3 +212,175 @@ test_table!(
     "| 0   | 0   | 0   | 0   |"
     "+-----+-----+-----+-----+"
 );
+
+test_table!(
+    iter_table_limit_rows,
+    IterTable::new((0..100).map(|i| vec![i.to_string(), format!("{}", i * 1000), format!("row {i}")])).limit_rows(5),
+    "+---+------+-------+"
+    "| 0 | 0    | row 0 |"
+    "+---+------+-------+"
+    "| 1 | 1000 | row 1 |"
+    "+---+------+-------+"
+    "| 2 | 2000 | row 2 |"
+    "+---+------+-------+"
+    "| 3 | 3000 | row 3 |"
+    "+---+------+-------+"
+    "| 4 | 4000 | row 4 |"
+    "+---+------+-------+"
+    "| …                |"
+    "+---+------+-------+"
+);
+
+test_table!(
+    iter_table_limit_rows_not_exceeded,
+    IterTable::new(Matrix::with_no_frame(3, 3).to_vec()).limit_rows(3),
+    "+-----+-----+-----+"
+    "| 0-0 | 0-1 | 0-2 |"
+    "+-----+-----+-----+"
+    "| 1-0 | 1-1 | 1-2 |"
+    "+-----+-----+-----+"
+    "| 2-0 | 2-1 | 2-2 |"
+    "+-----+-----+-----+"
+);
+
+test_table!(
+    iter_table_limit_rows_zero,
+    IterTable::new(Matrix::with_no_frame(3, 3).to_vec()).limit_rows(0),
+    ""
+);
+
+test_table!(
+    iter_table_limit_rows_single_column,
+    IterTable::new((0..100).map(|i| vec![i.to_string()])).limit_rows(2),
+    "+---+"
+    "| 0 |"
+    "+---+"
+    "| 1 |"
+    "+---+"
+    "| … |"
+    "+---+"
+);
+
+test_table!(
+    iter_table_limit_rows_with_columns_and_width,
+    IterTable::new((0..100).map(|i| vec![i.to_string(), format!("row {i}"), String::from("x")]))
+        .columns(2)
+        .width(3)
+        .limit_rows(2),
+    "+-----+-----+"
+    "| 0   | row |"
+    "+-----+-----+"
+    "| 1   | row |"
+    "+-----+-----+"
+    "| …         |"
+    "+-----+-----+"
+);
+
+test_table!(
+    iter_table_limit_rows_with_rows,
+    IterTable::new((0..100).map(|i| vec![i.to_string(), format!("row {i}")]))
+        .rows(4)
+        .limit_rows(2),
+    "+---+-------+"
+    "| 0 | row 0 |"
+    "+---+-------+"
+    "| 1 | row 1 |"
+    "+---+-------+"
+    "| …         |"
+    "+---+-------+"
+);
+
+#[test]
+fn iter_table_limit_rows_stops_reading() {
+    let mut count_read = 0;
+    let data = (0..100).map(|i| {
+        count_read += 1;
+        vec![i.to_string()]
+    });
+
+    let _ = IterTable::new(data).limit_rows(5).to_string();
+
+    assert_eq!(count_read, 6);
+}
+
+test_table!(
+    iter_table_widths,
+    IterTable::new(Matrix::with_no_frame(3, 3).to_vec()).widths([1, 3, 5]),
+    "+---+-----+-------+"
+    "| 0 | 0-1 | 0-2   |"
+    "+---+-----+-------+"
+    "| 1 | 1-1 | 1-2   |"
+    "+---+-----+-------+"
+    "| 2 | 2-1 | 2-2   |"
+    "+---+-----+-------+"
+);
+
+test_table!(
+    iter_table_widths_suffix,
+    IterTable::new(vec![vec!["Hello", "World"], vec!["Some text", "x"]])
+        .widths([5, 4])
+        .suffix(".."),
+    "+-------+------+"
+    "| Hello | Wo.. |"
+    "+-------+------+"
+    "| Som.. | x    |"
+    "+-------+------+"
+);
+
+test_table!(
+    iter_table_widths_with_columns,
+    IterTable::new(Matrix::with_no_frame(2, 3).to_vec())
+        .widths([3])
+        .columns(2),
+    "+-----+--+"
+    "| 0-0 |  |"
+    "+-----+--+"
+    "| 1-0 |  |"
+    "+-----+--+"
+);
+
+test_table!(
+    iter_table_widths_over_width,
+    IterTable::new(Matrix::with_no_frame(2, 2).to_vec())
+        .width(1)
+        .widths([3, 4]),
+    "+-----+------+"
+    "| 0-0 | 0-1  |"
+    "+-----+------+"
+    "| 1-0 | 1-1  |"
+    "+-----+------+"
+);
+
+#[test]
+fn iter_table_widths_single_pass() {
+    use std::{cell::Cell, fmt};
+
+    struct Writer<'a> {
+        count_read: &'a Cell<usize>,
+        read_before_write: Option<usize>,
+    }
+
+    impl fmt::Write for Writer<'_> {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            self.read_before_write.get_or_insert(self.count_read.get());
+            Ok(())
+        }
+    }
+
+    let count_read = Cell::new(0);
+    let data = (0..100).map(|i| {
+        count_read.set(count_read.get() + 1);
+        vec![i.to_string()]
+    });
+
+    let mut writer = Writer {
+        count_read: &count_read,
+        read_before_write: None,
+    };
+
+    IterTable::new(data).widths([3]).fmt(&mut writer).unwrap();
+
+    // a grid peeks one row ahead
+    assert_eq!(writer.read_before_write, Some(2));
+    assert_eq!(count_read.get(), 100);
+}
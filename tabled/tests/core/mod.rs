@@ -1,9 +1,11 @@
 mod builder_test;
 mod compact_table;
+mod display_test;
 mod extended_table_test;
 mod index_test;
 mod iter_table;
 mod iter_test;
 mod kv_test;
 mod pool_table;
+mod serde_test;
 mod table_test;
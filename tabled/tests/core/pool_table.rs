@@ -1,7 +1,10 @@
 #![cfg(feature = "std")]
 
 use tabled::{
-    grid::dimension::{DimensionPriority, PoolTableDimension},
+    grid::{
+        config::AlignmentHorizontal,
+        dimension::{DimensionPriority, PoolTableDimension},
+    },
     settings::{formatting::AlignmentStrategy, Alignment, Margin, Padding, Style},
     tables::{PoolTable, TableValue},
 };
@@ -630,3 +633,22 @@ test_table!(
     "|     | 1-9 |     |"
     "+-----+-----+-----+"
 );
+
+test_table!(
+    pool_table_alignment_columns,
+    PoolTable::from(TableValue::Column(vec![
+        TableValue::Row(vec![TableValue::Cell(String::from("Hello World")), TableValue::Cell(String::from("!"))]),
+        TableValue::Row(vec![
+            TableValue::Cell(String::from("1")),
+            TableValue::Cell(String::from("2")),
+            TableValue::Cell(String::from("3")),
+        ]),
+    ]))
+    .alignment_columns([AlignmentHorizontal::Right, AlignmentHorizontal::Center])
+    .with(Alignment::left()),
+    "+-------------+---+"
+    "| Hello World | ! |"
+    "+-----+-----+-+---+"
+    "|   1 |  2  | 3   |"
+    "+-----+-----+-----+"
+);
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "std", feature = "serde"))]
+
+use tabled::{builder::Builder, settings::Style, Table};
+
+#[test]
+fn serialize_table() {
+    let mut table = Table::new([("Tom", 8), ("Jerry", 7)]);
+    table.with(Style::modern());
+
+    let json = serde_json::to_value(&table).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "header": ["&str", "i32"],
+            "rows": [["Tom", "8"], ["Jerry", "7"]],
+        })
+    );
+}
+
+#[test]
+fn serialize_table_multiline() {
+    let table = Table::new(["Hello\nWorld"]);
+
+    let json = serde_json::to_string(&table).unwrap();
+
+    assert_eq!(json, r#"{"header":["&str"],"rows":[["Hello\nWorld"]]}"#);
+}
+
+#[test]
+fn serialize_empty_table() {
+    let table = Builder::default().build();
+
+    let json = serde_json::to_string(&table).unwrap();
+
+    assert_eq!(json, r#"{"header":[],"rows":[]}"#);
+}
+
+#[test]
+fn deserialize_builder() {
+    let table = Table::new([("Tom", 8), ("Jerry", 7)]);
+
+    let json = serde_json::to_string(&table).unwrap();
+    let builder: Builder = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(builder.build().to_string(), table.to_string());
+}
+
+#[test]
+fn deserialize_builder_missing_field() {
+    let result = serde_json::from_str::<Builder>(r#"{"header":["a"]}"#);
+
+    assert!(result.is_err());
+}
@@ -1,17 +1,29 @@
 #![cfg(feature = "std")]
 
-use std::iter::FromIterator;
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter::FromIterator,
+};
 
 use tabled::{
     builder::Builder,
+    grid::{
+        config::{ColoredConfig, Position},
+        dimension::{CompleteDimensionVecRecords, Dimension},
+        records::{
+            vec_records::{Text, VecRecords},
+            PeekableRecords, RecordsMut,
+        },
+    },
     settings::{
-        formatting::Charset, Height, Highlight, Modify, Padding, Settings, Shadow, Style, Width,
+        formatting::Charset, object::Columns, Alignment, Height, Highlight, Margin, Modify,
+        Padding, Settings, Shadow, Span, Style, TableOptionDyn, Width,
     },
-    Table,
+    Table, TableError,
 };
 
 use crate::matrix::Matrix;
-use testing_table::test_table;
+use testing_table::{assert_table, test_table};
 
 mod default_types {
     use super::*;
@@ -259,6 +271,49 @@ test_table!(
     "+------+"
 );
 
+test_table!(
+    table_tuple_12,
+    Table::new(vec![(0, 1u8, 2i8, 3u16, 4i16, 5u32, 6i32, 7u64, 8i64, '9', "10", false)]),
+    "+-----+----+----+-----+-----+-----+-----+-----+-----+------+------+-------+"
+    "| i32 | u8 | i8 | u16 | i16 | u32 | i32 | u64 | i64 | char | &str | bool  |"
+    "+-----+----+----+-----+-----+-----+-----+-----+-----+------+------+-------+"
+    "| 0   | 1  | 2  | 3   | 4   | 5   | 6   | 7   | 8   | 9    | 10   | false |"
+    "+-----+----+----+-----+-----+-----+-----+-----+-----+------+------+-------+"
+);
+
+test_table!(
+    table_tuple_16,
+    Table::new(vec![(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)]),
+    "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+"
+    "| i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 | i32 |"
+    "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+"
+    "| 0   | 1   | 2   | 3   | 4   | 5   | 6   | 7   | 8   | 9   | 10  | 11  | 12  | 13  | 14  | 15  |"
+    "+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+-----+"
+);
+
+#[test]
+fn table_tuple_length() {
+    use tabled::Tabled;
+
+    type T12 = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+    assert_eq!(<T12 as Tabled>::LENGTH, 12);
+    assert_eq!(<(T12, [u8; 2]) as Tabled>::LENGTH, 14);
+    assert_eq!(<[i32; 4] as Tabled>::LENGTH, 4);
+}
+
+test_table!(
+    table_array,
+    Table::new(vec![[1, 2, 3, 4], [5, 6, 7, 8]]),
+    "+---+---+---+---+"
+    "| 0 | 1 | 2 | 3 |"
+    "+---+---+---+---+"
+    "| 1 | 2 | 3 | 4 |"
+    "+---+---+---+---+"
+    "| 5 | 6 | 7 | 8 |"
+    "+---+---+---+---+"
+);
+
 test_table!(
     table_tuple_vec,
     #[allow(unknown_lints)]
@@ -371,6 +426,26 @@ mod derived {
         "+----+----+"
     );
 
+    test_table!(
+        table_empty_vector_structures_empty_text,
+        Table::new({let v: Vec<TestType> = Vec::new(); v}).with(Style::rounded()).empty_text("no data available"),
+        "╭─────────┬─────────╮"
+        "│ f1      │ f2      │"
+        "├─────────┼─────────┤"
+        "│ no data available │"
+        "╰─────────┴─────────╯"
+    );
+
+    test_table!(
+        table_vector_structures_empty_text,
+        Table::new([TestType { f1: 0, f2: "0" }]).empty_text("no data available"),
+        "+----+----+"
+        "| f1 | f2 |"
+        "+----+----+"
+        "| 0  | 0  |"
+        "+----+----+"
+    );
+
     test_table!(
         table_option,
         Table::new(Some(TestType { f1: 0, f2: "0" })),
@@ -903,3 +978,579 @@ test_table!(
     " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
     " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
 );
+
+test_table!(
+    table_map_cells_uppercase_test,
+    Matrix::new(3, 3)
+        .with(Style::markdown())
+        .map_cells(|_, text| text.to_uppercase()),
+    "| N | COLUMN 0 | COLUMN 1 | COLUMN 2 |"
+    "|---|----------|----------|----------|"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+);
+
+test_table!(
+    table_map_cells_position_test,
+    Matrix::new(2, 2)
+        .with(Style::markdown())
+        .map_cells(|pos, text| if pos.col() == 0 { text.to_owned() } else { format!("{}:{}", pos.row(), pos.col()) }),
+    "| N | 0:1 | 0:2 |"
+    "|---|-----|-----|"
+    "| 0 | 1:1 | 1:2 |"
+    "| 1 | 2:1 | 2:2 |"
+);
+
+#[test]
+fn table_map_cells_recomputes_dimension_test() {
+    let mut table = Matrix::new(2, 2).with(Width::increase(30));
+    assert_eq!(table.total_width(), 30);
+
+    table.map_cells(|_, text| text.repeat(3));
+
+    assert_eq!(table.total_width(), 61);
+    assert_table!(
+        table,
+        "+-----+--------------------------+--------------------------+"
+        "| NNN | column 0column 0column 0 | column 1column 1column 1 |"
+        "+-----+--------------------------+--------------------------+"
+        "| 000 |        0-00-00-0         |        0-10-10-1         |"
+        "+-----+--------------------------+--------------------------+"
+        "| 111 |        1-01-01-0         |        1-11-11-1         |"
+        "+-----+--------------------------+--------------------------+"
+    );
+}
+
+#[test]
+fn table_fmt_to_test() {
+    let table = Matrix::new(100, 20)
+        .with(Style::modern())
+        .modify(Columns::single(3), Width::increase(10))
+        .modify((10, 10), Span::column(3))
+        .clone();
+
+    let mut buf = String::new();
+    table.fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, table.to_string());
+}
+
+#[test]
+fn table_fmt_to_ignores_formatter_options_test() {
+    let table = Matrix::table(2, 2);
+
+    let mut buf = String::new();
+    table.fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, format!("{table}"));
+    assert_ne!(buf, format!("{table:>50}"));
+}
+
+#[test]
+fn table_fmt_to_empty_test() {
+    let mut buf = String::new();
+    Table::default().fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, "");
+}
+
+#[test]
+fn table_get_dimension_test() {
+    let table = Matrix::table(2, 2);
+    let dims = table.get_dimension();
+
+    assert_eq!(dims.get_widths(), Some(&[3, 10, 10][..]));
+    assert_eq!(dims.get_heights(), Some(&[1, 1, 1][..]));
+}
+
+#[test]
+fn table_get_dimension_truncate_test() {
+    let mut table = Matrix::table(2, 2);
+    table.modify(Columns::single(1), Width::truncate(5));
+
+    let dims = table.get_dimension();
+
+    // content is truncated to 5 and a padding is added
+    assert_eq!(dims.get_widths(), Some(&[3, 7, 10][..]));
+    assert_eq!(dims.get_width(1), 7);
+    assert_eq!(table.total_width(), 3 + 7 + 10 + 4);
+}
+
+#[test]
+fn table_get_dimension_padding_test() {
+    let mut table = Matrix::table(2, 2);
+    table.with(Padding::new(2, 2, 1, 0));
+    table.with(Margin::new(3, 3, 3, 3));
+
+    let dims = table.get_dimension();
+
+    assert_eq!(dims.get_widths(), Some(&[5, 12, 12][..]));
+    assert_eq!(dims.get_heights(), Some(&[2, 2, 2][..]));
+}
+
+#[test]
+fn table_get_dimension_multiline_test() {
+    let mut table = Matrix::table(2, 2);
+    table.map_cells(|pos, text| match pos {
+        p if p == (1, 1).into() => String::from("a\nb\nc"),
+        _ => text.to_owned(),
+    });
+
+    let dims = table.get_dimension();
+
+    assert_eq!(dims.get_heights(), Some(&[1, 3, 1][..]));
+}
+
+test_table!(
+    table_set_cell_test,
+    Matrix::new(2, 2).with(Style::modern()).set_cell((1, 1), "Hello\nWorld!!!"),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │ Hello    │   0-1    │"
+    "│   │ World!!! │          │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    table_set_cell_out_of_bounds_test,
+    Matrix::table(2, 2).set_cell((10, 10), "Hello"),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+#[test]
+fn table_set_cell_cached_dimension_test() {
+    let mut table = Matrix::table(2, 2);
+    table.cache_dimension();
+
+    table.set_cell((1, 1), "Hello World");
+    assert_eq!(table.get_dimension().get_widths(), Some(&[3, 13, 10][..]));
+    assert_eq!(table.get_dimension().get_heights(), Some(&[1, 1, 1][..]));
+
+    table.set_cell((2, 2), "1\n2\n3");
+    assert_eq!(table.get_dimension().get_widths(), Some(&[3, 13, 10][..]));
+    assert_eq!(table.get_dimension().get_heights(), Some(&[1, 1, 3][..]));
+
+    table.set_cell((1, 1), "");
+    assert_eq!(table.get_dimension().get_widths(), Some(&[3, 10, 10][..]));
+
+    let mut expected = Matrix::table(2, 2);
+    expected.set_cell((1, 1), "").set_cell((2, 2), "1\n2\n3");
+
+    assert_eq!(table.to_string(), expected.to_string());
+}
+
+#[test]
+fn table_set_cell_cached_dimension_spans_test() {
+    let mut table = Matrix::table(2, 2);
+    table.modify((1, 1), Span::column(2));
+    table.cache_dimension();
+
+    table.set_cell((1, 1), "A very long text to fit");
+
+    let mut expected = Matrix::table(2, 2);
+    expected.set_cell((1, 1), "A very long text to fit");
+    expected.modify((1, 1), Span::column(2));
+
+    assert_eq!(table.to_string(), expected.to_string());
+}
+
+#[test]
+fn table_get_records_mut_cached_dimension_test() {
+    let mut table = Matrix::table(2, 2);
+    table.cache_dimension();
+
+    table.get_records_mut().set((1, 1).into(), "Hello World");
+
+    assert_eq!(table.get_records().get_text((1, 1).into()), "Hello World");
+    assert_eq!(table.get_dimension().get_widths(), Some(&[3, 13, 10][..]));
+
+    let mut expected = Matrix::table(2, 2);
+    expected.set_cell((1, 1), "Hello World");
+
+    assert_eq!(table.to_string(), expected.to_string());
+}
+
+#[test]
+fn table_into_builder_round_trip_test() {
+    let data = Matrix::new(3, 3)
+        .insert((1, 1).into(), "multi\nline")
+        .to_vec();
+    let table = Builder::from_iter(data).build();
+    let expected = table.to_string();
+
+    let table = table.into_builder().build();
+
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn table_into_builder_mutate_cell_test() {
+    let mut table = Matrix::table(2, 2);
+    table.with(Style::modern());
+
+    let mut data: Vec<Vec<String>> = table.into_builder().into();
+    data[1][1] = String::from("changed\ncell");
+
+    let table = Builder::from(data).build();
+
+    assert_table!(
+        table,
+        "+---+----------+----------+"
+        "| N | column 0 | column 1 |"
+        "+---+----------+----------+"
+        "| 0 | changed  | 0-1      |"
+        "|   | cell     |          |"
+        "+---+----------+----------+"
+        "| 1 | 1-0      | 1-1      |"
+        "+---+----------+----------+"
+    );
+}
+
+#[test]
+fn table_into_builder_drops_spans_test() {
+    let mut table = Matrix::table(2, 2);
+    table.modify((1, 1), Span::column(2));
+
+    let table = table.into_builder().build();
+
+    assert_table!(
+        table,
+        "+---+----------+----------+"
+        "| N | column 0 | column 1 |"
+        "+---+----------+----------+"
+        "| 0 | 0-0      | 0-1      |"
+        "+---+----------+----------+"
+        "| 1 | 1-0      | 1-1      |"
+        "+---+----------+----------+"
+    );
+}
+
+test_table!(
+    table_pairs,
+    Table::pairs(vec![("b", 2), ("a", 1), ("c", 30)]),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+    "| b   | 2     |"
+    "+-----+-------+"
+    "| a   | 1     |"
+    "+-----+-------+"
+    "| c   | 30    |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_pairs_empty,
+    Table::pairs(Vec::<(&str, i32)>::new()),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_from_btree_map,
+    Table::from(BTreeMap::from_iter([("b", 2), ("a", 1), ("c", 30)])),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+    "| a   | 1     |"
+    "+-----+-------+"
+    "| b   | 2     |"
+    "+-----+-------+"
+    "| c   | 30    |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_from_btree_map_empty,
+    Table::from(BTreeMap::<String, String>::new()),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_from_hash_map,
+    Table::from(HashMap::<_, _>::from_iter([(10, "ten"), (2, "two"), (1, "one"), (3, "three")])),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+    "| 1   | one   |"
+    "+-----+-------+"
+    "| 10  | ten   |"
+    "+-----+-------+"
+    "| 2   | two   |"
+    "+-----+-------+"
+    "| 3   | three |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_from_hash_map_empty,
+    Table::from(HashMap::<u8, u8>::new()),
+    "+-----+-------+"
+    "| key | value |"
+    "+-----+-------+"
+);
+
+test_table!(
+    table_pairs_with_header,
+    Table::pairs_with_header(vec![("PATH", "/bin")], "name", String::from("env")),
+    "+------+------+"
+    "| name | env  |"
+    "+------+------+"
+    "| PATH | /bin |"
+    "+------+------+"
+);
+
+#[test]
+fn table_newline_crlf_test() {
+    let mut table = Builder::from_iter([["a", "b"], ["multi\nline", "c"]]).build();
+    table.with(Style::psql());
+
+    let (width, height) = (table.total_width(), table.total_height());
+    let lf = table.to_string();
+
+    table.newline("\r\n");
+
+    assert_eq!(table.get_newline(), "\r\n");
+    assert_eq!(table.total_width(), width);
+    assert_eq!(table.total_height(), height);
+    assert_eq!(
+        table.to_string(),
+        " a     | b \r\n\
+         -------+---\r\n \
+          multi | c \r\n \
+          line  |   "
+    );
+    assert_eq!(table.to_string(), lf.replace('\n', "\r\n"));
+    assert_eq!(table.to_string().lines().count(), height);
+
+    let mut buf = String::new();
+    table.fmt_to(&mut buf).unwrap();
+    assert_eq!(buf, table.to_string());
+
+    let aligned = format!("{table:>20}");
+    table.newline("\n");
+    assert_eq!(aligned, format!("{table:>20}").replace('\n', "\r\n"));
+}
+
+#[test]
+fn table_extend_test() {
+    let mut table = Table::new([("Hello", 1), ("World", 2)]);
+    table
+        .with(Style::modern())
+        .modify(Columns::single(1), Padding::new(3, 0, 0, 0));
+
+    table.extend([("Rust", 3)]);
+
+    assert_table!(
+        table,
+        "┌───────┬──────┐"
+        "│ &str  │   i32│"
+        "├───────┼──────┤"
+        "│ Hello │   1  │"
+        "├───────┼──────┤"
+        "│ World │   2  │"
+        "├───────┼──────┤"
+        "│ Rust  │   3  │"
+        "└───────┴──────┘"
+    );
+
+    assert_eq!(
+        table.push_row(["a", "b", "c"]).unwrap_err(),
+        TableError::ColumnCount {
+            expected: 2,
+            found: 3
+        }
+    );
+    assert_eq!(table.count_rows(), 4);
+}
+
+#[test]
+#[should_panic(expected = "expected a row with 2 columns but it has 1")]
+fn table_extend_column_count_mismatch_test() {
+    let mut table = Table::new([("Hello", 1)]);
+    table.extend([("World",)]);
+}
+
+type DynOption = Box<
+    dyn for<'a> TableOptionDyn<
+        VecRecords<Text<String>>,
+        ColoredConfig,
+        CompleteDimensionVecRecords<'a>,
+    >,
+>;
+
+#[test]
+fn table_with_dyn_options_test() {
+    let options: Vec<DynOption> = vec![
+        Box::new(Style::modern()),
+        Box::new(Alignment::right()),
+        Box::new(Modify::new(Columns::last()).with(Alignment::left())),
+        Box::new(Padding::new(2, 0, 0, 0)),
+    ];
+
+    let mut table = Matrix::table(2, 2);
+    table.with(options);
+
+    assert_table!(
+        table,
+        "┌───┬──────────┬──────────┐"
+        "│  N│  column 0│  column 1│"
+        "├───┼──────────┼──────────┤"
+        "│  0│       0-0│  0-1     │"
+        "├───┼──────────┼──────────┤"
+        "│  1│       1-0│  1-1     │"
+        "└───┴──────────┴──────────┘"
+    );
+}
+
+#[test]
+fn table_try_with_dyn_options_test() {
+    let mut table = Matrix::table(3, 3);
+    table.modify((1, 1), Span::column(2));
+
+    let expected = table.to_string();
+
+    let option: DynOption = Box::new(Modify::new((1, 2)).with(Span::row(2)));
+    let result = table.try_with(option);
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::SpanIntersection(Position::new(1, 2)))
+    );
+
+    assert_eq!(table.to_string(), expected);
+}
+
+test_table!(
+    table_try_from_iter_test,
+    Table::try_from_iter(vec![
+        Ok(("GET", 200)),
+        Err("connection reset"),
+        Ok(("POST", 201)),
+        Err("timeout"),
+    ]),
+    "+---------+--------+"
+    "| &str    | i32    |"
+    "+---------+--------+"
+    "| GET     | 200    |"
+    "+---------+--------+"
+    "| \u{1b}[31mconnection reset\u{1b}[39m |"
+    "+---------+--------+"
+    "| POST    | 201    |"
+    "+---------+--------+"
+    "| \u{1b}[31mtimeout\u{1b}[39m          |"
+    "+---------+--------+"
+);
+
+test_table!(
+    table_try_from_iter_single_column_test,
+    Table::try_from_iter(vec![Ok(1), Err("not a number"), Ok(3)]),
+    "+--------------+"
+    "| i32          |"
+    "+--------------+"
+    "| 1            |"
+    "+--------------+"
+    "| \u{1b}[31mnot a number\u{1b}[39m |"
+    "+--------------+"
+    "| 3            |"
+    "+--------------+"
+);
+
+#[test]
+fn table_try_from_iter_keeps_column_count_test() {
+    let table = Table::try_from_iter(vec![Ok(("GET", 200, true)), Err("timeout")]);
+
+    assert_eq!(table.shape(), (3, 3));
+    assert_eq!(
+        table.get_config().get_column_span(Position::new(2, 0)),
+        Some(3)
+    );
+    assert_eq!(table.get_records().get_text(Position::new(2, 0)), "timeout");
+}
+
+#[test]
+fn table_with_capacity_test() {
+    let records = (0..1000).map(|i| (i, format!("name {i}"), i * 2));
+
+    let mut table = Table::with_capacity(1000, 3);
+    for record in records.clone() {
+        table.push_row(record).unwrap();
+    }
+
+    let mut expected = Builder::default();
+    for (a, b, c) in records {
+        expected.push_record([a.to_string(), b, c.to_string()]);
+    }
+
+    assert_eq!(table.shape(), (1000, 3));
+    assert_eq!(table.to_string(), expected.build().to_string());
+}
+
+test_table!(
+    table_with_capacity_bigger_than_data_test,
+    {
+        let mut table = Table::with_capacity(100, 5);
+        table.push_row(("a", 1)).unwrap();
+        table.push_row(("b", 2)).unwrap();
+        assert_eq!(table.shape(), (2, 2));
+        table
+    },
+    "+---+---+"
+    "| a | 1 |"
+    "+---+---+"
+    "| b | 2 |"
+    "+---+---+"
+);
+
+#[test]
+fn table_try_from_iter_no_columns_test() {
+    let table = Table::try_from_iter(vec![Ok([0u8; 0]), Err("timeout"), Ok([])]);
+
+    assert_eq!(table.shape(), (4, 1));
+    assert_eq!(table.get_records().get_text(Position::new(2, 0)), "timeout");
+    assert_table!(
+        table,
+        "+---------+"
+        "|         |"
+        "+---------+"
+        "|         |"
+        "+---------+"
+        "| \u{1b}[31mtimeout\u{1b}[39m |"
+        "+---------+"
+        "|         |"
+        "+---------+"
+    );
+}
+
+test_table!(
+    table_empty_text_no_header,
+    Builder::default().build().with(Style::modern()).empty_text("no data"),
+    "┌─────────┐"
+    "│ no data │"
+    "└─────────┘"
+);
+
+#[test]
+fn table_empty_text_fmt_to_test() {
+    let mut table = Table::from_iter([["id", "name"]]);
+    table.empty_text("-");
+
+    let mut buf = String::new();
+    table.fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, table.to_string());
+    assert_eq!(table.get_empty_text(), Some("-"));
+    assert_eq!(table.count_rows(), 1);
+}
@@ -1,7 +1,9 @@
 #![cfg(feature = "derive")]
 #![cfg(feature = "std")]
 
-use tabled::Tabled;
+use std::borrow::Cow;
+
+use tabled::{CasingStyle, HeadersOptions, Tabled};
 
 // https://users.rust-lang.org/t/create-a-struct-from-macro-rules/19829
 macro_rules! test_tuple {
@@ -146,6 +148,8 @@ mod tuple {
     test_tuple!(order_12, { { #[tabled(order = 2)] u8 #[tabled(order = 2)] u8 #[tabled(order = 2)] u8} }, { 0 1 2 }, { ["0", "1", "2"], ["0", "1", "2"] });
     test_tuple!(order_13, { { #[tabled(order = 1)] u8 #[tabled(order = 1)] u8 #[tabled(order = 1)] u8} }, { 0 1 2 }, { ["0", "2", "1"], ["0", "2", "1"] });
     test_tuple!(order_14, { { #[tabled(order = 2)] u8 #[tabled(order = 1)] u8 #[tabled(order = 0)] u8} }, { 0 1 2 }, { ["2", "1", "0"], ["2", "1", "0"] });
+    test_tuple!(order_last, { { #[tabled(order = "last")] u8 u8 u8} },                                      { 0 1 2 }, { ["1", "2", "0"], ["1", "2", "0"] });
+    test_tuple!(order_first, { { u8 u8 #[tabled(order = "first")] u8} },                                    { 0 1 2 }, { ["2", "0", "1"], ["2", "0", "1"] });
 
     test_tuple!(rename_all, { #[tabled(rename_all = "UPPERCASE")] { u8 sstr } }, { 0 "123" }, { ["0", "1"], ["0", "123"] });
 
@@ -168,6 +172,17 @@ mod tuple {
         }
     );
 
+    test_tuple!(
+        display_with_by_value,
+        { { u8 #[tabled(display_with = "display_u32", by_value)] u32 } },
+        { 0 7 },
+        { ["0", "1"], ["0", "u32 7"] },
+        pre: {
+            fn display_u32(v: u32) -> String {
+                format!("u32 {v}")
+            }
+        }
+    );
     test_tuple!(
         display_option_args,
         { { u8 #[tabled(display_with("display_option", 1, "234"))] Option<sstr> } },
@@ -326,6 +341,24 @@ mod enum_ {
         }
     );
 
+    test_enum!(
+        inline_variant_with_sep,
+        {
+            {
+            #[tabled(inline(sep = "::"))] Auto { model: sstr, engine: sstr }
+            #[tabled(inline(prefix = "b", suffix = ")", sep = "("))] Bikecycle( #[tabled(rename = "name")] sstr )
+            Skateboard
+            }
+        },
+        {},
+        { ["Auto::model", "Auto::engine", "b(name)", "Skateboard"] },
+        {
+            Skateboard => ["", "", "", "+"],
+            Auto { model: "Mini", engine: "v8" } => ["Mini", "v8", "", ""],
+            Bikecycle("A bike") => ["", "", "A bike", ""],
+        }
+    );
+
     test_enum!(
         inline_field_with_display_function,
         {
@@ -350,6 +383,55 @@ mod enum_ {
         }
     );
 
+    test_enum!(
+        inline_field_with_display_context,
+        {
+            {
+                Frontend
+                #[tabled(inline("backend::"))]
+                Backend {
+                    id: u32,
+                    #[tabled(display_with("display", context))]
+                    value: u32
+                }
+            }
+        },
+        {
+            fn display(v: &u32, ctx: tabled::FieldContext) -> String {
+                format!("{} {v} {}", ctx.header(), ctx.index())
+            }
+        },
+        { ["Frontend", "backend::id", "backend::value"] },
+        {
+            Backend { id: 1, value: 123 } => ["", "1", "backend::value 123 2"],
+            Frontend => ["+", "", ""],
+        }
+    );
+
+    test_enum!(
+        inline_field_with_display_function_by_value,
+        {
+            {
+                #[tabled(inline("backend::"))]
+                Backend {
+                    #[tabled(display_with = "display", by_value, rename = "id")]
+                    value: u32
+                }
+                Frontend
+            }
+        },
+        {
+            fn display(v: u32) -> String {
+                format!("#{v}")
+            }
+        },
+        { ["backend::id", "Frontend"] },
+        {
+            Backend { value: 123 } => ["#123", ""],
+            Frontend => ["", "+"],
+        }
+    );
+
     test_enum!(
         inline_field_with_display_self_function,
         {
@@ -824,6 +906,8 @@ mod enum_ {
     test_enum!(order_13, { { #[tabled(order = 0)] V1(u8) #[tabled(order = 0)] V2(u8) #[tabled(order = 0)] V3(u8) } },     {}, { ["V3", "V1", "V2"] }, { V1(0) => ["", "+", ""], V2(0) => ["", "", "+"], V3(0) => ["+", "", ""],});
     test_enum!(order_14, { { #[tabled(order = 1)] V1(u8) #[tabled(order = 1)] V2(u8) #[tabled(order = 1)] V3(u8) } },     {}, { ["V1", "V3", "V2"] }, { V1(0) => ["+", "", ""], V2(0) => ["", "", "+"], V3(0) => ["", "+", ""],});
     test_enum!(order_15, { { #[tabled(order = 2)] V1(u8) #[tabled(order = 2)] V2(u8) #[tabled(order = 2)] V3(u8) } },     {}, { ["V1", "V2", "V3"] }, { V1(0) => ["+", "", ""], V2(0) => ["", "+", ""], V3(0) => ["", "", "+"],});
+    test_enum!(order_last,  { { #[tabled(order = "last")] V1(u8) V2(u8) V3(u8) } },                                      {}, { ["V2", "V3", "V1"] }, { V1(0) => ["", "", "+"], V2(0) => ["+", "", ""], V3(0) => ["", "+", ""],});
+    test_enum!(order_first, { { V1(u8) V2(u8) #[tabled(order = "first")] V3(u8) } },                                     {}, { ["V3", "V1", "V2"] }, { V1(0) => ["", "+", ""], V2(0) => ["", "", "+"], V3(0) => ["+", "", ""],});
 
     test_enum!(order_0_inlined, { #[tabled(inline)] { #[tabled(order = 1)] V1(u8) V2(u8) V3(u8) } }, {}, { ["TestType"] }, { V1(0) => ["V1"], V2(0) => ["V2"], V3(0) => ["V3"], });
 
@@ -917,6 +1001,74 @@ mod unit {
     }
 }
 
+mod length {
+    use super::*;
+
+    #[test]
+    fn structure_length_is_const() {
+        #[derive(Tabled)]
+        struct Inner {
+            f1: u8,
+            f2: u8,
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            f1: u8,
+            #[tabled(inline)]
+            f2: Inner,
+        }
+
+        const N: usize = <St as Tabled>::LENGTH;
+        let buf = [""; N];
+
+        assert_eq!(N, 3);
+        assert_eq!(buf.len(), St::headers().len());
+    }
+
+    #[test]
+    fn enum_length_is_const() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        enum En {
+            V1,
+            #[tabled(inline)]
+            V2 {
+                f1: u8,
+                f2: u8,
+            },
+        }
+
+        const N: usize = <En as Tabled>::LENGTH;
+        let buf = [""; N];
+
+        assert_eq!(N, 3);
+        assert_eq!(buf.len(), En::headers().len());
+    }
+
+    #[test]
+    fn inline_enum_length_is_const() {
+        #[allow(dead_code)]
+        #[derive(Tabled)]
+        enum Inner {
+            A,
+            B,
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            f1: u8,
+            #[tabled(inline)]
+            f2: Inner,
+        }
+
+        const N: usize = <St as Tabled>::LENGTH;
+
+        assert_eq!(N, 3);
+        assert_eq!(N, St::headers().len());
+    }
+}
+
 mod structure {
     use super::*;
 
@@ -1034,6 +1186,90 @@ mod structure {
             ["0", "Maxim", "BNTU", "true"]
         }
     );
+    test_struct!(
+        inline_with_sep,
+        {
+            {
+                name: sstr,
+                #[tabled(inline(sep = "."))]
+                addr: Address,
+            }
+        }
+        {
+            #[derive(Tabled)]
+            struct Address { city: sstr, street: sstr }
+        }
+        {
+            name: "Maxim", addr: Address { city: "Minsk", street: "Lenina" }
+        }
+        {
+            ["name", "addr.city", "addr.street"],
+            ["Maxim", "Minsk", "Lenina"]
+        }
+    );
+    test_struct!(
+        inline_with_sep_renamed,
+        {
+            {
+                name: sstr,
+                #[tabled(rename = "address", inline(sep = "."))]
+                addr: Address,
+            }
+        }
+        {
+            #[derive(Tabled)]
+            struct Address { city: sstr, street: sstr }
+        }
+        {
+            name: "Maxim", addr: Address { city: "Minsk", street: "Lenina" }
+        }
+        {
+            ["name", "address.city", "address.street"],
+            ["Maxim", "Minsk", "Lenina"]
+        }
+    );
+    test_struct!(
+        inline_with_prefix_suffix_sep,
+        {
+            {
+                name: sstr,
+                #[tabled(inline(prefix = "ed", suffix = "]", sep = "["))]
+                ed: Education,
+            }
+        }
+        {
+            #[derive(Tabled)]
+            struct Education { uni: sstr, graduated: bool }
+        }
+        {
+            name: "Maxim", ed: Education { uni: "BNTU", graduated: true }
+        }
+        {
+            ["name", "ed[uni]", "ed[graduated]"],
+            ["Maxim", "BNTU", "true"]
+        }
+    );
+    test_struct!(
+        inline_with_suffix,
+        {
+            {
+                name: sstr,
+                #[tabled(inline(suffix = " (ed)"))]
+                ed: Education,
+            }
+        }
+        {
+            #[derive(Tabled)]
+            struct Education { uni: sstr, graduated: bool }
+        }
+        {
+            name: "Maxim", ed: Education { uni: "BNTU", graduated: true }
+        }
+        {
+            ["name", "uni (ed)", "graduated (ed)"],
+            ["Maxim", "BNTU", "true"]
+        }
+    );
     test_struct!(
         display_with,
         {
@@ -1054,6 +1290,23 @@ mod structure {
         { f1: 0, f2: Some("v2") }
         { ["f1", "f2"], ["0", "some v2"] }
     );
+    test_struct!(
+        display_with_by_value,
+        {
+            {
+                f1: u8,
+                #[tabled(display_with = "display_u32", by_value = true)]
+                f2: u32,
+            }
+        }
+        {
+            fn display_u32(v: u32) -> String {
+                format!("u32 {v}")
+            }
+        }
+        { f1: 0, f2: 7 }
+        { ["f1", "f2"], ["0", "u32 7"] }
+    );
     test_struct!(
         display_with_args,
         {
@@ -1071,6 +1324,99 @@ mod structure {
         { f1: 0, f2: Some("v2") }
         { ["f1", "f2"], ["0", "1 2 3"] }
     );
+    test_struct!(
+        display_with_context,
+        {
+            {
+                f1: u8,
+                #[tabled(inline)]
+                f2: (u8, u8),
+                #[tabled(display_with("display_index", context), rename = "index")]
+                f3: u8,
+            }
+        }
+        {
+            fn display_index(v: &u8, ctx: tabled::FieldContext) -> String {
+                format!("{v} {} {}", ctx.header(), ctx.index())
+            }
+        }
+        { f1: 0, f2: (1, 2), f3: 3 }
+        { ["f1", "u8", "u8", "index"], ["0", "1", "2", "3 index 3"] }
+    );
+    test_struct!(
+        display_with_args_and_context,
+        {
+            {
+                f1: u8,
+                #[tabled(display_with("display_index", self.f1, "f2", context))]
+                f2: u8,
+            }
+        }
+        {
+            fn display_index(v: u8, text: &str, ctx: tabled::FieldContext) -> String {
+                format!("{v} {text} {}", ctx.index())
+            }
+        }
+        { f1: 0, f2: 1 }
+        { ["f1", "f2"], ["0", "0 f2 1"] }
+    );
+    test_struct!(
+        display_with_borrow,
+        {
+            {
+                #[tabled(display_with("display_flag", borrow))]
+                f1: bool,
+                #[tabled(display_with("display_trimmed", borrow))]
+                f2: sstr,
+            }
+        }
+        {
+            fn display_flag(v: &bool) -> &'static str {
+                if *v { "yes" } else { "no" }
+            }
+
+            fn display_trimmed<'a>(v: &'a &str) -> &'a str {
+                v.trim()
+            }
+        }
+        { f1: true, f2: "  v2  " }
+        { ["f1", "f2"], ["yes", "v2"] }
+    );
+    test_struct!(
+        display_with_context_and_borrow,
+        {
+            {
+                f1: u8,
+                #[tabled(display_with("display_header", context, borrow))]
+                f2: u8,
+            }
+        }
+        {
+            fn display_header(_: &u8, ctx: tabled::FieldContext) -> &'static str {
+                ctx.header()
+            }
+        }
+        { f1: 0, f2: 1 }
+        { ["f1", "f2"], ["0", "f2"] }
+    );
+    test_struct!(
+        display_with_context_ordered,
+        {
+            {
+                #[tabled(display_with("display_index", context))]
+                f1: u8,
+                #[tabled(display_with("display_index", context), order = 0)]
+                f2: u8,
+            }
+        }
+        {
+            fn display_index(v: &u8, ctx: tabled::FieldContext) -> String {
+                format!("{v} {}", ctx.index())
+            }
+        }
+        { f1: 0, f2: 1 }
+        { ["f2", "f1"], ["1 0", "0 1"] }
+    );
     test_struct!(
         display_with_args_using_self,
         {
@@ -1206,6 +1552,15 @@ mod structure {
     test_struct!(order_10, { { #[tabled(order = 2)] f0: u8, #[tabled(order = 1)] f1: u8, f2: u8 } }                      {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f1", "f0"], ["2", "1", "0"] });
     test_struct!(order_11, { { #[tabled(order = 2)] f0: u8, #[tabled(order = 2)] f1: u8, #[tabled(order = 1)] f2: u8 } } {} { f0: 0, f1: 1, f2: 2 } { ["f0", "f2", "f1"], ["0", "2", "1"] });
     test_struct!(order_12, { { #[tabled(order = 2)] f0: u8, #[tabled(order = 1)] f1: u8, #[tabled(order = 0)] f2: u8 } } {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f1", "f0"], ["2", "1", "0"] });
+    test_struct!(order_last_0, { { #[tabled(order = "last")] f0: u8, f1: u8, f2: u8 } }                                 {} { f0: 0, f1: 1, f2: 2 } { ["f1", "f2", "f0"], ["1", "2", "0"] });
+    test_struct!(order_last_1, { { f0: u8, #[tabled(order = "last")] f1: u8, f2: u8 } }                                 {} { f0: 0, f1: 1, f2: 2 } { ["f0", "f2", "f1"], ["0", "2", "1"] });
+    test_struct!(order_last_2, { { #[tabled(order = "last")] f0: u8, #[tabled(order = "last")] f1: u8, f2: u8 } }      {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f0", "f1"], ["2", "0", "1"] });
+    test_struct!(order_first_0, { { f0: u8, f1: u8, #[tabled(order = "first")] f2: u8 } }                               {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f0", "f1"], ["2", "0", "1"] });
+    test_struct!(order_first_1, { { f0: u8, #[tabled(order = "first")] f1: u8, #[tabled(order = "first")] f2: u8 } }    {} { f0: 0, f1: 1, f2: 2 } { ["f1", "f2", "f0"], ["1", "2", "0"] });
+    test_struct!(order_first_last, { { #[tabled(order = "last")] f0: u8, f1: u8, #[tabled(order = "first")] f2: u8 } }  {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f1", "f0"], ["2", "1", "0"] });
+    test_struct!(order_last_index, { { #[tabled(order = "last")] f0: u8, f1: u8, #[tabled(order = 2)] f2: u8 } }        {} { f0: 0, f1: 1, f2: 2 } { ["f1", "f0", "f2"], ["1", "0", "2"] });
+    test_struct!(order_first_index, { { f0: u8, #[tabled(order = "first")] f1: u8, #[tabled(order = 0)] f2: u8 } }      {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f1", "f0"], ["2", "1", "0"] });
+    test_struct!(order_last_skip, { { #[tabled(order = "last")] f0: u8, #[tabled(skip)] f1: u8, f2: u8 } }              {} { f0: 0, f1: 1, f2: 2 } { ["f2", "f0"], ["2", "0"] });
 
     test_struct!(
         rename_all,
@@ -1485,6 +1840,57 @@ fn test_skip_enum_0() {
     assert_eq!(Letters::Digit.fields(), vec!["", ""]);
 }
 
+#[test]
+fn test_skip_enum_variant_with_order_and_inline() {
+    #[allow(dead_code)]
+    #[derive(Tabled)]
+    enum Shape {
+        #[tabled(inline)]
+        Rect { width: u8, height: u8 },
+        #[tabled(skip)]
+        Unknown,
+        #[tabled(order = 0)]
+        Circle(u8),
+    }
+
+    assert_eq!(Shape::headers(), vec!["Circle", "width", "height"]);
+    assert_eq!(<Shape as Tabled>::LENGTH, 3);
+    assert_eq!(Shape::Circle(1).fields(), vec!["+", "", ""]);
+    assert_eq!(
+        Shape::Rect {
+            width: 2,
+            height: 3
+        }
+        .fields(),
+        vec!["", "2", "3"]
+    );
+    assert_eq!(Shape::Unknown.fields(), vec!["", "", ""]);
+
+    let data = [
+        Shape::Circle(1),
+        Shape::Unknown,
+        Shape::Rect {
+            width: 2,
+            height: 3,
+        },
+    ];
+
+    let table = tabled::Table::new(data).to_string();
+
+    assert_eq!(
+        table,
+        "+--------+-------+--------+\n\
+         | Circle | width | height |\n\
+         +--------+-------+--------+\n\
+         | +      |       |        |\n\
+         +--------+-------+--------+\n\
+         |        |       |        |\n\
+         +--------+-------+--------+\n\
+         |        | 2     | 3      |\n\
+         +--------+-------+--------+"
+    );
+}
+
 #[test]
 fn test_reimport_trait_by_crate_attribute() {
     pub mod new_module {
@@ -1525,6 +1931,155 @@ fn test_reimport_trait_by_crate_attribute() {
     );
 }
 
+#[test]
+fn test_reimport_trait_by_crate_attribute_inline() {
+    pub mod new_module {
+        pub trait Tabled {
+            const LENGTH: usize;
+
+            fn fields(&self) -> Vec<std::borrow::Cow<'_, str>>;
+            fn headers() -> Vec<std::borrow::Cow<'static, str>>;
+        }
+    }
+
+    mod tabled {}
+
+    #[derive(Tabled)]
+    #[tabled(crate = "new_module")]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    #[derive(Tabled)]
+    #[tabled(crate = "new_module")]
+    struct Shape {
+        name: &'static str,
+        #[tabled(inline)]
+        center: Point,
+    }
+
+    let shape = Shape {
+        name: "circle",
+        center: Point { x: 1, y: 2 },
+    };
+
+    assert_eq!(<Shape as new_module::Tabled>::LENGTH, 3);
+    assert_eq!(
+        <Shape as new_module::Tabled>::headers(),
+        vec!["name", "x", "y"]
+    );
+    assert_eq!(
+        <Shape as new_module::Tabled>::fields(&shape),
+        vec!["circle", "1", "2"]
+    );
+}
+
+#[test]
+fn test_crate_attribute_with_reexported_crate() {
+    mod my_shim {
+        pub use ::tabled;
+    }
+
+    mod tabled {}
+
+    #[allow(dead_code)]
+    #[derive(my_shim::tabled::Tabled)]
+    #[tabled(crate = "my_shim::tabled")]
+    enum Letters {
+        #[tabled(inline)]
+        Vowels {
+            character: char,
+            #[tabled(display_with("display_lang", context))]
+            lang: u8,
+        },
+        Consonant(char),
+    }
+
+    #[derive(my_shim::tabled::Tabled)]
+    #[tabled(crate = "my_shim::tabled")]
+    struct Letter {
+        name: &'static str,
+        #[tabled(inline)]
+        kind: Letters,
+    }
+
+    fn display_lang(lang: &u8, ctx: my_shim::tabled::FieldContext) -> String {
+        format!("{}={}", ctx.header(), lang)
+    }
+
+    use my_shim::tabled::Tabled;
+
+    assert_eq!(
+        Letter::headers(),
+        vec!["name", "character", "lang", "Consonant"]
+    );
+    assert_eq!(
+        Letter {
+            name: "a",
+            kind: Letters::Vowels {
+                character: 'a',
+                lang: 1
+            }
+        }
+        .fields(),
+        vec!["a", "a", "lang=1", ""]
+    );
+
+    let table = my_shim::tabled::Table::new([Letter {
+        name: "c",
+        kind: Letters::Consonant('c'),
+    }]);
+
+    assert_eq!(
+        table.to_string(),
+        "+------+-----------+------+-----------+\n\
+         | name | character | lang | Consonant |\n\
+         +------+-----------+------+-----------+\n\
+         | c    |           |      | +         |\n\
+         +------+-----------+------+-----------+"
+    );
+}
+
+#[test]
+fn test_display_with_borrow_doesnt_allocate() {
+    #[derive(Tabled)]
+    struct Struct {
+        #[tabled(display_with("str::trim", borrow))]
+        name: String,
+        #[tabled(display_with = "display_name")]
+        name2: String,
+    }
+
+    #[derive(Tabled)]
+    enum Enum {
+        #[tabled(display_with("display_variant", borrow))]
+        Variant,
+    }
+
+    fn display_name(s: &str) -> &str {
+        s
+    }
+
+    fn display_variant() -> &'static str {
+        "v"
+    }
+
+    let value = Struct {
+        name: String::from(" name "),
+        name2: String::from("name"),
+    };
+    let fields = value.fields();
+
+    assert_eq!(fields, ["name", "name"]);
+    assert!(matches!(fields[0], Cow::Borrowed(_)));
+    assert!(matches!(fields[1], Cow::Owned(_)));
+
+    let fields = Enum::Variant.fields();
+    assert_eq!(fields, ["v"]);
+    assert!(matches!(fields[0], Cow::Borrowed(_)));
+}
+
 #[test]
 fn test_display_with_2() {
     #[derive(tabled::Tabled)]
@@ -1637,3 +2192,358 @@ mod __ {
         struct __;
     }
 }
+
+#[test]
+#[allow(dead_code)]
+fn test_headers_cased() {
+    #[derive(Tabled)]
+    #[tabled(rename_all = "PascalCase")]
+    struct Address {
+        street_name: &'static str,
+        #[tabled(rename = "zip")]
+        zip_code: &'static str,
+    }
+
+    #[derive(Tabled)]
+    #[tabled(rename_all = "kebab-case")]
+    struct Person {
+        first_name: &'static str,
+        #[tabled(rename = "Last Name")]
+        last_name: &'static str,
+        #[tabled(inline)]
+        home_address: Address,
+        #[tabled(inline(sep = "."))]
+        work_address: Address,
+    }
+
+    assert_eq!(
+        Person::headers(),
+        [
+            "first-name",
+            "Last Name",
+            "StreetName",
+            "zip",
+            "work-address.StreetName",
+            "work-address.zip"
+        ]
+    );
+    assert_eq!(
+        Person::headers_cased(CasingStyle::Upper),
+        [
+            "FIRSTNAME",
+            "Last Name",
+            "STREETNAME",
+            "zip",
+            "WORKADDRESS.STREETNAME",
+            "WORKADDRESS.zip"
+        ]
+    );
+    assert_eq!(
+        Person::headers_cased(CasingStyle::Verbatim),
+        [
+            "first_name",
+            "Last Name",
+            "street_name",
+            "zip",
+            "work_address.street_name",
+            "work_address.zip"
+        ]
+    );
+}
+
+#[test]
+fn test_casing_style_cast() {
+    let name = "HTTPServer_name2";
+    assert_eq!(CasingStyle::Camel.cast(name), "httpServerName2");
+    assert_eq!(CasingStyle::Kebab.cast(name), "http-server-name2");
+    assert_eq!(CasingStyle::Pascal.cast(name), "HttpServerName2");
+    assert_eq!(CasingStyle::ScreamingSnake.cast(name), "HTTP_SERVER_NAME2");
+    assert_eq!(CasingStyle::Snake.cast(name), "http_server_name2");
+    assert_eq!(CasingStyle::Lower.cast(name), "httpservername2");
+    assert_eq!(CasingStyle::Upper.cast(name), "HTTPSERVERNAME2");
+    assert_eq!(CasingStyle::Verbatim.cast(name), name);
+
+    assert_eq!(CasingStyle::Snake.cast("__middleName  x"), "middle_name_x");
+    assert_eq!(CasingStyle::Pascal.cast("XMLHttpRequest"), "XmlHttpRequest");
+    assert_eq!(CasingStyle::Camel.cast(""), "");
+}
+
+#[test]
+#[allow(dead_code)]
+fn test_headers_cased_enum() {
+    #[derive(Tabled)]
+    enum Vehicle {
+        #[tabled(inline("Auto::"))]
+        SportCar {
+            max_speed: u8,
+            #[tabled(rename = "brand")]
+            brand_name: &'static str,
+        },
+        #[tabled(rename = "bike")]
+        Bicycle,
+        ElectricScooter(u8),
+    }
+
+    assert_eq!(
+        Vehicle::headers_cased(CasingStyle::ScreamingSnake),
+        ["Auto::MAX_SPEED", "Auto::brand", "bike", "ELECTRIC_SCOOTER"]
+    );
+    assert_eq!(
+        Vehicle::headers_with(HeadersOptions::new().casing(CasingStyle::Kebab)),
+        ["Auto::max-speed", "Auto::brand", "bike", "electric-scooter"]
+    );
+    assert_eq!(
+        Vehicle::headers_with(HeadersOptions::new()),
+        Vehicle::headers()
+    );
+    assert_eq!(
+        Vehicle::headers_cased(CasingStyle::Verbatim),
+        Vehicle::headers()
+    );
+}
+
+#[test]
+fn test_inline_depth() {
+    #[derive(Tabled)]
+    struct Point {
+        lat: f32,
+        lon: f32,
+    }
+
+    #[derive(Tabled)]
+    struct Address {
+        city: &'static str,
+        #[tabled(inline)]
+        point: Point,
+    }
+
+    #[derive(Tabled)]
+    #[tabled(rename_all = "UPPERCASE")]
+    struct Person {
+        name: &'static str,
+        #[tabled(inline)]
+        address: Address,
+    }
+
+    #[derive(Tabled)]
+    struct Company {
+        title: &'static str,
+        #[tabled(inline(depth = 1))]
+        owner: Person,
+    }
+
+    let company = Company {
+        title: "Tabled",
+        owner: Person {
+            name: "Maxim",
+            address: Address {
+                city: "Minsk",
+                point: Point {
+                    lat: 53.9,
+                    lon: 27.5,
+                },
+            },
+        },
+    };
+
+    assert_eq!(Company::LENGTH, 3);
+    assert_eq!(Company::headers(), ["title", "NAME", "ADDRESS"]);
+    assert_eq!(company.fields(), ["Tabled", "Maxim", "Minsk, 53.9, 27.5"]);
+    assert_eq!(
+        Company::headers_cased(CasingStyle::Verbatim),
+        ["title", "name", "address"]
+    );
+    assert_eq!(<Option<Company>>::fields(&None), ["", "", ""]);
+
+    assert_eq!(Person::LENGTH, 4);
+    assert_eq!(Person::LENGTH_LIMITED[..3], [2, 3, 4]);
+    assert_eq!(
+        Person::headers_with(HeadersOptions::new().depth(0)),
+        ["NAME", "ADDRESS"]
+    );
+    assert_eq!(
+        Person::headers_with(HeadersOptions::new().depth(1).casing(CasingStyle::Kebab)),
+        ["name", "city", "point"]
+    );
+    assert_eq!(
+        company.owner.fields_limited(1),
+        ["Maxim", "Minsk", "53.9, 27.5"]
+    );
+
+    let table = tabled::Table::new([company]).to_string();
+    assert_eq!(
+        table,
+        "+--------+-------+-------------------+\n\
+         | title  | NAME  | ADDRESS           |\n\
+         +--------+-------+-------------------+\n\
+         | Tabled | Maxim | Minsk, 53.9, 27.5 |\n\
+         +--------+-------+-------------------+"
+    );
+}
+
+#[test]
+fn test_inline_depth_nested() {
+    #[derive(Tabled)]
+    struct Point {
+        lat: f32,
+        lon: f32,
+    }
+
+    #[derive(Tabled)]
+    struct Address {
+        city: &'static str,
+        #[tabled(inline(sep = "."))]
+        point: Point,
+    }
+
+    #[derive(Tabled)]
+    struct Person {
+        name: &'static str,
+        #[tabled(inline(sep = ".", depth = 8))]
+        address: Address,
+    }
+
+    #[derive(Tabled)]
+    struct Company {
+        #[tabled(inline(depth = 1))]
+        owner: Person,
+        #[tabled(inline(depth = 2))]
+        manager: Person,
+    }
+
+    let person = Person {
+        name: "Maxim",
+        address: Address {
+            city: "Minsk",
+            point: Point {
+                lat: 53.9,
+                lon: 27.5,
+            },
+        },
+    };
+    assert_eq!(Person::LENGTH, 4);
+    assert_eq!(
+        Person::headers(),
+        [
+            "name",
+            "address.city",
+            "address.point.lat",
+            "address.point.lon"
+        ]
+    );
+    assert_eq!(person.fields(), ["Maxim", "Minsk", "53.9", "27.5"]);
+
+    let company = Company {
+        owner: Person {
+            name: "Maxim",
+            address: Address {
+                city: "Minsk",
+                point: Point { lat: 0.0, lon: 1.0 },
+            },
+        },
+        manager: person,
+    };
+    assert_eq!(Company::LENGTH, 5);
+    assert_eq!(
+        Company::headers(),
+        ["name", "address", "name", "address.city", "address.point"]
+    );
+    assert_eq!(
+        company.fields(),
+        ["Maxim", "Minsk, 0, 1", "Maxim", "Minsk", "53.9, 27.5"]
+    );
+}
+
+#[test]
+fn test_generic_bounds() {
+    #[derive(Tabled)]
+    struct Wrapper<T> {
+        value: T,
+        count: usize,
+    }
+
+    assert_eq!(Wrapper::<u8>::headers(), ["value", "count"]);
+    assert_eq!(Wrapper { value: 1, count: 2 }.fields(), ["1", "2"]);
+    assert_eq!(
+        Wrapper {
+            value: "str",
+            count: 2
+        }
+        .fields(),
+        ["str", "2"]
+    );
+}
+
+#[test]
+fn test_generic_bounds_inline() {
+    #[derive(Tabled)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Tabled)]
+    struct Pair<A, B> {
+        #[tabled(inline)]
+        left: A,
+        right: B,
+    }
+
+    #[derive(Tabled)]
+    enum Shape<T> {
+        #[tabled(inline)]
+        Dot(#[tabled(inline)] T),
+        #[allow(dead_code)]
+        Nothing(T),
+    }
+
+    let pair = Pair {
+        left: Point { x: 1, y: 2 },
+        right: "point",
+    };
+    assert_eq!(Pair::<Point, &str>::headers(), ["x", "y", "right"]);
+    assert_eq!(pair.fields(), ["1", "2", "point"]);
+
+    let shape = Shape::Dot(Point { x: 1, y: 2 });
+    assert_eq!(Shape::<Point>::headers(), ["x", "y", "Nothing"]);
+    assert_eq!(shape.fields(), ["1", "2", ""]);
+}
+
+#[test]
+fn test_generic_bounds_not_required() {
+    fn count<T>(list: &[T]) -> String {
+        list.len().to_string()
+    }
+
+    #[derive(Tabled)]
+    struct List<T> {
+        #[tabled(display_with = "count")]
+        items: Vec<T>,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        last: Option<T>,
+    }
+
+    #[derive(Tabled)]
+    struct Bounded<T: std::fmt::Display, U>
+    where
+        U: std::fmt::Display + Clone,
+    {
+        value: T,
+        other: U,
+    }
+
+    struct NoDisplay;
+
+    let list = List {
+        items: vec![NoDisplay, NoDisplay],
+        last: None,
+    };
+    assert_eq!(list.fields(), ["2"]);
+
+    let bounded = Bounded {
+        value: 1,
+        other: "a",
+    };
+    assert_eq!(bounded.fields(), ["1", "a"]);
+}
@@ -1 +1,3 @@
+// a few tests declare types only to check that the derive compiles for them
+#[allow(dead_code)]
 mod derive_test;
@@ -1,9 +1,10 @@
 #![cfg(feature = "std")]
 
 use tabled::settings::{
+    formatting::TrimStrategy,
     location::ByColumnName,
     object::{Columns, Rows, Segment},
-    Alignment, Modify, Padding, Style,
+    Alignment, Format, Modify, Padding, Style, Width,
 };
 
 use crate::matrix::Matrix;
@@ -136,3 +137,75 @@ test_table!(
         " 1 |   1-0    |   1-1    |   1-2    "
         " 2 |   2-0    |   2-1    |   2-2    "
 );
+
+test_table!(
+    alignment_by_column_name,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .modify(ByColumnName::new("column 1"), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |      0-1 |   0-2    "
+    " 1 |   1-0    |      1-1 |   1-2    "
+    " 2 |   2-0    |      2-1 |   2-2    "
+);
+
+test_table!(
+    alignment_by_column_name_duplicates,
+    Matrix::new(2, 3)
+        .with(Style::psql())
+        .modify(Rows::first(), Format::content(|_| String::from("name")))
+        .modify(ByColumnName::new("name"), Alignment::right()),
+    " name | name | name | name "
+    "------+------+------+------"
+    "    0 |  0-0 |  0-1 |  0-2 "
+    "    1 |  1-0 |  1-1 |  1-2 "
+);
+
+test_table!(
+    alignment_by_column_name_duplicates_first,
+    Matrix::new(2, 3)
+        .with(Style::psql())
+        .modify(Rows::first(), Format::content(|_| String::from("name")))
+        .modify(ByColumnName::new("name").first(), Alignment::right()),
+    " name | name | name | name "
+    "------+------+------+------"
+    "    0 | 0-0  | 0-1  | 0-2  "
+    "    1 | 1-0  | 1-1  | 1-2  "
+);
+
+test_table!(
+    alignment_per_line_wrapped,
+    Matrix::iter([["A long line which is wrapped", "1"]])
+        .with(Style::psql())
+        .modify(Columns::first(), Width::wrap(12).keep_words(true))
+        .modify(Columns::first(), Alignment::center().per_line())
+        .modify(Columns::first(), TrimStrategy::Horizontal),
+    "      0       | 1 "
+    "--------------+---"
+    " A long line  | 1 "
+    "   which is   |   "
+    "   wrapped    |   "
+);
+
+test_table!(
+    alignment_per_line_only_target,
+    Matrix::iter([["A long line\nshort", "A long line\nshort"]])
+        .with(Style::psql())
+        .modify(Columns::first(), Alignment::right().per_line())
+        .modify(Columns::last(), Alignment::right()),
+    "           0 |           1 "
+    "-------------+-------------"
+    " A long line | A long line "
+    "       short | short       "
+);
+
+test_table!(
+    alignment_column_from_end,
+    Matrix::new(3, 3).with(Style::psql()).modify(Columns::from_end(1), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |      0-1 |   0-2    "
+    " 1 |   1-0    |      1-1 |   1-2    "
+    " 2 |   2-0    |      2-1 |   2-2    "
+);
@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{object::Columns, Alignment, AutoAlign, Style},
+    Table,
+};
+
+use testing_table::test_table;
+
+test_table!(
+    auto_align_number_text_bool,
+    Table::new([("apple", 120, true), ("kiwi", 7, false), ("banana", 35, true)])
+        .with(Style::modern())
+        .with(AutoAlign::new()),
+    "┌────────┬─────┬───────┐"
+    "│ &str   │ i32 │ bool  │"
+    "├────────┼─────┼───────┤"
+    "│ apple  │ 120 │ true  │"
+    "├────────┼─────┼───────┤"
+    "│ kiwi   │   7 │ false │"
+    "├────────┼─────┼───────┤"
+    "│ banana │  35 │ true  │"
+    "└────────┴─────┴───────┘"
+);
+
+test_table!(
+    auto_align_header,
+    Table::new([("apple", 120, true), ("kiwi", 7, false), ("banana", 35, true)])
+        .with(Style::modern())
+        .with(AutoAlign::new().header(Alignment::center())),
+    "┌────────┬─────┬───────┐"
+    "│  &str  │ i32 │ bool  │"
+    "├────────┼─────┼───────┤"
+    "│ apple  │ 120 │ true  │"
+    "├────────┼─────┼───────┤"
+    "│ kiwi   │   7 │ false │"
+    "├────────┼─────┼───────┤"
+    "│ banana │  35 │ true  │"
+    "└────────┴─────┴───────┘"
+);
+
+test_table!(
+    auto_align_mixed_column,
+    Builder::from_iter([["name", "value"], ["a", "1.5"], ["b", "true"], ["c", "-1000"]])
+        .build()
+        .with(Style::modern())
+        .with(AutoAlign::new()),
+    "┌──────┬───────┐"
+    "│ name │ value │"
+    "├──────┼───────┤"
+    "│ a    │ 1.5   │"
+    "├──────┼───────┤"
+    "│ b    │ true  │"
+    "├──────┼───────┤"
+    "│ c    │ -1000 │"
+    "└──────┴───────┘"
+);
+
+test_table!(
+    auto_align_skips_empty_cells,
+    Builder::from_iter([["name", "value", "flag"], ["a", "1.5", ""], ["long name", "", ""], ["c", "-1000", ""]])
+        .build()
+        .with(Style::modern())
+        .with(AutoAlign::new()),
+    "┌───────────┬───────┬──────┐"
+    "│ name      │ value │ flag │"
+    "├───────────┼───────┼──────┤"
+    "│ a         │   1.5 │      │"
+    "├───────────┼───────┼──────┤"
+    "│ long name │       │      │"
+    "├───────────┼───────┼──────┤"
+    "│ c         │ -1000 │      │"
+    "└───────────┴───────┴──────┘"
+);
+
+test_table!(
+    auto_align_keep_manual,
+    Table::new([("apple", 120, 1), ("kiwi", 7, 20), ("banana", 35, 300)])
+        .with(Style::modern())
+        .modify(Columns::single(1), Alignment::center())
+        .with(AutoAlign::new().keep_manual(true)),
+    "┌────────┬─────┬─────┐"
+    "│ &str   │ i32 │ i32 │"
+    "├────────┼─────┼─────┤"
+    "│ apple  │ 120 │   1 │"
+    "├────────┼─────┼─────┤"
+    "│ kiwi   │  7  │  20 │"
+    "├────────┼─────┼─────┤"
+    "│ banana │ 35  │ 300 │"
+    "└────────┴─────┴─────┘"
+);
+
+test_table!(
+    auto_align_overrides_manual,
+    Table::new([("apple", 120, 1), ("kiwi", 7, 20), ("banana", 35, 300)])
+        .with(Style::modern())
+        .modify(Columns::single(1), Alignment::center())
+        .with(AutoAlign::new()),
+    "┌────────┬─────┬─────┐"
+    "│ &str   │ i32 │ i32 │"
+    "├────────┼─────┼─────┤"
+    "│ apple  │ 120 │   1 │"
+    "├────────┼─────┼─────┤"
+    "│ kiwi   │   7 │  20 │"
+    "├────────┼─────┼─────┤"
+    "│ banana │  35 │ 300 │"
+    "└────────┴─────┴─────┘"
+);
+
+test_table!(
+    auto_align_empty_table,
+    Builder::default().build().with(AutoAlign::new()),
+    ""
+);
+
+test_table!(
+    auto_align_non_finite_numbers,
+    Builder::from_iter([["name", "value", "ratio"], ["a", "inf", "1"], ["b", "NaN", "2.5"], ["c", "-infinity", "3"]])
+        .build()
+        .with(Style::modern())
+        .with(AutoAlign::new()),
+    "┌──────┬───────────┬───────┐"
+    "│ name │ value     │ ratio │"
+    "├──────┼───────────┼───────┤"
+    "│ a    │ inf       │     1 │"
+    "├──────┼───────────┼───────┤"
+    "│ b    │ NaN       │   2.5 │"
+    "├──────┼───────────┼───────┤"
+    "│ c    │ -infinity │     3 │"
+    "└──────┴───────────┴───────┘"
+);
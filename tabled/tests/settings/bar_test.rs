@@ -0,0 +1,77 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{Bar, Style},
+    Table,
+};
+
+use testing_table::test_table;
+
+test_table!(
+    bar_half,
+    Table::new([["a", "50"]]).with(Bar::column(1, 100.0).width(10)),
+    "+---+------------+"
+    "| 0 | 1          |"
+    "+---+------------+"
+    "| a | █████░░░░░ |"
+    "+---+------------+"
+);
+
+test_table!(
+    bar_column,
+    Table::new([
+        ["a", "0"],
+        ["b", "25"],
+        ["c", "100"],
+        ["d", "150"],
+        ["e", "-1"],
+        ["f", "n/a"]
+    ])
+    .with(Style::modern())
+    .with(Bar::column(1, 100.0).width(4)),
+    "┌───┬──────┐"
+    "│ 0 │ 1    │"
+    "├───┼──────┤"
+    "│ a │ ░░░░ │"
+    "├───┼──────┤"
+    "│ b │ █░░░ │"
+    "├───┼──────┤"
+    "│ c │ ████ │"
+    "├───┼──────┤"
+    "│ d │ ████ │"
+    "├───┼──────┤"
+    "│ e │ ░░░░ │"
+    "├───┼──────┤"
+    "│ f │ n/a  │"
+    "└───┴──────┘"
+);
+
+test_table!(
+    bar_symbols,
+    Table::new([["a", "3"], ["b", "1.5"]])
+        .with(Style::psql())
+        .with(Bar::column(1, 3.0).width(6).symbols('#', '.')),
+    " 0 | 1      "
+    "---+--------"
+    " a | ###### "
+    " b | ###... "
+);
+
+test_table!(
+    bar_column_out_of_bounds,
+    Table::new([["a", "3"]]).with(Bar::column(2, 3.0)),
+    "+---+---+"
+    "| 0 | 1 |"
+    "+---+---+"
+    "| a | 3 |"
+    "+---+---+"
+);
+
+#[test]
+fn bar_render() {
+    let bar = Bar::column(0, 100.0).width(10);
+    assert_eq!(bar.render(50.0), "█████░░░░░");
+    assert_eq!(bar.render(0.0), "░░░░░░░░░░");
+    assert_eq!(bar.render(1000.0), "██████████");
+    assert_eq!(Bar::column(0, 0.0).width(3).render(1.0), "░░░");
+}
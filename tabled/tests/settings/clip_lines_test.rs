@@ -0,0 +1,114 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    grid::util::string::get_line_width,
+    settings::{ClipLines, Style},
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    clip_lines,
+    Matrix::new(3, 3).with(ClipLines::new(20, ">")),
+    "+---+----------+--->"
+    "| N | column 0 | co>"
+    "+---+----------+--->"
+    "| 0 |   0-0    |   >"
+    "+---+----------+--->"
+    "| 1 |   1-0    |   >"
+    "+---+----------+--->"
+    "| 2 |   2-0    |   >"
+    "+---+----------+--->"
+);
+
+test_table!(
+    clip_lines_not_cut,
+    Matrix::new(3, 3).with(ClipLines::new(100, ">")),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    clip_lines_multibyte,
+    Matrix::new(3, 3)
+        .with(Style::modern())
+        .with(ClipLines::new(10, "..")),
+    "┌───┬───.."
+    "│ N │ co.."
+    "├───┼───.."
+    "│ 0 │   .."
+    "├───┼───.."
+    "│ 1 │   .."
+    "├───┼───.."
+    "│ 2 │   .."
+    "└───┴───.."
+);
+
+#[test]
+fn clip_lines_zero() {
+    let table = Matrix::new(1, 1).with(ClipLines::new(0, ">")).to_string();
+    assert_eq!(table, "\n\n\n\n");
+}
+
+test_table!(
+    clip_lines_marker_wider_than_line,
+    Matrix::new(1, 1).with(ClipLines::new(2, ">>>")),
+    ">>"
+    ">>"
+    ">>"
+    ">>"
+    ">>"
+);
+
+#[test]
+fn clip_lines_width() {
+    let width = 17;
+    let marker = "→";
+
+    let origin = Matrix::new(10, 10).with(Style::modern()).to_string();
+    let table = Matrix::new(10, 10)
+        .with(Style::modern())
+        .with(ClipLines::new(width, marker))
+        .to_string();
+
+    assert_eq!(origin.lines().count(), table.lines().count());
+
+    for (line, origin) in table.lines().zip(origin.lines()) {
+        assert!(get_line_width(line) <= width);
+
+        if get_line_width(origin) > width {
+            assert!(line.ends_with(marker));
+            assert_eq!(get_line_width(line), width);
+        } else {
+            assert_eq!(line, origin);
+        }
+    }
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn clip_lines_ansi() {
+    use tabled::settings::Color;
+
+    let table = Matrix::new(1, 1)
+        .with(Color::FG_RED)
+        .with(ClipLines::new(9, ">"))
+        .to_string();
+
+    for line in table.lines() {
+        assert!(get_line_width(line) <= 9);
+    }
+
+    assert_eq!(
+        ansi_str::AnsiStr::ansi_strip(&table),
+        "+---+--->\n| N | co>\n+---+--->\n| 0 |   >\n+---+--->"
+    );
+}
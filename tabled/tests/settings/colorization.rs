@@ -3,7 +3,7 @@
 use tabled::settings::{
     object::{Cell, Object},
     themes::Colorization,
-    Color,
+    Color, Modify,
 };
 
 use crate::matrix::Matrix;
@@ -51,6 +51,46 @@ test_table!(
     "+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41mN\u{1b}[49m\u{1b}[41m \u{1b}[49m| column 0 | column 1 | column 2 |\n+---+----------+----------+----------+\n| 0 |\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|   0-1    |   0-2    |\n+---+----------+----------+----------+\n| 1 |   1-0    |\u{1b}[1m \u{1b}[22m\u{1b}[1m  \u{1b}[22m\u{1b}[1m1-1\u{1b}[22m\u{1b}[1m   \u{1b}[22m\u{1b}[1m \u{1b}[22m|   1-2    |\n+---+----------+----------+----------+"
 );
 
+test_table!(
+    columns_alternating,
+    Matrix::new(1, 2).with(Colorization::columns([Color::FG_RED, Color::FG_BLUE])),
+    "+---+----------+----------+\n|\u{1b}[31m \u{1b}[39m\u{1b}[31mN\u{1b}[39m\u{1b}[31m \u{1b}[39m|\u{1b}[34m \u{1b}[39m\u{1b}[34mcolumn 0\u{1b}[39m\u{1b}[34m \u{1b}[39m|\u{1b}[31m \u{1b}[39m\u{1b}[31mcolumn 1\u{1b}[39m\u{1b}[31m \u{1b}[39m|\n+---+----------+----------+\n|\u{1b}[31m \u{1b}[39m\u{1b}[31m0\u{1b}[39m\u{1b}[31m \u{1b}[39m|\u{1b}[34m \u{1b}[39m\u{1b}[34m  \u{1b}[39m\u{1b}[34m0-0\u{1b}[39m\u{1b}[34m   \u{1b}[39m\u{1b}[34m \u{1b}[39m|\u{1b}[31m \u{1b}[39m\u{1b}[31m  \u{1b}[39m\u{1b}[31m0-1\u{1b}[39m\u{1b}[31m   \u{1b}[39m\u{1b}[31m \u{1b}[39m|\n+---+----------+----------+"
+);
+
+test_table!(
+    columns_skip_header,
+    Matrix::new(2, 3).with(Colorization::columns([color1(), color2(), color3()]).skip_header(true)),
+    "+---+----------+----------+----------+\n| N | column 0 | column 1 | column 2 |\n+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[1m \u{1b}[22m\u{1b}[1m  \u{1b}[22m\u{1b}[1m0-1\u{1b}[22m\u{1b}[1m   \u{1b}[22m\u{1b}[1m \u{1b}[22m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-2\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m1\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m1-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[1m \u{1b}[22m\u{1b}[1m  \u{1b}[22m\u{1b}[1m1-1\u{1b}[22m\u{1b}[1m   \u{1b}[22m\u{1b}[1m \u{1b}[22m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m1-2\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+----------+"
+);
+
+test_table!(
+    rows_skip_header,
+    Matrix::new(2, 3).with(Colorization::rows([color1(), color2()]).skip_header(true)),
+    "+---+----------+----------+----------+\n| N | column 0 | column 1 | column 2 |\n+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-0\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-1\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-2\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+----------+\n|\u{1b}[106m \u{1b}[49m\u{1b}[106m1\u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m1-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m1-1\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m1-2\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\n+---+----------+----------+----------+"
+);
+
+test_table!(
+    chess_skip_header,
+    Matrix::new(2, 3).with(Colorization::chess(color1(), color2()).skip_header(true)),
+    "+---+----------+----------+----------+\n| N | column 0 | column 1 | column 2 |\n+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-1\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-2\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\n+---+----------+----------+----------+\n|\u{1b}[106m \u{1b}[49m\u{1b}[106m1\u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m1-0\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m1-1\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m1-2\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+----------+"
+);
+
+test_table!(
+    columns_override_colors,
+    Matrix::new(1, 2)
+        .with(Modify::new(Cell::new(1, 1)).with(Color::FG_GREEN))
+        .with(Colorization::columns([color1(), color2()])),
+    "+---+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41mN\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106mcolumn 0\u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41mcolumn 1\u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-1\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+"
+);
+
+test_table!(
+    columns_keep_colors,
+    Matrix::new(1, 2)
+        .with(Modify::new(Cell::new(1, 1)).with(Color::FG_GREEN))
+        .with(Colorization::columns([color1(), color2()]).keep_colors(true)),
+    "+---+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41mN\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[106m \u{1b}[49m\u{1b}[106mcolumn 0\u{1b}[49m\u{1b}[106m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41mcolumn 1\u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|   \u{1b}[32m0-0\u{1b}[39m    |\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-1\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+"
+);
+
 fn color1() -> Color {
     Color::BG_RED
 }
@@ -1,6 +1,11 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{Concat, Style};
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{Concat, Style},
+};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -138,3 +143,87 @@ test_table!(
     " 0 |   0-0    |   0-1    |   0-2    "
     " 1 |   1-0    |   1-1    |   1-2    "
 );
+
+test_table!(
+    join_horizontal_2x2_with_2x1,
+    {
+        let mut table1 = Builder::from_iter([["a", "b"], ["c", "d"]]).build();
+        let table2 = Builder::from_iter([["1"], ["2"]]).build();
+        table1
+            .with(Concat::horizontal(table2))
+            .with(Style::psql())
+            .to_string()
+    },
+    " a | b | 1 "
+    "---+---+---"
+    " c | d | 2 "
+);
+
+test_table!(
+    join_vertical_3x2,
+    {
+        let mut table1 = Builder::from_iter([["id", "name"], ["0", "a"], ["1", "b"]]).build();
+        let table2 = Builder::from_iter([["id", "name"], ["2", "c"], ["3", "d"]]).build();
+        table1
+            .with(Concat::vertical(table2))
+            .with(Style::psql())
+            .to_string()
+    },
+    " id | name "
+    "----+------"
+    " 0  | a    "
+    " 1  | b    "
+    " id | name "
+    " 2  | c    "
+    " 3  | d    "
+);
+
+test_table!(
+    join_vertical_3x2_dedup_header,
+    {
+        let mut table1 = Builder::from_iter([["id", "name"], ["0", "a"], ["1", "b"]]).build();
+        let table2 = Builder::from_iter([["id", "name"], ["2", "c"], ["3", "d"]]).build();
+        table1
+            .with(Concat::vertical(table2).dedup_header(true))
+            .with(Style::psql())
+            .to_string()
+    },
+    " id | name "
+    "----+------"
+    " 0  | a    "
+    " 1  | b    "
+    " 2  | c    "
+    " 3  | d    "
+);
+
+test_table!(
+    join_vertical_dedup_header_different,
+    {
+        let mut table1 = Builder::from_iter([["id", "name"], ["0", "a"]]).build();
+        let table2 = Builder::from_iter([["id", "title"], ["1", "b"]]).build();
+        table1
+            .with(Concat::vertical(table2).dedup_header(true))
+            .with(Style::psql())
+            .to_string()
+    },
+    " id | name  "
+    "----+-------"
+    " 0  | a     "
+    " id | title "
+    " 1  | b     "
+);
+
+test_table!(
+    join_horizontal_dedup_header_is_ignored,
+    {
+        let mut table1 = Builder::from_iter([["id"], ["0"]]).build();
+        let table2 = Builder::from_iter([["id"], ["1"]]).build();
+        table1
+            .with(Concat::horizontal(table2).dedup_header(true))
+            .with(Style::psql())
+            .to_string()
+    },
+    " id | id "
+    "----+----"
+    " 0  | 1  "
+);
@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{
+    object::{Columns, Rows},
+    Alignment, Direction, Padding, Span, Style,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    direction_ltr,
+    Matrix::new(2, 2).with(Style::modern()).with(Direction::ltr()),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    direction_rtl,
+    Matrix::new(2, 2).with(Style::modern()).with(Direction::rtl()),
+    "┌──────────┬──────────┬───┐"
+    "│ column 1 │ column 0 │ N │"
+    "├──────────┼──────────┼───┤"
+    "│   0-1    │   0-0    │ 0 │"
+    "├──────────┼──────────┼───┤"
+    "│   1-1    │   1-0    │ 1 │"
+    "└──────────┴──────────┴───┘"
+);
+
+test_table!(
+    direction_rtl_twice,
+    Matrix::new(2, 2).with(Direction::rtl()).with(Direction::rtl()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    direction_rtl_alignment_and_padding,
+    Matrix::table(2, 2)
+        .with(Style::psql())
+        .with(Alignment::left())
+        .modify(Columns::first(), Alignment::right())
+        .modify(Rows::first(), Padding::new(3, 0, 0, 0))
+        .with(Direction::rtl()),
+    "column 1   |column 0   |N   "
+    "-----------+-----------+----"
+    "       0-1 |       0-0 | 0  "
+    "       1-1 |       1-0 | 1  "
+);
+
+test_table!(
+    direction_rtl_span,
+    Matrix::table(2, 2)
+        .modify((0, 0), Span::column(2))
+        .modify((2, 1), Span::column(2))
+        .with(Direction::rtl()),
+    "+----------+-----+---+"
+    "| column 1 |    N    |"
+    "+----------+-----+---+"
+    "|   0-1    | 0-0 | 0 |"
+    "+----------+-----+---+"
+    "|      1-0       | 1 |"
+    "+----------+-----+---+"
+);
+
+test_table!(
+    direction_rtl_then_modify,
+    Matrix::table(2, 2)
+        .with(Style::psql())
+        .with(Direction::rtl())
+        .modify(Columns::last(), Alignment::left()),
+    " column 1 | column 0 | N "
+    "----------+----------+---"
+    "   0-1    |   0-0    | 0 "
+    "   1-1    |   1-0    | 1 "
+);
@@ -4,7 +4,7 @@ use tabled::settings::{
     location::ByColumnName,
     object::{Columns, Rows, Segment},
     style::{HorizontalLine, Style},
-    Alignment, Modify, Remove,
+    Alignment, Modify, Padding, Remove, Span,
 };
 
 use crate::matrix::Matrix;
@@ -81,3 +81,54 @@ test_table!(
         .with(Remove::column(Columns::new(..))),
     ""
 );
+
+test_table!(
+    disable_column_then_modify_new_column,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .with(Remove::column(Columns::single(1)))
+        .with(Modify::new(Columns::single(1)).with(Alignment::left())),
+    " N | column 1 | column 2 "
+    "---+----------+----------"
+    " 0 | 0-1      |   0-2    "
+    " 1 | 1-1      |   1-2    "
+    " 2 | 2-1      |   2-2    "
+);
+
+test_table!(
+    disable_column_keeps_previous_settings,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(3)).with(Alignment::right()))
+        .with(Modify::new(Columns::single(2)).with(Padding::new(2, 2, 0, 0)))
+        .with(Remove::column(Columns::single(1))),
+    " N |  column 1  | column 2 "
+    "---+------------+----------"
+    " 0 |    0-1     |      0-2 "
+    " 1 |    1-1     |      1-2 "
+    " 2 |    2-1     |      2-2 "
+);
+
+test_table!(
+    disable_rows_keeps_previous_settings,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .with(Modify::new(Rows::last()).with(Alignment::right()))
+        .with(Remove::row(Rows::new(1..3))),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 2 |      2-0 |      2-1 |      2-2 "
+);
+
+test_table!(
+    disable_column_range_shrinks_span,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .with(Modify::new((0, 0)).with(Span::column(3)))
+        .with(Remove::column(Columns::new(1..3))),
+    " N | column 2 "
+    "---+----------"
+    " 0 |   0-2    "
+    " 1 |   1-2    "
+    " 2 |   2-2    "
+);
@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{object::Cell, Footnote, Style};
+
+use crate::matrix::Matrix;
+use testing_table::{assert_table, test_table};
+
+test_table!(
+    footnote_two_notes,
+    Matrix::new(2, 2)
+        .with(Footnote::new().attach(Cell::new(1, 1), "first note").attach((2, 2), "second note")),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0¹   |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1²   |"
+    "+---+----------+----------+"
+    "| ¹ first note            |"
+    "| ² second note           |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    footnote_with_style,
+    Matrix::new(1, 2)
+        .with(Footnote::new().attach((0, 0), "index"))
+        .with(Style::modern()),
+    "┌────┬──────────┬──────────┐"
+    "│ N¹ │ column 0 │ column 1 │"
+    "├────┼──────────┼──────────┤"
+    "│ 0  │   0-0    │   0-1    │"
+    "├────┼──────────┼──────────┤"
+    "│ ¹ index                  │"
+    "└────┴──────────┴──────────┘"
+);
+
+test_table!(
+    footnote_out_of_bounds_is_listed_without_marker,
+    Matrix::new(1, 1).with(Footnote::new().attach((10, 10), "missing")),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+    "| ¹ missing    |"
+    "+---+----------+"
+);
+
+test_table!(
+    footnote_empty,
+    Matrix::new(1, 1).with(Footnote::new()),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
+
+#[test]
+fn footnote_render() {
+    let footnotes = Footnote::new()
+        .attach((1, 1), "first")
+        .attach((2, 2), "second");
+
+    assert_eq!(footnotes.count(), 2);
+    assert_eq!(footnotes.render(), "¹ first\n² second");
+    assert_eq!(Footnote::marker(12), "¹²");
+
+    let table = Matrix::new(0, 1).with(footnotes);
+    assert_table!(
+        table,
+        "+---+----------+"
+        "| N | column 0 |"
+        "+---+----------+"
+        "| ¹ first      |"
+        "| ² second     |"
+        "+---+----------+"
+    );
+}
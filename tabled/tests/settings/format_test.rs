@@ -1,8 +1,13 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    object::{Cell, Columns, Object, Rows, Segment},
-    Alignment, Format, Modify, Padding, Style,
+use std::borrow::Cow;
+
+use tabled::{
+    grid::records::PeekableRecords,
+    settings::{
+        object::{Cell, Columns, Object, Rows, Segment},
+        Alignment, Format, Modify, Padding, Style,
+    },
 };
 
 use crate::matrix::Matrix;
@@ -267,3 +272,115 @@ test_table!(
     "   |          |          | \u{1b}[32m.com\u{1b}[39m     "
     "   |          |          | \u{1b}[32m/en\u{1b}[39m      "
 );
+
+test_table!(
+    formatting_content_cow_test,
+    Matrix::new(3, 3).with(Style::psql()).modify(
+        Segment::all(),
+        Format::content_cow(|s| match s.contains('1') {
+            true => Cow::Owned(format!("[{s}]")),
+            false => Cow::Borrowed(s),
+        })
+    ),
+    "  N  | column 0 | [column 1] | column 2 "
+    "-----+----------+------------+----------"
+    "  0  |   0-0    |   [0-1]    |   0-2    "
+    " [1] |  [1-0]   |   [1-1]    |  [1-2]   "
+    "  2  |   2-0    |   [2-1]    |   2-2    "
+);
+
+test_table!(
+    formatting_content_cow_borrowed_part_test,
+    Matrix::new(3, 3).with(Style::psql()).modify(
+        Columns::single(1),
+        Format::content_cow(|s| Cow::Borrowed(&s[..1]))
+    ),
+    " N | c | column 1 | column 2 "
+    "---+---+----------+----------"
+    " 0 | 0 |   0-1    |   0-2    "
+    " 1 | 1 |   1-1    |   1-2    "
+    " 2 | 2 |   2-1    |   2-2    "
+);
+
+test_table!(
+    formatting_content_cow_multiline_test,
+    Matrix::new(2, 2)
+        .insert((1, 1).into(), "a\nbb\nccc")
+        .with(Style::psql())
+        .modify(
+            Segment::all(),
+            Format::content_cow(|s| match s.len() {
+                2 => Cow::Owned(s.repeat(2)),
+                _ => Cow::Borrowed(s),
+            })
+            .multiline()
+        ),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   a      |   0-1    "
+    "   |   bbbb   |          "
+    "   |   ccc    |          "
+    " 1 |   1-0    |   1-1    "
+);
+
+#[test]
+fn formatting_content_cow_no_op_keeps_cells_test() {
+    let mut table = Matrix::new(3, 3).to_table();
+    let expected = table.to_string();
+    let cells = |table: &tabled::Table| {
+        let records = table.get_records();
+        (0..table.count_rows())
+            .flat_map(|row| (0..table.count_columns()).map(move |col| (row, col)))
+            .map(|pos| records.get_text(pos.into()).as_ptr())
+            .collect::<Vec<_>>()
+    };
+
+    let before = cells(&table);
+    table.with(Format::content_cow(|s| Cow::Borrowed(s)));
+    assert_eq!(cells(&table), before);
+    assert_eq!(table.to_string(), expected);
+
+    table.with(Format::content_cow(|s| Cow::Borrowed(s)).multiline());
+    assert_eq!(cells(&table), before);
+    assert_eq!(table.to_string(), expected);
+
+    table.with(Format::content(|s| s.to_string()));
+    assert_ne!(cells(&table), before);
+    assert_eq!(table.to_string(), expected);
+}
+
+test_table!(
+    formatting_content_cow_borrowed_same_length_test,
+    Matrix::new(2, 2).with(Style::psql()).modify(
+        Columns::single(1),
+        Format::content_cow(|s| match s {
+            "0-0" => Cow::Borrowed("yes"),
+            _ => Cow::Borrowed(s),
+        })
+    ),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   yes    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    formatting_content_cow_multiline_borrowed_same_length_test,
+    Matrix::new(2, 2)
+        .insert((1, 1).into(), "a\nbb\nccc")
+        .with(Style::psql())
+        .modify(
+            Segment::all(),
+            Format::content_cow(|s| match s {
+                "bb" => Cow::Borrowed("ok"),
+                _ => Cow::Borrowed(s),
+            })
+            .multiline()
+        ),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   a      |   0-1    "
+    "   |   ok     |          "
+    "   |   ccc    |          "
+    " 1 |   1-0    |   1-1    "
+);
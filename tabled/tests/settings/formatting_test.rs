@@ -1,8 +1,13 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{formatting::Justification, object::Columns, Color, Modify};
+use tabled::settings::{
+    formatting::{Charset, Justification, Strike},
+    object::Columns,
+    Color, Format, Modify, Span,
+};
 
 use crate::matrix::Matrix;
+use tabled::Table;
 use testing_table::test_table;
 
 test_table!(
@@ -66,3 +71,108 @@ test_table!(
     "| 2 | \u{1b}[44m##\u{1b}[49m2-0\u{1b}[44m###\u{1b}[49m | \u{1b}[41m@@\u{1b}[49m2-1\u{1b}[41m@@@\u{1b}[49m | \u{1b}[47m$$\u{1b}[49m2-2\u{1b}[47m$$$\u{1b}[49m |"
     "+---+----------+----------+----------+"
 );
+
+test_table!(
+    strike_cell,
+    Matrix::new(3, 3).with(Modify::new((2, 2)).with(Strike::diagonal())),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |╱╱╱╱╱╱╱╱╱╱|   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    strike_multiline_cell,
+    Matrix::new(3, 3)
+        .with(Modify::new((1, 1)).with(Format::content(|s| format!("{s}\n{s}"))))
+        .with(Modify::new((1, 1)).with(Strike::cross())),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |╳╳╳╳╳╳╳╳╳╳|   0-1    |   0-2    |"
+    "|   |╳╳╳╳╳╳╳╳╳╳|          |          |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    strike_spanned_cell,
+    Matrix::new(3, 3)
+        .with(Modify::new((1, 1)).with(Span::column(2)))
+        .with(Modify::new((1, 1)).with(Strike::new('x'))),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |xxxxxxxxxxxxxxxxxxxxx|   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    strike_none,
+    Matrix::new(3, 3)
+        .with(Strike::new('x'))
+        .with(Modify::new(Columns::new(1..)).with(Strike::none())),
+    "+---+----------+----------+----------+"
+    "|xxx| column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "|xxx|   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "|xxx|   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "|xxx|   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    charset_clean,
+    Table::new([["key", "value"], ["a\tb", "windows\r\nline"], ["bell\u{7}", "c"]])
+        .with(Charset::clean()),
+    "+------+---------+"
+    "| 0    | 1       |"
+    "+------+---------+"
+    "| key  | value   |"
+    "+------+---------+"
+    "| ab   | windows |"
+    "|      | line    |"
+    "+------+---------+"
+    "| bell | c       |"
+    "+------+---------+"
+);
+
+test_table!(
+    charset_clean_tab_size_and_placeholder,
+    Table::new([["key", "value"], ["a\tb", "windows\r\nline"], ["bell\u{7}", "c"]])
+        .with(Charset::clean().tab_size(4).placeholder('\u{FFFD}')),
+    "+--------+---------+"
+    "| 0      | 1       |"
+    "+--------+---------+"
+    "| key    | value   |"
+    "+--------+---------+"
+    "| a    b | windows |"
+    "|        | line    |"
+    "+--------+---------+"
+    "| bell�  | c       |"
+    "+--------+---------+"
+);
+
+test_table!(
+    charset_clean_cell,
+    Table::new([["a\tb", "c"]]).modify(Columns::first(), Charset::clean().tab_size(1)),
+    "+-----+---+"
+    "| 0   | 1 |"
+    "+-----+---+"
+    "| a b | c |"
+    "+-----+---+"
+);
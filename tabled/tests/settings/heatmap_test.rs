@@ -0,0 +1,71 @@
+#![cfg(feature = "ansi")]
+
+use tabled::{
+    settings::{Color, Heatmap},
+    Table,
+};
+
+#[test]
+fn heatmap_min_and_max_colors() {
+    let data = [("apple", 3), ("kiwi", 12), ("orange", 7)];
+
+    let mut table = Table::new(data);
+    table.with(Heatmap::column(1).colors(Color::rgb_bg(0, 0, 255), Color::rgb_bg(255, 0, 0)));
+    let table = table.to_string();
+
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[3],
+        "| apple  |\u{1b}[48;2;0;0;255m \u{1b}[49m\u{1b}[48;2;0;0;255m3\u{1b}[49m\u{1b}[48;2;0;0;255m  \u{1b}[49m\u{1b}[48;2;0;0;255m \u{1b}[49m|"
+    );
+    assert_eq!(
+        lines[5],
+        "| kiwi   |\u{1b}[48;2;255;0;0m \u{1b}[49m\u{1b}[48;2;255;0;0m12\u{1b}[49m\u{1b}[48;2;255;0;0m \u{1b}[49m\u{1b}[48;2;255;0;0m \u{1b}[49m|"
+    );
+    assert!(!lines[7].contains("\u{1b}[48;2;0;0;255m"));
+    assert!(!lines[7].contains("\u{1b}[48;2;255;0;0m"));
+}
+
+#[test]
+fn heatmap_skips_non_numeric_cells() {
+    let data = [["1", "x"], ["-", "y"], ["3", "z"]];
+
+    let mut table = Table::new(data);
+    table.with(Heatmap::column(0).colors(Color::rgb_bg(0, 0, 0), Color::rgb_bg(200, 100, 50)));
+    let table = table.to_string();
+
+    let lines = table.lines().collect::<Vec<_>>();
+    assert!(lines[1].contains("\u{1b}[48;2;0;0;0m"));
+    assert!(lines[3].contains("\u{1b}[48;2;67;33;17m"));
+    assert!(!lines[5].contains("\u{1b}[48"));
+    assert!(lines[7].contains("\u{1b}[48;2;200;100;50m"));
+}
+
+#[test]
+fn heatmap_range() {
+    let data = [[5], [10], [20]];
+
+    let mut table = Table::new(data);
+    table.with(
+        Heatmap::column(0)
+            .range(10.0, 15.0)
+            .colors(Color::BG_BLACK, Color::BG_WHITE),
+    );
+    let table = table.to_string();
+
+    let lines = table.lines().collect::<Vec<_>>();
+    assert!(lines[1].contains("\u{1b}[48;2;0;0;0m"));
+    assert!(lines[3].contains("\u{1b}[48;2;0;0;0m"));
+    assert!(lines[5].contains("\u{1b}[48;2;0;0;0m"));
+    assert!(lines[7].contains("\u{1b}[48;2;229;229;229m"));
+}
+
+#[test]
+fn heatmap_unsupported_colors() {
+    let data = [[1], [2]];
+
+    let mut table = Table::new(data);
+    table.with(Heatmap::column(0).colors(Color::BOLD, Color::BG_RED));
+
+    assert!(!table.to_string().contains('\u{1b}'));
+}
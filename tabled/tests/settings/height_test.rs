@@ -1,8 +1,12 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    object::{Columns, Segment},
-    Alignment, Format, Height, Modify, Style,
+use tabled::{
+    settings::{
+        object::{Columns, Segment},
+        peaker::Priority,
+        Alignment, Format, Height, Modify, Padding, Style,
+    },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -171,6 +175,39 @@ test_table!(
     "|--|--|--|--|"
 );
 
+test_table!(
+    table_height_limit_zero_priority_by_content,
+    Table::new([["a", "b\nc\nd"], ["e", "f"]])
+        .with(Height::limit(0).priority(Priority::by_content())),
+    "+--+--+"
+    "+--+--+"
+    "+--+--+"
+    "+--+--+"
+);
+
+test_table!(
+    table_height_limit_priority_column,
+    Table::new([["a", "b\nc\nd"], ["e", "f"]])
+        .with(Height::limit(4).priority(Priority::column(0))),
+    "+--+--+"
+    "+--+--+"
+    "+--+--+"
+    "+--+--+"
+);
+
+test_table!(
+    table_height_limit_priority_column_partial,
+    Table::new([["a", "b\nc\nd"], ["e", "f"]])
+        .with(Height::limit(7).priority(Priority::column(0))),
+    "+---+---+"
+    "+---+---+"
+    "| a | b |"
+    "|   | c |"
+    "+---+---+"
+    "| e | f |"
+    "+---+---+"
+);
+
 test_table!(
     table_height_limit_zero_1,
     Matrix::new(3, 3)
@@ -256,3 +293,58 @@ test_table!(
     "| +------+ |"
     "+----------+"
 );
+
+test_table!(
+    table_height_fill,
+    Matrix::new(2, 3).with(Style::ascii()).with(Height::fill(15)),
+    "+---+----------+----------+----------+"
+    "|   |          |          |          |"
+    "| N | column 0 | column 1 | column 2 |"
+    "|   |          |          |          |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+    "|   |          |          |          |"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "|   |          |          |          |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+    "|   |          |          |          |"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    table_height_fill_keeps_padding,
+    Matrix::new(2, 3)
+        .with(Style::ascii())
+        .modify((1, 1), Padding::new(1, 1, 2, 0))
+        .with(Height::fill(15)),
+    "+---+----------+----------+----------+"
+    "|   |          |          |          |"
+    "| N | column 0 | column 1 | column 2 |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+    "|   |          |          |          |"
+    "| 0 |          |   0-1    |   0-2    |"
+    "|   |          |          |          |"
+    "|   |   0-0    |          |          |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+    "|   |          |          |          |"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    table_height_fill_less_than_table,
+    Matrix::new(2, 3).with(Style::ascii()).with(Height::fill(3)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+);
@@ -1,11 +1,14 @@
 #![cfg(feature = "std")]
 
+use std::iter::FromIterator;
+
 use tabled::{
     builder::Builder,
     settings::{
         highlight::Highlight,
         object::{Cell, Columns, Frame, Object, Rows, Segment},
-        style::{Border, Style},
+        style::{Border, BorderColor, Style},
+        Color, Span,
     },
 };
 
@@ -219,6 +222,95 @@ test_table!(
 //     assert_eq!(table, expected);
 // }
 
+test_table!(
+    highlingt_by_bold_false,
+    Builder::from_iter([["a", "b", "c"], ["true", "false", "false"], ["false", "true", "true"]])
+        .build()
+        .with(Style::modern())
+        .with(Highlight::by(|text| text == "false").border(Border::full('━', '━', '┃', '┃', '┏', '┓', '┗', '┛'))),
+    "┌───────┬───────┬───────┐"
+    "│ a     │ b     │ c     │"
+    "├───────┏━━━━━━━━━━━━━━━┓"
+    "│ true  ┃ false │ false ┃"
+    "┏━━━━━━━┓━━━━━━━━━━━━━━━┛"
+    "┃ false ┃ true  │ true  │"
+    "┗━━━━━━━┛───────┴───────┘"
+);
+
+test_table!(
+    highlingt_by_no_matches,
+    Matrix::new(3, 3).with(Style::modern()).with(Highlight::by(|text| text == "false").border(Border::filled('*'))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    highlingt_by_without_border,
+    Matrix::new(3, 3).with(Style::modern()).with(Highlight::by(|text| text.ends_with('1'))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    highlingt_by_multiline,
+    Builder::from_iter([["a", "b"], ["1\nERROR", "2"], ["3", "ERROR\n4"]])
+        .build()
+        .with(Style::ascii())
+        .with(Highlight::by(|text| text.lines().any(|l| l == "ERROR")).border(Border::filled('*'))),
+    "+-------+-------+"
+    "| a     | b     |"
+    "*********-------+"
+    "* 1     * 2     |"
+    "* ERROR *       |"
+    "*****************"
+    "| 3     * ERROR *"
+    "|       * 4     *"
+    "+-------*********"
+);
+
+test_table!(
+    highlingt_by_empty_cells_skips_spanned,
+    Builder::from_iter([["a", "b", "c"], ["1", "", "3"], ["long", "", ""]])
+        .build()
+        .with(Style::ascii())
+        .modify((2, 0), Span::column(2))
+        .with(Highlight::by(str::is_empty).border(Border::filled('*'))),
+    "+---+---+---+"
+    "| a | b | c |"
+    "+---*****---+"
+    "| 1 *   * 3 |"
+    "+---*********"
+    "| long  *   *"
+    "+---+---*****"
+);
+
+test_table!(
+    highlingt_by_color,
+    Builder::from_iter([["a", "b"], ["false", "true"]])
+        .build()
+        .with(Style::ascii())
+        .with(Highlight::by(|text| text == "false").color(BorderColor::filled(Color::BOLD))),
+    "+-------+------+"
+    "| a     | b    |"
+    "\u{1b}[1m+-------+\u{1b}[22m------+"
+    "\u{1b}[1m|\u{1b}[22m false \u{1b}[1m|\u{1b}[22m true |"
+    "\u{1b}[1m+-------+\u{1b}[22m------+"
+);
+
 #[test]
 fn highlingt_complex_figures() {
     macro_rules! test_highlight {
@@ -187,3 +187,47 @@ fn margin_color_test() {
         "\u{1b}[34m\u{1b}[43m^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^\u{1b}[39m\u{1b}[49m"
     );
 }
+
+test_table!(
+    margin_fill_all,
+    Matrix::new(1, 1)
+        .with(Style::ascii())
+        .with(Margin::new(2, 1, 1, 1).fill_all('#')),
+    "###################"
+    "##+---+----------+#"
+    "##| N | column 0 |#"
+    "##+---+----------+#"
+    "##| 0 |   0-0    |#"
+    "##+---+----------+#"
+    "###################"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    margin_fill_all_color,
+    Matrix::new(1, 1)
+        .with(Style::ascii())
+        .with(Margin::new(1, 1, 1, 1).fill_all('█').color(Color::FG_BLUE)),
+    "\u{1b}[34m██████████████████\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m+---+----------+\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m| N | column 0 |\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m+---+----------+\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m| 0 |   0-0    |\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m+---+----------+\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m██████████████████\u{1b}[39m"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    margin_fill_all_color_offset,
+    {
+        use tabled::grid::config::{Offset, Sides};
+
+        let mut table = Matrix::table(1, 1);
+        table
+            .with(Style::ascii())
+            .with(Margin::new(1, 1, 1, 1).fill_all('█').color(Color::FG_BLUE));
+        table.get_config_mut().set_margin_offset(Sides::new(
+            Offset::Begin(1),
+            Offset::Begin(0),
+            Offset::Begin(2),
+            Offset::Begin(0),
+        ));
+        table
+    },
+    "  \u{1b}[34m████████████████\u{1b}[39m\n +---+----------+\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m| N | column 0 |\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m+---+----------+\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m| 0 |   0-0    |\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m█\u{1b}[39m+---+----------+\u{1b}[34m█\u{1b}[39m\n\u{1b}[34m██████████████████\u{1b}[39m"
+);
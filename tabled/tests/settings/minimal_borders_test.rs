@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{object::Columns, Alignment, MinimalBorders},
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    minimal_borders_right_aligned_number_before_text,
+    Table::new([
+        ("apple", 120, "fresh"),
+        ("kiwi", 7, "old"),
+        ("banana", 1500, "ripe")
+    ])
+    .modify(Columns::single(1), Alignment::right())
+    .with(MinimalBorders::new()),
+    " &str      i32 │ &str  "
+    "───────────────┼───────"
+    " apple     120 │ fresh "
+    " kiwi        7 │ old   "
+    " banana   1500 │ ripe  "
+);
+
+test_table!(
+    minimal_borders_text_before_right_aligned_number,
+    Table::new([("apple", 120), ("kiwi", 7), ("banana", 1500)])
+        .modify(Columns::single(1), Alignment::right())
+        .with(MinimalBorders::new()),
+    " &str      i32 "
+    "───────────────"
+    " apple     120 "
+    " kiwi        7 "
+    " banana   1500 "
+);
+
+test_table!(
+    minimal_borders_left_aligned,
+    Table::new([("apple", 120, "fresh"), ("kiwi", 7, "old")]).with(MinimalBorders::new()),
+    " &str    i32   &str  "
+    "─────────────────────"
+    " apple   120   fresh "
+    " kiwi    7     old   "
+);
+
+test_table!(
+    minimal_borders_centered,
+    Matrix::new(3, 3).with(MinimalBorders::new()),
+    " N   column 0   column 1   column 2 "
+    "────────────────────────────────────"
+    " 0     0-0        0-1        0-2    "
+    " 1     1-0        1-1        1-2    "
+    " 2     2-0        2-1        2-2    "
+);
+
+test_table!(
+    minimal_borders_chars,
+    Table::new([(1, "a"), (22, "b")])
+        .modify(Columns::single(0), Alignment::right())
+        .with(MinimalBorders::new().chars('|', '-', '+')),
+    " i32 | &str "
+    "-----+------"
+    "   1 | a    "
+    "  22 | b    "
+);
+
+test_table!(
+    minimal_borders_header_only,
+    Table::new(Vec::<(u8, u8)>::new()).with(MinimalBorders::new()),
+    " u8   u8 "
+);
+
+test_table!(
+    minimal_borders_empty,
+    Table::new(Vec::<u8>::new()).with(MinimalBorders::new()),
+    " u8 "
+);
@@ -1,22 +1,30 @@
 mod alignment_test;
+mod auto_align_test;
+mod bar_test;
+mod clip_lines_test;
 mod color_test;
 mod colorization;
 mod column_names_test;
 mod concat_test;
+mod direction_test;
 mod disable_test;
 mod duplicate_test;
 mod extract_test;
+mod footnote_test;
 mod format_test;
 mod formatting_test;
+mod heatmap_test;
 mod height_test;
 mod highlingt_test;
 mod layout_test;
 mod margin_test;
 mod merge_test;
+mod minimal_borders_test;
 mod object_test;
 mod padding_test;
 mod panel_test;
 mod render_settings;
+mod repeat_header_test;
 mod reverse_test;
 mod rotate_test;
 mod shadow_test;
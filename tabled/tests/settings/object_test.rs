@@ -1,10 +1,15 @@
 #![cfg(feature = "std")]
 
+use std::iter::FromIterator;
+
 use tabled::grid::config::Entity;
 use tabled::settings::{
-    object::{Columns, Object, ObjectIterator, Segment},
-    Alignment, Style,
+    object::{
+        Cell, Cells, Columns, Frame, Object, ObjectIterator, RowBands, Rows, Segment, Threshold,
+    },
+    Alignment, Border, Color, Format, Highlight, Modify, Padding, Span, Style, Width,
 };
+use tabled::{builder::Builder, Table};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -50,3 +55,347 @@ test_table!(
     " 1 |      1-0 |   1-1    |      1-2 "
     " 2 |      2-0 |   2-1    |      2-2 "
 );
+
+test_table!(
+    threshold_greater_than,
+    Table::new([
+        ["150", "a"],
+        ["99", "b"],
+        ["100", "c"],
+        ["100.5", "d"],
+        ["x", "e"]
+    ])
+    .modify(Threshold::column(0).greater_than(100.0), Color::FG_RED),
+    "+-------+---+"
+    "| 0     | 1 |"
+    "+-------+---+"
+    "| \u{1b}[31m150\u{1b}[39m   | a |"
+    "+-------+---+"
+    "| 99    | b |"
+    "+-------+---+"
+    "| 100   | c |"
+    "+-------+---+"
+    "| \u{1b}[31m100.5\u{1b}[39m | d |"
+    "+-------+---+"
+    "| x     | e |"
+    "+-------+---+"
+);
+
+test_table!(
+    threshold_less_than,
+    Table::new([
+        ["150", "a"],
+        ["99", "b"],
+        ["100", "c"],
+        ["-1", "d"],
+        ["x", "e"]
+    ])
+    .modify(
+        Threshold::column(0).less_than(100.0),
+        Format::content(|s| format!("<{s}>"))
+    ),
+    "+------+---+"
+    "| 0    | 1 |"
+    "+------+---+"
+    "| 150  | a |"
+    "+------+---+"
+    "| <99> | b |"
+    "+------+---+"
+    "| 100  | c |"
+    "+------+---+"
+    "| <-1> | d |"
+    "+------+---+"
+    "| x    | e |"
+    "+------+---+"
+);
+
+test_table!(
+    threshold_between,
+    Table::new([
+        ["150", "a"],
+        ["99", "b"],
+        ["100", "c"],
+        ["120", "d"],
+        [" 110 ", "e"]
+    ])
+    .modify(
+        Threshold::column(0).between(100.0, 120.0),
+        Format::content(|s| format!("<{s}>"))
+    ),
+    "+---------+---+"
+    "| 0       | 1 |"
+    "+---------+---+"
+    "| 150     | a |"
+    "+---------+---+"
+    "| 99      | b |"
+    "+---------+---+"
+    "| <100>   | c |"
+    "+---------+---+"
+    "| <120>   | d |"
+    "+---------+---+"
+    "| < 110 > | e |"
+    "+---------+---+"
+);
+
+test_table!(
+    threshold_skips_header,
+    Builder::from_iter([["1000"], ["1"], ["2000"]])
+        .build()
+        .modify(
+            Threshold::column(0).greater_than(1.0),
+            Format::content(|s| format!("<{s}>"))
+        ),
+    "+--------+"
+    "| 1000   |"
+    "+--------+"
+    "| 1      |"
+    "+--------+"
+    "| <2000> |"
+    "+--------+"
+);
+
+#[test]
+fn threshold_out_of_bounds_column() {
+    let table = Table::new([["150", "a"]]);
+    let cells = Threshold::column(5).cells(table.get_records()).count();
+    assert_eq!(cells, 0);
+}
+
+test_table!(
+    rows_band,
+    Matrix::new(8, 1)
+        .with(Style::psql())
+        .modify(Rows::band(1, 3), Format::content(|s| format!("<{s}>"))),
+    "  N  | column 0 "
+    "-----+----------"
+    "  0  |   0-0    "
+    "  1  |   1-0    "
+    " <2> |  <2-0>   "
+    " <3> |  <3-0>   "
+    " <4> |  <4-0>   "
+    "  5  |   5-0    "
+    "  6  |   6-0    "
+    "  7  |   7-0    "
+);
+
+#[test]
+fn rows_band_cells() {
+    let table = Matrix::new(8, 1).to_table();
+    let cells = Rows::band(1, 3)
+        .cells(table.get_records())
+        .collect::<Vec<_>>();
+    assert_eq!(cells, [Entity::Row(3), Entity::Row(4), Entity::Row(5)]);
+}
+
+test_table!(
+    row_bands,
+    Matrix::new(8, 1)
+        .with(Style::psql())
+        .modify(RowBands::even(3), Format::content(|s| format!("+{s}")))
+        .modify(RowBands::odd(3), Format::content(|s| format!("-{s}"))),
+    " +N | +column 0 "
+    "----+-----------"
+    " +0 |   +0-0    "
+    " +1 |   +1-0    "
+    " -2 |   -2-0    "
+    " -3 |   -3-0    "
+    " -4 |   -4-0    "
+    " +5 |   +5-0    "
+    " +6 |   +6-0    "
+    " +7 |   +7-0    "
+);
+
+test_table!(
+    row_bands_offset,
+    Matrix::new(8, 1).with(Style::psql()).modify(
+        RowBands::odd(3).offset(1),
+        Format::content(|s| format!("<{s}>"))
+    ),
+    "  N  | column 0 "
+    "-----+----------"
+    "  0  |   0-0    "
+    "  1  |   1-0    "
+    "  2  |   2-0    "
+    " <3> |  <3-0>   "
+    " <4> |  <4-0>   "
+    " <5> |  <5-0>   "
+    "  6  |   6-0    "
+    "  7  |   7-0    "
+);
+
+#[test]
+fn row_bands_empty() {
+    let table = Matrix::new(8, 1).to_table();
+    assert_eq!(RowBands::even(0).cells(table.get_records()).count(), 0);
+    assert_eq!(
+        RowBands::odd(3)
+            .offset(20)
+            .cells(table.get_records())
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn out_of_range_objects_produce_no_cells() {
+    let expected = Matrix::new(3, 3).to_string();
+
+    let table = Matrix::new(3, 3)
+        .with(Modify::new(Rows::single(10)).with(Format::content(|s| format!("[{s}]"))))
+        .with(Modify::new(Columns::single(10)).with(Format::content(|s| format!("[{s}]"))))
+        .with(Modify::new(Cell::new(10, 1)).with(Width::increase(100)))
+        .with(Modify::new((1, 10)).with(Span::column(2)))
+        .with(Modify::new(Rows::new(5..12)).with(Padding::new(4, 4, 4, 4)))
+        .with(Modify::new(Columns::new(4..=9)).with(Alignment::left()))
+        .to_string();
+
+    assert_eq!(table, expected);
+
+    let mut table = Table::new([[1, 2, 3]]);
+    table.modify(Rows::single(3), Border::new().left('*'));
+    table.with(Highlight::outline(Columns::single(4), '#'));
+    table.modify(Cell::new(3, 4), Color::FG_RED);
+
+    assert_eq!(table.to_string(), Table::new([[1, 2, 3]]).to_string());
+}
+
+test_table!(
+    out_of_range_rows_single,
+    Matrix::new(3, 3).with(Style::psql()).modify(Rows::single(4), Alignment::left()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |   0-1    |   0-2    "
+    " 1 |   1-0    |   1-1    |   1-2    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    out_of_range_columns_single,
+    Matrix::new(3, 3).with(Style::psql()).modify(Columns::single(4), Alignment::left()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |   0-1    |   0-2    "
+    " 1 |   1-0    |   1-1    |   1-2    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    frame_top,
+    Matrix::new(2, 2).with(Style::psql()).modify(Frame::top(), Format::content(|s| format!("[{s}]"))),
+    " [N] | [column 0] | [column 1] "
+    "-----+------------+------------"
+    "  0  |    0-0     |    0-1     "
+    "  1  |    1-0     |    1-1     "
+);
+
+test_table!(
+    frame_bottom,
+    Matrix::new(2, 2).with(Style::psql()).modify(Frame::bottom(), Format::content(|s| format!("[{s}]"))),
+    "  N  | column 0 | column 1 "
+    "-----+----------+----------"
+    "  0  |   0-0    |   0-1    "
+    " [1] |  [1-0]   |  [1-1]   "
+);
+
+test_table!(
+    frame_left,
+    Matrix::new(2, 2).with(Style::psql()).modify(Frame::left(), Format::content(|s| format!("[{s}]"))),
+    " [N] | column 0 | column 1 "
+    "-----+----------+----------"
+    " [0] |   0-0    |   0-1    "
+    " [1] |   1-0    |   1-1    "
+);
+
+test_table!(
+    frame_right,
+    Matrix::new(2, 2).with(Style::psql()).modify(Frame::right(), Alignment::right()),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |      0-1 "
+    " 1 |   1-0    |      1-1 "
+);
+
+test_table!(
+    frame_top_and_right_share_corner,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .modify(Frame::top(), Format::content(|s| format!("[{s}]")))
+        .modify(Frame::right(), Format::content(|s| format!("<{s}>"))),
+    " [N] | [column 0] | <[column 1]> "
+    "-----+------------+--------------"
+    "  0  |    0-0     |    <0-1>     "
+    "  1  |    1-0     |    <1-1>     "
+);
+
+test_table!(
+    cells_rect,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .modify(Cells::rect((1, 1), (2, 2)), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |      0-0 |      0-1 |   0-2    "
+    " 1 |      1-0 |      1-1 |   1-2    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    cells_rect_reversed,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .modify(Cells::rect((2, 1), (1, 2)), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |      0-0 |      0-1 |   0-2    "
+    " 1 |      1-0 |      1-1 |   1-2    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    cells_rect_clamped,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .modify(Cells::rect((100, 100), (2, 2)), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |   0-1    |   0-2    "
+    " 1 |   1-0    |      1-1 |      1-2 "
+    " 2 |   2-0    |      2-1 |      2-2 "
+);
+
+test_table!(
+    columns_from_end_and_rows_from_end,
+    Matrix::new(3, 3).with(Style::psql()).modify(Columns::from_end(1).and(Rows::from_end(2)), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |      0-0 |      0-1 |      0-2 "
+    " 1 |   1-0    |      1-1 |   1-2    "
+    " 2 |   2-0    |      2-1 |   2-2    "
+);
+
+test_table!(
+    columns_from_end_not,
+    Matrix::new(3, 3).with(Style::psql()).modify(Columns::new(1..).not(Columns::from_end(0)), Alignment::right()),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |      0-0 |      0-1 |   0-2    "
+    " 1 |      1-0 |      1-1 |   1-2    "
+    " 2 |      2-0 |      2-1 |   2-2    "
+);
+
+#[test]
+fn from_end_out_of_range() {
+    let table = Matrix::table(3, 3);
+    let records = table.get_records();
+
+    assert_eq!(
+        Columns::from_end(3).cells(records).collect::<Vec<_>>(),
+        [Entity::Column(0)]
+    );
+    assert_eq!(Columns::from_end(4).cells(records).count(), 0);
+    assert_eq!(
+        Rows::from_end(3).cells(records).collect::<Vec<_>>(),
+        [Entity::Row(0)]
+    );
+    assert_eq!(Rows::from_end(4).cells(records).count(), 0);
+}
@@ -1,8 +1,9 @@
 #![cfg(feature = "std")]
 
 use tabled::settings::{
+    formatting::Justification,
     object::{Rows, Segment},
-    Alignment, Modify, Padding, Style,
+    Alignment, Format, Modify, Padding, Style,
 };
 
 use crate::matrix::Matrix;
@@ -133,3 +134,45 @@ test_table!(
     "   |          |          |          "
     "   |          |          |          "
 );
+
+test_table!(
+    padding_fill_all,
+    Matrix::table(3, 3)
+        .with(Style::psql())
+        .modify((1, 1), Padding::new(2, 2, 1, 1).fill_all('·')),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |··········|   0-1    |   0-2    "
+    "   |·· 0-0  ··|          |          "
+    "   |··········|          |          "
+    " 1 |   1-0    |   1-1    |   1-2    "
+    " 2 |   2-0    |   2-1    |   2-2    "
+);
+
+test_table!(
+    padding_fill_all_multiline,
+    Matrix::table(1, 1)
+        .with(Style::modern())
+        .modify((1, 1), Format::content(|s| format!("{s}\nline")))
+        .modify((1, 1), Alignment::left())
+        .modify((1, 1), Padding::new(1, 1, 1, 1).fill_all('·'))
+        .modify((1, 1), Justification::new('-')),
+    "┌───┬──────────┐"
+    "│ N │ column 0 │"
+    "├───┼──────────┤"
+    "│ 0 │··········│"
+    "│   │·0-0---- ·│"
+    "│   │·line----·│"
+    "│   │··········│"
+    "└───┴──────────┘"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    padding_fill_all_color,
+    Matrix::table(1, 1)
+        .with(Style::psql())
+        .modify((1, 1), Padding::new(1, 1, 0, 0).fill_all('·'))
+        .modify((1, 1), PaddingColor::filled(Color::FG_RED)),
+    " N | column 0 \n---+----------\n 0 |\u{1b}[31m·\u{1b}[39m  0-0   \u{1b}[31m·\u{1b}[39m"
+);
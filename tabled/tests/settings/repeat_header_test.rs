@@ -0,0 +1,140 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{object::Rows, Alignment, Modify, Panel, RepeatHeader, Span, Style};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    repeat_header_every_20_of_50,
+    Matrix::new(50, 1).with(Style::psql()).with(RepeatHeader::every(20)),
+    " N  | column 0 "
+    "----+----------"
+    " 0  |   0-0    "
+    " 1  |   1-0    "
+    " 2  |   2-0    "
+    " 3  |   3-0    "
+    " 4  |   4-0    "
+    " 5  |   5-0    "
+    " 6  |   6-0    "
+    " 7  |   7-0    "
+    " 8  |   8-0    "
+    " 9  |   9-0    "
+    " 10 |   10-0   "
+    " 11 |   11-0   "
+    " 12 |   12-0   "
+    " 13 |   13-0   "
+    " 14 |   14-0   "
+    " 15 |   15-0   "
+    " 16 |   16-0   "
+    " 17 |   17-0   "
+    " 18 |   18-0   "
+    " 19 |   19-0   "
+    "----+----------"
+    " N  | column 0 "
+    "----+----------"
+    " 20 |   20-0   "
+    " 21 |   21-0   "
+    " 22 |   22-0   "
+    " 23 |   23-0   "
+    " 24 |   24-0   "
+    " 25 |   25-0   "
+    " 26 |   26-0   "
+    " 27 |   27-0   "
+    " 28 |   28-0   "
+    " 29 |   29-0   "
+    " 30 |   30-0   "
+    " 31 |   31-0   "
+    " 32 |   32-0   "
+    " 33 |   33-0   "
+    " 34 |   34-0   "
+    " 35 |   35-0   "
+    " 36 |   36-0   "
+    " 37 |   37-0   "
+    " 38 |   38-0   "
+    " 39 |   39-0   "
+    "----+----------"
+    " N  | column 0 "
+    "----+----------"
+    " 40 |   40-0   "
+    " 41 |   41-0   "
+    " 42 |   42-0   "
+    " 43 |   43-0   "
+    " 44 |   44-0   "
+    " 45 |   45-0   "
+    " 46 |   46-0   "
+    " 47 |   47-0   "
+    " 48 |   48-0   "
+    " 49 |   49-0   "
+);
+
+test_table!(
+    repeat_header_full_last_block,
+    Matrix::new(4, 1).with(Style::psql()).with(RepeatHeader::every(2)),
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+    " 1 |   1-0    "
+    "---+----------"
+    " N | column 0 "
+    "---+----------"
+    " 2 |   2-0    "
+    " 3 |   3-0    "
+);
+
+test_table!(
+    repeat_header_keeps_styling,
+    Matrix::new(3, 2)
+        .with(Panel::header("Numbers"))
+        .with(Style::modern())
+        .with(Modify::new(Rows::first()).with(Alignment::right()))
+        .with(RepeatHeader::every(2)),
+    "┌───┬──────────┬──────────┐"
+    "│                 Numbers │"
+    "├───┼──────────┼──────────┤"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│                 Numbers │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    repeat_header_not_inside_span,
+    Matrix::new(5, 2)
+        .with(Style::ascii())
+        .modify((2, 1), Span::row(2))
+        .with(RepeatHeader::every(2)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+          +----------+"
+    "| 2 |          |   2-1    |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 3 |   3-0    |   3-1    |"
+    "+---+----------+----------+"
+    "| 4 |   4-0    |   4-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    repeat_header_zero,
+    Matrix::new(2, 1).with(RepeatHeader::every(0)),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+    "| 1 |   1-0    |"
+    "+---+----------+"
+);
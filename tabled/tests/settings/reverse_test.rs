@@ -35,10 +35,10 @@ test_table!(
     "+-----+-----+-----+"
     "| i32 | i32 | i32 |"
     "+-----+-----+-----+"
-    "| 123 | 456 | 789 |"
-    "+-----+-----+-----+"
     "| 234 | 567 | 891 |"
     "+-----+-----+-----+"
+    "| 123 | 456 | 789 |"
+    "+-----+-----+-----+"
 );
 
 test_table!(
@@ -59,10 +59,10 @@ test_table!(
     "+-----+-----+-----+"
     "| i32 | i32 | i32 |"
     "+-----+-----+-----+"
-    "| 123 | 456 | 789 |"
-    "+-----+-----+-----+"
     "| 234 | 567 | 891 |"
     "+-----+-----+-----+"
+    "| 123 | 456 | 789 |"
+    "+-----+-----+-----+"
     "| 345 | 678 | 901 |"
     "+-----+-----+-----+"
 );
@@ -85,9 +85,9 @@ test_table!(
     "+-----+-----+-----+"
     "| i32 | i32 | i32 |"
     "+-----+-----+-----+"
-    "| 123 | 456 | 789 |"
+    "| 123 | 789 | 456 |"
     "+-----+-----+-----+"
-    "| 234 | 567 | 891 |"
+    "| 234 | 891 | 567 |"
     "+-----+-----+-----+"
 );
 
@@ -109,8 +109,36 @@ test_table!(
     "+-----+-----+-----+-----+"
     "| i32 | i32 | i32 | i32 |"
     "+-----+-----+-----+-----+"
-    "| 123 | 456 | 789 | 123 |"
+    "| 123 | 789 | 456 | 123 |"
     "+-----+-----+-----+-----+"
-    "| 234 | 567 | 891 | 234 |"
+    "| 234 | 891 | 567 | 234 |"
     "+-----+-----+-----+-----+"
 );
+
+test_table!(
+    test_reverse_rows_keep_header,
+    Matrix::iter([(1, "a"), (2, "b"), (3, "c"), (4, "d")]).with(Reverse::rows(1, 0)),
+    "+-----+------+"
+    "| i32 | &str |"
+    "+-----+------+"
+    "|  4  |  d   |"
+    "+-----+------+"
+    "|  3  |  c   |"
+    "+-----+------+"
+    "|  2  |  b   |"
+    "+-----+------+"
+    "|  1  |  a   |"
+    "+-----+------+"
+);
+
+test_table!(
+    test_reverse_columns_with_header,
+    Matrix::iter([(1, "a", 'x'), (2, "b", 'y')]).with(Reverse::columns(0, 0)),
+    "+------+------+-----+"
+    "| char | &str | i32 |"
+    "+------+------+-----+"
+    "|  x   |  a   |  1  |"
+    "+------+------+-----+"
+    "|  y   |  b   |  2  |"
+    "+------+------+-----+"
+);
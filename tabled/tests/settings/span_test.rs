@@ -7,11 +7,11 @@ use tabled::{
     builder::Builder,
     grid::config::Position,
     settings::{
-        object::{Columns, Segment},
+        object::{Cell, Columns, Object, Rows, Segment},
         style::{BorderSpanCorrection, Style},
         Alignment, Highlight, Modify, Padding, Panel, Span,
     },
-    Table,
+    Table, TableError,
 };
 
 use crate::matrix::Matrix;
@@ -1404,6 +1404,179 @@ test_table!(
     "+---+-----+-----+-----+"
 );
 
+test_table!(
+    span_column_at_first_cell,
+    Matrix::new(3, 3).with(Span::column_at(Columns::first().intersect(Rows::first()), 3)),
+    "+---+-----+-----+----------+"
+    "|       N       | column 2 |"
+    "+---+-----+-----+----------+"
+    "| 0 | 0-0 | 0-1 |   0-2    |"
+    "+---+-----+-----+----------+"
+    "| 1 | 1-0 | 1-1 |   1-2    |"
+    "+---+-----+-----+----------+"
+    "| 2 | 2-0 | 2-1 |   2-2    |"
+    "+---+-----+-----+----------+"
+);
+
+test_table!(
+    span_column_at_column,
+    Matrix::new(3, 3).with(Span::column_at(Columns::single(1).not(Rows::first()), 2)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |         0-0         |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |         1-0         |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |         2-0         |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    span_column_at_overlap_is_ignored,
+    Matrix::new(3, 3).with(Span::column_at(Cell::new(1, 2).and(Cell::new(1, 1)), 2)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |         0-1         |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    span_column_at_out_of_bounds,
+    Matrix::new(3, 3).with(Span::column_at(Cell::new(1, 2), 10)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |         0-1         |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    span_row_at_column,
+    Matrix::new(3, 3).with(Span::row_at(Columns::first().not(Rows::first()), 2)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+   +----------+----------+----------+"
+    "|   |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
 fn create_span_list(count_rows: usize, count_cols: usize) -> impl Iterator<Item = Position> {
     (0..count_rows).flat_map(move |r| (0..count_cols).map(move |c| (r, c).into()))
 }
+
+#[test]
+fn span_try_with_intersection_test() {
+    let mut table = Matrix::table(3, 3);
+    table.modify((1, 1), Span::column(2));
+
+    let expected = table.to_string();
+
+    let result = table.try_with(Modify::new((1, 2)).with(Span::row(2)));
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::SpanIntersection(Position::new(1, 2)))
+    );
+
+    assert_eq!(table.to_string(), expected);
+}
+
+#[test]
+fn span_try_with_row_intersection_test() {
+    let mut table = Matrix::table(3, 3);
+    table.modify((1, 1), Span::row(2));
+
+    let result = table.try_with(Modify::new((2, 1)).with(Span::column(2)));
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::SpanIntersection(Position::new(2, 1)))
+    );
+}
+
+test_table!(
+    span_try_with_valid_test,
+    {
+        let mut table = Matrix::table(2, 2);
+        table
+            .try_with(Modify::new((1, 1)).with(Span::column(2)))
+            .unwrap();
+        table
+    },
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |         0-0         |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+#[test]
+fn span_column_kept_on_insert_column() {
+    let mut table = Builder::from_iter([["a", "b", "c"], ["d", "e", "f"]]).build();
+    table
+        .with(Style::ascii())
+        .modify((0, 1), Span::column(2))
+        .modify((0, 1), Alignment::center());
+
+    tabled::grid::records::Resizable::insert_column(table.get_records_mut(), 0);
+    table.get_config_mut().insert_column(0);
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+--+---+---+---+"
+            "|  | a |   b   |"
+            "+--+---+---+---+"
+            "|  | d | e | f |"
+            "+--+---+---+---+"
+        )
+    );
+}
+
+#[test]
+fn span_try_with_out_of_bounds_test() {
+    let mut table = Matrix::table(3, 3);
+    let expected = table.to_string();
+
+    let result = table.try_with(Modify::new((10, 10)).with(Span::column(2)));
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::OutOfBounds(Position::new(10, 10)))
+    );
+
+    let result = table.try_with(Modify::new(Rows::new(2..10)).with(Span::row(2)));
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::OutOfBounds(Position::new(5, 0)))
+    );
+
+    let result = table.try_with(Modify::new(Columns::single(5)).with(Span::column(2)));
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::OutOfBounds(Position::new(0, 5)))
+    );
+
+    // the one past the end cell is not a part of an object bounds check,
+    // but it's still out of bounds for a span
+    let result = table.try_with(Modify::new((4, 0)).with(Span::column(2)));
+    assert_eq!(
+        result.map(|_| ()),
+        Err(TableError::OutOfBounds(Position::new(4, 0)))
+    );
+
+    assert_eq!(table.to_string(), expected);
+}
@@ -2,7 +2,11 @@
 
 use std::iter::FromIterator;
 
-use tabled::{builder::Builder, settings::split::Split, Table};
+use tabled::{
+    builder::Builder,
+    settings::{object::Columns, split::Split},
+    Table,
+};
 
 use testing_table::test_table;
 
@@ -275,3 +279,79 @@ test_table!(
     "| g |  |  |  |  |  |  |  |  |  |  |  |  |  |  |   |  |  |  |  |"
     "+---+--+--+--+--+--+--+--+--+--+--+--+--+--+--+---+--+--+--+--+"
 );
+
+test_table!(
+    split_by_width_test,
+    Table::new([["abcdefghijkl"]]).modify((1, 0), Split::by_width(4)),
+    "+------+"
+    "| 0    |"
+    "+------+"
+    "| abcd |"
+    "| efgh |"
+    "| ijkl |"
+    "+------+"
+);
+
+test_table!(
+    split_by_width_keeps_newlines_test,
+    Table::new([["abcdef\nghi"]]).modify((1, 0), Split::by_width(4)),
+    "+------+"
+    "| 0    |"
+    "+------+"
+    "| abcd |"
+    "| ef   |"
+    "| ghi  |"
+    "+------+"
+);
+
+test_table!(
+    split_by_width_does_not_change_short_cell_test,
+    Table::new([["abc", "abcdefgh"]]).modify(Columns::new(..), Split::by_width(4)),
+    "+-----+------+"
+    "| 0   | 1    |"
+    "+-----+------+"
+    "| abc | abcd |"
+    "|     | efgh |"
+    "+-----+------+"
+);
+
+test_table!(
+    split_by_width_drops_whitespace_at_split_test,
+    Table::new([["Hello World !"]]).modify((1, 0), Split::by_width(5)),
+    "+-------+"
+    "| 0     |"
+    "+-------+"
+    "| Hello |"
+    "| World |"
+    "| !     |"
+    "+-------+"
+);
+
+test_table!(
+    split_by_width_wide_chars_test,
+    Table::new([["一二三四五"]]).modify((1, 0), Split::by_width(5)),
+    "+------+"
+    "| 0    |"
+    "+------+"
+    "| 一二 |"
+    "| 三四 |"
+    "| 五   |"
+    "+------+"
+);
+
+test_table!(
+    split_by_width_zero_test,
+    Table::new([["abcdefghijkl"]]).modify((1, 0), Split::by_width(0)),
+    "+--------------+"
+    "| 0            |"
+    "+--------------+"
+    "| abcdefghijkl |"
+    "+--------------+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    split_by_width_ansi_test,
+    Table::new([["\u{1b}[31mabcdefghijkl\u{1b}[39m"]]).modify((1, 0), Split::by_width(4)),
+    "+------+\n| 0    |\n+------+\n| \u{1b}[31mabcd\u{1b}[39m |\n| \u{1b}[31mefgh\u{1b}[39m |\n| \u{1b}[31mijkl\u{1b}[39m |\n+------+"
+);
@@ -6,7 +6,7 @@ use tabled::{
     builder::Builder,
     grid::config::Border as GridBorder,
     settings::{
-        object::{Columns, Rows, Segment},
+        object::{Cell, Columns, Rows, Segment},
         style::{
             Border, BorderColor, BorderSpanCorrection, HorizontalLine, LineChar, LineText, Offset,
             On, Style, VerticalLine,
@@ -80,6 +80,34 @@ test_table!(
     "╰───┴──────────┴──────────┴──────────╯"
 );
 
+test_table!(
+    rounded_corners_ascii_style,
+    Matrix::new(3, 3).with(Style::ascii().rounded_corners()),
+    "╭---+----------+----------+----------╮"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "╰---+----------+----------+----------╯"
+);
+
+test_table!(
+    rounded_corners_modern_style,
+    Matrix::new(3, 3).with(Style::modern().rounded_corners()),
+    "╭───┬──────────┬──────────┬──────────╮"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "╰───┴──────────┴──────────┴──────────╯"
+);
+
 test_table!(
     modern_round_style,
     Matrix::new(3, 3).with(Style::modern_rounded()),
@@ -127,6 +155,37 @@ test_table!(
     " 2     2-0        2-1        2-2    "
 );
 
+test_table!(
+    framed_style,
+    Matrix::new(3, 3).with(Style::framed()),
+    "+---------------------------------+"
+    "| N  column 0  column 1  column 2 |"
+    "| 0    0-0       0-1       0-2    |"
+    "| 1    1-0       1-1       1-2    |"
+    "| 2    2-0       2-1       2-2    |"
+    "+---------------------------------+"
+);
+
+test_table!(
+    framed_style_with_horizontals,
+    Matrix::new(3, 3).with(Style::framed().horizontals([(1, HorizontalLine::new('-').left('+').right('+'))])),
+    "+---------------------------------+"
+    "| N  column 0  column 1  column 2 |"
+    "+---------------------------------+"
+    "| 0    0-0       0-1       0-2    |"
+    "| 1    1-0       1-1       1-2    |"
+    "| 2    2-0       2-1       2-2    |"
+    "+---------------------------------+"
+);
+
+test_table!(
+    framed_style_single_cell,
+    Matrix::table(0, 0).with(Style::framed()),
+    "+---+"
+    "| N |"
+    "+---+"
+);
+
 test_table!(
     extended_style,
     Matrix::new(3, 3).with(Style::extended()),
@@ -2316,6 +2375,46 @@ test_table!(
     "+---*----------+----------+----------+"
 );
 
+test_table!(
+    only_vertical_0,
+    Matrix::new(3, 3).with(Style::ascii().only_vertical(2)),
+    "+-------------+--------------------+"
+    "| N  column 0 | column 1  column 2 |"
+    "+-------------+--------------------+"
+    "| 0    0-0    |   0-1       0-2    |"
+    "+-------------+--------------------+"
+    "| 1    1-0    |   1-1       1-2    |"
+    "+-------------+--------------------+"
+    "| 2    2-0    |   2-1       2-2    |"
+    "+-------------+--------------------+"
+);
+
+test_table!(
+    only_vertical_1,
+    Matrix::new(3, 3).with(Style::rounded().only_vertical(1)),
+    "╭───┬──────────────────────────────╮"
+    "│ N │ column 0  column 1  column 2 │"
+    "├───┼──────────────────────────────┤"
+    "│ 0 │   0-0       0-1       0-2    │"
+    "│ 1 │   1-0       1-1       1-2    │"
+    "│ 2 │   2-0       2-1       2-2    │"
+    "╰───┴──────────────────────────────╯"
+);
+
+test_table!(
+    only_vertical_out_of_range,
+    Matrix::new(3, 3).with(Style::modern().only_vertical(10)),
+    "┌─────────────────────────────────┐"
+    "│ N  column 0  column 1  column 2 │"
+    "├─────────────────────────────────┤"
+    "│ 0    0-0       0-1       0-2    │"
+    "├─────────────────────────────────┤"
+    "│ 1    1-0       1-1       1-2    │"
+    "├─────────────────────────────────┤"
+    "│ 2    2-0       2-1       2-2    │"
+    "└─────────────────────────────────┘"
+);
+
 test_table!(
     verticals_4,
     {
@@ -2711,6 +2810,30 @@ test_table!(
     "+---+----------+----------x"
 );
 
+test_table!(
+    line_text_vertical_label_on_left_frame,
+    Matrix::table(2, 2).with(LineText::new("ID", Columns::first()).offset(1)),
+    "+---+----------+----------+"
+    "I N | column 0 | column 1 |"
+    "D---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    line_text_vertical_label_truncated,
+    Matrix::table(2, 2).with(LineText::new("IDENTIFIERS", Columns::last()).offset(2)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------I"
+    "| 0 |   0-0    |   0-1    D"
+    "+---+----------+----------E"
+    "| 1 |   1-0    |   1-1    N"
+    "+---+----------+----------T"
+);
+
 test_table!(
     line_text_vertical_2,
     Matrix::table(2, 2).with(LineText::new("-Tablex", Columns::single(2))),
@@ -2820,3 +2943,89 @@ test_table!(
     "| 1 |   1-0    |   1-1    |"
     "+---+----------+----------+"
 );
+
+test_table!(
+    horizontal_line_by_object_test,
+    Matrix::new(3, 3)
+        .with(Style::modern().remove_horizontal())
+        .modify(Rows::single(1), HorizontalLine::full('━', '┿', '┝', '┥')),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "┝━━━┿━━━━━━━━━━┿━━━━━━━━━━┿━━━━━━━━━━┥"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    horizontal_line_by_object_with_horizontals_test,
+    Matrix::new(3, 3)
+        .with(Style::modern().remove_horizontal().horizontals([(1, HorizontalLine::inherit(Style::modern()))]))
+        .modify(Rows::single(2), HorizontalLine::full('━', '┿', '┝', '┥')),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "┝━━━┿━━━━━━━━━━┿━━━━━━━━━━┿━━━━━━━━━━┥"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    horizontal_line_by_object_ignores_columns_test,
+    Matrix::new(2, 2)
+        .with(Style::modern().remove_horizontal())
+        .modify(Columns::first(), HorizontalLine::full('━', '┿', '┝', '┥')),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "│ 0 │   0-0    │   0-1    │"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    border_inherit_cell,
+    {
+        let mut table = Matrix::table(3, 3);
+        table.with(Style::modern());
+        table.with(Highlight::new(Cell::new(1, 1)).border(Border::filled('#').top('=').bottom('=')));
+
+        let border = Border::inherit_cell(&table, (1, 1));
+        table.modify(Cell::new(3, 3), border);
+        table
+    },
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───#==========#──────────┼──────────┤"
+    "│ 0 #   0-0    #   0-1    │   0-2    │"
+    "├───#==========#──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├───┼──────────┼──────────#==========#"
+    "│ 2 │   2-0    │   2-1    #   2-2    #"
+    "└───┴──────────┴──────────#==========#"
+);
+
+test_table!(
+    border_inherit_cell_missing_sides_are_unchanged,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.with(Style::ascii().remove_horizontal().remove_vertical());
+
+        let border = Border::inherit_cell(&table, (1, 2));
+        let inner = border.into_inner();
+        assert_eq!(inner.top, None);
+        assert_eq!(inner.left, None);
+        assert_eq!(inner.right, Some('|'));
+
+        table.modify(Cell::new(1, 1), Border::new().left('#'));
+        table.modify(Cell::new(1, 1), border);
+        table
+    },
+    "+--- ---------- ----------+"
+    "| N   column 0   column 1 |"
+    "| 0 #   0-0    |   0-1    |"
+    "| 1     1-0        1-1    |"
+    "+--- ---------- ----------+"
+);
@@ -1,6 +1,13 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{themes::Theme, Style};
+use tabled::{
+    grid::config::{AlignmentHorizontal, Indent, Sides},
+    settings::{
+        style::{HorizontalLine, VerticalLine},
+        themes::Theme,
+        Alignment, Color, Padding, Style,
+    },
+};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -28,3 +35,131 @@ test_table!(
     "| 1 |   1-0    |   1-1    |   1-2    |"
     "| 2 |   2-0    |   2-1    |   2-2    |"
 );
+
+#[test]
+fn theme_extract_modern() {
+    let mut table = Matrix::table(3, 3);
+    table.with(Style::modern());
+
+    let theme = Theme::extract(&table);
+
+    let mut other = Matrix::table(3, 3);
+    other.with(Style::ascii()).with(theme);
+
+    assert_eq!(table.to_string(), other.to_string());
+}
+
+#[test]
+fn theme_extract_lines_colors_padding_alignment() {
+    let style = Style::modern()
+        .remove_horizontal()
+        .horizontals([(1, HorizontalLine::inherit(Style::modern()))])
+        .verticals([(1, VerticalLine::filled('┃').remove_intersection())]);
+
+    let mut table = Matrix::table(3, 3);
+    table.with(style);
+
+    let mut theme = Theme::extract(&table);
+    theme.set_colors_bottom(Color::FG_RED);
+    theme.set_colors_left(Color::FG_BLUE);
+
+    table
+        .with(theme)
+        .with(Padding::new(2, 0, 1, 0))
+        .with(Alignment::right());
+
+    let theme = Theme::extract(&table);
+    assert_eq!(
+        theme.get_alignment_horizontal(),
+        Some(AlignmentHorizontal::Right)
+    );
+    assert_eq!(
+        theme.get_padding(),
+        Some(&Sides::new(
+            Indent::spaced(2),
+            Indent::spaced(0),
+            Indent::spaced(1),
+            Indent::spaced(0)
+        ))
+    );
+
+    let mut other = Matrix::table(3, 3);
+    other.with(theme);
+
+    let output = table.to_string();
+    assert!(output.contains("\u{1b}[31m"));
+    assert!(output.contains("┃"));
+    assert_eq!(output, other.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn theme_extract_serde_round_trip() {
+    let style = Style::modern()
+        .remove_horizontal()
+        .horizontals([(1, HorizontalLine::inherit(Style::modern()))])
+        .verticals([(1, VerticalLine::filled('┃').remove_intersection())]);
+
+    let mut table = Matrix::table(3, 3);
+    table
+        .with(style)
+        .with(Padding::new(2, 0, 1, 0))
+        .with(Alignment::right());
+
+    let mut theme = Theme::extract(&table);
+    theme.set_colors_bottom(Color::FG_RED);
+    table.with(theme.clone());
+
+    let json = serde_json::to_string(&theme).unwrap();
+    let restored: Theme = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    assert_eq!(restored.get_padding(), theme.get_padding());
+    assert_eq!(
+        restored.get_alignment_horizontal(),
+        Some(AlignmentHorizontal::Right)
+    );
+
+    let mut other = Matrix::table(3, 3);
+    other.with(restored);
+
+    assert_eq!(table.to_string(), other.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn theme_serde_unknown_field() {
+    let json = r#"{ "chars": { "top": "-", "middle": "+" } }"#;
+    assert!(serde_json::from_str::<Theme>(json).is_err());
+
+    let json = r#"{ "chars": { "top": "-" }, "alignment_horizontal": "left" }"#;
+    let theme: Theme = serde_json::from_str(json).unwrap();
+    assert_eq!(theme.get_borders_top(), Some('-'));
+    assert_eq!(
+        theme.get_alignment_horizontal(),
+        Some(AlignmentHorizontal::Left)
+    );
+}
+
+test_table!(
+    theme_padding_alignment,
+    {
+        let mut theme = Theme::from_style(Style::modern());
+        theme.set_padding(Sides::new(
+            Indent::spaced(0),
+            Indent::spaced(2),
+            Indent::spaced(0),
+            Indent::spaced(0),
+        ));
+        theme.set_alignment_horizontal(AlignmentHorizontal::Right);
+
+        Matrix::new(2, 2).with(theme)
+    },
+    "┌───┬──────────┬──────────┐"
+    "│N  │column 0  │column 1  │"
+    "├───┼──────────┼──────────┤"
+    "│0  │     0-0  │     0-1  │"
+    "├───┼──────────┼──────────┤"
+    "│1  │     1-0  │     1-1  │"
+    "└───┴──────────┴──────────┘"
+);
@@ -3,11 +3,11 @@
 use tabled::{
     grid::util::string::get_text_width,
     settings::{
-        formatting::{TabSize, TrimStrategy},
+        formatting::{Justification, TabSize, TrimStrategy},
         object::{Columns, Object, Rows, Segment},
-        peaker::{PriorityLeft, PriorityMax, PriorityMin, PriorityRight},
-        width::{Justify, MinWidth, SuffixLimit, Width},
-        Alignment, Margin, Modify, Padding, Panel, Settings, Span, Style,
+        peaker::{Priority, PriorityLeft, PriorityMax, PriorityMin, PriorityRight},
+        width::{ColumnWidths, EqualWidth, Justify, MinWidth, SuffixLimit, Width},
+        Alignment, Format, Margin, Modify, Padding, Panel, Settings, Span, Style,
     },
 };
 
@@ -353,6 +353,42 @@ test_table!(
     "|  \u{1b}[40m\u{1b}[32mthis\u{1b}[49m\u{1b}[39m  |"
 );
 
+#[cfg(feature = "ansi")]
+test_table!(
+    max_width_wrapped_color_each_line,
+    Matrix::iter(vec![Color::FG_RED.colorize("aaaaabbbbbccccc")])
+        .with(Style::markdown())
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(5))),
+    "| String |"
+    "|--------|"
+    "| \u{1b}[31maaaaa\u{1b}[39m  |"
+    "| \u{1b}[31mbbbbb\u{1b}[39m  |"
+    "| \u{1b}[31mccccc\u{1b}[39m  |"
+);
+
+#[cfg(feature = "ansi")]
+#[test]
+fn max_width_wrapped_color_each_line_not_reset() {
+    let text = "\u{1b}[31maaaaa \u{1b}[1mbbbbb ccccc";
+    for keep_words in [false, true] {
+        let table = Matrix::iter([text])
+            .with(Style::blank())
+            .with(Padding::zero())
+            .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).keep_words(keep_words)))
+            .to_string();
+
+        let lines = table.lines().skip(1).map(str::trim_end).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "\u{1b}[31maaaaa \u{1b}[39m",
+                "\u{1b}[1m\u{1b}[31mbbbbb \u{1b}[22m\u{1b}[39m",
+                "\u{1b}[1m\u{1b}[31mccccc\u{1b}[22m\u{1b}[39m",
+            ]
+        );
+    }
+}
+
 test_table!(
     max_width_wrapped_keep_words_long_word,
     Matrix::iter(["this is a long sentencesentencesentence"])
@@ -2209,6 +2245,72 @@ fn max_width_wrap_priority_min_with_span() {
     );
 }
 
+#[test]
+fn max_width_wrap_priority_by_content() {
+    let table = Matrix::new(3, 3)
+        .insert((2, 1).into(), "Hello World With Big Line")
+        .with(Style::markdown())
+        .with(Width::wrap(35).priority(Priority::by_content()))
+        .to_string();
+
+    assert_width!(table, 35);
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column  | column  | column  |"
+            "|   | 0       | 1       | 2       |"
+            "|---|---------|---------|---------|"
+            "| 0 |   0-0   |   0-1   |   0-2   |"
+            "| 1 | Hello W |   1-1   |   1-2   |"
+            "|   | orld Wi |         |         |"
+            "|   | th Big  |         |         |"
+            "|   | Line    |         |         |"
+            "| 2 |   2-0   |   2-1   |   2-2   |"
+        )
+    );
+}
+
+#[test]
+fn max_width_truncate_priority_by_content_keeps_1_char() {
+    let table = Matrix::new(3, 3)
+        .insert((2, 1).into(), "Hello World With Big Line")
+        .with(Style::markdown())
+        .with(Width::truncate(0).priority(Priority::by_content()))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | c | c | c |"
+            "|---|---|---|---|"
+            "| 0 | 0 | 0 | 0 |"
+            "| 1 | H | 1 | 1 |"
+            "| 2 | 2 | 2 | 2 |"
+        )
+    );
+}
+
+#[test]
+fn max_width_truncate_priority_column() {
+    let table = Matrix::new(3, 3)
+        .insert((2, 1).into(), "Hello World With Big Line")
+        .with(Style::markdown())
+        .with(Width::truncate(40).priority(Priority::column(2)))
+        .to_string();
+
+    assert_width!(table, 40);
+    assert_eq!(
+        table,
+        static_table!(
+            "| N |     column 0      | c | column 2 |"
+            "|---|-------------------|---|----------|"
+            "| 0 |        0-0        | 0 |   0-2    |"
+            "| 1 | Hello World With  | 1 |   1-2    |"
+            "| 2 |        2-0        | 2 |   2-2    |"
+        )
+    );
+}
+
 #[test]
 fn min_width_priority_max() {
     let table = Matrix::new(3, 3)
@@ -2249,6 +2351,47 @@ fn min_width_priority_min() {
     );
 }
 
+#[test]
+fn min_width_priority_column() {
+    let table = Matrix::new(3, 3)
+        .with(Style::markdown())
+        .with(MinWidth::new(60).priority(Priority::column(2)))
+        .to_string();
+
+    assert_eq!(get_text_width(&table), 60);
+    assert_eq!(
+        table,
+        static_table!(
+            "| N | column 0 |            column 1            | column 2 |"
+            "|---|----------|--------------------------------|----------|"
+            "| 0 |   0-0    |              0-1               |   0-2    |"
+            "| 1 |   1-0    |              1-1               |   1-2    |"
+            "| 2 |   2-0    |              2-1               |   2-2    |"
+        ),
+    );
+}
+
+#[test]
+fn min_width_priority_column_narrow() {
+    let table = Matrix::new(3, 3)
+        .with(Style::markdown())
+        .with(Padding::zero())
+        .with(Width::increase(40).priority(Priority::column(0)))
+        .to_string();
+
+    assert_eq!(get_text_width(&table), 40);
+    assert_eq!(
+        table,
+        static_table!(
+            "|     N     |column 0|column 1|column 2|"
+            "|-----------|--------|--------|--------|"
+            "|     0     |  0-0   |  0-1   |  0-2   |"
+            "|     1     |  1-0   |  1-1   |  1-2   |"
+            "|     2     |  2-0   |  2-1   |  2-2   |"
+        ),
+    );
+}
+
 #[test]
 fn max_width_tab_0() {
     let table =
@@ -2443,6 +2586,51 @@ fn table_truncate_multiline_with_suffix() {
     );
 }
 
+#[cfg(feature = "ansi")]
+test_table!(
+    cell_truncate_multiline_color_with_suffix,
+    Matrix::new(1, 2)
+        .insert((1, 1).into(), Color::FG_RED.colorize("Hello World\nSecond line"))
+        .with(Style::markdown())
+        .with(Modify::new(Columns::single(1)).with(Width::truncate(8).multiline(true).suffix("…"))),
+    "| N | column 0 | column 1 |\n|---|----------|----------|\n| 0 | \u{1b}[31mHello W\u{1b}[39m… |   0-1    |\n|   | \u{1b}[31mSecond \u{1b}[39m… |          |"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    cell_truncate_multiline_color_with_suffix_try_color,
+    Matrix::new(1, 2)
+        .insert((1, 1).into(), Color::FG_RED.colorize("Hello World\nSecond line"))
+        .with(Style::markdown())
+        .with(
+            Modify::new(Columns::single(1))
+                .with(Width::truncate(8).multiline(true).suffix("…").suffix_try_color(true)),
+        ),
+    "| N | column 0 | column 1 |\n|---|----------|----------|\n| 0 | \u{1b}[31mHello W\u{1b}[39m\u{1b}[31m…\u{1b}[39m |   0-1    |\n|   | \u{1b}[31mSecond \u{1b}[39m\u{1b}[31m…\u{1b}[39m |          |"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    cell_truncate_color_blocks_with_suffix,
+    Matrix::new(1, 2)
+        .insert((1, 1).into(), format!("{}{}", Color::FG_RED.colorize("Hel"), Color::FG_BLUE.colorize("lo World")))
+        .with(Style::markdown())
+        .with(Modify::new(Columns::single(1)).with(Width::truncate(6).suffix(".."))),
+    "| N | colu.. | column 1 |\n|---|--------|----------|\n| 0 | \u{1b}[31mHel\u{1b}[39m\u{1b}[34ml\u{1b}[39m.. |   0-1    |"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    column_truncate_suffix_per_column,
+    Matrix::new(1, 2)
+        .insert((1, 1).into(), Color::FG_RED.colorize("Hello World"))
+        .insert((1, 2).into(), Color::FG_BLUE.colorize("Hello World"))
+        .with(Style::markdown())
+        .with(Modify::new(Columns::single(1)).with(Width::truncate(6).suffix("..")))
+        .with(Modify::new(Columns::single(2)).with(Width::truncate(6).suffix("~"))),
+    "| N | colu.. | colum~ |\n|---|--------|--------|\n| 0 | \u{1b}[31mHell\u{1b}[39m.. | \u{1b}[34mHello\u{1b}[39m~ |"
+);
+
 test_table!(
     test_priority_left,
     Matrix::new(3, 10)
@@ -2917,3 +3105,294 @@ mod derived {
         );
     }
 }
+
+test_table!(
+    column_widths,
+    Matrix::new(3, 3).with(ColumnWidths::new([3, 5, 12, 2])),
+    "+---+-----+------------+--+"
+    "| N | col |  column 1  |  |"
+    "+---+-----+------------+--+"
+    "| 0 | 0-0 |    0-1     |  |"
+    "+---+-----+------------+--+"
+    "| 1 | 1-0 |    1-1     |  |"
+    "+---+-----+------------+--+"
+    "| 2 | 2-0 |    2-1     |  |"
+    "+---+-----+------------+--+"
+);
+
+test_table!(
+    column_widths_suffix,
+    Matrix::new(3, 3)
+        .with(Style::markdown())
+        .with(ColumnWidths::new([3, 6, 6]).suffix("..")),
+    "| N | co.. | co.. | column 2 |"
+    "|---|------|------|----------|"
+    "| 0 | 0-0  | 0-1  |   0-2    |"
+    "| 1 | 1-0  | 1-1  |   1-2    |"
+    "| 2 | 2-0  | 2-1  |   2-2    |"
+);
+
+test_table!(
+    column_widths_prefix,
+    Matrix::new(3, 3).with(ColumnWidths::new([5])),
+    "+-----+----------+----------+----------+"
+    "|  N  | column 0 | column 1 | column 2 |"
+    "+-----+----------+----------+----------+"
+    "|  0  |   0-0    |   0-1    |   0-2    |"
+    "+-----+----------+----------+----------+"
+    "|  1  |   1-0    |   1-1    |   1-2    |"
+    "+-----+----------+----------+----------+"
+    "|  2  |   2-0    |   2-1    |   2-2    |"
+    "+-----+----------+----------+----------+"
+);
+
+test_table!(
+    column_widths_longer_list,
+    Matrix::new(1, 1).with(ColumnWidths::new([1, 2, 3, 4, 5, 6])),
+    "+--+--+"
+    "|  |  |"
+    "+--+--+"
+    "|  |  |"
+    "+--+--+"
+);
+
+test_table!(
+    column_widths_justification,
+    Matrix::new(1, 1)
+        .with(Justification::new('#'))
+        .with(ColumnWidths::new([5, 12])),
+    "+-----+------------+"
+    "| #N# | #column 0# |"
+    "+-----+------------+"
+    "| #0# | ###0-0#### |"
+    "+-----+------------+"
+);
+
+test_table!(
+    column_widths_span,
+    Matrix::table(3, 3)
+        .modify((1, 1), Span::column(2))
+        .modify(
+            (1, 1),
+            Format::content(|_| String::from("a very long spanned text"))
+        )
+        .with(ColumnWidths::new([3, 5, 5, 5])),
+    "+---+-----+-----+-----+"
+    "| N | col | col | col |"
+    "+---+-----+-----+-----+"
+    "| 0 | a very lo | 0-2 |"
+    "+---+-----+-----+-----+"
+    "| 1 | 1-0 | 1-1 | 1-2 |"
+    "+---+-----+-----+-----+"
+    "| 2 | 2-0 | 2-1 | 2-2 |"
+    "+---+-----+-----+-----+"
+);
+
+test_table!(
+    column_widths_padding,
+    Matrix::new(1, 1)
+        .with(Padding::new(2, 2, 0, 0))
+        .with(ColumnWidths::new([6, 6])),
+    "+------+------+"
+    "|  N   |  co  |"
+    "+------+------+"
+    "|  0   |  0-  |"
+    "+------+------+"
+);
+
+#[test]
+fn column_widths_exact() {
+    let table = Matrix::new(5, 3)
+        .with(Style::modern())
+        .with(ColumnWidths::new([4, 7, 3, 20]))
+        .to_string();
+
+    for line in table.lines() {
+        assert_eq!(get_text_width(line), 1 + 4 + 1 + 7 + 1 + 3 + 1 + 20 + 1);
+    }
+
+    let widths = table
+        .lines()
+        .nth(1)
+        .unwrap()
+        .split('│')
+        .map(get_text_width)
+        .collect::<Vec<_>>();
+
+    assert_eq!(widths, [0, 4, 7, 3, 20, 0]);
+}
+
+test_table!(
+    equal_width,
+    Matrix::new(2, 2).with(Width::equal()),
+    "+----------+----------+----------+"
+    "|    N     | column 0 | column 1 |"
+    "+----------+----------+----------+"
+    "|    0     |   0-0    |   0-1    |"
+    "+----------+----------+----------+"
+    "|    1     |   1-0    |   1-1    |"
+    "+----------+----------+----------+"
+);
+
+test_table!(
+    equal_width_total,
+    Matrix::new(2, 2).with(Style::modern()).with(Width::equal().total(20)),
+    "┌──────┬─────┬─────┐"
+    "│  N   │ col │ col │"
+    "│      │ umn │ umn │"
+    "│      │  0  │  1  │"
+    "├──────┼─────┼─────┤"
+    "│  0   │ 0-0 │ 0-1 │"
+    "├──────┼─────┼─────┤"
+    "│  1   │ 1-0 │ 1-1 │"
+    "└──────┴─────┴─────┘"
+);
+
+test_table!(
+    equal_width_total_remainder,
+    Matrix::new(2, 2).with(Style::markdown()).with(EqualWidth::new().total(26)),
+    "|   N    | colum | colum |"
+    "|        | n 0   | n 1   |"
+    "|--------|-------|-------|"
+    "|   0    |  0-0  |  0-1  |"
+    "|   1    |  1-0  |  1-1  |"
+);
+
+test_table!(
+    equal_width_span,
+    Matrix::table(2, 2)
+        .modify((1, 1), Span::column(2))
+        .modify(
+            (1, 1),
+            Format::content(|_| String::from("a very long spanned text"))
+        )
+        .with(Width::equal().total(25)),
+    "+-------+-------+-------+"
+    "|   N   | colum | colum |"
+    "|       | n 0   | n 1   |"
+    "+-------+-------+-------+"
+    "|   0   | a very long s |"
+    "|       | panned text   |"
+    "+-------+-------+-------+"
+    "|   1   |  1-0  |  1-1  |"
+    "+-------+-------+-------+"
+);
+
+test_table!(
+    equal_width_padding,
+    Matrix::new(1, 1)
+        .with(Padding::new(4, 4, 0, 0))
+        .with(Width::equal().total(10)),
+    "+--------+--------+"
+    "|        |        |"
+    "+--------+--------+"
+    "|        |        |"
+    "+--------+--------+"
+);
+
+#[test]
+fn equal_width_exact() {
+    let table = Matrix::new(5, 2)
+        .with(Style::modern())
+        .with(Width::equal().total(40))
+        .to_string();
+
+    for line in table.lines() {
+        assert_eq!(get_text_width(line), 40);
+    }
+
+    let widths = table
+        .lines()
+        .nth(1)
+        .unwrap()
+        .split('│')
+        .map(get_text_width)
+        .collect::<Vec<_>>();
+
+    assert_eq!(widths, [0, 12, 12, 12, 0]);
+}
+
+test_table!(
+    wrap_keep_words_with_url,
+    {
+        let table = Matrix::iter(vec![
+            "https://github.com/zhiburt/tabled/blob/master/README.md",
+        ])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(20).keep_words_with(|c| c == '/')))
+        .to_string();
+
+        assert_width!(table, 20 + 2 + 2);
+
+        table
+    },
+    "| &str                 |"
+    "|----------------------|"
+    "| https://github.com/  |"
+    "| zhiburt/tabled/blob/ |"
+    "| master/README.md     |"
+);
+
+test_table!(
+    wrap_keep_words_with_url_in_sentence,
+    {
+        Matrix::iter(vec!["see https://example.com/docs/index.html for details"])
+            .with(Style::markdown())
+            .with(Modify::new(Segment::all()).with(Alignment::left()))
+            .with(Modify::new(Segment::all()).with(Width::wrap(16).keep_words_with(|c| c == '/')))
+            .to_string()
+    },
+    "| &str             |"
+    "|------------------|"
+    "| see https://     |"
+    "| example.com/     |"
+    "| docs/index.html  |"
+    "| for details      |"
+);
+
+test_table!(
+    wrap_keep_words_with_snake_case,
+    {
+        Matrix::iter(vec!["some_very_long_snake_case_name"])
+            .with(Style::markdown())
+            .with(Modify::new(Segment::all()).with(Alignment::left()))
+            .with(
+                Modify::new(Segment::all())
+                    .with(Width::wrap(10).keep_words_with(|c| c == '_' || c == '-')),
+            )
+            .to_string()
+    },
+    "| &str       |"
+    "|------------|"
+    "| some_very_ |"
+    "| long_      |"
+    "| snake_     |"
+    "| case_name  |"
+);
+
+test_table!(
+    wrap_keep_words_with_total_width,
+    Matrix::iter(vec!["/usr/local/share/applications"])
+        .with(Style::markdown())
+        .with(Width::wrap(22).keep_words_with(|c| c == '/')),
+    "|        &str        |"
+    "|--------------------|"
+    "| /usr/local/share/  |"
+    "| applications       |"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    wrap_keep_words_with_url_colored,
+    Matrix::iter(vec![
+        Color::FG_RED.colorize("https://example.com/path/to/page")
+    ])
+    .with(Style::markdown())
+    .with(Modify::new(Segment::all()).with(Width::wrap(14).keep_words_with(|c| c == '/'))),
+    "|     String     |"
+    "|----------------|"
+    "| \u{1b}[31mhttps://\u{1b}[39m       |"
+    "| \u{1b}[31mexample.com/\u{1b}[39m   |"
+    "| \u{1b}[31mpath/to/page\u{1b}[39m   |"
+);
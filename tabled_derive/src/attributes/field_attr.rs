@@ -1,4 +1,4 @@
-use syn::{Attribute, LitInt};
+use syn::{Attribute, LitInt, LitStr};
 
 use crate::{
     casing_style::CasingStyle,
@@ -11,15 +11,28 @@ pub struct FieldAttributes {
     pub is_ignored: bool,
     pub inline: bool,
     pub inline_prefix: Option<String>,
+    pub inline_suffix: Option<String>,
+    pub inline_sep: Option<String>,
+    pub inline_depth: Option<usize>,
     pub rename: Option<String>,
     pub rename_all: Option<CasingStyle>,
     pub display_with: Option<String>,
     pub display_with_args: Option<Vec<FormatArg>>,
-    pub order: Option<usize>,
+    pub display_with_by_value: bool,
+    pub display_with_context: bool,
+    pub display_with_borrow: bool,
+    pub order: Option<Order>,
     pub format: Option<String>,
     pub format_with_args: Option<Vec<FormatArg>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Index(usize),
+    First,
+    Last,
+}
+
 pub struct FormatArg {
     pub expr: syn::Expr,
 }
@@ -39,13 +52,30 @@ impl FieldAttributes {
     }
 
     fn fill_attributes(&mut self, attrs: &[Attribute]) -> Result<(), Error> {
+        let mut by_value = None;
         for attrs in parse_field_attributes(attrs) {
             let attrs = attrs?;
             for attr in attrs {
+                if let FieldAttrKind::ByValue(b) = &attr.kind {
+                    by_value = b.value.then(|| b.span());
+                }
+
                 self.insert_attribute(attr)?;
             }
         }
 
+        if let Some(span) = by_value {
+            if self.display_with.is_none() {
+                return Err(Error::new(
+                    "A `by_value` attribute is expected to be used with `display_with`",
+                    span,
+                    Some(String::from(
+                        "add a function, like #[tabled(display_with = \"some_function\", by_value)]",
+                    )),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -56,7 +86,7 @@ impl FieldAttributes {
                     self.is_ignored = true;
                 }
             }
-            FieldAttrKind::Inline(b, prefix) => {
+            FieldAttrKind::Inline(b, prefix, suffix, sep, depth) => {
                 if b.value {
                     self.inline = true;
                 }
@@ -64,6 +94,18 @@ impl FieldAttributes {
                 if let Some(prefix) = prefix {
                     self.inline_prefix = Some(prefix.value());
                 }
+
+                if let Some(suffix) = suffix {
+                    self.inline_suffix = Some(suffix.value());
+                }
+
+                if let Some(sep) = sep {
+                    self.inline_sep = Some(sep.value());
+                }
+
+                if let Some(depth) = depth {
+                    self.inline_depth = Some(lit_int_to_depth(&depth)?);
+                }
             }
             FieldAttrKind::Rename(value) => self.rename = Some(value.value()),
             FieldAttrKind::RenameAll(lit) => {
@@ -71,9 +113,29 @@ impl FieldAttributes {
             }
             FieldAttrKind::DisplayWith(path, comma, args) => {
                 self.display_with = Some(path.value());
+                self.display_with_context = false;
+                self.display_with_borrow = false;
                 if comma.is_some() {
-                    let args = args.into_iter().map(FormatArg::new).collect();
-                    self.display_with_args = Some(args);
+                    let mut args = args.into_iter().collect::<Vec<_>>();
+
+                    // a trailing `context` and `borrow` are keywords rather than arguments
+                    while let Some(arg) = args.last() {
+                        if is_keyword(arg, "context") {
+                            self.display_with_context = true;
+                        } else if is_keyword(arg, "borrow") {
+                            self.display_with_borrow = true;
+                        } else {
+                            break;
+                        }
+
+                        args.pop();
+                    }
+
+                    let has_keywords = self.display_with_context || self.display_with_borrow;
+                    if !(has_keywords && args.is_empty()) {
+                        let args = args.into_iter().map(FormatArg::new).collect();
+                        self.display_with_args = Some(args);
+                    }
                 }
             }
             FieldAttrKind::FormatWith(format, comma, args) => {
@@ -83,13 +145,48 @@ impl FieldAttributes {
                     self.format_with_args = Some(args);
                 }
             }
-            FieldAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::Order(value) => {
+                self.order = Some(Order::Index(lit_int_to_usize(&value)?))
+            }
+            FieldAttrKind::OrderRelative(value) => self.order = Some(lit_str_to_order(&value)?),
+            FieldAttrKind::ByValue(b) => self.display_with_by_value = b.value,
         }
 
         Ok(())
     }
 }
 
+fn is_keyword(expr: &syn::Expr, keyword: &str) -> bool {
+    matches!(expr, syn::Expr::Path(path) if path.qself.is_none() && path.path.is_ident(keyword))
+}
+
+fn lit_str_to_order(value: &LitStr) -> Result<Order, Error> {
+    match value.value().as_str() {
+        "first" => Ok(Order::First),
+        "last" => Ok(Order::Last),
+        order => Err(Error::new(
+            format!("Unexpected order {order:?}; expected a number, \"first\" or \"last\""),
+            value.span(),
+            None,
+        )),
+    }
+}
+
+// An upper bound is `tabled::MAX_INLINE_DEPTH`,
+// it's checked by the generated code as the bound is defined by the crate.
+fn lit_int_to_depth(value: &LitInt) -> Result<usize, Error> {
+    let depth = lit_int_to_usize(value)?;
+    if depth == 0 {
+        return Err(Error::new(
+            String::from("An inline depth is expected to be bigger than 0"),
+            value.span(),
+            None,
+        ));
+    }
+
+    Ok(depth)
+}
+
 fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
     value.base10_parse::<usize>().map_err(|e| {
         Error::new(
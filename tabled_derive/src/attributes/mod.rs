@@ -1,5 +1,5 @@
 mod field_attr;
 mod type_attr;
 
-pub use field_attr::{FieldAttributes, FormatArg};
+pub use field_attr::{FieldAttributes, FormatArg, Order};
 pub use type_attr::TypeAttributes;
@@ -17,11 +17,12 @@ use quote::{quote, ToTokens, TokenStreamExt};
 use std::{collections::HashMap, str};
 use syn::visit_mut::VisitMut;
 use syn::{
-    parse_macro_input, token, Data, DataEnum, DataStruct, DeriveInput, ExprPath, Field, Fields,
-    Ident, Index, PathSegment, Type, Variant,
+    parse_macro_input, punctuated::Punctuated, token, Data, DataEnum, DataStruct, DeriveInput,
+    ExprPath, Field, Fields, Generics, Ident, Index, PathSegment, Token, Type, TypeParamBound,
+    Variant, WherePredicate,
 };
 
-use crate::attributes::{FieldAttributes, TypeAttributes};
+use crate::attributes::{FieldAttributes, Order, TypeAttributes};
 use crate::error::Error;
 
 type FieldNameFn = fn(usize, &Field) -> TokenStream;
@@ -44,14 +45,27 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
     let length = get_tabled_length(ast, &attrs, &tabled_trait_path)
         .map_err(error::abort)
         .unwrap();
-    let info = collect_info(ast, &attrs, &tabled_trait_path)
+    let info = collect_info(ast, &attrs, &tabled_trait_path, false, false)
         .map_err(error::abort)
         .unwrap();
     let fields = info.values;
     let headers = info.headers;
+    let headers_with = get_headers_with(ast, &attrs, &tabled_trait_path)
+        .map_err(error::abort)
+        .unwrap();
+    let limited = get_limited_impl(ast, &attrs, &tabled_trait_path)
+        .map_err(error::abort)
+        .unwrap();
+    let depth_checks = get_inline_depth_checks(ast, &tabled_trait_path)
+        .map_err(error::abort)
+        .unwrap();
+
+    let generics = add_field_bounds(ast, &attrs, &tabled_trait_path)
+        .map_err(error::abort)
+        .unwrap();
 
     let name = &ast.ident;
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let expanded = quote! {
         impl #impl_generics #tabled_trait_path for #name #ty_generics #where_clause {
@@ -64,12 +78,263 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #headers_with
+
+            #limited
         }
+
+        #depth_checks
     };
 
     expanded
 }
 
+// Bounds for fields which use generic parameters, so they don't need to be set manually.
+//
+// A displayed field gets a `Display` bound and an inlined one gets a `Tabled` bound,
+// unless a bound with the same trait is already set for the type.
+fn add_field_bounds(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    trait_path: &ExprPath,
+) -> Result<Generics, Error> {
+    let mut generics = ast.generics.clone();
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        return Ok(generics);
+    }
+
+    let fields: Vec<&Field> = match &ast.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(_) if attrs.inline => Vec::new(),
+        Data::Enum(data) => {
+            let mut fields = Vec::new();
+            for variant in &data.variants {
+                let attr = FieldAttributes::parse(&variant.attrs)?;
+                if attr.inline && !attr.is_ignored {
+                    fields.extend(variant.fields.iter());
+                }
+            }
+
+            fields
+        }
+        Data::Union(_) => return Err(Error::message("Union type isn't supported")),
+    };
+
+    let display_path: ExprPath = syn::parse_quote!(::std::fmt::Display);
+
+    let mut bounds = Vec::new();
+    for field in fields {
+        let attr = FieldAttributes::parse(&field.attrs)?;
+        if attr.is_ignored || !is_type_generic(&field.ty, &params) {
+            continue;
+        }
+
+        let bound = if attr.inline {
+            trait_path
+        } else if attr.display_with.is_none() && attr.format.is_none() {
+            &display_path
+        } else {
+            continue;
+        };
+
+        let ty = &field.ty;
+        let predicate: WherePredicate = syn::parse_quote!(#ty: #bound);
+        let key = predicate.to_token_stream().to_string();
+        let exists = has_bound(&generics, ty, bound) || bounds.iter().any(|(k, _)| k == &key);
+        if !exists {
+            bounds.push((key, predicate));
+        }
+    }
+
+    let where_clause = generics.make_where_clause();
+    for (_, predicate) in bounds {
+        where_clause.predicates.push(predicate);
+    }
+
+    Ok(generics)
+}
+
+fn is_type_generic(ty: &Type, params: &[Ident]) -> bool {
+    fn has_param(tokens: TokenStream, params: &[Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => params.contains(&ident),
+            proc_macro2::TokenTree::Group(group) => has_param(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    has_param(ty.to_token_stream(), params)
+}
+
+// Checks whether a type already has a bound of a trait with the same name,
+// either in a parameter list or in a where clause.
+fn has_bound(generics: &Generics, ty: &Type, bound: &ExprPath) -> bool {
+    let name = match bound.path.segments.last() {
+        Some(segment) => &segment.ident,
+        None => return false,
+    };
+
+    let is_bound = |bounds: &Punctuated<TypeParamBound, Token![+]>| {
+        bounds.iter().any(|b| match b {
+            TypeParamBound::Trait(b) => b.path.segments.last().map(|s| &s.ident) == Some(name),
+            _ => false,
+        })
+    };
+
+    let is_param = generics
+        .type_params()
+        .any(|param| is_same_type(ty, &param.ident) && is_bound(&param.bounds));
+    if is_param {
+        return true;
+    }
+
+    let predicates = generics.where_clause.iter().flat_map(|w| &w.predicates);
+    for predicate in predicates {
+        if let WherePredicate::Type(predicate) = predicate {
+            let is_same = predicate.bounded_ty.to_token_stream().to_string()
+                == ty.to_token_stream().to_string();
+            if is_same && is_bound(&predicate.bounds) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn is_same_type(ty: &Type, ident: &Ident) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(ident))
+}
+
+// Headers are built according to the options at runtime.
+//
+// Only structs limit a depth of inlined fields,
+// enums ignore it the same way as they do in `fields_limited`.
+//
+// A custom crate path may point to a trait which declares only the required items,
+// so then the default implementation is used, which keeps the headers unchanged,
+// unless a depth is limited.
+fn get_headers_with(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    trait_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    if attrs.crate_name.is_some() && !is_depth_limited(ast, attrs)? {
+        return Ok(TokenStream::new());
+    }
+
+    let options_path = create_crate_item_path(trait_path, "HeadersOptions");
+    let limited = matches!(ast.data, Data::Struct(_));
+    let headers = collect_info(ast, attrs, trait_path, true, limited)?.headers;
+    let depth = match limited {
+        true => quote!(let depth = options.get_depth();),
+        false => TokenStream::new(),
+    };
+
+    Ok(quote! {
+        #[allow(unused_variables)]
+        fn headers_with(options: #options_path) -> Vec<::std::borrow::Cow<'static, str>> {
+            #depth
+            #headers
+        }
+    })
+}
+
+fn get_limited_impl(
+    ast: &DeriveInput,
+    attrs: &TypeAttributes,
+    trait_path: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let data = match &ast.data {
+        Data::Struct(data) if is_depth_limited(ast, attrs)? => data,
+        _ => return Ok(TokenStream::new()),
+    };
+
+    let max_depth = create_crate_item_path(trait_path, "MAX_INLINE_DEPTH");
+    let length = get_fields_length_limited(&data.fields, trait_path)?;
+    let fields = collect_info(ast, attrs, trait_path, false, true)?.values;
+
+    Ok(quote! {
+        const LENGTH_LIMITED: [usize; #max_depth] = {
+            let mut lengths = [0; #max_depth];
+            let mut depth = 0;
+            while depth < #max_depth {
+                lengths[depth] = #length;
+                depth += 1;
+            }
+
+            lengths
+        };
+
+        #[allow(unused_variables)]
+        fn fields_limited(&self, depth: usize) -> Vec<::std::borrow::Cow<'_, str>> {
+            #fields
+        }
+    })
+}
+
+// Only structs with inlined fields limit a depth,
+// the rest rely on the default implementation as a depth changes nothing for them.
+//
+// A custom crate path may point to a trait which declares only the required items,
+// so then a depth is limited only if it's set explicitly by `#[tabled(inline(depth = N))]`.
+fn is_depth_limited(ast: &DeriveInput, attrs: &TypeAttributes) -> Result<bool, Error> {
+    let data = match &ast.data {
+        Data::Struct(data) => data,
+        _ => return Ok(false),
+    };
+
+    let mut has_inline = false;
+    for field in &data.fields {
+        let attr = FieldAttributes::parse(&field.attrs)?;
+        if attr.is_ignored || !attr.inline {
+            continue;
+        }
+
+        if attr.inline_depth.is_some() {
+            return Ok(true);
+        }
+
+        has_inline = true;
+    }
+
+    Ok(has_inline && attrs.crate_name.is_none())
+}
+
+// A depth of `#[tabled(inline(depth = N))]` is checked against the bound defined by the crate.
+fn get_inline_depth_checks(ast: &DeriveInput, trait_path: &ExprPath) -> Result<TokenStream, Error> {
+    let attrs = match &ast.data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .map(|field| &field.attrs)
+            .collect::<Vec<_>>(),
+        Data::Enum(data) => data.variants.iter().map(|v| &v.attrs).collect(),
+        Data::Union(_) => return Ok(TokenStream::new()),
+    };
+
+    let max_depth = create_crate_item_path(trait_path, "MAX_INLINE_DEPTH");
+    let mut checks = TokenStream::new();
+    for attrs in attrs {
+        let attr = FieldAttributes::parse(attrs)?;
+        if let Some(depth) = attr.inline_depth {
+            checks.extend(quote! {
+                const _: () = assert!(
+                    #depth <= #max_depth,
+                    "An inline depth is bigger than tabled::MAX_INLINE_DEPTH",
+                );
+            });
+        }
+    }
+
+    Ok(checks)
+}
+
 fn get_tabled_length(
     ast: &DeriveInput,
     attrs: &TypeAttributes,
@@ -98,14 +363,7 @@ fn get_fields_length(fields: &Fields, tabled_trait: &ExprPath) -> Result<TokenSt
         .collect::<Result<Vec<_>, Error>>()?
         .into_iter()
         .filter(|(_, attr)| !attr.is_ignored)
-        .map(|(field, attr)| {
-            if attr.inline {
-                let field_type = &field.ty;
-                quote!({<#field_type as #tabled_trait>::LENGTH})
-            } else {
-                quote!({ 1 })
-            }
-        });
+        .map(|(field, attr)| get_field_length(field, &attr, tabled_trait));
 
     let size_components = std::iter::once(quote!(0)).chain(size_components);
 
@@ -115,20 +373,79 @@ fn get_fields_length(fields: &Fields, tabled_trait: &ExprPath) -> Result<TokenSt
     Ok(stream)
 }
 
-fn get_enum_length(enum_ast: &DataEnum, trait_path: &ExprPath) -> Result<TokenStream, Error> {
-    let variant_sizes = get_enum_variant_length(enum_ast, trait_path);
+fn get_field_length(field: &Field, attr: &FieldAttributes, tabled_trait: &ExprPath) -> TokenStream {
+    if !attr.inline {
+        return quote!({ 1 });
+    }
 
-    let mut stream = TokenStream::new();
-    for (i, size) in variant_sizes.enumerate() {
-        let size = size?;
+    let field_type = &field.ty;
+    match attr.inline_depth {
+        Some(depth) => {
+            let depth = depth - 1;
+            quote!({<#field_type as #tabled_trait>::LENGTH_LIMITED[#depth]})
+        }
+        None => quote!({<#field_type as #tabled_trait>::LENGTH}),
+    }
+}
 
-        if i != 0 {
-            stream.append_all(syn::token::Plus::default().into_token_stream());
+// A length for a `depth` variable, it's used in a loop over all depths.
+fn get_fields_length_limited(
+    fields: &Fields,
+    tabled_trait: &ExprPath,
+) -> Result<TokenStream, Error> {
+    let mut size_components = vec![quote!(0)];
+    for field in fields {
+        let attr = FieldAttributes::parse(&field.attrs)?;
+        if attr.is_ignored {
+            continue;
         }
 
-        stream.append_all(size);
+        let length = match attr.inline {
+            true => get_field_length_limited(field, &attr, tabled_trait),
+            false => quote!({ 1 }),
+        };
+
+        size_components.push(length);
     }
 
+    let mut stream = TokenStream::new();
+    stream.append_separated(size_components, syn::token::Plus::default());
+
+    Ok(stream)
+}
+
+fn get_field_length_limited(
+    field: &Field,
+    attr: &FieldAttributes,
+    tabled_trait: &ExprPath,
+) -> TokenStream {
+    let depth = match attr.inline_depth {
+        Some(limit) => quote!(if depth < #limit { depth } else { #limit }),
+        None => quote!(depth),
+    };
+
+    let field_type = &field.ty;
+    quote!({
+        let depth = #depth;
+        if depth == 0 {
+            1
+        } else {
+            <#field_type as #tabled_trait>::LENGTH_LIMITED[depth - 1]
+        }
+    })
+}
+
+fn get_enum_length(enum_ast: &DataEnum, trait_path: &ExprPath) -> Result<TokenStream, Error> {
+    let variant_sizes = get_enum_variant_length(enum_ast, trait_path)
+        .collect::<Result<Vec<_>, Error>>()?
+        .into_iter()
+        .map(|size| quote!({ #size }));
+
+    let variant_sizes = std::iter::once(quote!(0)).chain(variant_sizes);
+
+    let mut stream = TokenStream::new();
+    stream.append_separated(variant_sizes, syn::token::Plus::default());
+
     Ok(stream)
 }
 
@@ -155,14 +472,21 @@ fn get_enum_variant_length<'a>(
         })
 }
 
+// A `casing` flag makes headers to be built for `headers_with`,
+// so the names are converted by a casing from `options` at runtime.
+//
+// A `limited` flag makes headers and values to be built for `headers_with` and `fields_limited`,
+// so inlined fields are expanded only while a `depth` is left.
 fn collect_info(
     ast: &DeriveInput,
     attrs: &TypeAttributes,
     trait_path: &ExprPath,
+    casing: bool,
+    limited: bool,
 ) -> Result<Impl, Error> {
     match &ast.data {
-        Data::Struct(data) => collect_info_struct(data, attrs, trait_path),
-        Data::Enum(data) => collect_info_enum(data, attrs, &ast.ident, trait_path),
+        Data::Struct(data) => collect_info_struct(data, attrs, trait_path, casing, limited),
+        Data::Enum(data) => collect_info_enum(data, attrs, &ast.ident, trait_path, casing),
         Data::Union(_) => Err(Error::message("Union type isn't supported")),
     }
 }
@@ -171,65 +495,102 @@ fn collect_info_struct(
     ast: &DataStruct,
     attrs: &TypeAttributes,
     trait_path: &ExprPath,
+    casing: bool,
+    limited: bool,
 ) -> Result<Impl, Error> {
-    info_from_fields(&ast.fields, attrs, struct_field_name, "", trait_path)
+    info_from_fields(
+        &ast.fields,
+        attrs,
+        struct_field_name,
+        &[],
+        &[],
+        quote!(0),
+        trait_path,
+        casing,
+        limited,
+    )
 }
 
 // todo: refactoring. instead of using a lambda + prefix
 // we could just not emit `self.` `_x` inside
 // So the called would prefix it on its own
+#[allow(clippy::too_many_arguments)]
 fn info_from_fields(
     fields: &Fields,
     attrs: &TypeAttributes,
     field_name: FieldNameFn,
-    header_prefix: &str,
+    header_prefix: &[HeaderPart],
+    header_suffix: &[HeaderPart],
+    index_offset: TokenStream,
     trait_path: &ExprPath,
+    casing: bool,
+    limited: bool,
 ) -> Result<Impl, Error> {
-    let count_fields = fields.len();
-
-    let attributes = fields
-        .into_iter()
-        .enumerate()
-        .map(|(i, field)| -> Result<_, Error> {
-            let mut attributes = FieldAttributes::parse(&field.attrs)?;
-            merge_attributes(&mut attributes, attrs);
-
-            Ok((i, field, attributes))
-        });
-
-    let mut headers = Vec::new();
-    let mut values = Vec::new();
-    let mut reorder = HashMap::new();
-
-    let mut skipped = 0;
-    for result in attributes {
-        let (i, field, attributes) = result?;
+    let mut list = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut attributes = FieldAttributes::parse(&field.attrs)?;
+        merge_attributes(&mut attributes, attrs);
         if attributes.is_ignored {
-            skipped += 1;
             continue;
         }
 
-        if let Some(order) = attributes.order {
-            if order >= count_fields {
-                return Err(Error::message(format!(
-                    "An order index '{order}' is out of fields scope"
-                )));
-            }
+        list.push((i, field, attributes));
+    }
 
-            reorder.insert(order, i - skipped);
-        }
+    let orders = list
+        .iter()
+        .map(|(_, _, attr)| attr.order)
+        .collect::<Vec<_>>();
+
+    check_order_scope(&orders)?;
+
+    let mut positions = (0..list.len()).collect::<Vec<_>>();
+    if orders.iter().any(Option::is_some) {
+        positions = reorder_fields(&orders, &positions);
+    }
 
-        let header = field_headers(field, i, &attributes, header_prefix, trait_path);
+    let context_path = create_field_context_path(trait_path);
+
+    let mut headers = Vec::new();
+    let mut values = Vec::new();
+    let mut column = index_offset;
+    for pos in positions {
+        let (i, field, attributes) = &list[pos];
+
+        let header = field_headers(
+            field,
+            *i,
+            attributes,
+            header_prefix,
+            header_suffix,
+            trait_path,
+            casing,
+            limited,
+        );
         headers.push(header);
 
-        let field_name_result = field_name(i, field);
-        let value = get_field_fields(&field_name_result, &attributes, fields, field_name);
+        let header_name = field_header_name(field, attributes, *i);
+        let header_name = format!(
+            "{}{}{}",
+            header_text(header_prefix),
+            header_name,
+            header_text(header_suffix)
+        );
+        let context = quote!(#context_path::new(#header_name, #column));
+
+        let field_name_result = field_name(*i, field);
+        let value = get_field_fields(
+            &field_name_result,
+            attributes,
+            fields,
+            field_name,
+            &context,
+            limited,
+        );
         values.push(value);
-    }
 
-    if !reorder.is_empty() {
-        values = reorder_fields(&reorder, &values);
-        headers = reorder_fields(&reorder, &headers);
+        let length = get_field_length(field, attributes, trait_path);
+        column = quote!(#column + #length);
     }
 
     let headers = quote!({
@@ -247,63 +608,183 @@ fn info_from_fields(
     Ok(Impl { headers, values })
 }
 
-fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
-    let mut out: Vec<Option<T>> = Vec::with_capacity(elements.len());
-    out.resize(elements.len(), None);
-
-    for (pos, index) in order {
-        let value = elements[*index].clone();
-        out[*pos] = Some(value);
+// Elements with a numeric order take exactly their positions,
+// while the rest fill the gaps in the following order:
+// `first` ones, not ordered ones, and then `last` ones.
+//
+// A source order is kept within each group.
+fn reorder_fields<T: Clone>(orders: &[Option<Order>], elements: &[T]) -> Vec<T> {
+    let mut positions = HashMap::new();
+    for (i, order) in orders.iter().enumerate() {
+        if let Some(Order::Index(pos)) = order {
+            positions.insert(*pos, i);
+        }
     }
 
-    let mut j = 0;
-    for el in &mut out {
-        if el.is_some() {
-            continue;
-        }
+    let group = |i: usize| match orders[i] {
+        Some(Order::First) => 0,
+        Some(Order::Last) => 2,
+        _ => 1,
+    };
 
-        while order.values().any(|&pos| j == pos) {
-            j += 1;
-        }
+    let mut rest = (0..elements.len())
+        .filter(|i| !positions.values().any(|j| j == i))
+        .collect::<Vec<_>>();
+    rest.sort_by_key(|&i| group(i));
+
+    let mut rest = rest.into_iter();
+    let mut out = Vec::with_capacity(elements.len());
+    for pos in 0..elements.len() {
+        let index = match positions.get(&pos) {
+            Some(&index) => index,
+            None => rest
+                .next()
+                .expect("the numeric orders are expected to be checked"),
+        };
 
-        let v = elements[j].clone();
-        *el = Some(v);
+        out.push(elements[index].clone());
+    }
+
+    out
+}
 
-        j += 1;
+fn check_order_scope(orders: &[Option<Order>]) -> Result<(), Error> {
+    for order in orders {
+        if let Some(Order::Index(order)) = order {
+            if *order >= orders.len() {
+                return Err(Error::message(format!(
+                    "An order index '{order}' is out of fields scope"
+                )));
+            }
+        }
     }
 
-    out.into_iter().flatten().collect()
+    Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn field_headers(
     field: &Field,
     index: usize,
     attributes: &FieldAttributes,
-    prefix: &str,
+    prefix: &[HeaderPart],
+    suffix: &[HeaderPart],
     trait_path: &ExprPath,
+    casing: bool,
+    limited: bool,
 ) -> TokenStream {
+    let mut parts = prefix.to_vec();
+    parts.push(field_header_part(field, attributes, index, casing));
+    parts.extend_from_slice(suffix);
+
+    let header = header_tokens(&parts);
+
     if attributes.inline {
-        let prefix = attributes
-            .inline_prefix
-            .as_ref()
-            .map_or_else(|| "", |s| s.as_str());
-        return get_type_headers(&field.ty, prefix, "", trait_path);
+        let (prefix, suffix) = inline_affixes(attributes, || {
+            field_header_part(field, attributes, index, casing)
+        });
+        let depth = inline_depth(attributes, limited);
+        let headers = get_type_headers(&field.ty, &prefix, &suffix, &[], trait_path, casing, depth);
+
+        if limited {
+            return quote!(if depth == 0 { vec![#header] } else { #headers });
+        }
+
+        return headers;
     }
 
-    let header_name = field_header_name(field, attributes, index);
-    if prefix.is_empty() {
-        quote!(vec![::std::borrow::Cow::Borrowed(#header_name)])
-    } else {
-        let name = format!("{prefix}{header_name}");
-        quote!(vec![::std::borrow::Cow::Borrowed(#name)])
+    quote!(vec![#header])
+}
+
+// A depth passed to a type of an inlined field,
+// `None` means that the type is not limited.
+fn inline_depth(attr: &FieldAttributes, limited: bool) -> Option<TokenStream> {
+    match (attr.inline_depth, limited) {
+        (None, false) => None,
+        (Some(limit), false) => {
+            let depth = limit - 1;
+            Some(quote!(#depth))
+        }
+        (None, true) => Some(quote!(depth - 1)),
+        (Some(limit), true) => Some(quote!(::std::cmp::min(depth, #limit) - 1)),
     }
 }
 
+// A prefix and a suffix for inlined headers.
+//
+// If a separator is set without a prefix, a name of a field is used as a prefix.
+fn inline_affixes(
+    attr: &FieldAttributes,
+    name: impl FnOnce() -> HeaderPart,
+) -> (Vec<HeaderPart>, Vec<HeaderPart>) {
+    let mut prefix = Vec::new();
+    if let Some(text) = &attr.inline_prefix {
+        prefix.push(HeaderPart::Text(text.clone()));
+    }
+
+    if let Some(sep) = &attr.inline_sep {
+        if attr.inline_prefix.is_none() {
+            prefix.push(name());
+        }
+
+        prefix.push(HeaderPart::Text(sep.clone()));
+    }
+
+    let suffix = attr.inline_suffix.clone().map(HeaderPart::Text);
+    let suffix = suffix.into_iter().collect();
+
+    (prefix, suffix)
+}
+
+// A part of a header name.
+//
+// A name of a field or a variant is converted at runtime by `headers_with`,
+// unless a casing is not set then a default text is used,
+// the rest is used as is.
+#[derive(Clone)]
+enum HeaderPart {
+    Text(String),
+    Cased { name: String, text: String },
+}
+
+fn header_text(parts: &[HeaderPart]) -> String {
+    parts
+        .iter()
+        .map(|part| match part {
+            HeaderPart::Text(text) | HeaderPart::Cased { text, .. } => text.as_str(),
+        })
+        .collect()
+}
+
+fn header_part_tokens(part: &HeaderPart) -> TokenStream {
+    match part {
+        HeaderPart::Text(text) => quote!(::std::string::String::from(#text)),
+        HeaderPart::Cased { name, text } => quote! {
+            match options.get_casing() {
+                Some(casing) => casing.cast(#name),
+                None => ::std::string::String::from(#text),
+            }
+        },
+    }
+}
+
+fn header_tokens(parts: &[HeaderPart]) -> TokenStream {
+    let is_static = parts.iter().all(|p| matches!(p, HeaderPart::Text(_)));
+    if is_static {
+        let header = header_text(parts);
+        return quote!(::std::borrow::Cow::Borrowed(#header));
+    }
+
+    let parts = parts.iter().map(header_part_tokens);
+    quote!(::std::borrow::Cow::Owned([#(#parts),*].concat()))
+}
+
 fn collect_info_enum(
     ast: &DataEnum,
     attrs: &TypeAttributes,
     name: &Ident,
     trait_path: &ExprPath,
+    casing: bool,
 ) -> Result<Impl, Error> {
     match &attrs.inline {
         true => {
@@ -314,7 +795,7 @@ fn collect_info_enum(
 
             collect_info_enum_inlined(ast, attrs, enum_name)
         }
-        false => _collect_info_enum(ast, attrs, trait_path),
+        false => _collect_info_enum(ast, attrs, trait_path, casing),
     }
 }
 
@@ -322,12 +803,14 @@ fn _collect_info_enum(
     ast: &DataEnum,
     attrs: &TypeAttributes,
     trait_path: &ExprPath,
+    casing: bool,
 ) -> Result<Impl, Error> {
     // reorder variants according to order (if set)
     let orderedvariants = reodered_variants(ast)?;
 
     let mut headers_list = Vec::new();
     let mut variants = Vec::new();
+    let mut variant_sizes = Vec::new();
     for v in orderedvariants {
         let mut attributes = FieldAttributes::parse(&v.attrs)?;
         merge_attributes(&mut attributes, attrs);
@@ -335,15 +818,19 @@ fn _collect_info_enum(
             continue;
         }
 
-        let info = info_from_variant(v, &attributes, attrs, trait_path)?;
+        // sizes must follow the order of the variants, otherwise offsets get misaligned
+        let size = match attributes.inline {
+            true => get_fields_length(&v.fields, trait_path)?,
+            false => quote!(1),
+        };
+
+        let info = info_from_variant(v, &attributes, attrs, trait_path, casing)?;
         variants.push((v, info.values));
         headers_list.push(info.headers);
+        variant_sizes.push(size);
     }
 
-    let variant_sizes = get_enum_variant_length(ast, trait_path)
-        .collect::<Result<Vec<_>, Error>>()?
-        .into_iter();
-    let values = values_for_enum(variant_sizes, &variants, trait_path);
+    let values = values_for_enum(variant_sizes.into_iter(), &variants, trait_path);
 
     let headers = quote! {
         [
@@ -392,18 +879,20 @@ fn info_from_variant(
     attr: &FieldAttributes,
     attrs: &TypeAttributes,
     trait_path: &ExprPath,
+    casing: bool,
 ) -> Result<Impl, Error> {
     if attr.inline {
-        let prefix = attr
-            .inline_prefix
-            .as_ref()
-            .map_or_else(|| "", |s| s.as_str());
+        let (prefix, suffix) = inline_affixes(attr, || variant_header_part(variant, attr, casing));
         return info_from_fields(
             &variant.fields,
             attrs,
             variant_field_name,
-            prefix,
+            &prefix,
+            &suffix,
+            quote!(offset),
             trait_path,
+            casing,
+            false,
         );
     }
 
@@ -423,12 +912,16 @@ fn info_from_variant(
             },
         };
 
+        let context_path = create_field_context_path(trait_path);
+        let context = quote!(#context_path::new(#variant_name, offset));
+        let args = add_context_arg(args, attr, &context);
+
         let result = match args {
             Some(args) => use_function(&args, func),
             None => use_function_no_args(func),
         };
 
-        quote! { ::std::borrow::Cow::from(format!("{}", #result)) }
+        use_display_with_result(&result, attr)
     } else if let Some(custom_format) = &attr.format {
         let args = match &attr.format_with_args {
             None => None,
@@ -455,8 +948,8 @@ fn info_from_variant(
         quote! { ::std::borrow::Cow::Borrowed(#default_value) }
     };
 
-    // we need exactly string because of it must be inlined as string
-    let headers = quote! { vec![::std::borrow::Cow::Borrowed(#variant_name)] };
+    let header = header_tokens(&[variant_header_part(variant, attr, casing)]);
+    let headers = quote! { vec![#header] };
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![#value] };
 
@@ -470,21 +963,43 @@ struct Impl {
 
 fn get_type_headers(
     field_type: &Type,
-    inline_prefix: &str,
-    prefix: &str,
+    inline_prefix: &[HeaderPart],
+    inline_suffix: &[HeaderPart],
+    prefix: &[HeaderPart],
     tabled_trait: &ExprPath,
+    casing: bool,
+    depth: Option<TokenStream>,
 ) -> TokenStream {
-    if prefix.is_empty() && inline_prefix.is_empty() {
-        quote! { <#field_type as #tabled_trait>::headers() }
-    } else {
-        quote! {
-            <#field_type as #tabled_trait>::headers().into_iter()
-                .map(|header| {
-                    let header = format!("{}{}{}", #prefix, #inline_prefix, header);
-                    ::std::borrow::Cow::Owned(header)
-                })
-                .collect::<Vec<_>>()
-        }
+    let options_path = create_crate_item_path(tabled_trait, "HeadersOptions");
+    let options = match (depth, casing) {
+        (Some(depth), true) => Some(quote! { options.depth(#depth) }),
+        (Some(depth), false) => Some(quote! { #options_path::new().depth(#depth) }),
+        (None, true) => Some(quote! { options.depth(usize::MAX) }),
+        (None, false) => None,
+    };
+    let headers = match options {
+        Some(options) => quote! { <#field_type as #tabled_trait>::headers_with(#options) },
+        None => quote! { <#field_type as #tabled_trait>::headers() },
+    };
+
+    if prefix.is_empty() && inline_prefix.is_empty() && inline_suffix.is_empty() {
+        return headers;
+    }
+
+    let prefix = prefix
+        .iter()
+        .chain(inline_prefix)
+        .map(header_part_tokens)
+        .collect::<Vec<_>>();
+    let suffix = inline_suffix.iter().map(header_part_tokens);
+
+    quote! {
+        #headers.into_iter()
+            .map(|header| {
+                let header = [#(#prefix,)* header.into_owned(), #(#suffix),*].concat();
+                ::std::borrow::Cow::Owned(header)
+            })
+            .collect::<Vec<_>>()
     }
 }
 
@@ -493,14 +1008,56 @@ fn get_field_fields(
     attr: &FieldAttributes,
     fields: &Fields,
     field_name: FieldNameFn,
+    context: &TokenStream,
+    limited: bool,
 ) -> TokenStream {
     if attr.inline {
-        return quote! { #field.fields() };
+        return get_inline_field_fields(field, attr, limited);
     }
 
+    get_field_value(field, attr, fields, field_name, context)
+}
+
+// An inlined field deeper than a limit is put into a single cell,
+// which joins all its fields.
+//
+// A `display_with` and `format` attributes are not used as they're ignored for inlined fields.
+fn get_inline_field_fields(
+    field: &TokenStream,
+    attr: &FieldAttributes,
+    limited: bool,
+) -> TokenStream {
+    let values = match inline_depth(attr, limited) {
+        Some(depth) => quote! { #field.fields_limited(#depth) },
+        None => quote! { #field.fields() },
+    };
+
+    if !limited {
+        return values;
+    }
+
+    quote! {
+        if depth == 0 {
+            vec![::std::borrow::Cow::Owned(#field.fields().join(", "))]
+        } else {
+            #values
+        }
+    }
+}
+
+fn get_field_value(
+    field: &TokenStream,
+    attr: &FieldAttributes,
+    fields: &Fields,
+    field_name: FieldNameFn,
+    context: &TokenStream,
+) -> TokenStream {
     if let Some(func) = &attr.display_with {
         let args = match &attr.display_with_args {
-            None => Some(quote!(&#field)),
+            // a struct field is a place while a variant field is bound by reference,
+            // a method call resolves a value in both cases
+            None if attr.display_with_by_value => Some(quote!((#field).clone(),)),
+            None => Some(quote!(&#field,)),
             Some(args) => match args.is_empty() {
                 true => None,
                 false => {
@@ -512,13 +1069,16 @@ fn get_field_fields(
                 }
             },
         };
+        let args = add_context_arg(args, attr, context);
 
         let result = match args {
             Some(args) => use_function(&args, func),
             None => use_function_no_args(func),
         };
 
-        return quote!(vec![::std::borrow::Cow::from(format!("{}", #result))]);
+        let value = use_display_with_result(&result, attr);
+
+        return quote!(vec![#value]);
     } else if let Some(custom_format) = &attr.format {
         let args = match &attr.format_with_args {
             None => None,
@@ -545,6 +1105,33 @@ fn get_field_fields(
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
+// A `borrow` flag means a function returns a string reference,
+// which can be used as is without an allocation.
+fn use_display_with_result(result: &TokenStream, attr: &FieldAttributes) -> TokenStream {
+    if attr.display_with_borrow {
+        quote! { ::std::borrow::Cow::<str>::Borrowed(#result) }
+    } else {
+        quote! { ::std::borrow::Cow::from(format!("{}", #result)) }
+    }
+}
+
+// A context goes after all other arguments,
+// so the arguments are expected to end with a comma.
+fn add_context_arg(
+    args: Option<TokenStream>,
+    attr: &FieldAttributes,
+    context: &TokenStream,
+) -> Option<TokenStream> {
+    if !attr.display_with_context {
+        return args;
+    }
+
+    match args {
+        Some(args) => Some(quote!(#args #context)),
+        None => Some(context.clone()),
+    }
+}
+
 fn use_function(args: &TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
@@ -698,6 +1285,27 @@ fn variant_name(variant: &Variant, attributes: &FieldAttributes) -> String {
         .unwrap_or_else(|| variant.ident.to_string())
 }
 
+fn variant_header_part(variant: &Variant, attr: &FieldAttributes, casing: bool) -> HeaderPart {
+    let text = variant_name(variant, attr);
+    if casing && attr.rename.is_none() {
+        let name = variant.ident.to_string();
+        return HeaderPart::Cased { name, text };
+    }
+
+    HeaderPart::Text(text)
+}
+
+fn field_header_part(f: &Field, attr: &FieldAttributes, index: usize, casing: bool) -> HeaderPart {
+    let text = field_header_name(f, attr, index);
+    match &f.ident {
+        Some(name) if casing && attr.rename.is_none() => HeaderPart::Cased {
+            name: name.to_string(),
+            text,
+        },
+        _ => HeaderPart::Text(text),
+    }
+}
+
 fn field_header_name(f: &Field, attr: &FieldAttributes, index: usize) -> String {
     if let Some(name) = &attr.rename {
         return name.to_string();
@@ -807,34 +1415,18 @@ fn fnarg_tokens(arg: &FormatArg, fields: &Fields, field_name: FieldNameFn) -> To
 }
 
 fn reodered_variants(ast: &DataEnum) -> Result<Vec<&Variant>, Error> {
-    let mut reorder = HashMap::new();
-    let mut skip = 0;
-    let count = ast.variants.len();
-    for (i, attr) in ast
+    let orders = ast
         .variants
         .iter()
         .map(|v| FieldAttributes::parse(&v.attrs).unwrap_or_default())
-        .enumerate()
-    {
-        if attr.is_ignored {
-            skip += 1;
-            continue;
-        }
-
-        if let Some(order) = attr.order {
-            if order >= count {
-                return Err(Error::message(format!(
-                    "An order index '{order}' is out of fields scope"
-                )));
-            }
+        .map(|attr| if attr.is_ignored { None } else { attr.order })
+        .collect::<Vec<_>>();
 
-            reorder.insert(order, i - skip);
-        }
-    }
+    check_order_scope(&orders)?;
 
     let mut orderedvariants = ast.variants.iter().collect::<Vec<_>>();
-    if !reorder.is_empty() {
-        orderedvariants = reorder_fields(&reorder, &orderedvariants);
+    if orders.iter().any(Option::is_some) {
+        orderedvariants = reorder_fields(&orders, &orderedvariants);
     }
 
     Ok(orderedvariants)
@@ -860,3 +1452,17 @@ fn create_tabled_trait_path(mut p: ExprPath) -> ExprPath {
     });
     p
 }
+
+fn create_field_context_path(trait_path: &ExprPath) -> ExprPath {
+    create_crate_item_path(trait_path, "FieldContext")
+}
+
+fn create_crate_item_path(trait_path: &ExprPath, item: &str) -> ExprPath {
+    let mut p = trait_path.clone();
+    let _ = p.path.segments.pop();
+    p.path.segments.push(PathSegment {
+        ident: Ident::new(item, proc_macro2::Span::call_site()),
+        arguments: syn::PathArguments::None,
+    });
+    p
+}
@@ -27,11 +27,19 @@ impl FieldAttr {
 #[derive(Clone)]
 pub enum FieldAttrKind {
     Skip(LitBool),
-    Inline(LitBool, Option<LitStr>),
+    Inline(
+        LitBool,
+        Option<LitStr>,
+        Option<LitStr>,
+        Option<LitStr>,
+        Option<LitInt>,
+    ),
     Rename(LitStr),
     RenameAll(LitStr),
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
+    OrderRelative(LitStr),
+    ByValue(LitBool),
     FormatWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
 }
 
@@ -55,6 +63,7 @@ impl Parse for FieldAttr {
                         return Ok(Self::new(DisplayWith(lit, None, Punctuated::new())))
                     }
                     "format" => return Ok(Self::new(FormatWith(lit, None, Punctuated::new()))),
+                    "order" => return Ok(Self::new(OrderRelative(lit))),
                     _ => {}
                 }
             }
@@ -64,7 +73,8 @@ impl Parse for FieldAttr {
 
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(Skip(lit))),
-                    "inline" => return Ok(Self::new(Inline(lit, None))),
+                    "inline" => return Ok(Self::new(Inline(lit, None, None, None, None))),
+                    "by_value" => return Ok(Self::new(ByValue(lit))),
                     _ => {}
                 }
             }
@@ -117,12 +127,19 @@ impl Parse for FieldAttr {
                         return Ok(Self::new(Inline(
                             LitBool::new(true, Span::call_site()),
                             Some(lit),
+                            None,
+                            None,
+                            None,
                         )))
                     }
                     _ => {}
                 }
             }
 
+            if nested.peek(syn::Ident) && name_str.as_str() == "inline" {
+                return parse_inline_args(&nested);
+            }
+
             return Err(syn::Error::new(
                 _paren.span.span(),
                 "expected a `string literal` in parenthesis",
@@ -135,8 +152,12 @@ impl Parse for FieldAttr {
                 return Ok(Self::new(Inline(
                     LitBool::new(true, Span::call_site()),
                     None,
+                    None,
+                    None,
+                    None,
                 )))
             }
+            "by_value" => return Ok(Self::new(ByValue(LitBool::new(true, name.span())))),
             _ => {}
         }
 
@@ -146,3 +167,42 @@ impl Parse for FieldAttr {
         ))
     }
 }
+
+fn parse_inline_args(input: syn::parse::ParseStream) -> syn::Result<FieldAttr> {
+    let mut prefix = None;
+    let mut suffix = None;
+    let mut sep = None;
+    let mut depth = None;
+
+    while !input.is_empty() {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+
+        match name.to_string().as_str() {
+            "prefix" => prefix = Some(input.parse::<LitStr>()?),
+            "suffix" => suffix = Some(input.parse::<LitStr>()?),
+            "sep" => sep = Some(input.parse::<LitStr>()?),
+            "depth" => depth = Some(input.parse::<LitInt>()?),
+            name_str => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                    "unexpected inline argument: {name_str}; expected `prefix`, `suffix`, `sep` or `depth`"
+                ),
+                ))
+            }
+        }
+
+        if input.is_empty() {
+            break;
+        }
+
+        input.parse::<Token![,]>()?;
+    }
+
+    let lit = LitBool::new(true, Span::call_site());
+
+    Ok(FieldAttr::new(FieldAttrKind::Inline(
+        lit, prefix, suffix, sep, depth,
+    )))
+}
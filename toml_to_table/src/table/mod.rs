@@ -33,9 +33,19 @@ struct Settings {
     cfg: CompactMultilineConfig,
     plain: bool,
     object_orientation: Orientation,
+    root_object_orientation: Option<Orientation>,
     array_orientation: Orientation,
 }
 
+impl Settings {
+    fn get_map_orientation(&self, is_root: bool) -> Orientation {
+        match (is_root, self.root_object_orientation) {
+            (true, Some(orientation)) => orientation,
+            _ => self.object_orientation,
+        }
+    }
+}
+
 impl<V> TomlTable<V>
 where
     V: Borrow<Value>,
@@ -49,6 +59,7 @@ where
                 cfg: configure_grid(),
                 array_orientation: Orientation::Column,
                 object_orientation: Orientation::Column,
+                root_object_orientation: None,
             },
         }
     }
@@ -65,6 +76,14 @@ where
         self
     }
 
+    /// Set a table mode for a root [`Value::Table`].
+    ///
+    /// Nested tables are still rendered according to [`TomlTable::map_orientation`].
+    pub fn root_map_orientation(&mut self, mode: Orientation) -> &mut Self {
+        self.settings.root_object_orientation = Some(mode);
+        self
+    }
+
     /// Set a table mode for a [`Value::Array`].
     pub fn seq_orientation(&mut self, mode: Orientation) -> &mut Self {
         self.settings.array_orientation = mode;
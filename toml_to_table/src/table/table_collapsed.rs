@@ -1,5 +1,3 @@
-use std::iter::repeat;
-
 use tabled::{
     grid::{
         config::{AlignmentHorizontal, AlignmentVertical},
@@ -32,7 +30,10 @@ pub(super) fn table(value: &Value, cfg: &Settings) -> String {
         alignment_horizontal: cfg.cfg.get_alignment_horizontal(),
         alignment_vertical: cfg.cfg.get_alignment_vertical(),
     };
-    let value = convert_value_to_table_value(value, ctx);
+    let value = match value {
+        Value::Table(map) => convert_map(map, cfg.get_map_orientation(true), ctx),
+        value => convert_value_to_table_value(value, ctx),
+    };
 
     PoolTable::from(value)
         .with(cfg.cfg)
@@ -45,10 +46,7 @@ pub(super) fn table(value: &Value, cfg: &Settings) -> String {
 
 fn convert_value_to_table_value(value: &Value, ctx: CollapseCtx) -> TableValue {
     match value {
-        Value::Table(map) => match ctx.map_orientation {
-            Orientation::Row => convert_map_to_row(map, ctx),
-            Orientation::Column => convert_map_to_column(map, ctx),
-        },
+        Value::Table(map) => convert_map(map, ctx.map_orientation, ctx),
         Value::Array(list) => convert_list(list, ctx),
         Value::Boolean(boolean) => TableValue::Cell(boolean.to_string()),
         Value::Float(float) => TableValue::Cell(float.to_string()),
@@ -58,6 +56,13 @@ fn convert_value_to_table_value(value: &Value, ctx: CollapseCtx) -> TableValue {
     }
 }
 
+fn convert_map(map: &TomlMap, orientation: Orientation, ctx: CollapseCtx) -> TableValue {
+    match orientation {
+        Orientation::Row => convert_map_to_row(map, ctx),
+        Orientation::Column => convert_map_to_column(map, ctx),
+    }
+}
+
 fn convert_list(list: &[Value], ctx: CollapseCtx) -> TableValue {
     let list = list
         .iter()
@@ -136,9 +141,9 @@ fn increase_string_width(text: &str, by: usize, ah: AlignmentHorizontal) -> Stri
         let (left, right) = indent_horizontal(ah, width + by, width);
 
         let mut buf = String::new();
-        buf.extend(repeat(' ').take(left));
+        buf.extend(std::iter::repeat_n(' ', left));
         buf.push_str(&line);
-        buf.extend(repeat(' ').take(right));
+        buf.extend(std::iter::repeat_n(' ', right));
 
         out.push(buf);
     }
@@ -153,13 +158,13 @@ fn increase_string_height(text: &str, by: usize, av: AlignmentVertical) -> Strin
 
     let (top, bottom) = indent_vertical(av, count_lines + by, count_lines);
 
-    out.extend(repeat(String::new()).take(top));
+    out.extend(std::iter::repeat_n(String::new(), top));
 
     for line in get_lines(text) {
         out.push(line.into_owned());
     }
 
-    out.extend(repeat(String::new()).take(bottom));
+    out.extend(std::iter::repeat_n(String::new(), bottom));
 
     out.join("\n")
 }
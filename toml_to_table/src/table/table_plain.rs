@@ -17,7 +17,7 @@ fn _plain_table(value: &Value, cfg: &Settings, outer: bool) -> String {
             Orientation::Column => seq_column_table(arr, cfg, config),
             Orientation::Row => seq_row_table(arr, cfg, config),
         },
-        Value::Table(map) => match cfg.object_orientation {
+        Value::Table(map) => match cfg.get_map_orientation(outer) {
             Orientation::Column => map_column_table(map, cfg, config),
             Orientation::Row => map_row_table(map, cfg, config),
         },
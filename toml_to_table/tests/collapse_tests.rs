@@ -473,6 +473,25 @@ test_table!(
     "+---------------------------+"
 );
 
+test_table!(
+    test_root_map_orientation,
+    TomlTable::new(Value::Table(TomlMap::from_iter([
+        (String::from("a"), Value::Integer(1)),
+        (
+            String::from("b"),
+            Value::Table(TomlMap::from_iter([(String::from("c"), Value::Integer(2))])),
+        ),
+    ])))
+        .collapse()
+        .root_map_orientation(Orientation::Row)
+        .to_string(),
+    "+---+-------+"
+    "| a | b     |"
+    "+---+---+---+"
+    "| 1 | c | 2 |"
+    "+---+---+---+"
+);
+
 fn toml_table(value: Value) -> String {
     TomlTable::new(value).collapse().to_string()
 }